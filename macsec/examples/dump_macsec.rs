@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::TryStreamExt;
+
+fn main() {
+    let rt = tokio::runtime::Builder::new_current_thread()
+        .enable_io()
+        .build()
+        .unwrap();
+    rt.block_on(get_macsec());
+}
+
+async fn get_macsec() {
+    let (connection, handle, _) = macsec::new_connection().unwrap();
+    tokio::spawn(connection);
+
+    let mut macsec_handle = handle.get().execute().await;
+
+    let mut msgs = Vec::new();
+    while let Some(msg) = macsec_handle.try_next().await.unwrap() {
+        msgs.push(msg);
+    }
+    for msg in msgs {
+        println!("{:?}", msg);
+    }
+}
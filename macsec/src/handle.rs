@@ -0,0 +1,115 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{future::Either, FutureExt, Stream, StreamExt, TryStream};
+use genetlink::GenetlinkHandle;
+use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_ACK, NLM_F_DUMP, NLM_F_REQUEST};
+use netlink_packet_generic::GenlMessage;
+use netlink_packet_utils::DecodeError;
+
+use crate::{
+    try_macsec,
+    MacSecCmd,
+    MacSecError,
+    MacSecGetRequest,
+    MacSecMessage,
+    MacSecRxSaHandle,
+    MacSecRxScHandle,
+    MacSecTxSaHandle,
+};
+
+#[derive(Clone, Debug)]
+pub struct MacSecHandle {
+    pub handle: GenetlinkHandle,
+}
+
+impl MacSecHandle {
+    pub(crate) fn new(handle: GenetlinkHandle) -> Self {
+        MacSecHandle { handle }
+    }
+
+    /// Retrieve the MACsec links with their secure channels and associations
+    /// (equivalent to `ip macsec show`)
+    pub fn get(&self) -> MacSecGetRequest {
+        MacSecGetRequest::new(self.clone())
+    }
+
+    // equivalent to `ip macsec add|del DEV rx sci SCI` command
+    pub fn rxsc(&self) -> MacSecRxScHandle {
+        MacSecRxScHandle::new(self.clone())
+    }
+
+    // equivalent to `ip macsec add|del DEV tx sa AN` command
+    pub fn txsa(&self) -> MacSecTxSaHandle {
+        MacSecTxSaHandle::new(self.clone())
+    }
+
+    // equivalent to `ip macsec add|del DEV rx sci SCI sa AN` command
+    pub fn rxsa(&self) -> MacSecRxSaHandle {
+        MacSecRxSaHandle::new(self.clone())
+    }
+
+    pub async fn request(
+        &mut self,
+        message: NetlinkMessage<GenlMessage<MacSecMessage>>,
+    ) -> Result<
+        impl Stream<Item = Result<NetlinkMessage<GenlMessage<MacSecMessage>>, DecodeError>>,
+        MacSecError,
+    > {
+        self.handle
+            .request(message)
+            .await
+            .map_err(|e| MacSecError::RequestFailed(format!("BUG: Request failed with {}", e)))
+    }
+}
+
+pub(crate) async fn macsec_execute(
+    handle: &mut MacSecHandle,
+    macsec_msg: MacSecMessage,
+) -> impl TryStream<Ok = GenlMessage<MacSecMessage>, Error = MacSecError> {
+    let nl_header_flags = match macsec_msg.cmd {
+        MacSecCmd::GetTxSc => NLM_F_REQUEST | NLM_F_DUMP,
+        _ => NLM_F_REQUEST,
+    };
+
+    let mut nl_msg = NetlinkMessage::from(GenlMessage::from_payload(macsec_msg));
+
+    nl_msg.header.flags = nl_header_flags;
+
+    match handle.request(nl_msg).await {
+        Ok(response) => Either::Left(response.map(move |msg| Ok(try_macsec!(msg)))),
+        Err(e) => Either::Right(
+            futures::future::err::<GenlMessage<MacSecMessage>, MacSecError>(e).into_stream(),
+        ),
+    }
+}
+
+/// Send a request which the kernel only acknowledges, such as adding or deleting a secure
+/// channel or association.
+pub(crate) async fn macsec_execute_ack(
+    handle: &mut MacSecHandle,
+    macsec_msg: MacSecMessage,
+) -> Result<(), MacSecError> {
+    let mut nl_msg = NetlinkMessage::from(GenlMessage::from_payload(macsec_msg));
+
+    nl_msg.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+    let mut response = handle.request(nl_msg).await?;
+    while let Some(msg) = response.next().await {
+        match msg {
+            Ok(msg) => {
+                let (header, payload) = msg.into_parts();
+                match payload {
+                    NetlinkPayload::Error(err) => return Err(MacSecError::NetlinkError(err)),
+                    NetlinkPayload::Ack(_) => {}
+                    _ => {
+                        return Err(MacSecError::UnexpectedMessage(NetlinkMessage::new(
+                            header, payload,
+                        )))
+                    }
+                }
+            }
+            Err(e) => return Err(MacSecError::Bug(format!("BUG: decode error {:?}", e))),
+        }
+    }
+    Ok(())
+}
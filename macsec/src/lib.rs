@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT
+
+mod connection;
+mod error;
+mod handle;
+mod macros;
+mod message;
+mod nested;
+mod offload;
+mod rxsa;
+mod rxsc;
+mod sa;
+mod secy;
+mod txsa;
+
+#[cfg(feature = "tokio_socket")]
+pub use connection::new_connection;
+pub use connection::new_connection_with_socket;
+pub use error::MacSecError;
+pub use handle::MacSecHandle;
+pub use message::{MacSecAttr, MacSecCmd, MacSecMessage};
+pub use offload::MacSecOffloadAttr;
+pub use rxsa::{MacSecRxSaAddRequest, MacSecRxSaDelRequest, MacSecRxSaHandle};
+pub use rxsc::{MacSecRxScAddRequest, MacSecRxScAttr, MacSecRxScDelRequest, MacSecRxScHandle};
+pub use sa::{MacSecSaAttr, MACSEC_KEYID_LEN, MACSEC_SALT_LEN};
+pub use secy::{MacSecGetRequest, MacSecSecYAttr};
+pub use txsa::{MacSecTxSaAddRequest, MacSecTxSaDelRequest, MacSecTxSaHandle};
+
+pub(crate) use handle::{macsec_execute, macsec_execute_ack};
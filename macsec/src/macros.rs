@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+#[macro_export]
+macro_rules! try_macsec {
+    ($msg: expr) => {{
+        use netlink_packet_core::{NetlinkMessage, NetlinkPayload};
+        use $crate::MacSecError;
+
+        match $msg {
+            Ok(msg) => {
+                let (header, payload) = msg.into_parts();
+                match payload {
+                    NetlinkPayload::InnerMessage(msg) => msg,
+                    NetlinkPayload::Error(err) => return Err(MacSecError::NetlinkError(err)),
+                    _ => {
+                        return Err(MacSecError::UnexpectedMessage(NetlinkMessage::new(
+                            header, payload,
+                        )))
+                    }
+                }
+            }
+            Err(e) => return Err(MacSecError::Bug(format!("BUG: decode error {:?}", e))),
+        }
+    }};
+}
@@ -0,0 +1,329 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+use netlink_packet_core::DecodeError;
+use netlink_packet_generic::{GenlFamily, GenlHeader};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlasIterator},
+    parsers::parse_u32,
+    Emitable,
+    Parseable,
+    ParseableParametrized,
+};
+
+use crate::{
+    nested::{emit_nested_list, nested_list_len, parse_nested, parse_nested_list},
+    MacSecOffloadAttr,
+    MacSecRxScAttr,
+    MacSecSaAttr,
+    MacSecSecYAttr,
+};
+
+const MACSEC_CMD_GET_TXSC: u8 = 0;
+const MACSEC_CMD_ADD_RXSC: u8 = 1;
+const MACSEC_CMD_DEL_RXSC: u8 = 2;
+const MACSEC_CMD_UPD_RXSC: u8 = 3;
+const MACSEC_CMD_ADD_TXSA: u8 = 4;
+const MACSEC_CMD_DEL_TXSA: u8 = 5;
+const MACSEC_CMD_UPD_TXSA: u8 = 6;
+const MACSEC_CMD_ADD_RXSA: u8 = 7;
+const MACSEC_CMD_DEL_RXSA: u8 = 8;
+const MACSEC_CMD_UPD_RXSA: u8 = 9;
+const MACSEC_CMD_UPD_OFFLOAD: u8 = 10;
+
+const MACSEC_ATTR_IFINDEX: u16 = 1;
+const MACSEC_ATTR_RXSC_CONFIG: u16 = 2;
+const MACSEC_ATTR_SA_CONFIG: u16 = 3;
+const MACSEC_ATTR_SECY: u16 = 4;
+const MACSEC_ATTR_TXSA_LIST: u16 = 5;
+const MACSEC_ATTR_RXSC_LIST: u16 = 6;
+// const MACSEC_ATTR_TXSC_STATS: u16 = 7;
+// const MACSEC_ATTR_SECY_STATS: u16 = 8;
+const MACSEC_ATTR_OFFLOAD: u16 = 9;
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MacSecCmd {
+    /// Dump the MACsec links with their secure channels and associations
+    GetTxSc,
+    AddRxSc,
+    DelRxSc,
+    UpdRxSc,
+    AddTxSa,
+    DelTxSa,
+    UpdTxSa,
+    AddRxSa,
+    DelRxSa,
+    UpdRxSa,
+    UpdOffload,
+}
+
+impl From<MacSecCmd> for u8 {
+    fn from(cmd: MacSecCmd) -> Self {
+        match cmd {
+            MacSecCmd::GetTxSc => MACSEC_CMD_GET_TXSC,
+            MacSecCmd::AddRxSc => MACSEC_CMD_ADD_RXSC,
+            MacSecCmd::DelRxSc => MACSEC_CMD_DEL_RXSC,
+            MacSecCmd::UpdRxSc => MACSEC_CMD_UPD_RXSC,
+            MacSecCmd::AddTxSa => MACSEC_CMD_ADD_TXSA,
+            MacSecCmd::DelTxSa => MACSEC_CMD_DEL_TXSA,
+            MacSecCmd::UpdTxSa => MACSEC_CMD_UPD_TXSA,
+            MacSecCmd::AddRxSa => MACSEC_CMD_ADD_RXSA,
+            MacSecCmd::DelRxSa => MACSEC_CMD_DEL_RXSA,
+            MacSecCmd::UpdRxSa => MACSEC_CMD_UPD_RXSA,
+            MacSecCmd::UpdOffload => MACSEC_CMD_UPD_OFFLOAD,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MacSecAttr {
+    /// Index of the MACsec link
+    IfIndex(u32),
+    /// Receive secure channel added, deleted or holding the association of the request
+    RxScConfig(Vec<MacSecRxScAttr>),
+    /// Secure association of the request
+    SaConfig(Vec<MacSecSaAttr>),
+    SecY(Vec<MacSecSecYAttr>),
+    /// Transmit secure associations, as dumped by the kernel
+    TxSaList(Vec<Vec<MacSecSaAttr>>),
+    /// Receive secure channels, as dumped by the kernel
+    RxScList(Vec<Vec<MacSecRxScAttr>>),
+    /// Hardware offload of the MACsec link
+    Offload(Vec<MacSecOffloadAttr>),
+    Other(DefaultNla),
+}
+
+impl Nla for MacSecAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::IfIndex(_) => 4,
+            Self::RxScConfig(nlas) => nlas.as_slice().buffer_len(),
+            Self::SaConfig(nlas) => nlas.as_slice().buffer_len(),
+            Self::SecY(nlas) => nlas.as_slice().buffer_len(),
+            Self::TxSaList(list) => nested_list_len(list),
+            Self::RxScList(list) => nested_list_len(list),
+            Self::Offload(nlas) => nlas.as_slice().buffer_len(),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::IfIndex(_) => MACSEC_ATTR_IFINDEX,
+            Self::RxScConfig(_) => MACSEC_ATTR_RXSC_CONFIG,
+            Self::SaConfig(_) => MACSEC_ATTR_SA_CONFIG,
+            Self::SecY(_) => MACSEC_ATTR_SECY,
+            Self::TxSaList(_) => MACSEC_ATTR_TXSA_LIST,
+            Self::RxScList(_) => MACSEC_ATTR_RXSC_LIST,
+            Self::Offload(_) => MACSEC_ATTR_OFFLOAD,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::IfIndex(d) => NativeEndian::write_u32(buffer, *d),
+            Self::RxScConfig(nlas) => nlas.as_slice().emit(buffer),
+            Self::SaConfig(nlas) => nlas.as_slice().emit(buffer),
+            Self::SecY(nlas) => nlas.as_slice().emit(buffer),
+            Self::TxSaList(list) => emit_nested_list(list, buffer),
+            Self::RxScList(list) => emit_nested_list(list, buffer),
+            Self::Offload(nlas) => nlas.as_slice().emit(buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MacSecMessage {
+    pub cmd: MacSecCmd,
+    pub nlas: Vec<MacSecAttr>,
+}
+
+impl GenlFamily for MacSecMessage {
+    fn family_name() -> &'static str {
+        "macsec"
+    }
+
+    fn version(&self) -> u8 {
+        1
+    }
+
+    fn command(&self) -> u8 {
+        self.cmd.into()
+    }
+}
+
+impl MacSecMessage {
+    pub fn new_get() -> Self {
+        MacSecMessage {
+            cmd: MacSecCmd::GetTxSc,
+            nlas: vec![],
+        }
+    }
+}
+
+impl Emitable for MacSecMessage {
+    fn buffer_len(&self) -> usize {
+        self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.nlas.as_slice().emit(buffer)
+    }
+}
+
+fn parse_nlas(buffer: &[u8]) -> Result<Vec<MacSecAttr>, DecodeError> {
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(buffer) {
+        let error_msg = format!("Failed to parse macsec message attribute {:?}", nla);
+        let nla = &nla.context(error_msg)?;
+        let payload = nla.value();
+        nlas.push(match nla.kind() {
+            MACSEC_ATTR_IFINDEX => MacSecAttr::IfIndex(
+                parse_u32(payload).context("Failed to parse MACSEC_ATTR_IFINDEX")?,
+            ),
+            MACSEC_ATTR_RXSC_CONFIG => MacSecAttr::RxScConfig(parse_nested(
+                payload,
+                "Failed to parse MACSEC_ATTR_RXSC_CONFIG",
+            )?),
+            MACSEC_ATTR_SA_CONFIG => MacSecAttr::SaConfig(parse_nested(
+                payload,
+                "Failed to parse MACSEC_ATTR_SA_CONFIG",
+            )?),
+            MACSEC_ATTR_SECY => {
+                MacSecAttr::SecY(parse_nested(payload, "Failed to parse MACSEC_ATTR_SECY")?)
+            }
+            MACSEC_ATTR_TXSA_LIST => MacSecAttr::TxSaList(parse_nested_list(
+                payload,
+                "Failed to parse MACSEC_ATTR_TXSA_LIST",
+            )?),
+            MACSEC_ATTR_RXSC_LIST => MacSecAttr::RxScList(parse_nested_list(
+                payload,
+                "Failed to parse MACSEC_ATTR_RXSC_LIST",
+            )?),
+            MACSEC_ATTR_OFFLOAD => MacSecAttr::Offload(parse_nested(
+                payload,
+                "Failed to parse MACSEC_ATTR_OFFLOAD",
+            )?),
+            _ => MacSecAttr::Other(DefaultNla::parse(nla).context("invalid NLA (unknown kind)")?),
+        });
+    }
+    Ok(nlas)
+}
+
+impl ParseableParametrized<[u8], GenlHeader> for MacSecMessage {
+    fn parse_with_param(buffer: &[u8], header: GenlHeader) -> Result<Self, DecodeError> {
+        let cmd = match header.cmd {
+            MACSEC_CMD_GET_TXSC => MacSecCmd::GetTxSc,
+            MACSEC_CMD_ADD_RXSC => MacSecCmd::AddRxSc,
+            MACSEC_CMD_DEL_RXSC => MacSecCmd::DelRxSc,
+            MACSEC_CMD_UPD_RXSC => MacSecCmd::UpdRxSc,
+            MACSEC_CMD_ADD_TXSA => MacSecCmd::AddTxSa,
+            MACSEC_CMD_DEL_TXSA => MacSecCmd::DelTxSa,
+            MACSEC_CMD_UPD_TXSA => MacSecCmd::UpdTxSa,
+            MACSEC_CMD_ADD_RXSA => MacSecCmd::AddRxSa,
+            MACSEC_CMD_DEL_RXSA => MacSecCmd::DelRxSa,
+            MACSEC_CMD_UPD_RXSA => MacSecCmd::UpdRxSa,
+            MACSEC_CMD_UPD_OFFLOAD => MacSecCmd::UpdOffload,
+            cmd => {
+                return Err(DecodeError::from(format!(
+                    "Unsupported macsec reply command: {}",
+                    cmd
+                )))
+            }
+        };
+        Ok(Self {
+            cmd,
+            nlas: parse_nlas(buffer)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use netlink_packet_core::{NetlinkMessage, NetlinkPayload, NLM_F_MULTIPART};
+    use netlink_packet_generic::GenlMessage;
+
+    use super::*;
+    use crate::MACSEC_KEYID_LEN;
+
+    #[rustfmt::skip]
+    static DEL_RXSC: [u8; 24] = [
+        0x08, 0x00, // length
+        0x01, 0x00, // type = MACSEC_ATTR_IFINDEX
+        0x07, 0x00, 0x00, 0x00, // 7
+        0x10, 0x00, // length
+        0x02, 0x00, // type = MACSEC_ATTR_RXSC_CONFIG
+            0x0c, 0x00, // length
+            0x01, 0x00, // type = MACSEC_RXSC_ATTR_SCI
+            0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x00, 0x01, // 52:54:00:12:34:56 port 1
+    ];
+
+    #[test]
+    fn emit_del_rxsc() {
+        let msg = MacSecMessage {
+            cmd: MacSecCmd::DelRxSc,
+            nlas: vec![
+                MacSecAttr::IfIndex(7),
+                MacSecAttr::RxScConfig(vec![MacSecRxScAttr::Sci(0x5254_0012_3456_0001)]),
+            ],
+        };
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &DEL_RXSC[..]);
+    }
+
+    #[test]
+    fn serialize_then_deserialize_dump() {
+        let msg = MacSecMessage {
+            cmd: MacSecCmd::GetTxSc,
+            nlas: vec![
+                MacSecAttr::IfIndex(7),
+                MacSecAttr::SecY(vec![
+                    MacSecSecYAttr::Sci(0x5254_0012_3456_0001),
+                    MacSecSecYAttr::CipherSuite(0x0080_c200_0100_0001),
+                    MacSecSecYAttr::IcvLen(16),
+                    MacSecSecYAttr::Encrypt(1),
+                ]),
+                MacSecAttr::TxSaList(vec![
+                    vec![
+                        MacSecSaAttr::An(0),
+                        MacSecSaAttr::Active(1),
+                        MacSecSaAttr::Pn(1),
+                        MacSecSaAttr::KeyId([0x01; MACSEC_KEYID_LEN]),
+                    ],
+                    vec![
+                        MacSecSaAttr::An(1),
+                        MacSecSaAttr::Active(0),
+                        MacSecSaAttr::Pn(100),
+                        MacSecSaAttr::KeyId([0x02; MACSEC_KEYID_LEN]),
+                    ],
+                ]),
+                MacSecAttr::RxScList(vec![vec![
+                    MacSecRxScAttr::Sci(0x5254_00ab_cdef_0001),
+                    MacSecRxScAttr::Active(1),
+                    MacSecRxScAttr::SaList(vec![vec![
+                        MacSecSaAttr::An(0),
+                        MacSecSaAttr::Active(1),
+                        MacSecSaAttr::Pn(1),
+                        MacSecSaAttr::KeyId([0x03; MACSEC_KEYID_LEN]),
+                    ]]),
+                ]]),
+                MacSecAttr::Offload(vec![MacSecOffloadAttr::Type(0)]),
+            ],
+        };
+        let mut nl_msg = NetlinkMessage::from(GenlMessage::from_payload(msg.clone()));
+        nl_msg.header.flags = NLM_F_MULTIPART;
+        nl_msg.finalize();
+
+        let mut buf = vec![0; nl_msg.buffer_len()];
+        nl_msg.serialize(&mut buf);
+        let parsed = NetlinkMessage::<GenlMessage<MacSecMessage>>::deserialize(&buf).unwrap();
+        match parsed.payload {
+            NetlinkPayload::InnerMessage(genl_msg) => assert_eq!(genl_msg.payload, msg),
+            payload => panic!("unexpected payload {:?}", payload),
+        }
+    }
+}
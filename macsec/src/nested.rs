@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{Nla, NlaBuffer, NlasIterator},
+    DecodeError,
+    Emitable,
+    Parseable,
+};
+
+// The kernel dumps the secure channels and associations as a list of nested attributes,
+// numbered from 1 and each holding the attributes of one entry.
+struct NestedEntry<'a, T>(u16, &'a [T]);
+
+impl<'a, T: Nla> Nla for NestedEntry<'a, T> {
+    fn value_len(&self) -> usize {
+        self.1.buffer_len()
+    }
+
+    fn kind(&self) -> u16 {
+        self.0
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        self.1.emit(buffer)
+    }
+}
+
+fn entries<T: Nla>(list: &[Vec<T>]) -> Vec<NestedEntry<'_, T>> {
+    list.iter()
+        .enumerate()
+        .map(|(i, entry)| NestedEntry(i as u16 + 1, entry.as_slice()))
+        .collect()
+}
+
+pub(crate) fn nested_list_len<T: Nla>(list: &[Vec<T>]) -> usize {
+    entries(list).as_slice().buffer_len()
+}
+
+pub(crate) fn emit_nested_list<T: Nla>(list: &[Vec<T>], buffer: &mut [u8]) {
+    entries(list).as_slice().emit(buffer)
+}
+
+pub(crate) fn parse_nested<T>(payload: &[u8], error_msg: &str) -> Result<Vec<T>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = Vec::new();
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(error_msg.to_string())?;
+        nlas.push(T::parse(nla).context(error_msg.to_string())?);
+    }
+    Ok(nlas)
+}
+
+pub(crate) fn parse_nested_list<T>(
+    payload: &[u8],
+    error_msg: &str,
+) -> Result<Vec<Vec<T>>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut list = Vec::new();
+    for entry in NlasIterator::new(payload) {
+        let entry = entry.context(error_msg.to_string())?;
+        list.push(parse_nested(entry.value(), error_msg)?);
+    }
+    Ok(list)
+}
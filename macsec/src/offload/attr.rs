@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u8,
+    DecodeError,
+    Parseable,
+};
+
+const MACSEC_OFFLOAD_ATTR_TYPE: u16 = 1;
+// const MACSEC_OFFLOAD_ATTR_PAD: u16 = 2;
+
+/// Attributes of the hardware offload of a MACsec link.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MacSecOffloadAttr {
+    /// `MACSEC_OFFLOAD_*`: off (0), offloaded to the PHY (1) or to the MAC (2)
+    Type(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MacSecOffloadAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Type(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Type(_) => MACSEC_OFFLOAD_ATTR_TYPE,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Type(d) => buffer[0] = *d,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacSecOffloadAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_OFFLOAD_ATTR_TYPE => {
+                Self::Type(parse_u8(payload).context("invalid MACSEC_OFFLOAD_ATTR_TYPE value")?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use netlink_packet_utils::Emitable;
+
+    use super::*;
+    use crate::nested::parse_nested;
+
+    #[rustfmt::skip]
+    static OFFLOAD: [u8; 8] = [
+        0x05, 0x00, // length
+        0x01, 0x00, // type = MACSEC_OFFLOAD_ATTR_TYPE
+        0x02, 0x00, 0x00, 0x00, // MACSEC_OFFLOAD_MAC + padding
+    ];
+
+    #[test]
+    fn parse_offload() {
+        let parsed: Vec<MacSecOffloadAttr> = parse_nested(&OFFLOAD[..], "invalid offload").unwrap();
+        assert_eq!(parsed, vec![MacSecOffloadAttr::Type(2)]);
+    }
+
+    #[test]
+    fn emit_offload() {
+        let nlas = vec![MacSecOffloadAttr::Type(2)];
+        let mut buf = vec![0; nlas.as_slice().buffer_len()];
+        nlas.as_slice().emit(&mut buf);
+        assert_eq!(&buf[..], &OFFLOAD[..]);
+    }
+}
@@ -0,0 +1,5 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+
+pub use attr::MacSecOffloadAttr;
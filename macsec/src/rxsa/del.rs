@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    macsec_execute_ack,
+    MacSecAttr,
+    MacSecCmd,
+    MacSecError,
+    MacSecHandle,
+    MacSecMessage,
+    MacSecRxScAttr,
+    MacSecSaAttr,
+};
+
+pub struct MacSecRxSaDelRequest {
+    handle: MacSecHandle,
+    index: u32,
+    sci: u64,
+    an: u8,
+}
+
+impl MacSecRxSaDelRequest {
+    pub(crate) fn new(handle: MacSecHandle, index: u32, sci: u64, an: u8) -> Self {
+        MacSecRxSaDelRequest {
+            handle,
+            index,
+            sci,
+            an,
+        }
+    }
+
+    pub async fn execute(self) -> Result<(), MacSecError> {
+        let MacSecRxSaDelRequest {
+            mut handle,
+            index,
+            sci,
+            an,
+        } = self;
+
+        let macsec_msg = MacSecMessage {
+            cmd: MacSecCmd::DelRxSa,
+            nlas: vec![
+                MacSecAttr::IfIndex(index),
+                MacSecAttr::RxScConfig(vec![MacSecRxScAttr::Sci(sci)]),
+                MacSecAttr::SaConfig(vec![MacSecSaAttr::An(an)]),
+            ],
+        };
+        macsec_execute_ack(&mut handle, macsec_msg).await
+    }
+}
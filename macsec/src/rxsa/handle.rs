@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{MacSecHandle, MacSecRxSaAddRequest, MacSecRxSaDelRequest, MACSEC_KEYID_LEN};
+
+pub struct MacSecRxSaHandle(MacSecHandle);
+
+impl MacSecRxSaHandle {
+    pub fn new(handle: MacSecHandle) -> Self {
+        MacSecRxSaHandle(handle)
+    }
+
+    /// Add the receive secure association `an` to the receive secure channel `sci` of the
+    /// MACsec link of index `index`
+    /// (equivalent to `ip macsec add DEV rx sci SCI sa AN pn PN key KEYID KEY`)
+    pub fn add(
+        &mut self,
+        index: u32,
+        sci: u64,
+        an: u8,
+        pn: u32,
+        key_id: [u8; MACSEC_KEYID_LEN],
+        key: Vec<u8>,
+    ) -> MacSecRxSaAddRequest {
+        MacSecRxSaAddRequest::new(self.0.clone(), index, sci, an, pn, key_id, key)
+    }
+
+    /// Delete the receive secure association `an` of the receive secure channel `sci` of the
+    /// MACsec link of index `index`
+    /// (equivalent to `ip macsec del DEV rx sci SCI sa AN`)
+    pub fn del(&mut self, index: u32, sci: u64, an: u8) -> MacSecRxSaDelRequest {
+        MacSecRxSaDelRequest::new(self.0.clone(), index, sci, an)
+    }
+}
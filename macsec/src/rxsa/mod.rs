@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod add;
+mod del;
+mod handle;
+
+pub use add::MacSecRxSaAddRequest;
+pub use del::MacSecRxSaDelRequest;
+pub use handle::MacSecRxSaHandle;
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    macsec_execute_ack,
+    MacSecAttr,
+    MacSecCmd,
+    MacSecError,
+    MacSecHandle,
+    MacSecMessage,
+    MacSecRxScAttr,
+};
+
+pub struct MacSecRxScAddRequest {
+    handle: MacSecHandle,
+    index: u32,
+    nlas: Vec<MacSecRxScAttr>,
+}
+
+impl MacSecRxScAddRequest {
+    pub(crate) fn new(handle: MacSecHandle, index: u32, sci: u64) -> Self {
+        MacSecRxScAddRequest {
+            handle,
+            index,
+            nlas: vec![MacSecRxScAttr::Sci(sci)],
+        }
+    }
+
+    /// Whether the channel accepts packets. The kernel activates new channels by default.
+    pub fn active(mut self, active: bool) -> Self {
+        self.nlas.push(MacSecRxScAttr::Active(active as u8));
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), MacSecError> {
+        let MacSecRxScAddRequest {
+            mut handle,
+            index,
+            nlas,
+        } = self;
+
+        let macsec_msg = MacSecMessage {
+            cmd: MacSecCmd::AddRxSc,
+            nlas: vec![MacSecAttr::IfIndex(index), MacSecAttr::RxScConfig(nlas)],
+        };
+        macsec_execute_ack(&mut handle, macsec_msg).await
+    }
+}
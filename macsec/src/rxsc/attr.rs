@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u64, parse_u8},
+    DecodeError,
+    Parseable,
+};
+
+use crate::{
+    nested::{emit_nested_list, nested_list_len, parse_nested_list},
+    MacSecSaAttr,
+};
+
+const MACSEC_RXSC_ATTR_SCI: u16 = 1;
+const MACSEC_RXSC_ATTR_ACTIVE: u16 = 2;
+const MACSEC_RXSC_ATTR_SA_LIST: u16 = 3;
+// const MACSEC_RXSC_ATTR_STATS: u16 = 4;
+// const MACSEC_RXSC_ATTR_PAD: u16 = 5;
+
+/// Attributes of a receive secure channel. The SCI is given in host byte order, the MAC
+/// address of the peer followed by its port (e.g. `0x5254_0012_3456_0001`).
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MacSecRxScAttr {
+    Sci(u64),
+    Active(u8),
+    /// Receive secure associations of the channel, as dumped by the kernel
+    SaList(Vec<Vec<MacSecSaAttr>>),
+    Other(DefaultNla),
+}
+
+impl Nla for MacSecRxScAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Sci(_) => 8,
+            Self::Active(_) => 1,
+            Self::SaList(list) => nested_list_len(list),
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Sci(_) => MACSEC_RXSC_ATTR_SCI,
+            Self::Active(_) => MACSEC_RXSC_ATTR_ACTIVE,
+            Self::SaList(_) => MACSEC_RXSC_ATTR_SA_LIST,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Sci(d) => BigEndian::write_u64(buffer, *d),
+            Self::Active(d) => buffer[0] = *d,
+            Self::SaList(list) => emit_nested_list(list, buffer),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacSecRxScAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_RXSC_ATTR_SCI => Self::Sci(u64::from_be(
+                parse_u64(payload).context("invalid MACSEC_RXSC_ATTR_SCI value")?,
+            )),
+            MACSEC_RXSC_ATTR_ACTIVE => {
+                Self::Active(parse_u8(payload).context("invalid MACSEC_RXSC_ATTR_ACTIVE value")?)
+            }
+            MACSEC_RXSC_ATTR_SA_LIST => Self::SaList(parse_nested_list(
+                payload,
+                "invalid MACSEC_RXSC_ATTR_SA_LIST value",
+            )?),
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use netlink_packet_utils::Emitable;
+
+    use super::*;
+    use crate::nested::parse_nested;
+
+    #[rustfmt::skip]
+    static RXSC: [u8; 44] = [
+        0x0c, 0x00, // length
+        0x01, 0x00, // type = MACSEC_RXSC_ATTR_SCI
+        0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x00, 0x01, // 52:54:00:12:34:56 port 1
+        0x05, 0x00, // length
+        0x02, 0x00, // type = MACSEC_RXSC_ATTR_ACTIVE
+        0x01, 0x00, 0x00, 0x00, // 1 + padding
+        0x18, 0x00, // length
+        0x03, 0x00, // type = MACSEC_RXSC_ATTR_SA_LIST
+            0x14, 0x00, // length
+            0x01, 0x00, // entry 1
+                0x05, 0x00, // length
+                0x01, 0x00, // type = MACSEC_SA_ATTR_AN
+                0x00, 0x00, 0x00, 0x00, // 0 + padding
+                0x08, 0x00, // length
+                0x03, 0x00, // type = MACSEC_SA_ATTR_PN
+                0x01, 0x00, 0x00, 0x00, // 1
+    ];
+
+    fn rxsc() -> Vec<MacSecRxScAttr> {
+        vec![
+            MacSecRxScAttr::Sci(0x5254_0012_3456_0001),
+            MacSecRxScAttr::Active(1),
+            MacSecRxScAttr::SaList(vec![vec![MacSecSaAttr::An(0), MacSecSaAttr::Pn(1)]]),
+        ]
+    }
+
+    #[test]
+    fn parse_rxsc() {
+        let parsed: Vec<MacSecRxScAttr> = parse_nested(&RXSC[..], "invalid RXSC").unwrap();
+        assert_eq!(parsed, rxsc());
+    }
+
+    #[test]
+    fn emit_rxsc() {
+        let nlas = rxsc();
+        let mut buf = vec![0; nlas.as_slice().buffer_len()];
+        nlas.as_slice().emit(&mut buf);
+        assert_eq!(&buf[..], &RXSC[..]);
+    }
+}
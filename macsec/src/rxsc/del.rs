@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    macsec_execute_ack,
+    MacSecAttr,
+    MacSecCmd,
+    MacSecError,
+    MacSecHandle,
+    MacSecMessage,
+    MacSecRxScAttr,
+};
+
+pub struct MacSecRxScDelRequest {
+    handle: MacSecHandle,
+    index: u32,
+    sci: u64,
+}
+
+impl MacSecRxScDelRequest {
+    pub(crate) fn new(handle: MacSecHandle, index: u32, sci: u64) -> Self {
+        MacSecRxScDelRequest { handle, index, sci }
+    }
+
+    pub async fn execute(self) -> Result<(), MacSecError> {
+        let MacSecRxScDelRequest {
+            mut handle,
+            index,
+            sci,
+        } = self;
+
+        let macsec_msg = MacSecMessage {
+            cmd: MacSecCmd::DelRxSc,
+            nlas: vec![
+                MacSecAttr::IfIndex(index),
+                MacSecAttr::RxScConfig(vec![MacSecRxScAttr::Sci(sci)]),
+            ],
+        };
+        macsec_execute_ack(&mut handle, macsec_msg).await
+    }
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{MacSecHandle, MacSecRxScAddRequest, MacSecRxScDelRequest};
+
+pub struct MacSecRxScHandle(MacSecHandle);
+
+impl MacSecRxScHandle {
+    pub fn new(handle: MacSecHandle) -> Self {
+        MacSecRxScHandle(handle)
+    }
+
+    /// Add a receive secure channel to the MACsec link of index `index`
+    /// (equivalent to `ip macsec add DEV rx sci SCI`)
+    pub fn add(&mut self, index: u32, sci: u64) -> MacSecRxScAddRequest {
+        MacSecRxScAddRequest::new(self.0.clone(), index, sci)
+    }
+
+    /// Delete a receive secure channel of the MACsec link of index `index`
+    /// (equivalent to `ip macsec del DEV rx sci SCI`)
+    pub fn del(&mut self, index: u32, sci: u64) -> MacSecRxScDelRequest {
+        MacSecRxScDelRequest::new(self.0.clone(), index, sci)
+    }
+}
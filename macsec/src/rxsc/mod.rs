@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod add;
+mod attr;
+mod del;
+mod handle;
+
+pub use add::MacSecRxScAddRequest;
+pub use attr::MacSecRxScAttr;
+pub use del::MacSecRxScDelRequest;
+pub use handle::MacSecRxScHandle;
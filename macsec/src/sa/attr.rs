@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u32_be, parse_u64, parse_u8},
+    DecodeError,
+    Parseable,
+};
+use std::fmt;
+
+const MACSEC_SA_ATTR_AN: u16 = 1;
+const MACSEC_SA_ATTR_ACTIVE: u16 = 2;
+const MACSEC_SA_ATTR_PN: u16 = 3;
+const MACSEC_SA_ATTR_KEY: u16 = 4;
+const MACSEC_SA_ATTR_KEYID: u16 = 5;
+// const MACSEC_SA_ATTR_STATS: u16 = 6;
+// const MACSEC_SA_ATTR_PAD: u16 = 7;
+const MACSEC_SA_ATTR_SSCI: u16 = 8;
+const MACSEC_SA_ATTR_SALT: u16 = 9;
+
+pub const MACSEC_KEYID_LEN: usize = 16;
+pub const MACSEC_SALT_LEN: usize = 12;
+
+/// Attributes of a secure association. The SSCI is given in host byte order. The key is
+/// redacted from the `Debug` output.
+#[derive(PartialEq, Eq, Clone)]
+pub enum MacSecSaAttr {
+    /// Association number, 0 to 3
+    An(u8),
+    Active(u8),
+    /// Next packet number of a non extended packet numbering cipher suite
+    Pn(u32),
+    /// Next packet number of an extended packet numbering (XPN) cipher suite
+    XPn(u64),
+    /// Never dumped by the kernel
+    Key(Vec<u8>),
+    KeyId([u8; MACSEC_KEYID_LEN]),
+    /// Short secure channel identifier of an XPN cipher suite
+    Ssci(u32),
+    /// Salt of an XPN cipher suite
+    Salt([u8; MACSEC_SALT_LEN]),
+    Other(DefaultNla),
+}
+
+impl fmt::Debug for MacSecSaAttr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::An(d) => f.debug_tuple("An").field(d).finish(),
+            Self::Active(d) => f.debug_tuple("Active").field(d).finish(),
+            Self::Pn(d) => f.debug_tuple("Pn").field(d).finish(),
+            Self::XPn(d) => f.debug_tuple("XPn").field(d).finish(),
+            // netlink-proto traces the messages it sends, don't let the key end up in logs
+            Self::Key(bytes) => f
+                .debug_tuple("Key")
+                .field(&format_args!("<redacted, {} bytes>", bytes.len()))
+                .finish(),
+            Self::KeyId(bytes) => f.debug_tuple("KeyId").field(bytes).finish(),
+            Self::Ssci(d) => f.debug_tuple("Ssci").field(d).finish(),
+            Self::Salt(bytes) => f.debug_tuple("Salt").field(bytes).finish(),
+            Self::Other(attr) => f.debug_tuple("Other").field(attr).finish(),
+        }
+    }
+}
+
+impl Nla for MacSecSaAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::An(_) | Self::Active(_) => 1,
+            Self::Pn(_) | Self::Ssci(_) => 4,
+            Self::XPn(_) => 8,
+            Self::Key(bytes) => bytes.len(),
+            Self::KeyId(_) => MACSEC_KEYID_LEN,
+            Self::Salt(_) => MACSEC_SALT_LEN,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::An(_) => MACSEC_SA_ATTR_AN,
+            Self::Active(_) => MACSEC_SA_ATTR_ACTIVE,
+            Self::Pn(_) | Self::XPn(_) => MACSEC_SA_ATTR_PN,
+            Self::Key(_) => MACSEC_SA_ATTR_KEY,
+            Self::KeyId(_) => MACSEC_SA_ATTR_KEYID,
+            Self::Ssci(_) => MACSEC_SA_ATTR_SSCI,
+            Self::Salt(_) => MACSEC_SA_ATTR_SALT,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::An(d) | Self::Active(d) => buffer[0] = *d,
+            Self::Pn(d) => NativeEndian::write_u32(buffer, *d),
+            Self::XPn(d) => NativeEndian::write_u64(buffer, *d),
+            Self::Key(bytes) => buffer.copy_from_slice(bytes),
+            Self::KeyId(bytes) => buffer.copy_from_slice(bytes),
+            Self::Ssci(d) => BigEndian::write_u32(buffer, *d),
+            Self::Salt(bytes) => buffer.copy_from_slice(bytes),
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacSecSaAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_SA_ATTR_AN => {
+                Self::An(parse_u8(payload).context("invalid MACSEC_SA_ATTR_AN value")?)
+            }
+            MACSEC_SA_ATTR_ACTIVE => {
+                Self::Active(parse_u8(payload).context("invalid MACSEC_SA_ATTR_ACTIVE value")?)
+            }
+            MACSEC_SA_ATTR_PN if payload.len() == 4 => {
+                Self::Pn(parse_u32(payload).context("invalid MACSEC_SA_ATTR_PN value")?)
+            }
+            MACSEC_SA_ATTR_PN => {
+                Self::XPn(parse_u64(payload).context("invalid MACSEC_SA_ATTR_PN value")?)
+            }
+            MACSEC_SA_ATTR_KEY => Self::Key(payload.to_vec()),
+            MACSEC_SA_ATTR_KEYID => {
+                if payload.len() != MACSEC_KEYID_LEN {
+                    return Err(format!("invalid MACSEC_SA_ATTR_KEYID value {:?}", payload).into());
+                }
+                let mut key_id = [0; MACSEC_KEYID_LEN];
+                key_id.copy_from_slice(payload);
+                Self::KeyId(key_id)
+            }
+            MACSEC_SA_ATTR_SSCI => {
+                Self::Ssci(parse_u32_be(payload).context("invalid MACSEC_SA_ATTR_SSCI value")?)
+            }
+            MACSEC_SA_ATTR_SALT => {
+                if payload.len() != MACSEC_SALT_LEN {
+                    return Err(format!("invalid MACSEC_SA_ATTR_SALT value {:?}", payload).into());
+                }
+                let mut salt = [0; MACSEC_SALT_LEN];
+                salt.copy_from_slice(payload);
+                Self::Salt(salt)
+            }
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?),
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use netlink_packet_utils::Emitable;
+
+    use super::*;
+    use crate::nested::parse_nested;
+
+    #[rustfmt::skip]
+    static XPN_SA: [u8; 92] = [
+        0x05, 0x00, // length
+        0x01, 0x00, // type = MACSEC_SA_ATTR_AN
+        0x02, 0x00, 0x00, 0x00, // 2 + padding
+        0x05, 0x00, // length
+        0x02, 0x00, // type = MACSEC_SA_ATTR_ACTIVE
+        0x01, 0x00, 0x00, 0x00, // 1 + padding
+        0x0c, 0x00, // length
+        0x03, 0x00, // type = MACSEC_SA_ATTR_PN
+        0x01, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, // 0x1_0000_0001
+        0x14, 0x00, // length
+        0x04, 0x00, // type = MACSEC_SA_ATTR_KEY
+        0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+        0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa, 0xaa,
+        0x14, 0x00, // length
+        0x05, 0x00, // type = MACSEC_SA_ATTR_KEYID
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x08, 0x00, // length
+        0x08, 0x00, // type = MACSEC_SA_ATTR_SSCI
+        0x00, 0x00, 0x00, 0x01, // 1 (big endian)
+        0x10, 0x00, // length
+        0x09, 0x00, // type = MACSEC_SA_ATTR_SALT
+        0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a, 0x5a,
+        0x5a, 0x5a, 0x5a, 0x5a,
+    ];
+
+    fn xpn_sa() -> Vec<MacSecSaAttr> {
+        vec![
+            MacSecSaAttr::An(2),
+            MacSecSaAttr::Active(1),
+            MacSecSaAttr::XPn(0x1_0000_0001),
+            MacSecSaAttr::Key(vec![0xaa; 16]),
+            MacSecSaAttr::KeyId([0x01; MACSEC_KEYID_LEN]),
+            MacSecSaAttr::Ssci(1),
+            MacSecSaAttr::Salt([0x5a; MACSEC_SALT_LEN]),
+        ]
+    }
+
+    #[test]
+    fn parse_xpn_sa() {
+        let parsed: Vec<MacSecSaAttr> = parse_nested(&XPN_SA[..], "invalid SA").unwrap();
+        assert_eq!(parsed, xpn_sa());
+    }
+
+    #[test]
+    fn emit_xpn_sa() {
+        let nlas = xpn_sa();
+        let mut buf = vec![0; nlas.as_slice().buffer_len()];
+        nlas.as_slice().emit(&mut buf);
+        assert_eq!(&buf[..], &XPN_SA[..]);
+    }
+
+    #[test]
+    fn debug_redacts_key() {
+        let debug = format!("{:?}", MacSecSaAttr::Key(vec![0xaa; 16]));
+        assert_eq!(debug, "Key(<redacted, 16 bytes>)");
+        assert_eq!(format!("{:?}", MacSecSaAttr::An(2)), "An(2)");
+    }
+}
@@ -0,0 +1,156 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+use netlink_packet_utils::{
+    nla::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u32, parse_u64, parse_u8},
+    DecodeError,
+    Parseable,
+};
+
+const MACSEC_SECY_ATTR_SCI: u16 = 1;
+const MACSEC_SECY_ATTR_ENCODING_SA: u16 = 2;
+const MACSEC_SECY_ATTR_WINDOW: u16 = 3;
+const MACSEC_SECY_ATTR_CIPHER_SUITE: u16 = 4;
+const MACSEC_SECY_ATTR_ICV_LEN: u16 = 5;
+const MACSEC_SECY_ATTR_PROTECT: u16 = 6;
+const MACSEC_SECY_ATTR_REPLAY: u16 = 7;
+const MACSEC_SECY_ATTR_OPER: u16 = 8;
+const MACSEC_SECY_ATTR_VALIDATE: u16 = 9;
+const MACSEC_SECY_ATTR_ENCRYPT: u16 = 10;
+const MACSEC_SECY_ATTR_INC_SCI: u16 = 11;
+const MACSEC_SECY_ATTR_ES: u16 = 12;
+const MACSEC_SECY_ATTR_SCB: u16 = 13;
+// const MACSEC_SECY_ATTR_PAD: u16 = 14;
+
+/// Attributes of a secure entity, the MACsec link and its transmit secure channel, as
+/// configured with `ip link add ... type macsec`. The SCI is given in host byte order.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MacSecSecYAttr {
+    /// Secure channel identifier of the transmit secure channel
+    Sci(u64),
+    /// Association number of the transmit secure association in use
+    EncodingSa(u8),
+    Window(u32),
+    /// `MACSEC_CIPHER_ID_*`
+    CipherSuite(u64),
+    IcvLen(u8),
+    Protect(u8),
+    Replay(u8),
+    /// The link is operational (1)
+    Oper(u8),
+    /// `MACSEC_VALIDATE_*`
+    Validate(u8),
+    Encrypt(u8),
+    IncSci(u8),
+    Es(u8),
+    Scb(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MacSecSecYAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            Self::Sci(_) | Self::CipherSuite(_) => 8,
+            Self::Window(_) => 4,
+            Self::EncodingSa(_)
+            | Self::IcvLen(_)
+            | Self::Protect(_)
+            | Self::Replay(_)
+            | Self::Oper(_)
+            | Self::Validate(_)
+            | Self::Encrypt(_)
+            | Self::IncSci(_)
+            | Self::Es(_)
+            | Self::Scb(_) => 1,
+            Self::Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            Self::Sci(_) => MACSEC_SECY_ATTR_SCI,
+            Self::EncodingSa(_) => MACSEC_SECY_ATTR_ENCODING_SA,
+            Self::Window(_) => MACSEC_SECY_ATTR_WINDOW,
+            Self::CipherSuite(_) => MACSEC_SECY_ATTR_CIPHER_SUITE,
+            Self::IcvLen(_) => MACSEC_SECY_ATTR_ICV_LEN,
+            Self::Protect(_) => MACSEC_SECY_ATTR_PROTECT,
+            Self::Replay(_) => MACSEC_SECY_ATTR_REPLAY,
+            Self::Oper(_) => MACSEC_SECY_ATTR_OPER,
+            Self::Validate(_) => MACSEC_SECY_ATTR_VALIDATE,
+            Self::Encrypt(_) => MACSEC_SECY_ATTR_ENCRYPT,
+            Self::IncSci(_) => MACSEC_SECY_ATTR_INC_SCI,
+            Self::Es(_) => MACSEC_SECY_ATTR_ES,
+            Self::Scb(_) => MACSEC_SECY_ATTR_SCB,
+            Self::Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            Self::Sci(d) => BigEndian::write_u64(buffer, *d),
+            Self::CipherSuite(d) => NativeEndian::write_u64(buffer, *d),
+            Self::Window(d) => NativeEndian::write_u32(buffer, *d),
+            Self::EncodingSa(d)
+            | Self::IcvLen(d)
+            | Self::Protect(d)
+            | Self::Replay(d)
+            | Self::Oper(d)
+            | Self::Validate(d)
+            | Self::Encrypt(d)
+            | Self::IncSci(d)
+            | Self::Es(d)
+            | Self::Scb(d) => buffer[0] = *d,
+            Self::Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MacSecSecYAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MACSEC_SECY_ATTR_SCI => Self::Sci(u64::from_be(
+                parse_u64(payload).context("invalid MACSEC_SECY_ATTR_SCI value")?,
+            )),
+            MACSEC_SECY_ATTR_ENCODING_SA => Self::EncodingSa(
+                parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ENCODING_SA value")?,
+            ),
+            MACSEC_SECY_ATTR_WINDOW => {
+                Self::Window(parse_u32(payload).context("invalid MACSEC_SECY_ATTR_WINDOW value")?)
+            }
+            MACSEC_SECY_ATTR_CIPHER_SUITE => Self::CipherSuite(
+                parse_u64(payload).context("invalid MACSEC_SECY_ATTR_CIPHER_SUITE value")?,
+            ),
+            MACSEC_SECY_ATTR_ICV_LEN => {
+                Self::IcvLen(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ICV_LEN value")?)
+            }
+            MACSEC_SECY_ATTR_PROTECT => {
+                Self::Protect(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_PROTECT value")?)
+            }
+            MACSEC_SECY_ATTR_REPLAY => {
+                Self::Replay(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_REPLAY value")?)
+            }
+            MACSEC_SECY_ATTR_OPER => {
+                Self::Oper(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_OPER value")?)
+            }
+            MACSEC_SECY_ATTR_VALIDATE => Self::Validate(
+                parse_u8(payload).context("invalid MACSEC_SECY_ATTR_VALIDATE value")?,
+            ),
+            MACSEC_SECY_ATTR_ENCRYPT => {
+                Self::Encrypt(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ENCRYPT value")?)
+            }
+            MACSEC_SECY_ATTR_INC_SCI => {
+                Self::IncSci(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_INC_SCI value")?)
+            }
+            MACSEC_SECY_ATTR_ES => {
+                Self::Es(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_ES value")?)
+            }
+            MACSEC_SECY_ATTR_SCB => {
+                Self::Scb(parse_u8(payload).context("invalid MACSEC_SECY_ATTR_SCB value")?)
+            }
+            _ => Self::Other(DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?),
+        })
+    }
+}
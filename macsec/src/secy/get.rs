@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT
+
+use futures::TryStream;
+use netlink_packet_generic::GenlMessage;
+
+use crate::{macsec_execute, MacSecError, MacSecHandle, MacSecMessage};
+
+pub struct MacSecGetRequest {
+    handle: MacSecHandle,
+}
+
+impl MacSecGetRequest {
+    pub(crate) fn new(handle: MacSecHandle) -> Self {
+        MacSecGetRequest { handle }
+    }
+
+    pub async fn execute(
+        self,
+    ) -> impl TryStream<Ok = GenlMessage<MacSecMessage>, Error = MacSecError> {
+        let MacSecGetRequest { mut handle } = self;
+
+        let macsec_msg = MacSecMessage::new_get();
+        macsec_execute(&mut handle, macsec_msg).await
+    }
+}
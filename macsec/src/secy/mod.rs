@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: MIT
+
+mod attr;
+mod get;
+
+pub use attr::MacSecSecYAttr;
+pub use get::MacSecGetRequest;
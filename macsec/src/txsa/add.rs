@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    macsec_execute_ack,
+    MacSecAttr,
+    MacSecCmd,
+    MacSecError,
+    MacSecHandle,
+    MacSecMessage,
+    MacSecSaAttr,
+    MACSEC_KEYID_LEN,
+    MACSEC_SALT_LEN,
+};
+
+pub struct MacSecTxSaAddRequest {
+    handle: MacSecHandle,
+    index: u32,
+    nlas: Vec<MacSecSaAttr>,
+}
+
+impl MacSecTxSaAddRequest {
+    pub(crate) fn new(
+        handle: MacSecHandle,
+        index: u32,
+        an: u8,
+        pn: u32,
+        key_id: [u8; MACSEC_KEYID_LEN],
+        key: Vec<u8>,
+    ) -> Self {
+        MacSecTxSaAddRequest {
+            handle,
+            index,
+            nlas: vec![
+                MacSecSaAttr::An(an),
+                MacSecSaAttr::Pn(pn),
+                MacSecSaAttr::KeyId(key_id),
+                MacSecSaAttr::Key(key),
+            ],
+        }
+    }
+
+    /// Whether the association is used to send packets. The kernel leaves new associations
+    /// inactive by default.
+    pub fn active(mut self, active: bool) -> Self {
+        self.nlas.push(MacSecSaAttr::Active(active as u8));
+        self
+    }
+
+    /// Set a 64 bits packet number, for the extended packet numbering (XPN) cipher suites
+    pub fn xpn(mut self, pn: u64) -> Self {
+        self.nlas
+            .retain(|nla| !matches!(nla, MacSecSaAttr::Pn(_) | MacSecSaAttr::XPn(_)));
+        self.nlas.push(MacSecSaAttr::XPn(pn));
+        self
+    }
+
+    /// Short secure channel identifier, mandatory for the XPN cipher suites
+    pub fn ssci(mut self, ssci: u32) -> Self {
+        self.nlas.push(MacSecSaAttr::Ssci(ssci));
+        self
+    }
+
+    /// Salt, mandatory for the XPN cipher suites
+    pub fn salt(mut self, salt: [u8; MACSEC_SALT_LEN]) -> Self {
+        self.nlas.push(MacSecSaAttr::Salt(salt));
+        self
+    }
+
+    pub async fn execute(self) -> Result<(), MacSecError> {
+        let MacSecTxSaAddRequest {
+            mut handle,
+            index,
+            nlas,
+        } = self;
+
+        let macsec_msg = MacSecMessage {
+            cmd: MacSecCmd::AddTxSa,
+            nlas: vec![MacSecAttr::IfIndex(index), MacSecAttr::SaConfig(nlas)],
+        };
+        macsec_execute_ack(&mut handle, macsec_msg).await
+    }
+}
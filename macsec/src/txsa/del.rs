@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    macsec_execute_ack,
+    MacSecAttr,
+    MacSecCmd,
+    MacSecError,
+    MacSecHandle,
+    MacSecMessage,
+    MacSecSaAttr,
+};
+
+pub struct MacSecTxSaDelRequest {
+    handle: MacSecHandle,
+    index: u32,
+    an: u8,
+}
+
+impl MacSecTxSaDelRequest {
+    pub(crate) fn new(handle: MacSecHandle, index: u32, an: u8) -> Self {
+        MacSecTxSaDelRequest { handle, index, an }
+    }
+
+    pub async fn execute(self) -> Result<(), MacSecError> {
+        let MacSecTxSaDelRequest {
+            mut handle,
+            index,
+            an,
+        } = self;
+
+        let macsec_msg = MacSecMessage {
+            cmd: MacSecCmd::DelTxSa,
+            nlas: vec![
+                MacSecAttr::IfIndex(index),
+                MacSecAttr::SaConfig(vec![MacSecSaAttr::An(an)]),
+            ],
+        };
+        macsec_execute_ack(&mut handle, macsec_msg).await
+    }
+}
@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{MacSecHandle, MacSecTxSaAddRequest, MacSecTxSaDelRequest, MACSEC_KEYID_LEN};
+
+pub struct MacSecTxSaHandle(MacSecHandle);
+
+impl MacSecTxSaHandle {
+    pub fn new(handle: MacSecHandle) -> Self {
+        MacSecTxSaHandle(handle)
+    }
+
+    /// Add the transmit secure association `an` to the MACsec link of index `index`
+    /// (equivalent to `ip macsec add DEV tx sa AN pn PN key KEYID KEY`)
+    pub fn add(
+        &mut self,
+        index: u32,
+        an: u8,
+        pn: u32,
+        key_id: [u8; MACSEC_KEYID_LEN],
+        key: Vec<u8>,
+    ) -> MacSecTxSaAddRequest {
+        MacSecTxSaAddRequest::new(self.0.clone(), index, an, pn, key_id, key)
+    }
+
+    /// Delete the transmit secure association `an` of the MACsec link of index `index`
+    /// (equivalent to `ip macsec del DEV tx sa AN`)
+    pub fn del(&mut self, index: u32, an: u8) -> MacSecTxSaDelRequest {
+        MacSecTxSaDelRequest::new(self.0.clone(), index, an)
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod add;
+mod del;
+mod handle;
+
+pub use add::MacSecTxSaAddRequest;
+pub use del::MacSecTxSaDelRequest;
+pub use handle::MacSecTxSaHandle;
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT
+
+use super::{AddrLabelMessageBuffer, ADDR_LABEL_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// High level representation of `struct ifaddrlblmsg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AddrLabelHeader {
+    pub family: u8,
+    pub prefix_len: u8,
+    pub flags: u8,
+    /// Index of the interface the label is restricted to, or 0 for all the
+    /// interfaces
+    pub index: u32,
+    /// Sequence number of the label table. It is used by the kernel to detect
+    /// that the table changed during a dump.
+    pub seq: u32,
+}
+
+impl Emitable for AddrLabelHeader {
+    fn buffer_len(&self) -> usize {
+        ADDR_LABEL_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = AddrLabelMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_reserved(0);
+        packet.set_prefix_len(self.prefix_len);
+        packet.set_flags(self.flags);
+        packet.set_index(self.index);
+        packet.set_seq(self.seq);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<AddrLabelMessageBuffer<T>> for AddrLabelHeader {
+    fn parse(buf: &AddrLabelMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(AddrLabelHeader {
+            family: buf.family(),
+            prefix_len: buf.prefix_len(),
+            flags: buf.flags(),
+            index: buf.index(),
+            seq: buf.seq(),
+        })
+    }
+}
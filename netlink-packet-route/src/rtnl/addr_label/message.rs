@@ -0,0 +1,121 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::addr_label::Nla,
+    traits::{Emitable, Parseable},
+    AddrLabelHeader,
+    AddrLabelMessageBuffer,
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct AddrLabelMessage {
+    pub header: AddrLabelHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddrLabelMessageBuffer<&'a T>> for AddrLabelMessage {
+    fn parse(buf: &AddrLabelMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: AddrLabelHeader::parse(buf)
+                .context("failed to parse address label message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse address label message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddrLabelMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &AddrLabelMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for AddrLabelMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv6Addr;
+
+    use crate::{
+        nlas::addr_label::Nla,
+        traits::{Emitable, ParseableParametrized},
+        AddrLabelHeader,
+        AddrLabelMessage,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_INET6,
+        RTM_NEWADDRLABEL,
+    };
+
+    // ip addrlabel add prefix 2001:db8::/32 label 102
+    #[rustfmt::skip]
+    static NEW_ADDR_LABEL: [u8; 56] = [
+        0x38, 0x00, 0x00, 0x00, // length = 56
+        0x48, 0x00, // message type = 72 = RTM_NEWADDRLABEL
+        0x02, 0x00, // flags = NLM_F_MULTI
+        0x00, 0x00, 0x00, 0x00, // seq number
+        0x00, 0x00, 0x00, 0x00, // pid
+
+        // address label message
+        0x0a, // family = AF_INET6
+        0x00, // reserved
+        0x20, // prefix length = 32
+        0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // index = 0
+        0x0c, 0x00, 0x00, 0x00, // seq = 12
+        0x14, 0x00, 0x01, 0x00, // length = 20, type = IFAL_ADDRESS
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, // 2001:db8::
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x02, 0x00, 0x66, 0x00, 0x00, 0x00, // IFAL_LABEL = 102
+    ];
+
+    fn new_addr_label() -> AddrLabelMessage {
+        AddrLabelMessage {
+            header: AddrLabelHeader {
+                family: AF_INET6 as u8,
+                prefix_len: 32,
+                flags: 0,
+                index: 0,
+                seq: 12,
+            },
+            nlas: vec![
+                Nla::Address(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)),
+                Nla::Label(102),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_new_addr_label() {
+        let nl_buffer = NetlinkBuffer::new(&NEW_ADDR_LABEL).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWADDRLABEL).unwrap();
+        assert_eq!(RtnlMessage::NewAddrLabel(new_addr_label()), actual);
+    }
+
+    #[test]
+    fn emit_new_addr_label() {
+        let msg = new_addr_label();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &NEW_ADDR_LABEL[16..]);
+    }
+}
@@ -83,3 +83,157 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<AddressMessageBuffer<&'a T>> for Vec<Nla
         Ok(nlas)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::address::{CacheInfo, Nla},
+        traits::{Emitable, Parseable, ParseableParametrized},
+        AddressHeader,
+        AddressMessage,
+        AddressMessageBuffer,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_INET6,
+        IFAPROT_KERNEL_RA,
+        IFA_F_NODAD,
+        IFA_F_NOPREFIXROUTE,
+        IFA_F_PERMANENT,
+        INFINITY_LIFE_TIME,
+        RTM_GETMULTICAST,
+        RT_SCOPE_LINK,
+        RT_SCOPE_UNIVERSE,
+    };
+
+    // ff02::1 joined by lo, as dumped by RTM_GETMULTICAST
+    #[rustfmt::skip]
+    static GET_MULTICAST: [u8; 64] = [
+        0x40, 0x00, 0x00, 0x00, // length = 64
+        0x3a, 0x00, // message type = 58 = RTM_GETMULTICAST
+        0x02, 0x00, // flags = NLM_F_MULTI
+        0x00, 0x00, 0x00, 0x00, // seq number
+        0x00, 0x00, 0x00, 0x00, // pid
+
+        // address message
+        0x0a, // family = AF_INET6
+        0x80, // prefix length = 128
+        0x80, // flags = IFA_F_PERMANENT
+        0xfd, // scope = RT_SCOPE_LINK
+        0x01, 0x00, 0x00, 0x00, // index = 1
+        0x14, 0x00, 0x07, 0x00, // length = 20, type = IFA_MULTICAST
+            0xff, 0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // ff02::1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x14, 0x00, 0x06, 0x00, // length = 20, type = IFA_CACHEINFO
+            0xff, 0xff, 0xff, 0xff, // preferred = INFINITY_LIFE_TIME
+            0xff, 0xff, 0xff, 0xff, // valid = INFINITY_LIFE_TIME
+            0x0a, 0x00, 0x00, 0x00, // cstamp = 10
+            0x0a, 0x00, 0x00, 0x00, // tstamp = 10
+    ];
+
+    fn get_multicast() -> AddressMessage {
+        AddressMessage {
+            header: AddressHeader {
+                family: AF_INET6 as u8,
+                prefix_len: 128,
+                flags: IFA_F_PERMANENT as u8,
+                scope: RT_SCOPE_LINK,
+                index: 1,
+            },
+            nlas: vec![
+                Nla::Multicast(vec![
+                    0xff, 0x02, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01,
+                ]),
+                Nla::CacheInfo(CacheInfo {
+                    ifa_preferred: INFINITY_LIFE_TIME,
+                    ifa_valid: INFINITY_LIFE_TIME,
+                    cstamp: 10,
+                    tstamp: 10,
+                }),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_get_multicast() {
+        let nl_buffer = NetlinkBuffer::new(&GET_MULTICAST).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_GETMULTICAST).unwrap();
+        assert_eq!(RtnlMessage::GetMulticastAddress(get_multicast()), actual);
+    }
+
+    #[test]
+    fn emit_get_multicast() {
+        let msg = get_multicast();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &GET_MULTICAST[16..]);
+    }
+
+    // ip -6 address add 2001:db8::1/64 dev lo valid_lft 3600 preferred_lft 1800 nodad \
+    //     noprefixroute metric 100 proto ra
+    #[rustfmt::skip]
+    static NEW_ADDRESS: [u8; 72] = [
+        0x0a, // family = AF_INET6
+        0x40, // prefix length = 64
+        0x02, // flags = IFA_F_NODAD
+        0x00, // scope = RT_SCOPE_UNIVERSE
+        0x01, 0x00, 0x00, 0x00, // index = 1
+        0x14, 0x00, 0x01, 0x00, // length = 20, type = IFA_ADDRESS
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, // 2001:db8::1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+        0x14, 0x00, 0x06, 0x00, // length = 20, type = IFA_CACHEINFO
+            0x08, 0x07, 0x00, 0x00, // preferred = 1800
+            0x10, 0x0e, 0x00, 0x00, // valid = 3600
+            0x00, 0x00, 0x00, 0x00, // cstamp
+            0x00, 0x00, 0x00, 0x00, // tstamp
+        0x08, 0x00, 0x08, 0x00, // length = 8, type = IFA_FLAGS
+            0x02, 0x02, 0x00, 0x00, // IFA_F_NODAD | IFA_F_NOPREFIXROUTE
+        0x08, 0x00, 0x09, 0x00, // length = 8, type = IFA_RT_PRIORITY
+            0x64, 0x00, 0x00, 0x00, // 100
+        0x05, 0x00, 0x0b, 0x00, // length = 5, type = IFA_PROTO
+            0x02, 0x00, 0x00, 0x00, // IFAPROT_KERNEL_RA
+    ];
+
+    fn new_address() -> AddressMessage {
+        AddressMessage {
+            header: AddressHeader {
+                family: AF_INET6 as u8,
+                prefix_len: 64,
+                flags: IFA_F_NODAD as u8,
+                scope: RT_SCOPE_UNIVERSE,
+                index: 1,
+            },
+            nlas: vec![
+                Nla::Address(vec![
+                    0x20, 0x01, 0x0d, 0xb8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0x01,
+                ]),
+                Nla::CacheInfo(CacheInfo {
+                    ifa_preferred: 1800,
+                    ifa_valid: 3600,
+                    cstamp: 0,
+                    tstamp: 0,
+                }),
+                Nla::Flags(IFA_F_NODAD | IFA_F_NOPREFIXROUTE),
+                Nla::RtPriority(100),
+                Nla::Protocol(IFAPROT_KERNEL_RA),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_new_address() {
+        let actual =
+            AddressMessage::parse(&AddressMessageBuffer::new_checked(&&NEW_ADDRESS[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, new_address());
+    }
+
+    #[test]
+    fn emit_new_address() {
+        let msg = new_address();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &NEW_ADDRESS[..]);
+    }
+}
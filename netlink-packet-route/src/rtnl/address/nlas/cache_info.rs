@@ -5,20 +5,25 @@ use crate::{
     DecodeError,
 };
 
+/// Lifetimes and timestamps of an address (`struct ifa_cacheinfo`)
 #[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
 pub struct CacheInfo {
-    pub ifa_preferred: i32,
-    pub ifa_valid: i32,
-    pub cstamp: i32,
-    pub tstamp: i32,
+    /// Remaining preferred lifetime in seconds, or `INFINITY_LIFE_TIME`
+    pub ifa_preferred: u32,
+    /// Remaining valid lifetime in seconds, or `INFINITY_LIFE_TIME`
+    pub ifa_valid: u32,
+    /// Creation time, in hundredths of seconds since boot
+    pub cstamp: u32,
+    /// Last update time, in hundredths of seconds since boot
+    pub tstamp: u32,
 }
 
 pub const ADDRESSS_CACHE_INFO_LEN: usize = 16;
 buffer!(CacheInfoBuffer(ADDRESSS_CACHE_INFO_LEN) {
-    ifa_preferred: (i32, 0..4),
-    ifa_valid: (i32, 4..8),
-    cstamp: (i32, 8..12),
-    tstamp: (i32, 12..16),
+    ifa_preferred: (u32, 0..4),
+    ifa_valid: (u32, 4..8),
+    cstamp: (u32, 8..12),
+    tstamp: (u32, 12..16),
 });
 
 impl<T: AsRef<[u8]>> Parseable<CacheInfoBuffer<T>> for CacheInfo {
@@ -11,8 +11,8 @@ use byteorder::{ByteOrder, NativeEndian};
 use crate::{
     constants::*,
     nlas::{self, DefaultNla, NlaBuffer},
-    parsers::{parse_string, parse_u32},
-    traits::Parseable,
+    parsers::{parse_i32, parse_string, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -24,9 +24,15 @@ pub enum Nla {
     Label(String),
     Broadcast(Vec<u8>),
     Anycast(Vec<u8>),
-    CacheInfo(Vec<u8>),
+    CacheInfo(CacheInfo),
     Multicast(Vec<u8>),
+    /// `IFA_F_*` flags, including the ones that don't fit in the header
     Flags(u32),
+    /// Metric of the prefix route of the address
+    RtPriority(u32),
+    TargetNetnsId(i32),
+    /// `IFAPROT_*`
+    Protocol(u8),
     Other(DefaultNla),
 }
 
@@ -47,10 +53,12 @@ impl nlas::Nla for Nla {
             Label(ref string) => string.as_bytes().len() + 1,
 
             // u32
-            Flags(_) => size_of::<u32>(),
+            Flags(_) | RtPriority(_) | TargetNetnsId(_) => size_of::<u32>(),
+
+            Protocol(_) => size_of::<u8>(),
 
             // Native
-            CacheInfo(ref buffer) => buffer.len(),
+            CacheInfo(ref cache_info) => cache_info.buffer_len(),
 
             // Defaults
             Other(ref attr)  => attr.value_len(),
@@ -67,9 +75,10 @@ impl nlas::Nla for Nla {
                 | Local(ref bytes)
                 | Broadcast(ref bytes)
                 | Anycast(ref bytes)
-                | CacheInfo(ref bytes)
                 | Multicast(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
 
+            CacheInfo(ref cache_info) => cache_info.emit(buffer),
+
             // String
             Label(ref string) => {
                 buffer[..string.len()].copy_from_slice(string.as_bytes());
@@ -77,8 +86,10 @@ impl nlas::Nla for Nla {
             }
 
             // u32
-            Flags(ref value) => NativeEndian::write_u32(buffer, *value),
+            Flags(ref value) | RtPriority(ref value) => NativeEndian::write_u32(buffer, *value),
+            TargetNetnsId(ref value) => NativeEndian::write_i32(buffer, *value),
 
+            Protocol(ref value) => buffer[0] = *value,
 
             // Default
             Other(ref attr) => attr.emit_value(buffer),
@@ -97,6 +108,9 @@ impl nlas::Nla for Nla {
             CacheInfo(_) => IFA_CACHEINFO,
             Multicast(_) => IFA_MULTICAST,
             Flags(_) => IFA_FLAGS,
+            RtPriority(_) => IFA_RT_PRIORITY,
+            TargetNetnsId(_) => IFA_TARGET_NETNSID,
+            Protocol(_) => IFA_PROTO,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -113,9 +127,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             IFA_LABEL => Label(parse_string(payload).context("invalid IFA_LABEL value")?),
             IFA_BROADCAST => Broadcast(payload.to_vec()),
             IFA_ANYCAST => Anycast(payload.to_vec()),
-            IFA_CACHEINFO => CacheInfo(payload.to_vec()),
+            IFA_CACHEINFO => CacheInfo(
+                CacheInfoBuffer::new_checked(payload)
+                    .and_then(|buf| self::CacheInfo::parse(&buf))
+                    .context("invalid IFA_CACHEINFO value")?,
+            ),
             IFA_MULTICAST => Multicast(payload.to_vec()),
             IFA_FLAGS => Flags(parse_u32(payload).context("invalid IFA_FLAGS value")?),
+            IFA_RT_PRIORITY => {
+                RtPriority(parse_u32(payload).context("invalid IFA_RT_PRIORITY value")?)
+            }
+            IFA_TARGET_NETNSID => {
+                TargetNetnsId(parse_i32(payload).context("invalid IFA_TARGET_NETNSID value")?)
+            }
+            IFA_PROTO => Protocol(parse_u8(payload).context("invalid IFA_PROTO value")?),
             kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
         })
     }
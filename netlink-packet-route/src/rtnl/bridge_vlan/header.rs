@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: MIT
+
+use super::{BridgeVlanMessageBuffer, BRIDGE_VLAN_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// High level representation of `struct br_vlan_msg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BridgeVlanHeader {
+    pub family: u8,
+    /// Index of the bridge or bridge port
+    pub ifindex: u32,
+}
+
+impl Emitable for BridgeVlanHeader {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_VLAN_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = BridgeVlanMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_reserved1(0);
+        packet.set_reserved2(0);
+        packet.set_ifindex(self.ifindex);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeVlanMessageBuffer<T>> for BridgeVlanHeader {
+    fn parse(buf: &BridgeVlanMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(BridgeVlanHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+        })
+    }
+}
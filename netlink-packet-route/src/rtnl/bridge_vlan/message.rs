@@ -0,0 +1,50 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::bridge_vlan::Nla,
+    traits::{Emitable, Parseable},
+    BridgeVlanHeader,
+    BridgeVlanMessageBuffer,
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct BridgeVlanMessage {
+    pub header: BridgeVlanHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<BridgeVlanMessageBuffer<&'a T>> for BridgeVlanMessage {
+    fn parse(buf: &BridgeVlanMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: BridgeVlanHeader::parse(buf)
+                .context("failed to parse bridge vlan message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse bridge vlan message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<BridgeVlanMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &BridgeVlanMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for BridgeVlanMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
@@ -0,0 +1,250 @@
+// SPDX-License-Identifier: MIT
+
+use std::convert::TryFrom;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    link::nlas::BridgeVlanInfo,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attributes of `BRIDGE_VLANDB_ENTRY`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanEntryNla {
+    /// First VLAN of the entry and its `BRIDGE_VLAN_INFO_*` flags
+    Info(BridgeVlanInfo),
+    /// Last VLAN of the entry, when it covers a range of VLANs
+    Range(u16),
+    /// `BR_STATE_*` STP state of the VLAN
+    State(u8),
+    TunnelInfo(Vec<VlanTunnelNla>),
+    Stats(Vec<VlanStatsNla>),
+    /// `MDB_RTR_TYPE_*` multicast router mode of the port in the VLAN
+    McastRouter(u8),
+    McastNGroups(u32),
+    McastMaxGroups(u32),
+    NeighSuppress(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for VlanEntryNla {
+    fn value_len(&self) -> usize {
+        use self::VlanEntryNla::*;
+        match self {
+            Info(_) => 4,
+            Range(_) => 2,
+            State(_) | McastRouter(_) | NeighSuppress(_) => 1,
+            TunnelInfo(nlas) => nlas.as_slice().buffer_len(),
+            Stats(nlas) => nlas.as_slice().buffer_len(),
+            McastNGroups(_) | McastMaxGroups(_) => 4,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VlanEntryNla::*;
+        match self {
+            Info(info) => buffer.copy_from_slice(&<[u8; 4]>::from(info)),
+            Range(value) => NativeEndian::write_u16(buffer, *value),
+            State(value) | McastRouter(value) => buffer[0] = *value,
+            NeighSuppress(value) => buffer[0] = *value as u8,
+            TunnelInfo(nlas) => nlas.as_slice().emit(buffer),
+            Stats(nlas) => nlas.as_slice().emit(buffer),
+            McastNGroups(value) | McastMaxGroups(value) => NativeEndian::write_u32(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VlanEntryNla::*;
+        match self {
+            Info(_) => BRIDGE_VLANDB_ENTRY_INFO,
+            Range(_) => BRIDGE_VLANDB_ENTRY_RANGE,
+            State(_) => BRIDGE_VLANDB_ENTRY_STATE,
+            TunnelInfo(_) => BRIDGE_VLANDB_ENTRY_TUNNEL_INFO | NLA_F_NESTED,
+            Stats(_) => BRIDGE_VLANDB_ENTRY_STATS | NLA_F_NESTED,
+            McastRouter(_) => BRIDGE_VLANDB_ENTRY_MCAST_ROUTER,
+            McastNGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS,
+            McastMaxGroups(_) => BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS,
+            NeighSuppress(_) => BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanEntryNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VlanEntryNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_ENTRY_INFO => Info(
+                BridgeVlanInfo::try_from(payload)
+                    .context("invalid BRIDGE_VLANDB_ENTRY_INFO value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_RANGE => {
+                Range(parse_u16(payload).context("invalid BRIDGE_VLANDB_ENTRY_RANGE value")?)
+            }
+            BRIDGE_VLANDB_ENTRY_STATE => {
+                State(parse_u8(payload).context("invalid BRIDGE_VLANDB_ENTRY_STATE value")?)
+            }
+            BRIDGE_VLANDB_ENTRY_TUNNEL_INFO => {
+                let error_msg = "invalid BRIDGE_VLANDB_ENTRY_TUNNEL_INFO value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(VlanTunnelNla::parse(nla).context(error_msg)?);
+                }
+                TunnelInfo(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_STATS => {
+                let error_msg = "invalid BRIDGE_VLANDB_ENTRY_STATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(VlanStatsNla::parse(nla).context(error_msg)?);
+                }
+                Stats(nlas)
+            }
+            BRIDGE_VLANDB_ENTRY_MCAST_ROUTER => McastRouter(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_ENTRY_MCAST_ROUTER value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS => McastNGroups(
+                parse_u32(payload).context("invalid BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS => McastMaxGroups(
+                parse_u32(payload).context("invalid BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS value")?,
+            ),
+            BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS => NeighSuppress(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS value")? != 0,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of `BRIDGE_VLANDB_ENTRY_TUNNEL_INFO`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanTunnelNla {
+    /// Tunnel ID (VNI) mapped to the VLAN
+    Id(u32),
+    /// `RTM_SETLINK` to add the mapping, `RTM_DELLINK` to remove it
+    Cmd(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for VlanTunnelNla {
+    fn value_len(&self) -> usize {
+        match self {
+            VlanTunnelNla::Id(_) | VlanTunnelNla::Cmd(_) => 4,
+            VlanTunnelNla::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            VlanTunnelNla::Id(value) | VlanTunnelNla::Cmd(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            VlanTunnelNla::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            VlanTunnelNla::Id(_) => BRIDGE_VLANDB_TINFO_ID,
+            VlanTunnelNla::Cmd(_) => BRIDGE_VLANDB_TINFO_CMD,
+            VlanTunnelNla::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanTunnelNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_TINFO_ID => VlanTunnelNla::Id(
+                parse_u32(payload).context("invalid BRIDGE_VLANDB_TINFO_ID value")?,
+            ),
+            BRIDGE_VLANDB_TINFO_CMD => VlanTunnelNla::Cmd(
+                parse_u32(payload).context("invalid BRIDGE_VLANDB_TINFO_CMD value")?,
+            ),
+            kind => VlanTunnelNla::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+/// Attributes of `BRIDGE_VLANDB_ENTRY_STATS`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanStatsNla {
+    RxBytes(u64),
+    RxPackets(u64),
+    TxBytes(u64),
+    TxPackets(u64),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for VlanStatsNla {
+    fn value_len(&self) -> usize {
+        use self::VlanStatsNla::*;
+        match self {
+            RxBytes(_) | RxPackets(_) | TxBytes(_) | TxPackets(_) => 8,
+            Pad(bytes) => bytes.len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VlanStatsNla::*;
+        match self {
+            RxBytes(value) | RxPackets(value) | TxBytes(value) | TxPackets(value) => {
+                NativeEndian::write_u64(buffer, *value)
+            }
+            Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VlanStatsNla::*;
+        match self {
+            RxBytes(_) => BRIDGE_VLANDB_STATS_RX_BYTES,
+            RxPackets(_) => BRIDGE_VLANDB_STATS_RX_PACKETS,
+            TxBytes(_) => BRIDGE_VLANDB_STATS_TX_BYTES,
+            TxPackets(_) => BRIDGE_VLANDB_STATS_TX_PACKETS,
+            Pad(_) => BRIDGE_VLANDB_STATS_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanStatsNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VlanStatsNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_STATS_RX_BYTES => {
+                RxBytes(parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_RX_BYTES value")?)
+            }
+            BRIDGE_VLANDB_STATS_RX_PACKETS => RxPackets(
+                parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_RX_PACKETS value")?,
+            ),
+            BRIDGE_VLANDB_STATS_TX_BYTES => {
+                TxBytes(parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_TX_BYTES value")?)
+            }
+            BRIDGE_VLANDB_STATS_TX_PACKETS => TxPackets(
+                parse_u64(payload).context("invalid BRIDGE_VLANDB_STATS_TX_PACKETS value")?,
+            ),
+            BRIDGE_VLANDB_STATS_PAD => Pad(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{mdb::RouterNla, DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u64, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attributes of `BRIDGE_VLANDB_GLOBAL_OPTIONS`. The intervals are in
+/// hundredths of seconds.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum VlanGlobalNla {
+    /// First VLAN the options apply to
+    Id(u16),
+    /// Last VLAN the options apply to, when they cover a range of VLANs
+    Range(u16),
+    McastSnooping(u8),
+    McastIgmpVersion(u8),
+    McastMldVersion(u8),
+    McastLastMemberCnt(u32),
+    McastStartupQueryCnt(u32),
+    McastLastMemberIntvl(u64),
+    Pad(Vec<u8>),
+    McastMembershipIntvl(u64),
+    McastQuerierIntvl(u64),
+    McastQueryIntvl(u64),
+    McastQueryResponseIntvl(u64),
+    McastStartupQueryIntvl(u64),
+    McastQuerier(u8),
+    McastRouterPorts(Vec<RouterNla>),
+    /// `BRIDGE_QUERIER_*` attributes, left undecoded
+    McastQuerierState(Vec<u8>),
+    /// Multiple spanning tree instance the VLAN belongs to
+    Msti(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for VlanGlobalNla {
+    fn value_len(&self) -> usize {
+        use self::VlanGlobalNla::*;
+        match self {
+            Id(_) | Range(_) | Msti(_) => 2,
+            McastSnooping(_) | McastIgmpVersion(_) | McastMldVersion(_) | McastQuerier(_) => 1,
+            McastLastMemberCnt(_) | McastStartupQueryCnt(_) => 4,
+            McastLastMemberIntvl(_)
+            | McastMembershipIntvl(_)
+            | McastQuerierIntvl(_)
+            | McastQueryIntvl(_)
+            | McastQueryResponseIntvl(_)
+            | McastStartupQueryIntvl(_) => 8,
+            Pad(bytes) | McastQuerierState(bytes) => bytes.len(),
+            McastRouterPorts(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::VlanGlobalNla::*;
+        match self {
+            Id(value) | Range(value) | Msti(value) => NativeEndian::write_u16(buffer, *value),
+            McastSnooping(value)
+            | McastIgmpVersion(value)
+            | McastMldVersion(value)
+            | McastQuerier(value) => buffer[0] = *value,
+            McastLastMemberCnt(value) | McastStartupQueryCnt(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            McastLastMemberIntvl(value)
+            | McastMembershipIntvl(value)
+            | McastQuerierIntvl(value)
+            | McastQueryIntvl(value)
+            | McastQueryResponseIntvl(value)
+            | McastStartupQueryIntvl(value) => NativeEndian::write_u64(buffer, *value),
+            Pad(bytes) | McastQuerierState(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            McastRouterPorts(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::VlanGlobalNla::*;
+        match self {
+            Id(_) => BRIDGE_VLANDB_GOPTS_ID,
+            Range(_) => BRIDGE_VLANDB_GOPTS_RANGE,
+            McastSnooping(_) => BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING,
+            McastIgmpVersion(_) => BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION,
+            McastMldVersion(_) => BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION,
+            McastLastMemberCnt(_) => BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT,
+            McastStartupQueryCnt(_) => BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT,
+            McastLastMemberIntvl(_) => BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL,
+            Pad(_) => BRIDGE_VLANDB_GOPTS_PAD,
+            McastMembershipIntvl(_) => BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL,
+            McastQuerierIntvl(_) => BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL,
+            McastQueryIntvl(_) => BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL,
+            McastQueryResponseIntvl(_) => BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL,
+            McastStartupQueryIntvl(_) => BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL,
+            McastQuerier(_) => BRIDGE_VLANDB_GOPTS_MCAST_QUERIER,
+            McastRouterPorts(_) => BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS | NLA_F_NESTED,
+            McastQuerierState(_) => BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE | NLA_F_NESTED,
+            Msti(_) => BRIDGE_VLANDB_GOPTS_MSTI,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VlanGlobalNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::VlanGlobalNla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_GOPTS_ID => {
+                Id(parse_u16(payload).context("invalid BRIDGE_VLANDB_GOPTS_ID value")?)
+            }
+            BRIDGE_VLANDB_GOPTS_RANGE => {
+                Range(parse_u16(payload).context("invalid BRIDGE_VLANDB_GOPTS_RANGE value")?)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING => McastSnooping(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION => McastIgmpVersion(
+                parse_u8(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION => McastMldVersion(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT => McastLastMemberCnt(
+                parse_u32(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT => McastStartupQueryCnt(
+                parse_u32(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL => McastLastMemberIntvl(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_PAD => Pad(payload.to_vec()),
+            BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL => McastMembershipIntvl(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL => McastQuerierIntvl(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL => McastQueryIntvl(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL => McastQueryResponseIntvl(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL => McastStartupQueryIntvl(
+                parse_u64(payload)
+                    .context("invalid BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER => McastQuerier(
+                parse_u8(payload).context("invalid BRIDGE_VLANDB_GOPTS_MCAST_QUERIER value")?,
+            ),
+            BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS => {
+                let error_msg = "invalid BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(RouterNla::parse(nla).context(error_msg)?);
+                }
+                McastRouterPorts(nlas)
+            }
+            BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE => McastQuerierState(payload.to_vec()),
+            BRIDGE_VLANDB_GOPTS_MSTI => {
+                Msti(parse_u16(payload).context("invalid BRIDGE_VLANDB_GOPTS_MSTI value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+
+mod entry;
+pub use self::entry::*;
+
+mod global;
+pub use self::global::*;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// A VLAN, or a range of VLANs sharing the same flags and options
+    Entry(Vec<VlanEntryNla>),
+    /// Bridge-wide options of a VLAN, or of a range of VLANs
+    GlobalOptions(Vec<VlanGlobalNla>),
+    /// `BRIDGE_VLANDB_DUMPF_*` flags. This attribute is only used in dump
+    /// requests: it has the same number as `BRIDGE_VLANDB_ENTRY`, so it is
+    /// parsed as [`Nla::Entry`].
+    DumpFlags(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Entry(nlas) => nlas.as_slice().buffer_len(),
+            GlobalOptions(nlas) => nlas.as_slice().buffer_len(),
+            DumpFlags(_) => 4,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Entry(nlas) => nlas.as_slice().emit(buffer),
+            GlobalOptions(nlas) => nlas.as_slice().emit(buffer),
+            DumpFlags(value) => NativeEndian::write_u32(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => BRIDGE_VLANDB_UNSPEC,
+            Entry(_) => BRIDGE_VLANDB_ENTRY | NLA_F_NESTED,
+            GlobalOptions(_) => BRIDGE_VLANDB_GLOBAL_OPTIONS | NLA_F_NESTED,
+            DumpFlags(_) => BRIDGE_VLANDB_DUMP_FLAGS,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_VLANDB_UNSPEC => Unspec(payload.to_vec()),
+            BRIDGE_VLANDB_ENTRY => {
+                let error_msg = "invalid BRIDGE_VLANDB_ENTRY value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(VlanEntryNla::parse(nla).context(error_msg)?);
+                }
+                Entry(nlas)
+            }
+            BRIDGE_VLANDB_GLOBAL_OPTIONS => {
+                let error_msg = "invalid BRIDGE_VLANDB_GLOBAL_OPTIONS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(VlanGlobalNla::parse(nla).context(error_msg)?);
+                }
+                GlobalOptions(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
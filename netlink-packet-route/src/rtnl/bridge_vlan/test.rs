@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    link::nlas::BridgeVlanInfo,
+    nlas::bridge_vlan::*,
+    traits::{Emitable, Parseable},
+    BridgeVlanHeader,
+    BridgeVlanMessage,
+    BridgeVlanMessageBuffer,
+};
+
+// bridge vlan add dev veth0 vid 1 pvid untagged
+// bridge vlan add dev veth0 vid 10-20
+#[rustfmt::skip]
+static VLAN_ENTRIES: [u8; 56] = [
+    0x07, // family = AF_BRIDGE
+    0x00, // reserved1
+    0x00, 0x00, // reserved2
+    0x05, 0x00, 0x00, 0x00, // ifindex = 5
+
+    0x14, 0x00, 0x01, 0x80, // length = 20, type = BRIDGE_VLANDB_ENTRY | NLA_F_NESTED
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = BRIDGE_VLANDB_ENTRY_INFO
+            0x06, 0x00, // flags = BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED
+            0x01, 0x00, // vid = 1
+        0x05, 0x00, 0x03, 0x00, // length = 5, type = BRIDGE_VLANDB_ENTRY_STATE
+            0x03, 0x00, 0x00, 0x00, // BR_STATE_FORWARDING
+
+    0x1c, 0x00, 0x01, 0x80, // length = 28, type = BRIDGE_VLANDB_ENTRY | NLA_F_NESTED
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = BRIDGE_VLANDB_ENTRY_INFO
+            0x00, 0x00, // flags
+            0x0a, 0x00, // vid = 10
+        0x06, 0x00, 0x02, 0x00, // length = 6, type = BRIDGE_VLANDB_ENTRY_RANGE
+            0x14, 0x00, 0x00, 0x00, // 20
+        0x05, 0x00, 0x03, 0x00, // length = 5, type = BRIDGE_VLANDB_ENTRY_STATE
+            0x03, 0x00, 0x00, 0x00, // BR_STATE_FORWARDING
+];
+
+#[test]
+fn parse_vlan_entries() {
+    let expected = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AF_BRIDGE as u8,
+            ifindex: 5,
+        },
+        nlas: vec![
+            Nla::Entry(vec![
+                VlanEntryNla::Info(BridgeVlanInfo {
+                    flags: BRIDGE_VLAN_INFO_PVID | BRIDGE_VLAN_INFO_UNTAGGED,
+                    vid: 1,
+                }),
+                VlanEntryNla::State(BR_STATE_FORWARDING),
+            ]),
+            Nla::Entry(vec![
+                VlanEntryNla::Info(BridgeVlanInfo { flags: 0, vid: 10 }),
+                VlanEntryNla::Range(20),
+                VlanEntryNla::State(BR_STATE_FORWARDING),
+            ]),
+        ],
+    };
+    let actual = BridgeVlanMessage::parse(&BridgeVlanMessageBuffer::new(&VLAN_ENTRIES)).unwrap();
+    assert_eq!(actual, expected);
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(&buf[..], &VLAN_ENTRIES[..]);
+}
+
+#[test]
+fn emit_parse_global_options() {
+    let msg = BridgeVlanMessage {
+        header: BridgeVlanHeader {
+            family: AF_BRIDGE as u8,
+            ifindex: 3,
+        },
+        nlas: vec![Nla::GlobalOptions(vec![
+            VlanGlobalNla::Id(100),
+            VlanGlobalNla::Range(110),
+            VlanGlobalNla::McastSnooping(1),
+            VlanGlobalNla::McastIgmpVersion(3),
+            VlanGlobalNla::McastQueryIntvl(12500),
+            VlanGlobalNla::Msti(2),
+        ])],
+    };
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    let parsed = BridgeVlanMessage::parse(&BridgeVlanMessageBuffer::new(&buf)).unwrap();
+    assert_eq!(parsed, msg);
+}
@@ -3,24 +3,44 @@
 use crate::{
     constants::*,
     traits::{Parseable, ParseableParametrized},
+    AddrLabelMessage,
+    AddrLabelMessageBuffer,
     AddressHeader,
     AddressMessage,
     AddressMessageBuffer,
+    BridgeVlanMessage,
+    BridgeVlanMessageBuffer,
+    CacheReportMessage,
+    CacheReportMessageBuffer,
     DecodeError,
     LinkMessage,
     LinkMessageBuffer,
+    MdbMessage,
+    MdbMessageBuffer,
+    NdUserOptMessage,
+    NdUserOptMessageBuffer,
     NeighbourMessage,
     NeighbourMessageBuffer,
     NeighbourTableMessage,
     NeighbourTableMessageBuffer,
+    NetconfMessage,
+    NetconfMessageBuffer,
+    NexthopMessage,
+    NexthopMessageBuffer,
     NsidMessage,
     NsidMessageBuffer,
+    PrefixMessage,
+    PrefixMessageBuffer,
     RouteHeader,
     RouteMessage,
     RouteMessageBuffer,
     RtnlMessage,
     RuleMessage,
     RuleMessageBuffer,
+    StatsMessage,
+    StatsMessageBuffer,
+    TcActionMessage,
+    TcActionMessageBuffer,
     TcMessage,
     TcMessageBuffer,
 };
@@ -88,6 +108,22 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
+            // Multicast and anycast address messages
+            RTM_NEWMULTICAST | RTM_GETMULTICAST | RTM_DELMULTICAST |
+            RTM_NEWANYCAST | RTM_GETANYCAST | RTM_DELANYCAST => {
+                let err = "invalid multicast or anycast address message";
+                let msg = AddressMessage::parse(&AddressMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWMULTICAST => NewMulticastAddress(msg),
+                    RTM_DELMULTICAST => DelMulticastAddress(msg),
+                    RTM_GETMULTICAST => GetMulticastAddress(msg),
+                    RTM_NEWANYCAST => NewAnycastAddress(msg),
+                    RTM_DELANYCAST => DelAnycastAddress(msg),
+                    RTM_GETANYCAST => GetAnycastAddress(msg),
+                    _ => unreachable!(),
+                }
+            }
+
             // Neighbour messages
             RTM_NEWNEIGH | RTM_GETNEIGH | RTM_DELNEIGH => {
                 let err = "invalid neighbour message";
@@ -189,6 +225,107 @@ impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<RtnlMessageBuffer<&'a T>
                 }
             }
 
+            // Nexthop Messages
+            RTM_NEWNEXTHOP | RTM_GETNEXTHOP | RTM_DELNEXTHOP => {
+                let err = "invalid nexthop message";
+                let msg = NexthopMessage::parse(&NexthopMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWNEXTHOP => NewNexthop(msg),
+                    RTM_DELNEXTHOP => DelNexthop(msg),
+                    RTM_GETNEXTHOP => GetNexthop(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Netconf Messages
+            RTM_NEWNETCONF | RTM_GETNETCONF | RTM_DELNETCONF => {
+                let err = "invalid netconf message";
+                let msg = NetconfMessage::parse(&NetconfMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWNETCONF => NewNetconf(msg),
+                    RTM_DELNETCONF => DelNetconf(msg),
+                    RTM_GETNETCONF => GetNetconf(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Stats Messages
+            RTM_NEWSTATS | RTM_GETSTATS => {
+                let err = "invalid stats message";
+                let msg = StatsMessage::parse(&StatsMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWSTATS => NewStats(msg),
+                    RTM_GETSTATS => GetStats(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // MDB Messages
+            RTM_NEWMDB | RTM_GETMDB | RTM_DELMDB => {
+                let err = "invalid mdb message";
+                let msg = MdbMessage::parse(&MdbMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWMDB => NewMdb(msg),
+                    RTM_DELMDB => DelMdb(msg),
+                    RTM_GETMDB => GetMdb(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Bridge VLAN Messages
+            RTM_NEWVLAN | RTM_GETVLAN | RTM_DELVLAN => {
+                let err = "invalid bridge vlan message";
+                let msg = BridgeVlanMessage::parse(&BridgeVlanMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWVLAN => NewBridgeVlan(msg),
+                    RTM_DELVLAN => DelBridgeVlan(msg),
+                    RTM_GETVLAN => GetBridgeVlan(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // TC Action Messages
+            RTM_NEWACTION | RTM_GETACTION | RTM_DELACTION => {
+                let err = "invalid tc action message";
+                let msg = TcActionMessage::parse(&TcActionMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWACTION => NewTrafficAction(msg),
+                    RTM_DELACTION => DelTrafficAction(msg),
+                    RTM_GETACTION => GetTrafficAction(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Address Label Messages
+            RTM_NEWADDRLABEL | RTM_GETADDRLABEL | RTM_DELADDRLABEL => {
+                let err = "invalid address label message";
+                let msg = AddrLabelMessage::parse(&AddrLabelMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?;
+                match message_type {
+                    RTM_NEWADDRLABEL => NewAddrLabel(msg),
+                    RTM_DELADDRLABEL => DelAddrLabel(msg),
+                    RTM_GETADDRLABEL => GetAddrLabel(msg),
+                    _ => unreachable!(),
+                }
+            }
+
+            // Prefix Messages
+            RTM_NEWPREFIX => {
+                let err = "invalid prefix message";
+                NewPrefix(PrefixMessage::parse(&PrefixMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?)
+            }
+
+            // ND User Option Messages
+            RTM_NEWNDUSEROPT => {
+                let err = "invalid nd user option message";
+                NewNdUserOpt(NdUserOptMessage::parse(&NdUserOptMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?)
+            }
+
+            // Multicast Routing Cache Reports
+            RTM_NEWCACHEREPORT => {
+                let err = "invalid cache report message";
+                NewCacheReport(CacheReportMessage::parse(&CacheReportMessageBuffer::new_checked(&buf.inner()).context(err)?).context(err)?)
+            }
+
             _ => return Err(format!("Unknown message type: {}", message_type).into()),
         };
         Ok(message)
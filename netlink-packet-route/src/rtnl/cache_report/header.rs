@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT
+
+use super::{CacheReportMessageBuffer, CACHE_REPORT_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// High level representation of the `struct rtgenmsg` header of the
+/// `RTM_NEWCACHEREPORT` messages
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CacheReportHeader {
+    /// `RTNL_FAMILY_IPMR` or `RTNL_FAMILY_IP6MR`
+    pub family: u8,
+}
+
+impl Emitable for CacheReportHeader {
+    fn buffer_len(&self) -> usize {
+        CACHE_REPORT_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = CacheReportMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<CacheReportMessageBuffer<T>> for CacheReportHeader {
+    fn parse(buf: &CacheReportMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(CacheReportHeader {
+            family: buf.family(),
+        })
+    }
+}
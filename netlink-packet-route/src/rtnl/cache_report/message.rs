@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::cache_report::Nla,
+    traits::{Emitable, Parseable},
+    CacheReportHeader,
+    CacheReportMessageBuffer,
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct CacheReportMessage {
+    pub header: CacheReportHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<CacheReportMessageBuffer<&'a T>> for CacheReportMessage {
+    fn parse(buf: &CacheReportMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: CacheReportHeader::parse(buf)
+                .context("failed to parse cache report message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse cache report message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<CacheReportMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &CacheReportMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for CacheReportMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv4Addr;
+
+    use crate::{
+        nlas::cache_report::Nla,
+        traits::{Emitable, ParseableParametrized},
+        CacheReportHeader,
+        CacheReportMessage,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        IGMPMSG_NOCACHE,
+        RTM_NEWCACHEREPORT,
+        RTNL_FAMILY_IPMR,
+    };
+
+    // A packet from 10.0.0.1 to 239.1.1.1 received on VIF 1 without matching
+    // entry in the cache
+    #[rustfmt::skip]
+    static NOCACHE_REPORT: [u8; 72] = [
+        0x48, 0x00, 0x00, 0x00, // length = 72
+        0x60, 0x00, // message type = 96 = RTM_NEWCACHEREPORT
+        0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // seq number
+        0x00, 0x00, 0x00, 0x00, // pid
+
+        // cache report message
+        0x80, // family = RTNL_FAMILY_IPMR
+        0x00, 0x00, 0x00, // padding
+        0x05, 0x00, 0x01, 0x00, // length = 5, type = IPMRA_CREPORT_MSGTYPE
+            0x01, 0x00, 0x00, 0x00, // IGMPMSG_NOCACHE + padding
+        0x08, 0x00, 0x02, 0x00, // length = 8, type = IPMRA_CREPORT_VIF_ID
+            0x01, 0x00, 0x00, 0x00, // 1
+        0x08, 0x00, 0x03, 0x00, // length = 8, type = IPMRA_CREPORT_SRC_ADDR
+            0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+        0x08, 0x00, 0x04, 0x00, // length = 8, type = IPMRA_CREPORT_DST_ADDR
+            0xef, 0x01, 0x01, 0x01, // 239.1.1.1
+        0x08, 0x00, 0x06, 0x00, // length = 8, type = IPMRA_CREPORT_TABLE
+            0xfe, 0x00, 0x00, 0x00, // RT_TABLE_MAIN
+        0x0c, 0x00, 0x05, 0x00, // length = 12, type = IPMRA_CREPORT_PKT
+            0x13, 0x88, 0x13, 0x88, 0x00, 0x08, 0x00, 0x00, // UDP header
+    ];
+
+    fn nocache_report() -> CacheReportMessage {
+        CacheReportMessage {
+            header: CacheReportHeader {
+                family: RTNL_FAMILY_IPMR,
+            },
+            nlas: vec![
+                Nla::MsgType(IGMPMSG_NOCACHE),
+                Nla::VifId(1),
+                Nla::SrcAddr(Ipv4Addr::new(10, 0, 0, 1).into()),
+                Nla::DstAddr(Ipv4Addr::new(239, 1, 1, 1).into()),
+                Nla::Table(254),
+                Nla::Packet(vec![0x13, 0x88, 0x13, 0x88, 0x00, 0x08, 0x00, 0x00]),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_nocache_report() {
+        let nl_buffer = NetlinkBuffer::new(&NOCACHE_REPORT).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWCACHEREPORT).unwrap();
+        assert_eq!(RtnlMessage::NewCacheReport(nocache_report()), actual);
+    }
+
+    #[test]
+    fn emit_nocache_report() {
+        let msg = nocache_report();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &NOCACHE_REPORT[16..]);
+    }
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::{parse_ip, parse_u32, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Attributes of the IPv4 (`IPMRA_CREPORT_*`) and IPv6 (`IP6MRA_CREPORT_*`)
+/// cache reports. Both families share the same attribute numbers.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Reason of the report: `IGMPMSG_*` for IPv4, `MRT6MSG_*` for IPv6
+    MsgType(u8),
+    /// Virtual interface the packet was received on
+    VifId(u32),
+    SrcAddr(IpAddr),
+    DstAddr(IpAddr),
+    /// The packet that triggered the report. For `IGMPMSG_WHOLEPKT` and
+    /// `MRT6MSG_WHOLEPKT` reports, this is the whole packet to encapsulate
+    /// in a PIM register message.
+    Packet(Vec<u8>),
+    /// Multicast routing table of the entry (IPv4 only)
+    Table(u32),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) | Packet(ref bytes) => bytes.len(),
+            MsgType(_) => 1,
+            VifId(_) | Table(_) => 4,
+            SrcAddr(ref addr) | DstAddr(ref addr) => match addr {
+                IpAddr::V4(_) => 4,
+                IpAddr::V6(_) => 16,
+            },
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) | Packet(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            MsgType(value) => buffer[0] = value,
+            VifId(value) | Table(value) => NativeEndian::write_u32(buffer, value),
+            SrcAddr(ref addr) | DstAddr(ref addr) => match addr {
+                IpAddr::V4(addr) => buffer.copy_from_slice(&addr.octets()),
+                IpAddr::V6(addr) => buffer.copy_from_slice(&addr.octets()),
+            },
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Unspec(_) => IPMRA_CREPORT_UNSPEC,
+            MsgType(_) => IPMRA_CREPORT_MSGTYPE,
+            VifId(_) => IPMRA_CREPORT_VIF_ID,
+            SrcAddr(_) => IPMRA_CREPORT_SRC_ADDR,
+            DstAddr(_) => IPMRA_CREPORT_DST_ADDR,
+            Packet(_) => IPMRA_CREPORT_PKT,
+            Table(_) => IPMRA_CREPORT_TABLE,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IPMRA_CREPORT_UNSPEC => Unspec(payload.to_vec()),
+            IPMRA_CREPORT_MSGTYPE => {
+                MsgType(parse_u8(payload).context("invalid IPMRA_CREPORT_MSGTYPE value")?)
+            }
+            IPMRA_CREPORT_VIF_ID => {
+                VifId(parse_u32(payload).context("invalid IPMRA_CREPORT_VIF_ID value")?)
+            }
+            IPMRA_CREPORT_SRC_ADDR => {
+                SrcAddr(parse_ip(payload).context("invalid IPMRA_CREPORT_SRC_ADDR value")?)
+            }
+            IPMRA_CREPORT_DST_ADDR => {
+                DstAddr(parse_ip(payload).context("invalid IPMRA_CREPORT_DST_ADDR value")?)
+            }
+            IPMRA_CREPORT_PKT => Packet(payload.to_vec()),
+            IPMRA_CREPORT_TABLE => {
+                Table(parse_u32(payload).context("invalid IPMRA_CREPORT_TABLE value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
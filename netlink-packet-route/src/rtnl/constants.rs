@@ -32,7 +32,11 @@ pub const RTM_NEWACTION: u16 = 48;
 pub const RTM_DELACTION: u16 = 49;
 pub const RTM_GETACTION: u16 = 50;
 pub const RTM_NEWPREFIX: u16 = 52;
+pub const RTM_NEWMULTICAST: u16 = 56;
+pub const RTM_DELMULTICAST: u16 = 57;
 pub const RTM_GETMULTICAST: u16 = 58;
+pub const RTM_NEWANYCAST: u16 = 60;
+pub const RTM_DELANYCAST: u16 = 61;
 pub const RTM_GETANYCAST: u16 = 62;
 pub const RTM_NEWNEIGHTBL: u16 = 64;
 pub const RTM_GETNEIGHTBL: u16 = 66;
@@ -58,8 +62,14 @@ pub const RTM_NEWCACHEREPORT: u16 = 96;
 pub const RTM_NEWCHAIN: u16 = 100;
 pub const RTM_DELCHAIN: u16 = 101;
 pub const RTM_GETCHAIN: u16 = 102;
+pub const RTM_NEWNEXTHOP: u16 = 104;
+pub const RTM_DELNEXTHOP: u16 = 105;
+pub const RTM_GETNEXTHOP: u16 = 106;
 pub const RTM_NEWLINKPROP: u16 = 108;
 pub const RTM_DELLINKPROP: u16 = 109;
+pub const RTM_NEWVLAN: u16 = 112;
+pub const RTM_DELVLAN: u16 = 113;
+pub const RTM_GETVLAN: u16 = 114;
 
 /// Unknown route
 pub const RTN_UNSPEC: u8 = 0;
@@ -269,6 +279,8 @@ pub const AF_PHONET: u16 = libc::AF_PHONET as u16;
 pub const AF_IEEE802154: u16 = libc::AF_IEEE802154 as u16;
 pub const AF_CAIF: u16 = libc::AF_CAIF as u16;
 pub const AF_ALG: u16 = libc::AF_ALG as u16;
+// libc only defines AF_MPLS for some targets
+pub const AF_MPLS: u16 = 28;
 
 pub const NETNSA_NONE: u16 = 0;
 pub const NETNSA_NSID: u16 = 1;
@@ -304,8 +316,13 @@ pub const NTF_MASTER: u8 = 4;
 pub const NTF_PROXY: u8 = 8;
 pub const NTF_EXT_LEARNED: u8 = 16;
 pub const NTF_OFFLOADED: u8 = 32;
+pub const NTF_STICKY: u8 = 64;
 pub const NTF_ROUTER: u8 = 128;
 
+// Extended neighbour cache entry flags (`NDA_FLAGS_EXT`)
+pub const NTF_EXT_MANAGED: u32 = 1;
+pub const NTF_EXT_LOCKED: u32 = 2;
+
 pub const TCA_UNSPEC: u16 = 0;
 pub const TCA_KIND: u16 = 1;
 pub const TCA_OPTIONS: u16 = 2;
@@ -368,6 +385,10 @@ pub const RTA_EXPIRES: u16 = 23;
 pub const RTA_PAD: u16 = 24;
 pub const RTA_UID: u16 = 25;
 pub const RTA_TTL_PROPAGATE: u16 = 26;
+pub const RTA_IP_PROTO: u16 = 27;
+pub const RTA_SPORT: u16 = 28;
+pub const RTA_DPORT: u16 = 29;
+pub const RTA_NH_ID: u16 = 30;
 
 pub const RTAX_UNSPEC: u16 = 0;
 pub const RTAX_LOCK: u16 = 1;
@@ -387,6 +408,7 @@ pub const RTAX_INITRWND: u16 = 14;
 pub const RTAX_QUICKACK: u16 = 15;
 pub const RTAX_CC_ALGO: u16 = 16;
 pub const RTAX_FASTOPEN_NO_COOKIE: u16 = 17;
+pub const RTAX_MAX: u16 = RTAX_FASTOPEN_NO_COOKIE;
 
 pub const IFLA_INFO_UNSPEC: u16 = 0;
 pub const IFLA_INFO_KIND: u16 = 1;
@@ -401,10 +423,12 @@ pub const BRIDGE_FLAGS_SELF: u16 = 2; /* Bridge command to/from lowerdev */
 
 pub const IFLA_BRIDGE_VLAN_INFO: u16 = 2;
 pub const BRIDGE_VLAN_INFO_MASTER: u16 = 1;
-pub const BRIDGE_VLAN_INFO_PVID: u16 = 4;
-pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 8;
-pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 16;
-pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 32;
+pub const BRIDGE_VLAN_INFO_PVID: u16 = 2;
+pub const BRIDGE_VLAN_INFO_UNTAGGED: u16 = 4;
+pub const BRIDGE_VLAN_INFO_RANGE_BEGIN: u16 = 8;
+pub const BRIDGE_VLAN_INFO_RANGE_END: u16 = 16;
+pub const BRIDGE_VLAN_INFO_BRENTRY: u16 = 32;
+pub const BRIDGE_VLAN_INFO_ONLY_OPTS: u16 = 64;
 
 pub const IFLA_BR_UNSPEC: u16 = 0;
 pub const IFLA_BR_FORWARD_DELAY: u16 = 1;
@@ -555,6 +579,13 @@ pub const IFA_ANYCAST: u16 = 5;
 pub const IFA_CACHEINFO: u16 = 6;
 pub const IFA_MULTICAST: u16 = 7;
 pub const IFA_FLAGS: u16 = 8;
+pub const IFA_RT_PRIORITY: u16 = 9;
+pub const IFA_TARGET_NETNSID: u16 = 10;
+pub const IFA_PROTO: u16 = 11;
+
+pub const IFAL_UNSPEC: u16 = 0;
+pub const IFAL_ADDRESS: u16 = 1;
+pub const IFAL_LABEL: u16 = 2;
 
 pub const IFLA_UNSPEC: u16 = 0;
 pub const IFLA_ADDRESS: u16 = 1;
@@ -689,6 +720,19 @@ pub const NDA_IFINDEX: u16 = 8;
 pub const NDA_MASTER: u16 = 9;
 pub const NDA_LINK_NETNSID: u16 = 10;
 pub const NDA_SRC_VNI: u16 = 11;
+pub const NDA_PROTOCOL: u16 = 12;
+pub const NDA_NH_ID: u16 = 13;
+pub const NDA_FDB_EXT_ATTRS: u16 = 14;
+pub const NDA_FLAGS_EXT: u16 = 15;
+pub const NDA_NDM_STATE_MASK: u16 = 16;
+pub const NDA_NDM_FLAGS_MASK: u16 = 17;
+
+pub const NFEA_UNSPEC: u16 = 0;
+pub const NFEA_ACTIVITY_NOTIFY: u16 = 1;
+pub const NFEA_DONT_REFRESH: u16 = 2;
+
+pub const FDB_NOTIFY_BIT: u8 = 1;
+pub const FDB_NOTIFY_INACTIVE_BIT: u8 = 2;
 
 /// see `https://github.com/torvalds/linux/blob/master/include/uapi/linux/fib_rules.h`
 
@@ -790,8 +834,18 @@ pub const IFA_F_NOPREFIXROUTE: u32 = 512;
 pub const IFA_F_MCAUTOJOIN: u32 = 1024;
 pub const IFA_F_STABLE_PRIVACY: u32 = 2048;
 
-// pub const RTNL_FAMILY_IPMR: int = 128;
-// pub const RTNL_FAMILY_IP6MR: int = 129;
+/// Lifetime of the addresses that never expire
+pub const INFINITY_LIFE_TIME: u32 = 0xffff_ffff;
+
+/// Origin of an address (`IFA_PROTO`)
+pub const IFAPROT_UNSPEC: u8 = 0;
+pub const IFAPROT_KERNEL_LO: u8 = 1;
+pub const IFAPROT_KERNEL_RA: u8 = 2;
+pub const IFAPROT_KERNEL_LL: u8 = 3;
+
+/// Address families of the IPv4 and IPv6 multicast forwarding cache entries
+pub const RTNL_FAMILY_IPMR: u8 = 128;
+pub const RTNL_FAMILY_IP6MR: u8 = 129;
 // pub const RTNL_FAMILY_MAX: int = 129;
 // pub const RTA_ALIGNTO: int = 4;
 //
@@ -804,11 +858,11 @@ pub const RTNH_F_UNRESOLVED: u8 = 32;
 // pub const RTNH_COMPARE_MASK: int = 25;
 // pub const RTNH_ALIGNTO: int = 4;
 // pub const RTNETLINK_HAVE_PEERINFO: int = 1;
-// pub const RTAX_FEATURE_ECN: int = 1;
-// pub const RTAX_FEATURE_SACK: int = 2;
-// pub const RTAX_FEATURE_TIMESTAMP: int = 4;
-// pub const RTAX_FEATURE_ALLFRAG: int = 8;
-// pub const RTAX_FEATURE_MASK: int = 15;
+pub const RTAX_FEATURE_ECN: u32 = 1;
+pub const RTAX_FEATURE_SACK: u32 = 2;
+pub const RTAX_FEATURE_TIMESTAMP: u32 = 4;
+pub const RTAX_FEATURE_ALLFRAG: u32 = 8;
+pub const RTAX_FEATURE_MASK: u32 = 15;
 pub const TCM_IFINDEX_MAGIC_BLOCK: u32 = 0xffff_ffff;
 // pub const TCA_FLAG_LARGE_DUMP_ON: int = 1;
 
@@ -953,19 +1007,162 @@ pub const IFLA_VXLAN_GPE: u16 = 27;
 pub const IFLA_VXLAN_TTL_INHERIT: u16 = 28;
 pub const IFLA_VXLAN_DF: u16 = 29;
 pub const __IFLA_VXLAN_MAX: u16 = 30;
-//
-// pub const IFLA_GENEVE_UNSPEC: int = 0;
-// pub const IFLA_GENEVE_ID: int = 1;
-// pub const IFLA_GENEVE_REMOTE: int = 2;
-// pub const IFLA_GENEVE_TTL: int = 3;
-// pub const IFLA_GENEVE_TOS: int = 4;
-// pub const IFLA_GENEVE_PORT: int = 5;
-// pub const IFLA_GENEVE_COLLECT_METADATA: int = 6;
-// pub const IFLA_GENEVE_REMOTE6: int = 7;
-// pub const IFLA_GENEVE_UDP_CSUM: int = 8;
-// pub const IFLA_GENEVE_UDP_ZERO_CSUM6_TX: int = 9;
-// pub const IFLA_GENEVE_UDP_ZERO_CSUM6_RX: int = 10;
-// pub const IFLA_GENEVE_LABEL: int = 11;
+
+pub const IFLA_GRE_UNSPEC: u16 = 0;
+pub const IFLA_GRE_LINK: u16 = 1;
+pub const IFLA_GRE_IFLAGS: u16 = 2;
+pub const IFLA_GRE_OFLAGS: u16 = 3;
+pub const IFLA_GRE_IKEY: u16 = 4;
+pub const IFLA_GRE_OKEY: u16 = 5;
+pub const IFLA_GRE_LOCAL: u16 = 6;
+pub const IFLA_GRE_REMOTE: u16 = 7;
+pub const IFLA_GRE_TTL: u16 = 8;
+pub const IFLA_GRE_TOS: u16 = 9;
+pub const IFLA_GRE_PMTUDISC: u16 = 10;
+pub const IFLA_GRE_ENCAP_LIMIT: u16 = 11;
+pub const IFLA_GRE_FLOWINFO: u16 = 12;
+pub const IFLA_GRE_FLAGS: u16 = 13;
+pub const IFLA_GRE_ENCAP_TYPE: u16 = 14;
+pub const IFLA_GRE_ENCAP_FLAGS: u16 = 15;
+pub const IFLA_GRE_ENCAP_SPORT: u16 = 16;
+pub const IFLA_GRE_ENCAP_DPORT: u16 = 17;
+pub const IFLA_GRE_COLLECT_METADATA: u16 = 18;
+pub const IFLA_GRE_IGNORE_DF: u16 = 19;
+pub const IFLA_GRE_FWMARK: u16 = 20;
+pub const IFLA_GRE_ERSPAN_INDEX: u16 = 21;
+pub const IFLA_GRE_ERSPAN_VER: u16 = 22;
+pub const IFLA_GRE_ERSPAN_DIR: u16 = 23;
+pub const IFLA_GRE_ERSPAN_HWID: u16 = 24;
+
+/// GRE header flags, in host byte order, of `IFLA_GRE_IFLAGS` and `IFLA_GRE_OFLAGS`
+pub const GRE_CSUM: u16 = 0x8000;
+pub const GRE_ROUTING: u16 = 0x4000;
+pub const GRE_KEY: u16 = 0x2000;
+pub const GRE_SEQ: u16 = 0x1000;
+pub const GRE_STRICT: u16 = 0x0800;
+pub const GRE_REC: u16 = 0x0700;
+pub const GRE_ACK: u16 = 0x0080;
+pub const GRE_FLAGS: u16 = 0x0078;
+pub const GRE_VERSION: u16 = 0x0007;
+
+/// Flags of the IPv6 tunnels (`IFLA_GRE_FLAGS`)
+pub const IP6_TNL_F_IGN_ENCAP_LIMIT: u32 = 0x1;
+pub const IP6_TNL_F_USE_ORIG_TCLASS: u32 = 0x2;
+pub const IP6_TNL_F_USE_ORIG_FLOWLABEL: u32 = 0x4;
+pub const IP6_TNL_F_MIP6_DEV: u32 = 0x8;
+pub const IP6_TNL_F_RCV_DSCP_COPY: u32 = 0x10;
+pub const IP6_TNL_F_USE_ORIG_FWMARK: u32 = 0x20;
+pub const IP6_TNL_F_ALLOW_LOCAL_REMOTE: u32 = 0x40;
+
+/// UDP encapsulations of the IP tunnels (`IFLA_GRE_ENCAP_TYPE`)
+pub const TUNNEL_ENCAP_NONE: u16 = 0;
+pub const TUNNEL_ENCAP_FOU: u16 = 1;
+pub const TUNNEL_ENCAP_GUE: u16 = 2;
+pub const TUNNEL_ENCAP_MPLS: u16 = 3;
+
+pub const TUNNEL_ENCAP_FLAG_CSUM: u16 = 1;
+pub const TUNNEL_ENCAP_FLAG_CSUM6: u16 = 2;
+pub const TUNNEL_ENCAP_FLAG_REMCSUM: u16 = 4;
+
+/// Direction of the mirrored traffic of an ERSPAN version 2 tunnel
+pub const ERSPAN_DIR_INGRESS: u8 = 0;
+pub const ERSPAN_DIR_EGRESS: u8 = 1;
+
+pub const IFLA_IPTUN_UNSPEC: u16 = 0;
+pub const IFLA_IPTUN_LINK: u16 = 1;
+pub const IFLA_IPTUN_LOCAL: u16 = 2;
+pub const IFLA_IPTUN_REMOTE: u16 = 3;
+pub const IFLA_IPTUN_TTL: u16 = 4;
+pub const IFLA_IPTUN_TOS: u16 = 5;
+pub const IFLA_IPTUN_ENCAP_LIMIT: u16 = 6;
+pub const IFLA_IPTUN_FLOWINFO: u16 = 7;
+pub const IFLA_IPTUN_FLAGS: u16 = 8;
+pub const IFLA_IPTUN_PROTO: u16 = 9;
+pub const IFLA_IPTUN_PMTUDISC: u16 = 10;
+pub const IFLA_IPTUN_6RD_PREFIX: u16 = 11;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIX: u16 = 12;
+pub const IFLA_IPTUN_6RD_PREFIXLEN: u16 = 13;
+pub const IFLA_IPTUN_6RD_RELAY_PREFIXLEN: u16 = 14;
+pub const IFLA_IPTUN_ENCAP_TYPE: u16 = 15;
+pub const IFLA_IPTUN_ENCAP_FLAGS: u16 = 16;
+pub const IFLA_IPTUN_ENCAP_SPORT: u16 = 17;
+pub const IFLA_IPTUN_ENCAP_DPORT: u16 = 18;
+pub const IFLA_IPTUN_COLLECT_METADATA: u16 = 19;
+pub const IFLA_IPTUN_FWMARK: u16 = 20;
+
+/// Flag of the SIT tunnels (`IFLA_IPTUN_FLAGS`)
+pub const SIT_ISATAP: u16 = 0x0001;
+
+pub const IFLA_VTI_UNSPEC: u16 = 0;
+pub const IFLA_VTI_LINK: u16 = 1;
+pub const IFLA_VTI_IKEY: u16 = 2;
+pub const IFLA_VTI_OKEY: u16 = 3;
+pub const IFLA_VTI_LOCAL: u16 = 4;
+pub const IFLA_VTI_REMOTE: u16 = 5;
+pub const IFLA_VTI_FWMARK: u16 = 6;
+
+pub const IFLA_XFRM_UNSPEC: u16 = 0;
+pub const IFLA_XFRM_LINK: u16 = 1;
+pub const IFLA_XFRM_IF_ID: u16 = 2;
+pub const IFLA_XFRM_COLLECT_METADATA: u16 = 3;
+
+pub const IFLA_GENEVE_UNSPEC: u16 = 0;
+pub const IFLA_GENEVE_ID: u16 = 1;
+pub const IFLA_GENEVE_REMOTE: u16 = 2;
+pub const IFLA_GENEVE_TTL: u16 = 3;
+pub const IFLA_GENEVE_TOS: u16 = 4;
+pub const IFLA_GENEVE_PORT: u16 = 5;
+pub const IFLA_GENEVE_COLLECT_METADATA: u16 = 6;
+pub const IFLA_GENEVE_REMOTE6: u16 = 7;
+pub const IFLA_GENEVE_UDP_CSUM: u16 = 8;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_TX: u16 = 9;
+pub const IFLA_GENEVE_UDP_ZERO_CSUM6_RX: u16 = 10;
+pub const IFLA_GENEVE_LABEL: u16 = 11;
+pub const IFLA_GENEVE_TTL_INHERIT: u16 = 12;
+pub const IFLA_GENEVE_DF: u16 = 13;
+pub const IFLA_GENEVE_INNER_PROTO_INHERIT: u16 = 14;
+
+pub const GENEVE_DF_UNSET: u8 = 0;
+pub const GENEVE_DF_SET: u8 = 1;
+pub const GENEVE_DF_INHERIT: u8 = 2;
+
+pub const IFLA_BAREUDP_UNSPEC: u16 = 0;
+pub const IFLA_BAREUDP_PORT: u16 = 1;
+pub const IFLA_BAREUDP_ETHERTYPE: u16 = 2;
+pub const IFLA_BAREUDP_SRCPORT_MIN: u16 = 3;
+pub const IFLA_BAREUDP_MULTIPROTO_MODE: u16 = 4;
+
+pub const IFLA_MACSEC_UNSPEC: u16 = 0;
+pub const IFLA_MACSEC_SCI: u16 = 1;
+pub const IFLA_MACSEC_PORT: u16 = 2;
+pub const IFLA_MACSEC_ICV_LEN: u16 = 3;
+pub const IFLA_MACSEC_CIPHER_SUITE: u16 = 4;
+pub const IFLA_MACSEC_WINDOW: u16 = 5;
+pub const IFLA_MACSEC_ENCODING_SA: u16 = 6;
+pub const IFLA_MACSEC_ENCRYPT: u16 = 7;
+pub const IFLA_MACSEC_PROTECT: u16 = 8;
+pub const IFLA_MACSEC_INC_SCI: u16 = 9;
+pub const IFLA_MACSEC_ES: u16 = 10;
+pub const IFLA_MACSEC_SCB: u16 = 11;
+pub const IFLA_MACSEC_REPLAY_PROTECT: u16 = 12;
+pub const IFLA_MACSEC_VALIDATION: u16 = 13;
+pub const IFLA_MACSEC_PAD: u16 = 14;
+pub const IFLA_MACSEC_OFFLOAD: u16 = 15;
+
+pub const MACSEC_CIPHER_ID_GCM_AES_128: u64 = 0x0080_c200_0100_0001;
+pub const MACSEC_CIPHER_ID_GCM_AES_256: u64 = 0x0080_c200_0100_0002;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_128: u64 = 0x0080_c200_0100_0003;
+pub const MACSEC_CIPHER_ID_GCM_AES_XPN_256: u64 = 0x0080_c200_0100_0004;
+pub const MACSEC_DEFAULT_CIPHER_ID: u64 = MACSEC_CIPHER_ID_GCM_AES_128;
+pub const MACSEC_DEFAULT_ICV_LEN: u8 = 16;
+
+pub const MACSEC_VALIDATE_DISABLED: u8 = 0;
+pub const MACSEC_VALIDATE_CHECK: u8 = 1;
+pub const MACSEC_VALIDATE_STRICT: u8 = 2;
+
+pub const MACSEC_OFFLOAD_OFF: u8 = 0;
+pub const MACSEC_OFFLOAD_PHY: u8 = 1;
+pub const MACSEC_OFFLOAD_MAC: u8 = 2;
 //
 // pub const IFLA_PPP_UNSPEC: int = 0;
 // pub const IFLA_PPP_DEV_FD: int = 1;
@@ -1048,10 +1245,18 @@ pub const IFLA_BOND_AD_INFO_PARTNER_MAC: u16 = 5;
 //
 // pub const IFLA_VF_VLAN_INFO_UNSPEC: int = 0;
 // pub const IFLA_VF_VLAN_INFO: int = 1;
-//
-// pub const NDUSEROPT_UNSPEC: int = 0;
-// pub const NDUSEROPT_SRCADDR: int = 1;
-//
+
+pub const NDUSEROPT_UNSPEC: u16 = 0;
+pub const NDUSEROPT_SRCADDR: u16 = 1;
+
+/// Types of the neighbour discovery options relayed by `RTM_NEWNDUSEROPT`
+pub const ND_OPT_PREFIX_INFO: u8 = 3;
+pub const ND_OPT_ROUTE_INFO: u8 = 24;
+pub const ND_OPT_RDNSS: u8 = 25;
+pub const ND_OPT_DNSSL: u8 = 31;
+pub const ND_OPT_CAPTIVE_PORTAL: u8 = 37;
+pub const ND_OPT_PREF64: u8 = 38;
+
 pub const RTNLGRP_NONE: u32 = 0;
 pub const RTNLGRP_LINK: u32 = 1;
 pub const RTNLGRP_NOTIFY: u32 = 2;
@@ -1084,6 +1289,8 @@ pub const RTNLGRP_NSID: u32 = 28;
 pub const RTNLGRP_MPLS_NETCONF: u32 = 29;
 pub const RTNLGRP_IPV4_MROUTE_R: u32 = 30;
 pub const RTNLGRP_IPV6_MROUTE_R: u32 = 31;
+pub const RTNLGRP_NEXTHOP: u32 = 32;
+pub const RTNLGRP_BRVLAN: u32 = 33;
 //
 // pub const IFLA_VF_LINK_STATE_AUTO: int = 0;
 // pub const IFLA_VF_LINK_STATE_ENABLE: int = 1;
@@ -1200,10 +1407,13 @@ pub const IFLA_XDP_PROG_ID: u32 = 4;
 //
 // #[allow(overflowing_literals)]
 // pub const RT_TABLE_MAX: int = 0xffff_ffff;
-//
-// pub const PREFIX_UNSPEC: int = 0;
-// pub const PREFIX_ADDRESS: int = 1;
-// pub const PREFIX_CACHEINFO: int = 2;
+
+pub const PREFIX_UNSPEC: u16 = 0;
+pub const PREFIX_ADDRESS: u16 = 1;
+pub const PREFIX_CACHEINFO: u16 = 2;
+
+pub const IF_PREFIX_ONLINK: u8 = 1;
+pub const IF_PREFIX_AUTOCONF: u8 = 2;
 
 pub const LWTUNNEL_ENCAP_NONE: u16 = 0;
 pub const LWTUNNEL_ENCAP_MPLS: u16 = 1;
@@ -1214,7 +1424,348 @@ pub const LWTUNNEL_ENCAP_SEG6: u16 = 5;
 pub const LWTUNNEL_ENCAP_BPF: u16 = 6;
 pub const LWTUNNEL_ENCAP_SEG6_LOCAL: u16 = 7;
 pub const LWTUNNEL_ENCAP_RPL: u16 = 8;
+pub const LWTUNNEL_ENCAP_IOAM6: u16 = 9;
+pub const LWTUNNEL_ENCAP_XFRM: u16 = 10;
 
 pub const MPLS_IPTUNNEL_UNSPEC: u16 = 0;
 pub const MPLS_IPTUNNEL_DST: u16 = 1;
 pub const MPLS_IPTUNNEL_TTL: u16 = 2;
+
+pub const LWTUNNEL_IP_UNSPEC: u16 = 0;
+pub const LWTUNNEL_IP_ID: u16 = 1;
+pub const LWTUNNEL_IP_DST: u16 = 2;
+pub const LWTUNNEL_IP_SRC: u16 = 3;
+pub const LWTUNNEL_IP_TTL: u16 = 4;
+pub const LWTUNNEL_IP_TOS: u16 = 5;
+pub const LWTUNNEL_IP_FLAGS: u16 = 6;
+pub const LWTUNNEL_IP_PAD: u16 = 7;
+pub const LWTUNNEL_IP_OPTS: u16 = 8;
+
+pub const LWTUNNEL_IP6_UNSPEC: u16 = 0;
+pub const LWTUNNEL_IP6_ID: u16 = 1;
+pub const LWTUNNEL_IP6_DST: u16 = 2;
+pub const LWTUNNEL_IP6_SRC: u16 = 3;
+pub const LWTUNNEL_IP6_HOPLIMIT: u16 = 4;
+pub const LWTUNNEL_IP6_TC: u16 = 5;
+pub const LWTUNNEL_IP6_FLAGS: u16 = 6;
+pub const LWTUNNEL_IP6_PAD: u16 = 7;
+pub const LWTUNNEL_IP6_OPTS: u16 = 8;
+
+pub const LWT_BPF_UNSPEC: u16 = 0;
+pub const LWT_BPF_IN: u16 = 1;
+pub const LWT_BPF_OUT: u16 = 2;
+pub const LWT_BPF_XMIT: u16 = 3;
+pub const LWT_BPF_XMIT_HEADROOM: u16 = 4;
+
+pub const LWT_BPF_PROG_UNSPEC: u16 = 0;
+pub const LWT_BPF_PROG_FD: u16 = 1;
+pub const LWT_BPF_PROG_NAME: u16 = 2;
+
+pub const SEG6_IPTUNNEL_UNSPEC: u16 = 0;
+pub const SEG6_IPTUNNEL_SRH: u16 = 1;
+
+pub const SEG6_IPTUN_MODE_INLINE: u32 = 0;
+pub const SEG6_IPTUN_MODE_ENCAP: u32 = 1;
+pub const SEG6_IPTUN_MODE_L2ENCAP: u32 = 2;
+pub const SEG6_IPTUN_MODE_ENCAP_RED: u32 = 3;
+pub const SEG6_IPTUN_MODE_L2ENCAP_RED: u32 = 4;
+
+pub const SEG6_LOCAL_UNSPEC: u16 = 0;
+pub const SEG6_LOCAL_ACTION: u16 = 1;
+pub const SEG6_LOCAL_SRH: u16 = 2;
+pub const SEG6_LOCAL_TABLE: u16 = 3;
+pub const SEG6_LOCAL_NH4: u16 = 4;
+pub const SEG6_LOCAL_NH6: u16 = 5;
+pub const SEG6_LOCAL_IIF: u16 = 6;
+pub const SEG6_LOCAL_OIF: u16 = 7;
+pub const SEG6_LOCAL_BPF: u16 = 8;
+pub const SEG6_LOCAL_VRFTABLE: u16 = 9;
+pub const SEG6_LOCAL_COUNTERS: u16 = 10;
+pub const SEG6_LOCAL_FLAVORS: u16 = 11;
+
+pub const SEG6_LOCAL_ACTION_UNSPEC: u32 = 0;
+pub const SEG6_LOCAL_ACTION_END: u32 = 1;
+pub const SEG6_LOCAL_ACTION_END_X: u32 = 2;
+pub const SEG6_LOCAL_ACTION_END_T: u32 = 3;
+pub const SEG6_LOCAL_ACTION_END_DX2: u32 = 4;
+pub const SEG6_LOCAL_ACTION_END_DX6: u32 = 5;
+pub const SEG6_LOCAL_ACTION_END_DX4: u32 = 6;
+pub const SEG6_LOCAL_ACTION_END_DT6: u32 = 7;
+pub const SEG6_LOCAL_ACTION_END_DT4: u32 = 8;
+pub const SEG6_LOCAL_ACTION_END_B6: u32 = 9;
+pub const SEG6_LOCAL_ACTION_END_B6_ENCAP: u32 = 10;
+pub const SEG6_LOCAL_ACTION_END_BM: u32 = 11;
+pub const SEG6_LOCAL_ACTION_END_S: u32 = 12;
+pub const SEG6_LOCAL_ACTION_END_AS: u32 = 13;
+pub const SEG6_LOCAL_ACTION_END_AM: u32 = 14;
+pub const SEG6_LOCAL_ACTION_END_BPF: u32 = 15;
+pub const SEG6_LOCAL_ACTION_END_DT46: u32 = 16;
+
+pub const IPV6_SRCRT_TYPE_4: u8 = 4;
+
+pub const SR6_FLAG1_PROTECTED: u8 = 1 << 6;
+pub const SR6_FLAG1_OAM: u8 = 1 << 5;
+pub const SR6_FLAG1_ALERT: u8 = 1 << 4;
+pub const SR6_FLAG1_HMAC: u8 = 1 << 3;
+
+pub const IOAM6_IPTUNNEL_UNSPEC: u16 = 0;
+pub const IOAM6_IPTUNNEL_MODE: u16 = 1;
+pub const IOAM6_IPTUNNEL_DST: u16 = 2;
+pub const IOAM6_IPTUNNEL_TRACE: u16 = 3;
+pub const IOAM6_IPTUNNEL_FREQ_K: u16 = 4;
+pub const IOAM6_IPTUNNEL_FREQ_N: u16 = 5;
+
+pub const IOAM6_IPTUNNEL_MODE_INLINE: u8 = 1;
+pub const IOAM6_IPTUNNEL_MODE_ENCAP: u8 = 2;
+pub const IOAM6_IPTUNNEL_MODE_AUTO: u8 = 3;
+
+pub const NHA_UNSPEC: u16 = 0;
+pub const NHA_ID: u16 = 1;
+pub const NHA_GROUP: u16 = 2;
+pub const NHA_GROUP_TYPE: u16 = 3;
+pub const NHA_BLACKHOLE: u16 = 4;
+pub const NHA_OIF: u16 = 5;
+pub const NHA_GATEWAY: u16 = 6;
+pub const NHA_ENCAP_TYPE: u16 = 7;
+pub const NHA_ENCAP: u16 = 8;
+pub const NHA_GROUPS: u16 = 9;
+pub const NHA_MASTER: u16 = 10;
+pub const NHA_FDB: u16 = 11;
+pub const NHA_RES_GROUP: u16 = 12;
+pub const NHA_RES_BUCKET: u16 = 13;
+
+pub const NEXTHOP_GRP_TYPE_MPATH: u16 = 0;
+pub const NEXTHOP_GRP_TYPE_RES: u16 = 1;
+
+pub const NHA_RES_GROUP_PAD: u16 = 0;
+pub const NHA_RES_GROUP_BUCKETS: u16 = 1;
+pub const NHA_RES_GROUP_IDLE_TIMER: u16 = 2;
+pub const NHA_RES_GROUP_UNBALANCED_TIMER: u16 = 3;
+pub const NHA_RES_GROUP_UNBALANCED_TIME: u16 = 4;
+
+pub const NHA_RES_BUCKET_PAD: u16 = 0;
+pub const NHA_RES_BUCKET_INDEX: u16 = 1;
+pub const NHA_RES_BUCKET_IDLE_TIME: u16 = 2;
+pub const NHA_RES_BUCKET_NH_ID: u16 = 3;
+
+pub const NETCONFA_UNSPEC: u16 = 0;
+pub const NETCONFA_IFINDEX: u16 = 1;
+pub const NETCONFA_FORWARDING: u16 = 2;
+pub const NETCONFA_RP_FILTER: u16 = 3;
+pub const NETCONFA_MC_FORWARDING: u16 = 4;
+pub const NETCONFA_PROXY_NEIGH: u16 = 5;
+pub const NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN: u16 = 6;
+pub const NETCONFA_INPUT: u16 = 7;
+pub const NETCONFA_BC_FORWARDING: u16 = 8;
+
+pub const NETCONFA_IFINDEX_ALL: i32 = -1;
+pub const NETCONFA_IFINDEX_DEFAULT: i32 = -2;
+
+pub const IFLA_STATS_UNSPEC: u16 = 0;
+pub const IFLA_STATS_LINK_64: u16 = 1;
+pub const IFLA_STATS_LINK_XSTATS: u16 = 2;
+pub const IFLA_STATS_LINK_XSTATS_SLAVE: u16 = 3;
+pub const IFLA_STATS_LINK_OFFLOAD_XSTATS: u16 = 4;
+pub const IFLA_STATS_AF_SPEC: u16 = 5;
+
+// Bits of the if_stats_msg filter mask, see IFLA_STATS_FILTER_BIT()
+pub const IFLA_STATS_FILTER_BIT_LINK_64: u32 = 1 << (IFLA_STATS_LINK_64 - 1);
+pub const IFLA_STATS_FILTER_BIT_LINK_XSTATS: u32 = 1 << (IFLA_STATS_LINK_XSTATS - 1);
+pub const IFLA_STATS_FILTER_BIT_LINK_XSTATS_SLAVE: u32 = 1 << (IFLA_STATS_LINK_XSTATS_SLAVE - 1);
+pub const IFLA_STATS_FILTER_BIT_LINK_OFFLOAD_XSTATS: u32 =
+    1 << (IFLA_STATS_LINK_OFFLOAD_XSTATS - 1);
+pub const IFLA_STATS_FILTER_BIT_AF_SPEC: u32 = 1 << (IFLA_STATS_AF_SPEC - 1);
+
+pub const LINK_XSTATS_TYPE_UNSPEC: u16 = 0;
+pub const LINK_XSTATS_TYPE_BRIDGE: u16 = 1;
+pub const LINK_XSTATS_TYPE_BOND: u16 = 2;
+
+pub const BRIDGE_XSTATS_UNSPEC: u16 = 0;
+pub const BRIDGE_XSTATS_VLAN: u16 = 1;
+pub const BRIDGE_XSTATS_MCAST: u16 = 2;
+pub const BRIDGE_XSTATS_PAD: u16 = 3;
+pub const BRIDGE_XSTATS_STP: u16 = 4;
+
+pub const BOND_XSTATS_UNSPEC: u16 = 0;
+pub const BOND_XSTATS_3AD: u16 = 1;
+pub const BOND_XSTATS_PAD: u16 = 2;
+
+pub const BOND_3AD_STAT_LACPDU_RX: u16 = 0;
+pub const BOND_3AD_STAT_LACPDU_TX: u16 = 1;
+pub const BOND_3AD_STAT_LACPDU_UNKNOWN_RX: u16 = 2;
+pub const BOND_3AD_STAT_LACPDU_ILLEGAL_RX: u16 = 3;
+pub const BOND_3AD_STAT_MARKER_RX: u16 = 4;
+pub const BOND_3AD_STAT_MARKER_TX: u16 = 5;
+pub const BOND_3AD_STAT_MARKER_RESP_RX: u16 = 6;
+pub const BOND_3AD_STAT_MARKER_RESP_TX: u16 = 7;
+pub const BOND_3AD_STAT_MARKER_UNKNOWN_RX: u16 = 8;
+pub const BOND_3AD_STAT_PAD: u16 = 9;
+
+pub const IFLA_OFFLOAD_XSTATS_UNSPEC: u16 = 0;
+pub const IFLA_OFFLOAD_XSTATS_CPU_HIT: u16 = 1;
+pub const IFLA_OFFLOAD_XSTATS_HW_S_INFO: u16 = 2;
+pub const IFLA_OFFLOAD_XSTATS_L3_STATS: u16 = 3;
+
+pub const IFLA_OFFLOAD_XSTATS_HW_S_INFO_UNSPEC: u16 = 0;
+pub const IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST: u16 = 1;
+pub const IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED: u16 = 2;
+
+pub const MPLS_STATS_UNSPEC: u16 = 0;
+pub const MPLS_STATS_LINK: u16 = 1;
+
+pub const MDBA_UNSPEC: u16 = 0;
+pub const MDBA_MDB: u16 = 1;
+pub const MDBA_ROUTER: u16 = 2;
+
+pub const MDBA_MDB_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY: u16 = 1;
+
+pub const MDBA_MDB_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_MDB_ENTRY_INFO: u16 = 1;
+
+pub const MDBA_MDB_EATTR_UNSPEC: u16 = 0;
+pub const MDBA_MDB_EATTR_TIMER: u16 = 1;
+pub const MDBA_MDB_EATTR_SRC_LIST: u16 = 2;
+pub const MDBA_MDB_EATTR_GROUP_MODE: u16 = 3;
+pub const MDBA_MDB_EATTR_SOURCE: u16 = 4;
+pub const MDBA_MDB_EATTR_RTPROT: u16 = 5;
+
+pub const MDBA_MDB_SRCLIST_UNSPEC: u16 = 0;
+pub const MDBA_MDB_SRCLIST_ENTRY: u16 = 1;
+
+pub const MDBA_MDB_SRCATTR_UNSPEC: u16 = 0;
+pub const MDBA_MDB_SRCATTR_ADDRESS: u16 = 1;
+pub const MDBA_MDB_SRCATTR_TIMER: u16 = 2;
+
+pub const MDBA_ROUTER_UNSPEC: u16 = 0;
+pub const MDBA_ROUTER_PORT: u16 = 1;
+
+pub const MDBA_ROUTER_PATTR_UNSPEC: u16 = 0;
+pub const MDBA_ROUTER_PATTR_TIMER: u16 = 1;
+pub const MDBA_ROUTER_PATTR_TYPE: u16 = 2;
+pub const MDBA_ROUTER_PATTR_INET_TIMER: u16 = 3;
+pub const MDBA_ROUTER_PATTR_INET6_TIMER: u16 = 4;
+pub const MDBA_ROUTER_PATTR_VID: u16 = 5;
+
+pub const MDBA_SET_ENTRY_UNSPEC: u16 = 0;
+pub const MDBA_SET_ENTRY: u16 = 1;
+pub const MDBA_SET_ENTRY_ATTRS: u16 = 2;
+
+pub const MDBE_ATTR_UNSPEC: u16 = 0;
+pub const MDBE_ATTR_SOURCE: u16 = 1;
+pub const MDBE_ATTR_SRC_LIST: u16 = 2;
+pub const MDBE_ATTR_GROUP_MODE: u16 = 3;
+pub const MDBE_ATTR_RTPROT: u16 = 4;
+
+pub const MDBE_SRC_LIST_UNSPEC: u16 = 0;
+pub const MDBE_SRC_LIST_ENTRY: u16 = 1;
+
+pub const MDBE_SRCATTR_UNSPEC: u16 = 0;
+pub const MDBE_SRCATTR_ADDRESS: u16 = 1;
+
+pub const MDB_TEMPORARY: u8 = 0;
+pub const MDB_PERMANENT: u8 = 1;
+
+pub const MDB_FLAGS_OFFLOAD: u8 = 1 << 0;
+pub const MDB_FLAGS_FAST_LEAVE: u8 = 1 << 1;
+pub const MDB_FLAGS_STAR_EXCL: u8 = 1 << 2;
+pub const MDB_FLAGS_BLOCKED: u8 = 1 << 3;
+pub const MDB_FLAGS_OFFLOAD_FAILED: u8 = 1 << 4;
+
+pub const MDB_RTR_TYPE_DISABLED: u8 = 0;
+pub const MDB_RTR_TYPE_TEMP_QUERY: u8 = 1;
+pub const MDB_RTR_TYPE_PERM: u8 = 2;
+pub const MDB_RTR_TYPE_TEMP: u8 = 3;
+
+pub const MCAST_EXCLUDE: u8 = 0;
+pub const MCAST_INCLUDE: u8 = 1;
+
+pub const ETH_P_IP: u16 = 0x0800;
+pub const ETH_P_IPV6: u16 = 0x86dd;
+
+pub const BRIDGE_VLANDB_DUMP_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_DUMP_FLAGS: u16 = 1;
+
+pub const BRIDGE_VLANDB_DUMPF_STATS: u32 = 1 << 0;
+pub const BRIDGE_VLANDB_DUMPF_GLOBAL: u32 = 1 << 1;
+
+pub const BRIDGE_VLANDB_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_ENTRY: u16 = 1;
+pub const BRIDGE_VLANDB_GLOBAL_OPTIONS: u16 = 2;
+
+pub const BRIDGE_VLANDB_ENTRY_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_ENTRY_INFO: u16 = 1;
+pub const BRIDGE_VLANDB_ENTRY_RANGE: u16 = 2;
+pub const BRIDGE_VLANDB_ENTRY_STATE: u16 = 3;
+pub const BRIDGE_VLANDB_ENTRY_TUNNEL_INFO: u16 = 4;
+pub const BRIDGE_VLANDB_ENTRY_STATS: u16 = 5;
+pub const BRIDGE_VLANDB_ENTRY_MCAST_ROUTER: u16 = 6;
+pub const BRIDGE_VLANDB_ENTRY_MCAST_N_GROUPS: u16 = 7;
+pub const BRIDGE_VLANDB_ENTRY_MCAST_MAX_GROUPS: u16 = 8;
+pub const BRIDGE_VLANDB_ENTRY_NEIGH_SUPPRESS: u16 = 9;
+
+pub const BRIDGE_VLANDB_TINFO_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_TINFO_ID: u16 = 1;
+pub const BRIDGE_VLANDB_TINFO_CMD: u16 = 2;
+
+pub const BRIDGE_VLANDB_STATS_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_STATS_RX_BYTES: u16 = 1;
+pub const BRIDGE_VLANDB_STATS_RX_PACKETS: u16 = 2;
+pub const BRIDGE_VLANDB_STATS_TX_BYTES: u16 = 3;
+pub const BRIDGE_VLANDB_STATS_TX_PACKETS: u16 = 4;
+pub const BRIDGE_VLANDB_STATS_PAD: u16 = 5;
+
+pub const BRIDGE_VLANDB_GOPTS_UNSPEC: u16 = 0;
+pub const BRIDGE_VLANDB_GOPTS_ID: u16 = 1;
+pub const BRIDGE_VLANDB_GOPTS_RANGE: u16 = 2;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_SNOOPING: u16 = 3;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_IGMP_VERSION: u16 = 4;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_MLD_VERSION: u16 = 5;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_CNT: u16 = 6;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_CNT: u16 = 7;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_LAST_MEMBER_INTVL: u16 = 8;
+pub const BRIDGE_VLANDB_GOPTS_PAD: u16 = 9;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_MEMBERSHIP_INTVL: u16 = 10;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_INTVL: u16 = 11;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_QUERY_INTVL: u16 = 12;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_QUERY_RESPONSE_INTVL: u16 = 13;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_STARTUP_QUERY_INTVL: u16 = 14;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER: u16 = 15;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_ROUTER_PORTS: u16 = 16;
+pub const BRIDGE_VLANDB_GOPTS_MCAST_QUERIER_STATE: u16 = 17;
+pub const BRIDGE_VLANDB_GOPTS_MSTI: u16 = 18;
+
+pub const BR_STATE_DISABLED: u8 = 0;
+pub const BR_STATE_LISTENING: u8 = 1;
+pub const BR_STATE_LEARNING: u8 = 2;
+pub const BR_STATE_FORWARDING: u8 = 3;
+pub const BR_STATE_BLOCKING: u8 = 4;
+
+/// Maximum number of IPv4 (resp. IPv6) multicast virtual interfaces
+pub const MAXVIFS: usize = 32;
+pub const MAXMIFS: usize = 32;
+
+pub const IPMRA_CREPORT_UNSPEC: u16 = 0;
+pub const IPMRA_CREPORT_MSGTYPE: u16 = 1;
+pub const IPMRA_CREPORT_VIF_ID: u16 = 2;
+pub const IPMRA_CREPORT_SRC_ADDR: u16 = 3;
+pub const IPMRA_CREPORT_DST_ADDR: u16 = 4;
+pub const IPMRA_CREPORT_PKT: u16 = 5;
+pub const IPMRA_CREPORT_TABLE: u16 = 6;
+
+pub const IP6MRA_CREPORT_UNSPEC: u16 = 0;
+pub const IP6MRA_CREPORT_MSGTYPE: u16 = 1;
+pub const IP6MRA_CREPORT_MIF_ID: u16 = 2;
+pub const IP6MRA_CREPORT_SRC_ADDR: u16 = 3;
+pub const IP6MRA_CREPORT_DST_ADDR: u16 = 4;
+pub const IP6MRA_CREPORT_PKT: u16 = 5;
+
+pub const IGMPMSG_NOCACHE: u8 = 1;
+pub const IGMPMSG_WRONGVIF: u8 = 2;
+pub const IGMPMSG_WHOLEPKT: u8 = 3;
+pub const IGMPMSG_WRVIFWHOLE: u8 = 4;
+
+pub const MRT6MSG_NOCACHE: u8 = 1;
+pub const MRT6MSG_WRONGMIF: u8 = 2;
+pub const MRT6MSG_WHOLEPKT: u8 = 3;
+pub const MRT6MSG_WRMIFWHOLE: u8 = 4;
@@ -0,0 +1,82 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u16_be},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// `IFLA_INFO_DATA` attributes of the BAREUDP links. The port and ethertype are given in
+/// host byte order.
+// https://elixir.bootlin.com/linux/v6.1/source/drivers/net/bareudp.c#L554
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoBareUdp {
+    /// UDP destination port
+    Port(u16),
+    /// Ethertype of the tunneled packets, e.g. `ETH_P_MPLS_UC` (0x8847) or `ETH_P_IP`
+    /// (0x0800)
+    EtherType(u16),
+    /// Lowest UDP source port of the sent packets
+    SourcePortMin(u16),
+    /// Also carry `ETH_P_MPLS_MC` (resp. `ETH_P_IPV6`) packets on an `ETH_P_MPLS_UC` (resp.
+    /// `ETH_P_IP`) link
+    MultiprotoMode,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoBareUdp {
+    fn value_len(&self) -> usize {
+        use self::InfoBareUdp::*;
+        match self {
+            Port(_) | EtherType(_) | SourcePortMin(_) => 2,
+            MultiprotoMode => 0,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoBareUdp::*;
+        match self {
+            Port(value) | EtherType(value) => BigEndian::write_u16(buffer, *value),
+            SourcePortMin(value) => NativeEndian::write_u16(buffer, *value),
+            MultiprotoMode => {}
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoBareUdp::*;
+        match self {
+            Port(_) => IFLA_BAREUDP_PORT,
+            EtherType(_) => IFLA_BAREUDP_ETHERTYPE,
+            SourcePortMin(_) => IFLA_BAREUDP_SRCPORT_MIN,
+            MultiprotoMode => IFLA_BAREUDP_MULTIPROTO_MODE,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoBareUdp {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoBareUdp::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_BAREUDP_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_BAREUDP_PORT value")?)
+            }
+            IFLA_BAREUDP_ETHERTYPE => {
+                EtherType(parse_u16_be(payload).context("invalid IFLA_BAREUDP_ETHERTYPE value")?)
+            }
+            IFLA_BAREUDP_SRCPORT_MIN => {
+                SourcePortMin(parse_u16(payload).context("invalid IFLA_BAREUDP_SRCPORT_MIN value")?)
+            }
+            IFLA_BAREUDP_MULTIPROTO_MODE => MultiprotoMode,
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
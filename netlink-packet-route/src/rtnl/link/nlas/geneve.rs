@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// `IFLA_INFO_DATA` attributes of the GENEVE links. The port and flow label are given in host
+/// byte order.
+// https://elixir.bootlin.com/linux/v6.1/source/drivers/net/geneve.c#L1272
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoGeneve {
+    /// Virtual network identifier, 24 bits
+    Id(u32),
+    Remote(Ipv4Addr),
+    Remote6(Ipv6Addr),
+    Ttl(u8),
+    Tos(u8),
+    /// UDP destination port, 6081 by default
+    Port(u16),
+    /// The link is used by lightweight tunnel routes or BPF programs
+    CollectMetadata,
+    /// Checksum the UDP packets sent over IPv4 (1) or not (0)
+    UdpCsum(u8),
+    /// Don't checksum the UDP packets sent over IPv6 (1)
+    UdpZeroCsum6Tx(u8),
+    /// Accept the UDP packets received over IPv6 without checksum (1)
+    UdpZeroCsum6Rx(u8),
+    /// Flow label of the packets sent over IPv6
+    Label(u32),
+    /// Inherit the TTL of the packets sent from the inner packet (1)
+    TtlInherit(u8),
+    /// `GENEVE_DF_*` DF bit of the packets sent over IPv4
+    Df(u8),
+    /// The link carries layer 3 packets, whose protocol is given by the GENEVE header
+    InnerProtoInherit,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoGeneve {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoGeneve::*;
+        match self {
+            CollectMetadata | InnerProtoInherit => 0,
+            Ttl(_)
+                | Tos(_)
+                | UdpCsum(_)
+                | UdpZeroCsum6Tx(_)
+                | UdpZeroCsum6Rx(_)
+                | TtlInherit(_)
+                | Df(_)
+            => 1,
+            Port(_) => 2,
+            Id(_) | Remote(_) | Label(_) => 4,
+            Remote6(_) => 16,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoGeneve::*;
+        match self {
+            CollectMetadata | InnerProtoInherit => {}
+            Ttl(value)
+                | Tos(value)
+                | UdpCsum(value)
+                | UdpZeroCsum6Tx(value)
+                | UdpZeroCsum6Rx(value)
+                | TtlInherit(value)
+                | Df(value)
+            => buffer[0] = *value,
+            Port(value) => BigEndian::write_u16(buffer, *value),
+            Id(value) => NativeEndian::write_u32(buffer, *value),
+            Label(value) => BigEndian::write_u32(buffer, *value),
+            Remote(addr) => buffer.copy_from_slice(&addr.octets()),
+            Remote6(addr) => buffer.copy_from_slice(&addr.octets()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoGeneve::*;
+        match self {
+            Id(_) => IFLA_GENEVE_ID,
+            Remote(_) => IFLA_GENEVE_REMOTE,
+            Remote6(_) => IFLA_GENEVE_REMOTE6,
+            Ttl(_) => IFLA_GENEVE_TTL,
+            Tos(_) => IFLA_GENEVE_TOS,
+            Port(_) => IFLA_GENEVE_PORT,
+            CollectMetadata => IFLA_GENEVE_COLLECT_METADATA,
+            UdpCsum(_) => IFLA_GENEVE_UDP_CSUM,
+            UdpZeroCsum6Tx(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_TX,
+            UdpZeroCsum6Rx(_) => IFLA_GENEVE_UDP_ZERO_CSUM6_RX,
+            Label(_) => IFLA_GENEVE_LABEL,
+            TtlInherit(_) => IFLA_GENEVE_TTL_INHERIT,
+            Df(_) => IFLA_GENEVE_DF,
+            InnerProtoInherit => IFLA_GENEVE_INNER_PROTO_INHERIT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGeneve {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoGeneve::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_GENEVE_ID => Id(parse_u32(payload).context("invalid IFLA_GENEVE_ID value")?),
+            IFLA_GENEVE_REMOTE => Remote(Ipv4Addr::from(
+                parse_u32_be(payload).context("invalid IFLA_GENEVE_REMOTE value")?,
+            )),
+            IFLA_GENEVE_REMOTE6 => Remote6(Ipv6Addr::from(
+                parse_ipv6(payload).context("invalid IFLA_GENEVE_REMOTE6 value")?,
+            )),
+            IFLA_GENEVE_TTL => Ttl(parse_u8(payload).context("invalid IFLA_GENEVE_TTL value")?),
+            IFLA_GENEVE_TOS => Tos(parse_u8(payload).context("invalid IFLA_GENEVE_TOS value")?),
+            IFLA_GENEVE_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_GENEVE_PORT value")?)
+            }
+            IFLA_GENEVE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GENEVE_UDP_CSUM => {
+                UdpCsum(parse_u8(payload).context("invalid IFLA_GENEVE_UDP_CSUM value")?)
+            }
+            IFLA_GENEVE_UDP_ZERO_CSUM6_TX => UdpZeroCsum6Tx(
+                parse_u8(payload).context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_TX value")?,
+            ),
+            IFLA_GENEVE_UDP_ZERO_CSUM6_RX => UdpZeroCsum6Rx(
+                parse_u8(payload).context("invalid IFLA_GENEVE_UDP_ZERO_CSUM6_RX value")?,
+            ),
+            IFLA_GENEVE_LABEL => {
+                Label(parse_u32_be(payload).context("invalid IFLA_GENEVE_LABEL value")?)
+            }
+            IFLA_GENEVE_TTL_INHERIT => {
+                TtlInherit(parse_u8(payload).context("invalid IFLA_GENEVE_TTL_INHERIT value")?)
+            }
+            IFLA_GENEVE_DF => Df(parse_u8(payload).context("invalid IFLA_GENEVE_DF value")?),
+            IFLA_GENEVE_INNER_PROTO_INHERIT => InnerProtoInherit,
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,233 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// `IFLA_INFO_DATA` attributes of the GRE, GRETAP, IP6GRE, IP6GRETAP, ERSPAN and IP6ERSPAN
+/// links. The flags, keys and ports are given in host byte order.
+// https://elixir.bootlin.com/linux/v6.1/source/net/ipv4/ip_gre.c#L1431
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoGre {
+    /// Index of the underlying link
+    Link(u32),
+    /// `GRE_*` flags of the received packets
+    IFlags(u16),
+    /// `GRE_*` flags of the sent packets
+    OFlags(u16),
+    /// Key of the received packets, used when `IFlags` has `GRE_KEY`
+    IKey(u32),
+    /// Key of the sent packets, used when `OFlags` has `GRE_KEY`
+    OKey(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    Ttl(u8),
+    Tos(u8),
+    /// Path MTU discovery (1) or not (0)
+    PathMtuDiscovery(u8),
+    /// Tunnel encapsulation limit of an IPv6 tunnel
+    EncapLimit(u8),
+    /// Flow label (and traffic class) of an IPv6 tunnel
+    FlowInfo(u32),
+    /// `IP6_TNL_F_*` flags of an IPv6 tunnel
+    Flags(u32),
+    /// `TUNNEL_ENCAP_*` UDP encapsulation
+    EncapType(u16),
+    /// `TUNNEL_ENCAP_FLAG_*`
+    EncapFlags(u16),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
+    /// The tunnel is used by lightweight tunnel routes or BPF programs
+    CollectMetadata,
+    IgnoreDf(u8),
+    FwMark(u32),
+    /// ERSPAN version 1 port index
+    ErspanIndex(u32),
+    /// ERSPAN version, 1 or 2
+    ErspanVersion(u8),
+    /// ERSPAN version 2 direction, `ERSPAN_DIR_*`
+    ErspanDirection(u8),
+    /// ERSPAN version 2 hardware id
+    ErspanHwid(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoGre {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoGre::*;
+        match self {
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | PathMtuDiscovery(_)
+                | EncapLimit(_)
+                | IgnoreDf(_)
+                | ErspanVersion(_)
+                | ErspanDirection(_)
+            => 1,
+            IFlags(_)
+                | OFlags(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSourcePort(_)
+                | EncapDestinationPort(_)
+                | ErspanHwid(_)
+            => 2,
+            Link(_)
+                | IKey(_)
+                | OKey(_)
+                | FlowInfo(_)
+                | Flags(_)
+                | FwMark(_)
+                | ErspanIndex(_)
+            => 4,
+            Local(IpAddr::V4(_)) | Remote(IpAddr::V4(_)) => 4,
+            Local(IpAddr::V6(_)) | Remote(IpAddr::V6(_)) => 16,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoGre::*;
+        match self {
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | PathMtuDiscovery(value)
+                | EncapLimit(value)
+                | IgnoreDf(value)
+                | ErspanVersion(value)
+                | ErspanDirection(value)
+            => buffer[0] = *value,
+            EncapType(value)
+                | EncapFlags(value)
+                | ErspanHwid(value)
+            => NativeEndian::write_u16(buffer, *value),
+            IFlags(value)
+                | OFlags(value)
+                | EncapSourcePort(value)
+                | EncapDestinationPort(value)
+            => BigEndian::write_u16(buffer, *value),
+            Link(value)
+                | Flags(value)
+                | FwMark(value)
+                | ErspanIndex(value)
+            => NativeEndian::write_u32(buffer, *value),
+            IKey(value)
+                | OKey(value)
+                | FlowInfo(value)
+            => BigEndian::write_u32(buffer, *value),
+            Local(IpAddr::V4(addr)) | Remote(IpAddr::V4(addr)) => {
+                buffer.copy_from_slice(&addr.octets())
+            }
+            Local(IpAddr::V6(addr)) | Remote(IpAddr::V6(addr)) => {
+                buffer.copy_from_slice(&addr.octets())
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoGre::*;
+        match self {
+            Link(_) => IFLA_GRE_LINK,
+            IFlags(_) => IFLA_GRE_IFLAGS,
+            OFlags(_) => IFLA_GRE_OFLAGS,
+            IKey(_) => IFLA_GRE_IKEY,
+            OKey(_) => IFLA_GRE_OKEY,
+            Local(_) => IFLA_GRE_LOCAL,
+            Remote(_) => IFLA_GRE_REMOTE,
+            Ttl(_) => IFLA_GRE_TTL,
+            Tos(_) => IFLA_GRE_TOS,
+            PathMtuDiscovery(_) => IFLA_GRE_PMTUDISC,
+            EncapLimit(_) => IFLA_GRE_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_GRE_FLOWINFO,
+            Flags(_) => IFLA_GRE_FLAGS,
+            EncapType(_) => IFLA_GRE_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_GRE_ENCAP_FLAGS,
+            EncapSourcePort(_) => IFLA_GRE_ENCAP_SPORT,
+            EncapDestinationPort(_) => IFLA_GRE_ENCAP_DPORT,
+            CollectMetadata => IFLA_GRE_COLLECT_METADATA,
+            IgnoreDf(_) => IFLA_GRE_IGNORE_DF,
+            FwMark(_) => IFLA_GRE_FWMARK,
+            ErspanIndex(_) => IFLA_GRE_ERSPAN_INDEX,
+            ErspanVersion(_) => IFLA_GRE_ERSPAN_VER,
+            ErspanDirection(_) => IFLA_GRE_ERSPAN_DIR,
+            ErspanHwid(_) => IFLA_GRE_ERSPAN_HWID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoGre {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoGre::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_GRE_LINK => Link(parse_u32(payload).context("invalid IFLA_GRE_LINK value")?),
+            IFLA_GRE_IFLAGS => {
+                IFlags(parse_u16_be(payload).context("invalid IFLA_GRE_IFLAGS value")?)
+            }
+            IFLA_GRE_OFLAGS => {
+                OFlags(parse_u16_be(payload).context("invalid IFLA_GRE_OFLAGS value")?)
+            }
+            IFLA_GRE_IKEY => IKey(parse_u32_be(payload).context("invalid IFLA_GRE_IKEY value")?),
+            IFLA_GRE_OKEY => OKey(parse_u32_be(payload).context("invalid IFLA_GRE_OKEY value")?),
+            IFLA_GRE_LOCAL => Local(parse_ip(payload).context("invalid IFLA_GRE_LOCAL value")?),
+            IFLA_GRE_REMOTE => Remote(parse_ip(payload).context("invalid IFLA_GRE_REMOTE value")?),
+            IFLA_GRE_TTL => Ttl(parse_u8(payload).context("invalid IFLA_GRE_TTL value")?),
+            IFLA_GRE_TOS => Tos(parse_u8(payload).context("invalid IFLA_GRE_TOS value")?),
+            IFLA_GRE_PMTUDISC => {
+                PathMtuDiscovery(parse_u8(payload).context("invalid IFLA_GRE_PMTUDISC value")?)
+            }
+            IFLA_GRE_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_GRE_ENCAP_LIMIT value")?)
+            }
+            IFLA_GRE_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_GRE_FLOWINFO value")?)
+            }
+            IFLA_GRE_FLAGS => Flags(parse_u32(payload).context("invalid IFLA_GRE_FLAGS value")?),
+            IFLA_GRE_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_TYPE value")?)
+            }
+            IFLA_GRE_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_GRE_ENCAP_FLAGS value")?)
+            }
+            IFLA_GRE_ENCAP_SPORT => EncapSourcePort(
+                parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_SPORT value")?,
+            ),
+            IFLA_GRE_ENCAP_DPORT => EncapDestinationPort(
+                parse_u16_be(payload).context("invalid IFLA_GRE_ENCAP_DPORT value")?,
+            ),
+            IFLA_GRE_COLLECT_METADATA => CollectMetadata,
+            IFLA_GRE_IGNORE_DF => {
+                IgnoreDf(parse_u8(payload).context("invalid IFLA_GRE_IGNORE_DF value")?)
+            }
+            IFLA_GRE_FWMARK => FwMark(parse_u32(payload).context("invalid IFLA_GRE_FWMARK value")?),
+            IFLA_GRE_ERSPAN_INDEX => {
+                ErspanIndex(parse_u32(payload).context("invalid IFLA_GRE_ERSPAN_INDEX value")?)
+            }
+            IFLA_GRE_ERSPAN_VER => {
+                ErspanVersion(parse_u8(payload).context("invalid IFLA_GRE_ERSPAN_VER value")?)
+            }
+            IFLA_GRE_ERSPAN_DIR => {
+                ErspanDirection(parse_u8(payload).context("invalid IFLA_GRE_ERSPAN_DIR value")?)
+            }
+            IFLA_GRE_ERSPAN_HWID => {
+                ErspanHwid(parse_u16(payload).context("invalid IFLA_GRE_ERSPAN_HWID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_ipv6, parse_u16, parse_u16_be, parse_u32, parse_u32_be, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// `IFLA_INFO_DATA` attributes of the IPIP, SIT and IP6TNL links. The flow info and ports are
+/// given in host byte order.
+// https://elixir.bootlin.com/linux/v6.1/source/net/ipv6/sit.c#L1731
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoIpTunnel {
+    /// Index of the underlying link
+    Link(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    Ttl(u8),
+    Tos(u8),
+    /// Tunnel encapsulation limit of an IP6TNL link
+    EncapLimit(u8),
+    /// Flow label (and traffic class) of an IP6TNL link
+    FlowInfo(u32),
+    /// `IP6_TNL_F_*` flags of an IP6TNL link
+    Flags(u32),
+    /// `SIT_ISATAP` flag of a SIT link
+    SitFlags(u16),
+    /// Protocol of the tunneled packets (`IPPROTO_*`), 0 for any of the supported ones
+    Protocol(u8),
+    /// Path MTU discovery (1) or not (0)
+    PathMtuDiscovery(u8),
+    /// IPv6 rapid deployment prefix of a SIT link
+    SixRdPrefix(Ipv6Addr),
+    SixRdRelayPrefix(Ipv4Addr),
+    SixRdPrefixLen(u16),
+    SixRdRelayPrefixLen(u16),
+    /// `TUNNEL_ENCAP_*` UDP encapsulation
+    EncapType(u16),
+    /// `TUNNEL_ENCAP_FLAG_*`
+    EncapFlags(u16),
+    EncapSourcePort(u16),
+    EncapDestinationPort(u16),
+    /// The tunnel is used by lightweight tunnel routes or BPF programs
+    CollectMetadata,
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoIpTunnel {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoIpTunnel::*;
+        match self {
+            CollectMetadata => 0,
+            Ttl(_)
+                | Tos(_)
+                | EncapLimit(_)
+                | Protocol(_)
+                | PathMtuDiscovery(_)
+            => 1,
+            SitFlags(_)
+                | SixRdPrefixLen(_)
+                | SixRdRelayPrefixLen(_)
+                | EncapType(_)
+                | EncapFlags(_)
+                | EncapSourcePort(_)
+                | EncapDestinationPort(_)
+            => 2,
+            Link(_)
+                | FlowInfo(_)
+                | Flags(_)
+                | SixRdRelayPrefix(_)
+                | FwMark(_)
+            => 4,
+            SixRdPrefix(_) => 16,
+            Local(IpAddr::V4(_)) | Remote(IpAddr::V4(_)) => 4,
+            Local(IpAddr::V6(_)) | Remote(IpAddr::V6(_)) => 16,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoIpTunnel::*;
+        match self {
+            CollectMetadata => {}
+            Ttl(value)
+                | Tos(value)
+                | EncapLimit(value)
+                | Protocol(value)
+                | PathMtuDiscovery(value)
+            => buffer[0] = *value,
+            SitFlags(value)
+                | SixRdPrefixLen(value)
+                | SixRdRelayPrefixLen(value)
+                | EncapType(value)
+                | EncapFlags(value)
+            => NativeEndian::write_u16(buffer, *value),
+            EncapSourcePort(value) | EncapDestinationPort(value) => {
+                BigEndian::write_u16(buffer, *value)
+            }
+            Link(value) | Flags(value) | FwMark(value) => NativeEndian::write_u32(buffer, *value),
+            FlowInfo(value) => BigEndian::write_u32(buffer, *value),
+            SixRdPrefix(addr) => buffer.copy_from_slice(&addr.octets()),
+            SixRdRelayPrefix(addr) => buffer.copy_from_slice(&addr.octets()),
+            Local(IpAddr::V4(addr)) | Remote(IpAddr::V4(addr)) => {
+                buffer.copy_from_slice(&addr.octets())
+            }
+            Local(IpAddr::V6(addr)) | Remote(IpAddr::V6(addr)) => {
+                buffer.copy_from_slice(&addr.octets())
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoIpTunnel::*;
+        match self {
+            Link(_) => IFLA_IPTUN_LINK,
+            Local(_) => IFLA_IPTUN_LOCAL,
+            Remote(_) => IFLA_IPTUN_REMOTE,
+            Ttl(_) => IFLA_IPTUN_TTL,
+            Tos(_) => IFLA_IPTUN_TOS,
+            EncapLimit(_) => IFLA_IPTUN_ENCAP_LIMIT,
+            FlowInfo(_) => IFLA_IPTUN_FLOWINFO,
+            Flags(_) | SitFlags(_) => IFLA_IPTUN_FLAGS,
+            Protocol(_) => IFLA_IPTUN_PROTO,
+            PathMtuDiscovery(_) => IFLA_IPTUN_PMTUDISC,
+            SixRdPrefix(_) => IFLA_IPTUN_6RD_PREFIX,
+            SixRdRelayPrefix(_) => IFLA_IPTUN_6RD_RELAY_PREFIX,
+            SixRdPrefixLen(_) => IFLA_IPTUN_6RD_PREFIXLEN,
+            SixRdRelayPrefixLen(_) => IFLA_IPTUN_6RD_RELAY_PREFIXLEN,
+            EncapType(_) => IFLA_IPTUN_ENCAP_TYPE,
+            EncapFlags(_) => IFLA_IPTUN_ENCAP_FLAGS,
+            EncapSourcePort(_) => IFLA_IPTUN_ENCAP_SPORT,
+            EncapDestinationPort(_) => IFLA_IPTUN_ENCAP_DPORT,
+            CollectMetadata => IFLA_IPTUN_COLLECT_METADATA,
+            FwMark(_) => IFLA_IPTUN_FWMARK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoIpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoIpTunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_IPTUN_LINK => Link(parse_u32(payload).context("invalid IFLA_IPTUN_LINK value")?),
+            IFLA_IPTUN_LOCAL => Local(parse_ip(payload).context("invalid IFLA_IPTUN_LOCAL value")?),
+            IFLA_IPTUN_REMOTE => {
+                Remote(parse_ip(payload).context("invalid IFLA_IPTUN_REMOTE value")?)
+            }
+            IFLA_IPTUN_TTL => Ttl(parse_u8(payload).context("invalid IFLA_IPTUN_TTL value")?),
+            IFLA_IPTUN_TOS => Tos(parse_u8(payload).context("invalid IFLA_IPTUN_TOS value")?),
+            IFLA_IPTUN_ENCAP_LIMIT => {
+                EncapLimit(parse_u8(payload).context("invalid IFLA_IPTUN_ENCAP_LIMIT value")?)
+            }
+            IFLA_IPTUN_FLOWINFO => {
+                FlowInfo(parse_u32_be(payload).context("invalid IFLA_IPTUN_FLOWINFO value")?)
+            }
+            // SIT links report a 16 bits value, IP6TNL links a 32 bits one
+            IFLA_IPTUN_FLAGS if payload.len() == 2 => {
+                SitFlags(parse_u16(payload).context("invalid IFLA_IPTUN_FLAGS value")?)
+            }
+            IFLA_IPTUN_FLAGS => {
+                Flags(parse_u32(payload).context("invalid IFLA_IPTUN_FLAGS value")?)
+            }
+            IFLA_IPTUN_PROTO => {
+                Protocol(parse_u8(payload).context("invalid IFLA_IPTUN_PROTO value")?)
+            }
+            IFLA_IPTUN_PMTUDISC => {
+                PathMtuDiscovery(parse_u8(payload).context("invalid IFLA_IPTUN_PMTUDISC value")?)
+            }
+            IFLA_IPTUN_6RD_PREFIX => SixRdPrefix(Ipv6Addr::from(
+                parse_ipv6(payload).context("invalid IFLA_IPTUN_6RD_PREFIX value")?,
+            )),
+            IFLA_IPTUN_6RD_RELAY_PREFIX => SixRdRelayPrefix(Ipv4Addr::from(
+                parse_u32_be(payload).context("invalid IFLA_IPTUN_6RD_RELAY_PREFIX value")?,
+            )),
+            IFLA_IPTUN_6RD_PREFIXLEN => SixRdPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_PREFIXLEN value")?,
+            ),
+            IFLA_IPTUN_6RD_RELAY_PREFIXLEN => SixRdRelayPrefixLen(
+                parse_u16(payload).context("invalid IFLA_IPTUN_6RD_RELAY_PREFIXLEN value")?,
+            ),
+            IFLA_IPTUN_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_TYPE value")?)
+            }
+            IFLA_IPTUN_ENCAP_FLAGS => {
+                EncapFlags(parse_u16(payload).context("invalid IFLA_IPTUN_ENCAP_FLAGS value")?)
+            }
+            IFLA_IPTUN_ENCAP_SPORT => EncapSourcePort(
+                parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_SPORT value")?,
+            ),
+            IFLA_IPTUN_ENCAP_DPORT => EncapDestinationPort(
+                parse_u16_be(payload).context("invalid IFLA_IPTUN_ENCAP_DPORT value")?,
+            ),
+            IFLA_IPTUN_COLLECT_METADATA => CollectMetadata,
+            IFLA_IPTUN_FWMARK => {
+                FwMark(parse_u32(payload).context("invalid IFLA_IPTUN_FWMARK value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
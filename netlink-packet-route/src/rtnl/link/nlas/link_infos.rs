@@ -1,6 +1,15 @@
 // SPDX-License-Identifier: MIT
 
-use super::bond::InfoBond;
+use super::{
+    bareudp::InfoBareUdp,
+    bond::InfoBond,
+    geneve::InfoGeneve,
+    gre::InfoGre,
+    ip_tunnel::InfoIpTunnel,
+    macsec::InfoMacSec,
+    vti::InfoVti,
+    xfrm::InfoXfrm,
+};
 use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
@@ -30,13 +39,21 @@ const GRETAP: &str = "gretap";
 const IP6GRETAP: &str = "ip6gretap";
 const IPIP: &str = "ipip";
 const SIT: &str = "sit";
+const IP6TNL: &str = "ip6tnl";
 const GRE: &str = "gre";
 const IP6GRE: &str = "ip6gre";
+const ERSPAN: &str = "erspan";
+const IP6ERSPAN: &str = "ip6erspan";
 const VTI: &str = "vti";
+const VTI6: &str = "vti6";
+const XFRM: &str = "xfrm";
 const VRF: &str = "vrf";
 const GTP: &str = "gtp";
 const IPOIB: &str = "ipoib";
 const WIREGUARD: &str = "wireguard";
+const GENEVE: &str = "geneve";
+const BAREUDP: &str = "bareudp";
+const MACSEC: &str = "macsec";
 
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Info {
@@ -211,13 +228,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 }
                                 InfoData::MacVtap(v)
                             }
-                            InfoKind::GreTap => InfoData::GreTap(payload.to_vec()),
-                            InfoKind::GreTap6 => InfoData::GreTap6(payload.to_vec()),
-                            InfoKind::IpTun => InfoData::IpTun(payload.to_vec()),
-                            InfoKind::SitTun => InfoData::SitTun(payload.to_vec()),
-                            InfoKind::GreTun => InfoData::GreTun(payload.to_vec()),
-                            InfoKind::GreTun6 => InfoData::GreTun6(payload.to_vec()),
-                            InfoKind::Vti => InfoData::Vti(payload.to_vec()),
+                            InfoKind::GreTap => InfoData::GreTap(parse_nlas(payload, GRETAP)?),
+                            InfoKind::GreTap6 => InfoData::GreTap6(parse_nlas(payload, IP6GRETAP)?),
+                            InfoKind::IpTun => InfoData::IpTun(parse_nlas(payload, IPIP)?),
+                            InfoKind::SitTun => InfoData::SitTun(parse_nlas(payload, SIT)?),
+                            InfoKind::IpTun6 => InfoData::IpTun6(parse_nlas(payload, IP6TNL)?),
+                            InfoKind::GreTun => InfoData::GreTun(parse_nlas(payload, GRE)?),
+                            InfoKind::GreTun6 => InfoData::GreTun6(parse_nlas(payload, IP6GRE)?),
+                            InfoKind::Erspan => InfoData::Erspan(parse_nlas(payload, ERSPAN)?),
+                            InfoKind::Ip6Erspan => {
+                                InfoData::Ip6Erspan(parse_nlas(payload, IP6ERSPAN)?)
+                            }
+                            InfoKind::Vti => InfoData::Vti(parse_nlas(payload, VTI)?),
+                            InfoKind::Vti6 => InfoData::Vti6(parse_nlas(payload, VTI6)?),
+                            InfoKind::Xfrm => InfoData::Xfrm(parse_nlas(payload, XFRM)?),
                             InfoKind::Vrf => {
                                 let mut v = Vec::new();
                                 let err =
@@ -242,6 +266,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
                                 InfoData::Ipoib(v)
                             }
                             InfoKind::Wireguard => InfoData::Wireguard(payload.to_vec()),
+                            InfoKind::Geneve => InfoData::Geneve(parse_nlas(payload, GENEVE)?),
+                            InfoKind::BareUdp => InfoData::BareUdp(parse_nlas(payload, BAREUDP)?),
+                            InfoKind::MacSec => InfoData::MacSec(parse_nlas(payload, MACSEC)?),
                             InfoKind::Other(_) => InfoData::Other(payload.to_vec()),
                         };
                         res.push(Info::Data(info_data));
@@ -257,6 +284,23 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for VecInfo {
     }
 }
 
+fn parse_nlas<T>(payload: &[u8], kind: &str) -> Result<Vec<T>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut v = Vec::new();
+    let err = format!(
+        "failed to parse IFLA_INFO_DATA (IFLA_INFO_KIND is '{}')",
+        kind
+    );
+    for nla in NlasIterator::new(payload) {
+        let nla = &nla.context(err.clone())?;
+        let parsed = T::parse(nla).context(err.clone())?;
+        v.push(parsed);
+    }
+    Ok(v)
+}
+
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum InfoData {
     Bridge(Vec<InfoBridge>),
@@ -271,17 +315,25 @@ pub enum InfoData {
     IpVlan(Vec<InfoIpVlan>),
     MacVlan(Vec<InfoMacVlan>),
     MacVtap(Vec<InfoMacVtap>),
-    GreTap(Vec<u8>),
-    GreTap6(Vec<u8>),
-    IpTun(Vec<u8>),
-    SitTun(Vec<u8>),
-    GreTun(Vec<u8>),
-    GreTun6(Vec<u8>),
-    Vti(Vec<u8>),
+    GreTap(Vec<InfoGre>),
+    GreTap6(Vec<InfoGre>),
+    IpTun(Vec<InfoIpTunnel>),
+    SitTun(Vec<InfoIpTunnel>),
+    IpTun6(Vec<InfoIpTunnel>),
+    GreTun(Vec<InfoGre>),
+    GreTun6(Vec<InfoGre>),
+    Erspan(Vec<InfoGre>),
+    Ip6Erspan(Vec<InfoGre>),
+    Vti(Vec<InfoVti>),
+    Vti6(Vec<InfoVti>),
+    Xfrm(Vec<InfoXfrm>),
     Vrf(Vec<InfoVrf>),
     Gtp(Vec<u8>),
     Ipoib(Vec<InfoIpoib>),
     Wireguard(Vec<u8>),
+    Geneve(Vec<InfoGeneve>),
+    BareUdp(Vec<InfoBareUdp>),
+    MacSec(Vec<InfoMacSec>),
     Other(Vec<u8>),
 }
 
@@ -300,17 +352,26 @@ impl Nla for InfoData {
             MacVtap(ref nlas) => nlas.as_slice().buffer_len(),
             Vrf(ref nlas) => nlas.as_slice().buffer_len(),
             Vxlan(ref nlas) => nlas.as_slice().buffer_len(),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                | Erspan(ref nlas)
+                | Ip6Erspan(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            IpTun(ref nlas)
+                | SitTun(ref nlas)
+                | IpTun6(ref nlas)
+                => nlas.as_slice().buffer_len(),
+            Vti(ref nlas) | Vti6(ref nlas) => nlas.as_slice().buffer_len(),
+            Xfrm(ref nlas) => nlas.as_slice().buffer_len(),
+            Geneve(ref nlas) => nlas.as_slice().buffer_len(),
+            BareUdp(ref nlas) => nlas.as_slice().buffer_len(),
+            MacSec(ref nlas) => nlas.as_slice().buffer_len(),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
                 | Gtp(ref bytes)
                 | Wireguard(ref bytes)
                 | Other(ref bytes)
@@ -332,17 +393,26 @@ impl Nla for InfoData {
             MacVtap(ref nlas) => nlas.as_slice().emit(buffer),
             Vrf(ref nlas) => nlas.as_slice().emit(buffer),
             Vxlan(ref nlas) => nlas.as_slice().emit(buffer),
+            GreTap(ref nlas)
+                | GreTap6(ref nlas)
+                | GreTun(ref nlas)
+                | GreTun6(ref nlas)
+                | Erspan(ref nlas)
+                | Ip6Erspan(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            IpTun(ref nlas)
+                | SitTun(ref nlas)
+                | IpTun6(ref nlas)
+                => nlas.as_slice().emit(buffer),
+            Vti(ref nlas) | Vti6(ref nlas) => nlas.as_slice().emit(buffer),
+            Xfrm(ref nlas) => nlas.as_slice().emit(buffer),
+            Geneve(ref nlas) => nlas.as_slice().emit(buffer),
+            BareUdp(ref nlas) => nlas.as_slice().emit(buffer),
+            MacSec(ref nlas) => nlas.as_slice().emit(buffer),
             Dummy(ref bytes)
                 | Tun(ref bytes)
                 | Nlmon(ref bytes)
                 | Ifb(ref bytes)
-                | GreTap(ref bytes)
-                | GreTap6(ref bytes)
-                | IpTun(ref bytes)
-                | SitTun(ref bytes)
-                | GreTun(ref bytes)
-                | GreTun6(ref bytes)
-                | Vti(ref bytes)
                 | Gtp(ref bytes)
                 | Wireguard(ref bytes)
                 | Other(ref bytes)
@@ -373,13 +443,21 @@ pub enum InfoKind {
     GreTap6,
     IpTun,
     SitTun,
+    IpTun6,
     GreTun,
     GreTun6,
+    Erspan,
+    Ip6Erspan,
     Vti,
+    Vti6,
+    Xfrm,
     Vrf,
     Gtp,
     Ipoib,
     Wireguard,
+    Geneve,
+    BareUdp,
+    MacSec,
     Other(String),
 }
 
@@ -403,13 +481,21 @@ impl Nla for InfoKind {
             GreTap6 => IP6GRETAP.len(),
             IpTun => IPIP.len(),
             SitTun => SIT.len(),
+            IpTun6 => IP6TNL.len(),
             GreTun => GRE.len(),
             GreTun6 => IP6GRE.len(),
+            Erspan => ERSPAN.len(),
+            Ip6Erspan => IP6ERSPAN.len(),
             Vti => VTI.len(),
+            Vti6 => VTI6.len(),
+            Xfrm => XFRM.len(),
             Vrf => VRF.len(),
             Gtp => GTP.len(),
             Ipoib => IPOIB.len(),
             Wireguard => WIREGUARD.len(),
+            Geneve => GENEVE.len(),
+            BareUdp => BAREUDP.len(),
+            MacSec => MACSEC.len(),
             Other(ref s) => s.len(),
         };
         len + 1
@@ -434,13 +520,21 @@ impl Nla for InfoKind {
             GreTap6 => IP6GRETAP,
             IpTun => IPIP,
             SitTun => SIT,
+            IpTun6 => IP6TNL,
             GreTun => GRE,
             GreTun6 => IP6GRE,
+            Erspan => ERSPAN,
+            Ip6Erspan => IP6ERSPAN,
             Vti => VTI,
+            Vti6 => VTI6,
+            Xfrm => XFRM,
             Vrf => VRF,
             Gtp => GTP,
             Ipoib => IPOIB,
             Wireguard => WIREGUARD,
+            Geneve => GENEVE,
+            BareUdp => BAREUDP,
+            MacSec => MACSEC,
             Other(ref s) => s.as_str(),
         };
         buffer[..s.len()].copy_from_slice(s.as_bytes());
@@ -478,13 +572,21 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoKind {
             IP6GRETAP => GreTap6,
             IPIP => IpTun,
             SIT => SitTun,
+            IP6TNL => IpTun6,
             GRE => GreTun,
             IP6GRE => GreTun6,
+            ERSPAN => Erspan,
+            IP6ERSPAN => Ip6Erspan,
             VTI => Vti,
+            VTI6 => Vti6,
+            XFRM => Xfrm,
             VRF => Vrf,
             GTP => Gtp,
             IPOIB => Ipoib,
             WIREGUARD => Wireguard,
+            GENEVE => Geneve,
+            BAREUDP => BareUdp,
+            MACSEC => MacSec,
             _ => Other(s),
         })
     }
@@ -2201,6 +2303,354 @@ mod tests {
         assert_eq!(&vec[..], &MACVLAN_SOURCE_SET[..]);
     }
 
+    #[rustfmt::skip]
+    static GRETAP: [u8; 76] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x67, 0x72, 0x65, 0x74, 0x61, 0x70, 0x00, // V = "gretap\0"
+        0x00, // padding
+        0x40, 0x00, // length = 64
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, 0x06, 0x00, // length = 8, type = IFLA_GRE_LOCAL
+                0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+            0x08, 0x00, 0x07, 0x00, // length = 8, type = IFLA_GRE_REMOTE
+                0x0a, 0x00, 0x00, 0x02, // 10.0.0.2
+            0x08, 0x00, 0x04, 0x00, // length = 8, type = IFLA_GRE_IKEY
+                0x00, 0x00, 0x00, 0x2a, // 42, network byte order
+            0x06, 0x00, 0x02, 0x00, // length = 6, type = IFLA_GRE_IFLAGS
+                0x20, 0x00, 0x00, 0x00, // GRE_KEY, network byte order
+            0x08, 0x00, 0x05, 0x00, // length = 8, type = IFLA_GRE_OKEY
+                0x00, 0x00, 0x00, 0x2a, // 42, network byte order
+            0x06, 0x00, 0x03, 0x00, // length = 6, type = IFLA_GRE_OFLAGS
+                0x20, 0x00, 0x00, 0x00, // GRE_KEY, network byte order
+            0x05, 0x00, 0x08, 0x00, // length = 5, type = IFLA_GRE_TTL
+                0x40, 0x00, 0x00, 0x00, // 64
+            0x04, 0x00, 0x12, 0x00, // length = 4, type = IFLA_GRE_COLLECT_METADATA
+    ];
+
+    lazy_static! {
+        static ref GRETAP_INFO: Vec<InfoGre> = vec![
+            InfoGre::Local(Ipv4Addr::new(10, 0, 0, 1).into()),
+            InfoGre::Remote(Ipv4Addr::new(10, 0, 0, 2).into()),
+            InfoGre::IKey(42),
+            InfoGre::IFlags(GRE_KEY),
+            InfoGre::OKey(42),
+            InfoGre::OFlags(GRE_KEY),
+            InfoGre::Ttl(64),
+            InfoGre::CollectMetadata,
+        ];
+    }
+
+    #[test]
+    fn parse_info_gretap() {
+        let nla = NlaBuffer::new_checked(&GRETAP[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::GreTap),
+            Info::Data(InfoData::GreTap(GRETAP_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_gretap() {
+        let nlas = vec![
+            Info::Kind(InfoKind::GreTap),
+            Info::Data(InfoData::GreTap(GRETAP_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 76);
+
+        let mut vec = vec![0xff; 76];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &GRETAP[..]);
+    }
+
+    #[rustfmt::skip]
+    static SIT_6RD: [u8; 96] = [
+        0x08, 0x00, // length = 8
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x73, 0x69, 0x74, 0x00, // V = "sit\0"
+        0x58, 0x00, // length = 88
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = IFLA_IPTUN_LOCAL
+                0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+            0x05, 0x00, 0x04, 0x00, // length = 5, type = IFLA_IPTUN_TTL
+                0x40, 0x00, 0x00, 0x00, // 64
+            0x05, 0x00, 0x0a, 0x00, // length = 5, type = IFLA_IPTUN_PMTUDISC
+                0x01, 0x00, 0x00, 0x00, // 1
+            0x05, 0x00, 0x09, 0x00, // length = 5, type = IFLA_IPTUN_PROTO
+                0x29, 0x00, 0x00, 0x00, // 41 = IPPROTO_IPV6
+            0x06, 0x00, 0x08, 0x00, // length = 6, type = IFLA_IPTUN_FLAGS
+                0x00, 0x00, 0x00, 0x00, // no SIT_ISATAP
+            0x14, 0x00, 0x0b, 0x00, // length = 20, type = IFLA_IPTUN_6RD_PREFIX
+                0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, // 2001:db8::
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x06, 0x00, 0x0d, 0x00, // length = 6, type = IFLA_IPTUN_6RD_PREFIXLEN
+                0x20, 0x00, 0x00, 0x00, // 32
+            0x08, 0x00, 0x0c, 0x00, // length = 8, type = IFLA_IPTUN_6RD_RELAY_PREFIX
+                0x0a, 0x00, 0x00, 0x00, // 10.0.0.0
+            0x06, 0x00, 0x0e, 0x00, // length = 6, type = IFLA_IPTUN_6RD_RELAY_PREFIXLEN
+                0x08, 0x00, 0x00, 0x00, // 8
+    ];
+
+    lazy_static! {
+        static ref SIT_6RD_INFO: Vec<InfoIpTunnel> = vec![
+            InfoIpTunnel::Local(Ipv4Addr::new(10, 0, 0, 1).into()),
+            InfoIpTunnel::Ttl(64),
+            InfoIpTunnel::PathMtuDiscovery(1),
+            InfoIpTunnel::Protocol(41),
+            InfoIpTunnel::SitFlags(0),
+            InfoIpTunnel::SixRdPrefix(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)),
+            InfoIpTunnel::SixRdPrefixLen(32),
+            InfoIpTunnel::SixRdRelayPrefix(Ipv4Addr::new(10, 0, 0, 0)),
+            InfoIpTunnel::SixRdRelayPrefixLen(8),
+        ];
+    }
+
+    #[test]
+    fn parse_info_sit_6rd() {
+        let nla = NlaBuffer::new_checked(&SIT_6RD[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::SitTun),
+            Info::Data(InfoData::SitTun(SIT_6RD_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_sit_6rd() {
+        let nlas = vec![
+            Info::Kind(InfoKind::SitTun),
+            Info::Data(InfoData::SitTun(SIT_6RD_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 96);
+
+        let mut vec = vec![0xff; 96];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &SIT_6RD[..]);
+    }
+
+    // ip link add v0 type vti local 10.0.0.1 remote 10.0.0.2 key 42 fwmark 0
+    #[rustfmt::skip]
+    static VTI: [u8; 52] = [
+        0x08, 0x00, // length = 8
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x76, 0x74, 0x69, 0x00, // V = "vti\0"
+        0x2c, 0x00, // length = 44
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = IFLA_VTI_IKEY
+                0x00, 0x00, 0x00, 0x2a, // 42, network byte order
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = IFLA_VTI_OKEY
+                0x00, 0x00, 0x00, 0x2a, // 42, network byte order
+            0x08, 0x00, 0x04, 0x00, // length = 8, type = IFLA_VTI_LOCAL
+                0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+            0x08, 0x00, 0x05, 0x00, // length = 8, type = IFLA_VTI_REMOTE
+                0x0a, 0x00, 0x00, 0x02, // 10.0.0.2
+            0x08, 0x00, 0x06, 0x00, // length = 8, type = IFLA_VTI_FWMARK
+                0x00, 0x00, 0x00, 0x00, // 0
+    ];
+
+    lazy_static! {
+        static ref VTI_INFO: Vec<InfoVti> = vec![
+            InfoVti::IKey(42),
+            InfoVti::OKey(42),
+            InfoVti::Local(Ipv4Addr::new(10, 0, 0, 1).into()),
+            InfoVti::Remote(Ipv4Addr::new(10, 0, 0, 2).into()),
+            InfoVti::FwMark(0),
+        ];
+    }
+
+    #[test]
+    fn parse_info_data_vti() {
+        let nla = NlaBuffer::new_checked(&VTI[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Vti),
+            Info::Data(InfoData::Vti(VTI_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_vti() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Vti),
+            Info::Data(InfoData::Vti(VTI_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 52);
+
+        let mut vec = vec![0xff; 52];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &VTI[..]);
+    }
+
+    // ip link add x0 type xfrm dev lo if_id 42
+    #[rustfmt::skip]
+    static XFRM: [u8; 32] = [
+        0x09, 0x00, // length = 9
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x78, 0x66, 0x72, 0x6d, 0x00, // V = "xfrm\0"
+        0x00, 0x00, 0x00, // padding
+        0x14, 0x00, // length = 20
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = IFLA_XFRM_IF_ID
+                0x2a, 0x00, 0x00, 0x00, // 42
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = IFLA_XFRM_LINK
+                0x01, 0x00, 0x00, 0x00, // 1
+    ];
+
+    #[test]
+    fn parse_info_xfrm() {
+        let nla = NlaBuffer::new_checked(&XFRM[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Xfrm),
+            Info::Data(InfoData::Xfrm(vec![InfoXfrm::IfId(42), InfoXfrm::Link(1)])),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_xfrm() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Xfrm),
+            Info::Data(InfoData::Xfrm(vec![InfoXfrm::IfId(42), InfoXfrm::Link(1)])),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 32);
+
+        let mut vec = vec![0xff; 32];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &XFRM[..]);
+    }
+
+    // ip link add g0 type geneve id 42 remote 10.0.0.2 ttl inherit dstport 6081 df inherit \
+    //     udpcsum
+    #[rustfmt::skip]
+    static GENEVE: [u8; 88] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x67, 0x65, 0x6e, 0x65, 0x76, 0x65, 0x00, // V = "geneve\0"
+        0x00, // padding
+        0x4c, 0x00, // length = 76
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x05, 0x00, 0x0c, 0x00, // length = 5, type = IFLA_GENEVE_TTL_INHERIT
+                0x01, 0x00, 0x00, 0x00, // 1
+            0x05, 0x00, 0x0d, 0x00, // length = 5, type = IFLA_GENEVE_DF
+                0x02, 0x00, 0x00, 0x00, // GENEVE_DF_INHERIT
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = IFLA_GENEVE_ID
+                0x2a, 0x00, 0x00, 0x00, // 42
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = IFLA_GENEVE_REMOTE
+                0x0a, 0x00, 0x00, 0x02, // 10.0.0.2
+            0x08, 0x00, 0x0b, 0x00, // length = 8, type = IFLA_GENEVE_LABEL
+                0x00, 0x00, 0x00, 0x00, // 0
+            0x05, 0x00, 0x03, 0x00, // length = 5, type = IFLA_GENEVE_TTL
+                0x00, 0x00, 0x00, 0x00, // 0
+            0x05, 0x00, 0x04, 0x00, // length = 5, type = IFLA_GENEVE_TOS
+                0x00, 0x00, 0x00, 0x00, // 0
+            0x06, 0x00, 0x05, 0x00, // length = 6, type = IFLA_GENEVE_PORT
+                0x17, 0xc1, 0x00, 0x00, // 6081, network byte order
+            0x05, 0x00, 0x08, 0x00, // length = 5, type = IFLA_GENEVE_UDP_CSUM
+                0x01, 0x00, 0x00, 0x00, // 1
+    ];
+
+    lazy_static! {
+        static ref GENEVE_INFO: Vec<InfoGeneve> = vec![
+            InfoGeneve::TtlInherit(1),
+            InfoGeneve::Df(GENEVE_DF_INHERIT),
+            InfoGeneve::Id(42),
+            InfoGeneve::Remote(Ipv4Addr::new(10, 0, 0, 2)),
+            InfoGeneve::Label(0),
+            InfoGeneve::Ttl(0),
+            InfoGeneve::Tos(0),
+            InfoGeneve::Port(6081),
+            InfoGeneve::UdpCsum(1),
+        ];
+    }
+
+    #[test]
+    fn parse_info_geneve() {
+        let nla = NlaBuffer::new_checked(&GENEVE[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::Geneve),
+            Info::Data(InfoData::Geneve(GENEVE_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_geneve() {
+        let nlas = vec![
+            Info::Kind(InfoKind::Geneve),
+            Info::Data(InfoData::Geneve(GENEVE_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 88);
+
+        let mut vec = vec![0xff; 88];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &GENEVE[..]);
+    }
+
+    // ip link add link lo m0 type macsec sci 5254001234560001 cipher gcm-aes-256 encrypt on \
+    //     validate strict
+    #[rustfmt::skip]
+    static MACSEC: [u8; 56] = [
+        0x0b, 0x00, // length = 11
+        0x01, 0x00, // type = 1 = IFLA_INFO_KIND
+        0x6d, 0x61, 0x63, 0x73, 0x65, 0x63, 0x00, // V = "macsec\0"
+        0x00, // padding
+        0x2c, 0x00, // length = 44
+        0x02, 0x00, // type = 2 = IFLA_INFO_DATA
+            0x0c, 0x00, 0x01, 0x00, // length = 12, type = IFLA_MACSEC_SCI
+                0x52, 0x54, 0x00, 0x12, 0x34, 0x56, 0x00, 0x01, // network byte order
+            0x0c, 0x00, 0x04, 0x00, // length = 12, type = IFLA_MACSEC_CIPHER_SUITE
+                0x02, 0x00, 0x00, 0x01, 0x00, 0xc2, 0x80, 0x00, // GCM_AES_256
+            0x05, 0x00, 0x07, 0x00, // length = 5, type = IFLA_MACSEC_ENCRYPT
+                0x01, 0x00, 0x00, 0x00, // 1
+            0x05, 0x00, 0x0d, 0x00, // length = 5, type = IFLA_MACSEC_VALIDATION
+                0x02, 0x00, 0x00, 0x00, // MACSEC_VALIDATE_STRICT
+    ];
+
+    lazy_static! {
+        static ref MACSEC_INFO: Vec<InfoMacSec> = vec![
+            InfoMacSec::Sci(0x5254_0012_3456_0001),
+            InfoMacSec::CipherSuite(MACSEC_CIPHER_ID_GCM_AES_256),
+            InfoMacSec::Encrypt(1),
+            InfoMacSec::Validation(MACSEC_VALIDATE_STRICT),
+        ];
+    }
+
+    #[test]
+    fn parse_info_macsec() {
+        let nla = NlaBuffer::new_checked(&MACSEC[..]).unwrap();
+        let parsed = VecInfo::parse(&nla).unwrap().0;
+        let expected = vec![
+            Info::Kind(InfoKind::MacSec),
+            Info::Data(InfoData::MacSec(MACSEC_INFO.clone())),
+        ];
+        assert_eq!(expected, parsed);
+    }
+
+    #[test]
+    fn emit_info_macsec() {
+        let nlas = vec![
+            Info::Kind(InfoKind::MacSec),
+            Info::Data(InfoData::MacSec(MACSEC_INFO.clone())),
+        ];
+
+        assert_eq!(nlas.as_slice().buffer_len(), 56);
+
+        let mut vec = vec![0xff; 56];
+        nlas.as_slice().emit(&mut vec);
+        assert_eq!(&vec[..], &MACSEC[..]);
+    }
+
     #[test]
     fn parse() {
         let nla = NlaBuffer::new_checked(&BRIDGE[..]).unwrap();
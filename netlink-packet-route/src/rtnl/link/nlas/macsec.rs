@@ -0,0 +1,161 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16_be, parse_u32, parse_u64, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// `IFLA_INFO_DATA` attributes of the MACSEC links. The SCI and port are given in host byte
+/// order, the SCI being the MAC address followed by the port (e.g. `0x5254_0012_3456_0001`).
+// https://elixir.bootlin.com/linux/v6.1/source/drivers/net/macsec.c#L4372
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoMacSec {
+    /// Secure channel identifier of the transmit secure channel
+    Sci(u64),
+    /// Port of the SCI, the MAC address of the link being used
+    Port(u16),
+    /// Length of the integrity check value, 16 bytes by default
+    IcvLen(u8),
+    /// `MACSEC_CIPHER_ID_*`
+    CipherSuite(u64),
+    /// Replay protection window, in packets
+    Window(u32),
+    /// Association number of the secure association used to send the packets
+    EncodingSa(u8),
+    Encrypt(u8),
+    Protect(u8),
+    /// Include the SCI in the sent packets (1)
+    IncSci(u8),
+    /// End station bit of the sent packets
+    Es(u8),
+    /// Single copy broadcast bit of the sent packets
+    Scb(u8),
+    ReplayProtect(u8),
+    /// `MACSEC_VALIDATE_*` validation of the received packets
+    Validation(u8),
+    /// `MACSEC_OFFLOAD_*`
+    Offload(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoMacSec {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::InfoMacSec::*;
+        match self {
+            IcvLen(_)
+                | EncodingSa(_)
+                | Encrypt(_)
+                | Protect(_)
+                | IncSci(_)
+                | Es(_)
+                | Scb(_)
+                | ReplayProtect(_)
+                | Validation(_)
+                | Offload(_)
+            => 1,
+            Port(_) => 2,
+            Window(_) => 4,
+            Sci(_) | CipherSuite(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoMacSec::*;
+        match self {
+            IcvLen(value)
+                | EncodingSa(value)
+                | Encrypt(value)
+                | Protect(value)
+                | IncSci(value)
+                | Es(value)
+                | Scb(value)
+                | ReplayProtect(value)
+                | Validation(value)
+                | Offload(value)
+            => buffer[0] = *value,
+            Port(value) => BigEndian::write_u16(buffer, *value),
+            Window(value) => NativeEndian::write_u32(buffer, *value),
+            Sci(value) => BigEndian::write_u64(buffer, *value),
+            CipherSuite(value) => NativeEndian::write_u64(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoMacSec::*;
+        match self {
+            Sci(_) => IFLA_MACSEC_SCI,
+            Port(_) => IFLA_MACSEC_PORT,
+            IcvLen(_) => IFLA_MACSEC_ICV_LEN,
+            CipherSuite(_) => IFLA_MACSEC_CIPHER_SUITE,
+            Window(_) => IFLA_MACSEC_WINDOW,
+            EncodingSa(_) => IFLA_MACSEC_ENCODING_SA,
+            Encrypt(_) => IFLA_MACSEC_ENCRYPT,
+            Protect(_) => IFLA_MACSEC_PROTECT,
+            IncSci(_) => IFLA_MACSEC_INC_SCI,
+            Es(_) => IFLA_MACSEC_ES,
+            Scb(_) => IFLA_MACSEC_SCB,
+            ReplayProtect(_) => IFLA_MACSEC_REPLAY_PROTECT,
+            Validation(_) => IFLA_MACSEC_VALIDATION,
+            Offload(_) => IFLA_MACSEC_OFFLOAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoMacSec {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoMacSec::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_MACSEC_SCI => Sci(u64::from_be(
+                parse_u64(payload).context("invalid IFLA_MACSEC_SCI value")?,
+            )),
+            IFLA_MACSEC_PORT => {
+                Port(parse_u16_be(payload).context("invalid IFLA_MACSEC_PORT value")?)
+            }
+            IFLA_MACSEC_ICV_LEN => {
+                IcvLen(parse_u8(payload).context("invalid IFLA_MACSEC_ICV_LEN value")?)
+            }
+            IFLA_MACSEC_CIPHER_SUITE => {
+                CipherSuite(parse_u64(payload).context("invalid IFLA_MACSEC_CIPHER_SUITE value")?)
+            }
+            IFLA_MACSEC_WINDOW => {
+                Window(parse_u32(payload).context("invalid IFLA_MACSEC_WINDOW value")?)
+            }
+            IFLA_MACSEC_ENCODING_SA => {
+                EncodingSa(parse_u8(payload).context("invalid IFLA_MACSEC_ENCODING_SA value")?)
+            }
+            IFLA_MACSEC_ENCRYPT => {
+                Encrypt(parse_u8(payload).context("invalid IFLA_MACSEC_ENCRYPT value")?)
+            }
+            IFLA_MACSEC_PROTECT => {
+                Protect(parse_u8(payload).context("invalid IFLA_MACSEC_PROTECT value")?)
+            }
+            IFLA_MACSEC_INC_SCI => {
+                IncSci(parse_u8(payload).context("invalid IFLA_MACSEC_INC_SCI value")?)
+            }
+            IFLA_MACSEC_ES => Es(parse_u8(payload).context("invalid IFLA_MACSEC_ES value")?),
+            IFLA_MACSEC_SCB => Scb(parse_u8(payload).context("invalid IFLA_MACSEC_SCB value")?),
+            IFLA_MACSEC_REPLAY_PROTECT => ReplayProtect(
+                parse_u8(payload).context("invalid IFLA_MACSEC_REPLAY_PROTECT value")?,
+            ),
+            IFLA_MACSEC_VALIDATION => {
+                Validation(parse_u8(payload).context("invalid IFLA_MACSEC_VALIDATION value")?)
+            }
+            IFLA_MACSEC_OFFLOAD => {
+                Offload(parse_u8(payload).context("invalid IFLA_MACSEC_OFFLOAD value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
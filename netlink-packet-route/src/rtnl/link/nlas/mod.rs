@@ -15,9 +15,30 @@ pub use self::af_spec_bridge::*;
 mod link_infos;
 pub use self::link_infos::*;
 
+mod bareudp;
+pub use self::bareudp::*;
+
 mod bond;
 pub use self::bond::*;
 
+mod geneve;
+pub use self::geneve::*;
+
+mod gre;
+pub use self::gre::*;
+
+mod ip_tunnel;
+pub use self::ip_tunnel::*;
+
+mod macsec;
+pub use self::macsec::*;
+
+mod vti;
+pub use self::vti::*;
+
+mod xfrm;
+pub use self::xfrm::*;
+
 mod prop_list;
 pub use self::prop_list::*;
 
@@ -0,0 +1,87 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ip, parse_u32, parse_u32_be},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// `IFLA_INFO_DATA` attributes of the VTI and VTI6 links. The keys are given in host byte
+/// order.
+// https://elixir.bootlin.com/linux/v6.1/source/net/ipv4/ip_vti.c#L630
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoVti {
+    /// Index of the underlying link
+    Link(u32),
+    /// Key of the received packets, used as the mark of their IPsec policy lookup
+    IKey(u32),
+    /// Key of the sent packets, used as the mark of their IPsec policy lookup
+    OKey(u32),
+    Local(IpAddr),
+    Remote(IpAddr),
+    FwMark(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for InfoVti {
+    fn value_len(&self) -> usize {
+        use self::InfoVti::*;
+        match self {
+            Link(_) | IKey(_) | OKey(_) | FwMark(_) => 4,
+            Local(IpAddr::V4(_)) | Remote(IpAddr::V4(_)) => 4,
+            Local(IpAddr::V6(_)) | Remote(IpAddr::V6(_)) => 16,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoVti::*;
+        match self {
+            Link(value) | FwMark(value) => NativeEndian::write_u32(buffer, *value),
+            IKey(value) | OKey(value) => BigEndian::write_u32(buffer, *value),
+            Local(IpAddr::V4(addr)) | Remote(IpAddr::V4(addr)) => {
+                buffer.copy_from_slice(&addr.octets())
+            }
+            Local(IpAddr::V6(addr)) | Remote(IpAddr::V6(addr)) => {
+                buffer.copy_from_slice(&addr.octets())
+            }
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoVti::*;
+        match self {
+            Link(_) => IFLA_VTI_LINK,
+            IKey(_) => IFLA_VTI_IKEY,
+            OKey(_) => IFLA_VTI_OKEY,
+            Local(_) => IFLA_VTI_LOCAL,
+            Remote(_) => IFLA_VTI_REMOTE,
+            FwMark(_) => IFLA_VTI_FWMARK,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoVti {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoVti::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_VTI_LINK => Link(parse_u32(payload).context("invalid IFLA_VTI_LINK value")?),
+            IFLA_VTI_IKEY => IKey(parse_u32_be(payload).context("invalid IFLA_VTI_IKEY value")?),
+            IFLA_VTI_OKEY => OKey(parse_u32_be(payload).context("invalid IFLA_VTI_OKEY value")?),
+            IFLA_VTI_LOCAL => Local(parse_ip(payload).context("invalid IFLA_VTI_LOCAL value")?),
+            IFLA_VTI_REMOTE => Remote(parse_ip(payload).context("invalid IFLA_VTI_REMOTE value")?),
+            IFLA_VTI_FWMARK => FwMark(parse_u32(payload).context("invalid IFLA_VTI_FWMARK value")?),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
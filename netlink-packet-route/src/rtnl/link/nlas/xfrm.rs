@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u32,
+    traits::Parseable,
+    DecodeError,
+};
+
+/// `IFLA_INFO_DATA` attributes of the XFRM links.
+// https://elixir.bootlin.com/linux/v6.2/source/net/xfrm/xfrm_interface.c#L958
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum InfoXfrm {
+    /// Index of the underlying link
+    Link(u32),
+    /// Id of the interface, matched against the `if_id` of the IPsec states and policies
+    IfId(u32),
+    /// The interface is used by lightweight tunnel routes or BPF programs
+    CollectMetadata,
+    Other(DefaultNla),
+}
+
+impl Nla for InfoXfrm {
+    fn value_len(&self) -> usize {
+        use self::InfoXfrm::*;
+        match self {
+            Link(_) | IfId(_) => 4,
+            CollectMetadata => 0,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::InfoXfrm::*;
+        match self {
+            Link(value) | IfId(value) => NativeEndian::write_u32(buffer, *value),
+            CollectMetadata => {}
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::InfoXfrm::*;
+        match self {
+            Link(_) => IFLA_XFRM_LINK,
+            IfId(_) => IFLA_XFRM_IF_ID,
+            CollectMetadata => IFLA_XFRM_COLLECT_METADATA,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for InfoXfrm {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::InfoXfrm::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_XFRM_LINK => Link(parse_u32(payload).context("invalid IFLA_XFRM_LINK value")?),
+            IFLA_XFRM_IF_ID => IfId(parse_u32(payload).context("invalid IFLA_XFRM_IF_ID value")?),
+            IFLA_XFRM_COLLECT_METADATA => CollectMetadata,
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
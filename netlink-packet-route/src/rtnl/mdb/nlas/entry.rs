@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const MDB_ENTRY_LEN: usize = 28;
+
+buffer!(MdbEntryBuffer(MDB_ENTRY_LEN) {
+    ifindex: (u32, 0..4),
+    state: (u8, 4),
+    flags: (u8, 5),
+    vid: (u16, 6..8),
+    addr: (slice, 8..24),
+    proto: (slice, 24..26),
+});
+
+/// Multicast group of an MDB entry
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum MdbGroup {
+    Ipv4(Ipv4Addr),
+    Ipv6(Ipv6Addr),
+    /// Layer 2 multicast address
+    L2([u8; 6]),
+    /// Address of an unknown protocol: the ethertype and the raw address
+    Other(u16, [u8; 16]),
+}
+
+impl Default for MdbGroup {
+    fn default() -> Self {
+        MdbGroup::Ipv4(Ipv4Addr::UNSPECIFIED)
+    }
+}
+
+impl MdbGroup {
+    fn proto(&self) -> u16 {
+        match self {
+            MdbGroup::Ipv4(_) => ETH_P_IP,
+            MdbGroup::Ipv6(_) => ETH_P_IPV6,
+            MdbGroup::L2(_) => 0,
+            MdbGroup::Other(proto, _) => *proto,
+        }
+    }
+
+    fn emit_addr(&self, buffer: &mut [u8]) {
+        for byte in buffer.iter_mut() {
+            *byte = 0;
+        }
+        match self {
+            MdbGroup::Ipv4(addr) => buffer[..4].copy_from_slice(&addr.octets()),
+            MdbGroup::Ipv6(addr) => buffer.copy_from_slice(&addr.octets()),
+            MdbGroup::L2(mac) => buffer[..6].copy_from_slice(mac),
+            MdbGroup::Other(_, addr) => buffer.copy_from_slice(addr),
+        }
+    }
+
+    fn parse(proto: u16, addr: &[u8]) -> Self {
+        match proto {
+            ETH_P_IP => MdbGroup::Ipv4(Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3])),
+            ETH_P_IPV6 => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(addr);
+                MdbGroup::Ipv6(Ipv6Addr::from(octets))
+            }
+            0 => {
+                let mut mac = [0; 6];
+                mac.copy_from_slice(&addr[..6]);
+                MdbGroup::L2(mac)
+            }
+            _ => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(addr);
+                MdbGroup::Other(proto, octets)
+            }
+        }
+    }
+}
+
+/// High level representation of `struct br_mdb_entry`: a port that joined a
+/// multicast group
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbEntry {
+    /// Index of the bridge port, or of the bridge itself for host joins
+    pub ifindex: u32,
+    /// `MDB_TEMPORARY` or `MDB_PERMANENT`
+    pub state: u8,
+    /// `MDB_FLAGS_*`
+    pub flags: u8,
+    pub vid: u16,
+    pub group: MdbGroup,
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<MdbEntryBuffer<&'a T>> for MdbEntry {
+    fn parse(buf: &MdbEntryBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(MdbEntry {
+            ifindex: buf.ifindex(),
+            state: buf.state(),
+            flags: buf.flags(),
+            vid: buf.vid(),
+            group: MdbGroup::parse(BigEndian::read_u16(buf.proto()), buf.addr()),
+        })
+    }
+}
+
+impl Emitable for MdbEntry {
+    fn buffer_len(&self) -> usize {
+        MDB_ENTRY_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MdbEntryBuffer::new(buffer);
+        buffer.set_ifindex(self.ifindex);
+        buffer.set_state(self.state);
+        buffer.set_flags(self.flags);
+        buffer.set_vid(self.vid);
+        self.group.emit_addr(buffer.addr_mut());
+        BigEndian::write_u16(buffer.proto_mut(), self.group.proto());
+        // trailing padding
+        for byte in buffer.inner_mut()[26..MDB_ENTRY_LEN].iter_mut() {
+            *byte = 0;
+        }
+    }
+}
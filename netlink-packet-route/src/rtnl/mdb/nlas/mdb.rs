@@ -0,0 +1,311 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use super::{emit_ip, ip_len, MdbEntry, MdbEntryBuffer, MDB_ENTRY_LEN};
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_ip, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attributes of `MDBA_MDB`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbNla {
+    /// One multicast group and the ports that joined it
+    Entry(Vec<MdbEntryNla>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbNla {
+    fn value_len(&self) -> usize {
+        match self {
+            MdbNla::Entry(nlas) => nlas.as_slice().buffer_len(),
+            MdbNla::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            MdbNla::Entry(nlas) => nlas.as_slice().emit(buffer),
+            MdbNla::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            MdbNla::Entry(_) => MDBA_MDB_ENTRY,
+            MdbNla::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY => {
+                let error_msg = "invalid MDBA_MDB_ENTRY value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(buf.value()) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(MdbEntryNla::parse(nla).context(error_msg)?);
+                }
+                MdbNla::Entry(nlas)
+            }
+            kind => {
+                MdbNla::Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?)
+            }
+        })
+    }
+}
+
+/// Attributes of `MDBA_MDB_ENTRY`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbEntryNla {
+    Info(MdbEntryInfo),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryNla {
+    fn value_len(&self) -> usize {
+        match self {
+            MdbEntryNla::Info(info) => MDB_ENTRY_LEN + info.nlas.as_slice().buffer_len(),
+            MdbEntryNla::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            MdbEntryNla::Info(info) => {
+                info.entry.emit(buffer);
+                info.nlas.as_slice().emit(&mut buffer[MDB_ENTRY_LEN..]);
+            }
+            MdbEntryNla::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            MdbEntryNla::Info(_) => MDBA_MDB_ENTRY_INFO,
+            MdbEntryNla::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntryNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_ENTRY_INFO => {
+                let error_msg = "invalid MDBA_MDB_ENTRY_INFO value";
+                let entry =
+                    MdbEntry::parse(&MdbEntryBuffer::new_checked(payload).context(error_msg)?)
+                        .context(error_msg)?;
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(&payload[MDB_ENTRY_LEN..]) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(MdbEntryAttr::parse(nla).context(error_msg)?);
+                }
+                MdbEntryNla::Info(MdbEntryInfo { entry, nlas })
+            }
+            kind => MdbEntryNla::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+/// A port member of a multicast group, along with its attributes
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct MdbEntryInfo {
+    pub entry: MdbEntry,
+    pub nlas: Vec<MdbEntryAttr>,
+}
+
+/// `MDBA_MDB_EATTR_*`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbEntryAttr {
+    /// Remaining time before the entry expires, in hundredths of seconds
+    Timer(u32),
+    SourceList(Vec<MdbSourceEntry>),
+    /// `MCAST_INCLUDE` or `MCAST_EXCLUDE`
+    GroupMode(u8),
+    /// Source address of an (S, G) entry
+    Source(IpAddr),
+    /// `RTPROT_*` of the entry creator
+    Protocol(u8),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbEntryAttr {
+    fn value_len(&self) -> usize {
+        use self::MdbEntryAttr::*;
+        match self {
+            Timer(_) => 4,
+            SourceList(nlas) => nlas.as_slice().buffer_len(),
+            GroupMode(_) | Protocol(_) => 1,
+            Source(addr) => ip_len(addr),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbEntryAttr::*;
+        match self {
+            Timer(value) => NativeEndian::write_u32(buffer, *value),
+            SourceList(nlas) => nlas.as_slice().emit(buffer),
+            GroupMode(value) | Protocol(value) => buffer[0] = *value,
+            Source(addr) => emit_ip(addr, buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbEntryAttr::*;
+        match self {
+            Timer(_) => MDBA_MDB_EATTR_TIMER,
+            SourceList(_) => MDBA_MDB_EATTR_SRC_LIST,
+            GroupMode(_) => MDBA_MDB_EATTR_GROUP_MODE,
+            Source(_) => MDBA_MDB_EATTR_SOURCE,
+            Protocol(_) => MDBA_MDB_EATTR_RTPROT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbEntryAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MdbEntryAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_EATTR_TIMER => {
+                Timer(parse_u32(payload).context("invalid MDBA_MDB_EATTR_TIMER value")?)
+            }
+            MDBA_MDB_EATTR_SRC_LIST => {
+                let error_msg = "invalid MDBA_MDB_EATTR_SRC_LIST value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(MdbSourceEntry::parse(nla).context(error_msg)?);
+                }
+                SourceList(nlas)
+            }
+            MDBA_MDB_EATTR_GROUP_MODE => {
+                GroupMode(parse_u8(payload).context("invalid MDBA_MDB_EATTR_GROUP_MODE value")?)
+            }
+            MDBA_MDB_EATTR_SOURCE => {
+                Source(parse_ip(payload).context("invalid MDBA_MDB_EATTR_SOURCE value")?)
+            }
+            MDBA_MDB_EATTR_RTPROT => {
+                Protocol(parse_u8(payload).context("invalid MDBA_MDB_EATTR_RTPROT value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Attributes of `MDBA_MDB_EATTR_SRC_LIST`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbSourceEntry {
+    Entry(Vec<MdbSourceAttr>),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSourceEntry {
+    fn value_len(&self) -> usize {
+        match self {
+            MdbSourceEntry::Entry(nlas) => nlas.as_slice().buffer_len(),
+            MdbSourceEntry::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            MdbSourceEntry::Entry(nlas) => nlas.as_slice().emit(buffer),
+            MdbSourceEntry::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            MdbSourceEntry::Entry(_) => MDBA_MDB_SRCLIST_ENTRY,
+            MdbSourceEntry::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbSourceEntry {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(match buf.kind() {
+            MDBA_MDB_SRCLIST_ENTRY => {
+                let error_msg = "invalid MDBA_MDB_SRCLIST_ENTRY value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(buf.value()) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(MdbSourceAttr::parse(nla).context(error_msg)?);
+                }
+                MdbSourceEntry::Entry(nlas)
+            }
+            kind => MdbSourceEntry::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+/// `MDBA_MDB_SRCATTR_*`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbSourceAttr {
+    Address(IpAddr),
+    /// Remaining time before the source expires, in hundredths of seconds
+    Timer(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for MdbSourceAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            MdbSourceAttr::Address(addr) => ip_len(addr),
+            MdbSourceAttr::Timer(_) => 4,
+            MdbSourceAttr::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            MdbSourceAttr::Address(addr) => emit_ip(addr, buffer),
+            MdbSourceAttr::Timer(value) => NativeEndian::write_u32(buffer, *value),
+            MdbSourceAttr::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            MdbSourceAttr::Address(_) => MDBA_MDB_SRCATTR_ADDRESS,
+            MdbSourceAttr::Timer(_) => MDBA_MDB_SRCATTR_TIMER,
+            MdbSourceAttr::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbSourceAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_MDB_SRCATTR_ADDRESS => MdbSourceAttr::Address(
+                parse_ip(payload).context("invalid MDBA_MDB_SRCATTR_ADDRESS value")?,
+            ),
+            MDBA_MDB_SRCATTR_TIMER => MdbSourceAttr::Timer(
+                parse_u32(payload).context("invalid MDBA_MDB_SRCATTR_TIMER value")?,
+            ),
+            kind => MdbSourceAttr::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+
+mod entry;
+pub use self::entry::*;
+
+mod mdb;
+pub use self::mdb::*;
+
+mod router;
+pub use self::router::*;
+
+mod set;
+pub use self::set::*;
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// The multicast groups and their member ports
+    Mdb(Vec<MdbNla>),
+    /// The multicast router ports
+    Router(Vec<RouterNla>),
+    /// The entry to add or remove. This attribute is only used in requests: it
+    /// has the same number as `MDBA_MDB`, so it is parsed as [`Nla::Mdb`].
+    SetEntry(MdbEntry),
+    /// Additional attributes of the entry to add or remove. This attribute is
+    /// only used in requests: it has the same number as `MDBA_ROUTER`, so it
+    /// is parsed as [`Nla::Router`].
+    SetEntryAttrs(Vec<MdbSetAttr>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Mdb(nlas) => nlas.as_slice().buffer_len(),
+            Router(nlas) => nlas.as_slice().buffer_len(),
+            SetEntry(entry) => entry.buffer_len(),
+            SetEntryAttrs(nlas) => nlas.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Mdb(nlas) => nlas.as_slice().emit(buffer),
+            Router(nlas) => nlas.as_slice().emit(buffer),
+            SetEntry(entry) => entry.emit(buffer),
+            SetEntryAttrs(nlas) => nlas.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => MDBA_UNSPEC,
+            Mdb(_) => MDBA_MDB,
+            Router(_) => MDBA_ROUTER,
+            SetEntry(_) => MDBA_SET_ENTRY,
+            SetEntryAttrs(_) => MDBA_SET_ENTRY_ATTRS | NLA_F_NESTED,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_UNSPEC => Unspec(payload.to_vec()),
+            MDBA_MDB => {
+                let error_msg = "invalid MDBA_MDB value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(MdbNla::parse(nla).context(error_msg)?);
+                }
+                Mdb(nlas)
+            }
+            MDBA_ROUTER => {
+                let error_msg = "invalid MDBA_ROUTER value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(RouterNla::parse(nla).context(error_msg)?);
+                }
+                Router(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+fn ip_len(addr: &IpAddr) -> usize {
+    match addr {
+        IpAddr::V4(_) => 4,
+        IpAddr::V6(_) => 16,
+    }
+}
+
+fn emit_ip(addr: &IpAddr, buffer: &mut [u8]) {
+    match addr {
+        IpAddr::V4(ip) => buffer.copy_from_slice(&ip.octets()),
+        IpAddr::V6(ip) => buffer.copy_from_slice(&ip.octets()),
+    }
+}
@@ -0,0 +1,151 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Attributes of `MDBA_ROUTER`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RouterNla {
+    /// A port behind which a multicast router has been detected or configured
+    Port(RouterPort),
+    Other(DefaultNla),
+}
+
+/// A multicast router port, along with its attributes
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct RouterPort {
+    pub ifindex: u32,
+    pub nlas: Vec<RouterPortAttr>,
+}
+
+impl Nla for RouterNla {
+    fn value_len(&self) -> usize {
+        match self {
+            RouterNla::Port(port) => 4 + port.nlas.as_slice().buffer_len(),
+            RouterNla::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            RouterNla::Port(port) => {
+                NativeEndian::write_u32(buffer, port.ifindex);
+                port.nlas.as_slice().emit(&mut buffer[4..]);
+            }
+            RouterNla::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            RouterNla::Port(_) => MDBA_ROUTER_PORT,
+            RouterNla::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouterNla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PORT => {
+                let error_msg = "invalid MDBA_ROUTER_PORT value";
+                if payload.len() < 4 {
+                    return Err(error_msg.into());
+                }
+                let ifindex = NativeEndian::read_u32(&payload[..4]);
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(&payload[4..]) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(RouterPortAttr::parse(nla).context(error_msg)?);
+                }
+                RouterNla::Port(RouterPort { ifindex, nlas })
+            }
+            kind => RouterNla::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+/// `MDBA_ROUTER_PATTR_*`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RouterPortAttr {
+    /// Remaining time before the router port expires, in hundredths of seconds
+    Timer(u32),
+    /// `MDB_RTR_TYPE_*`
+    Type(u8),
+    InetTimer(u32),
+    Inet6Timer(u32),
+    Vid(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for RouterPortAttr {
+    fn value_len(&self) -> usize {
+        use self::RouterPortAttr::*;
+        match self {
+            Timer(_) | InetTimer(_) | Inet6Timer(_) => 4,
+            Type(_) => 1,
+            Vid(_) => 2,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::RouterPortAttr::*;
+        match self {
+            Timer(value) | InetTimer(value) | Inet6Timer(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Type(value) => buffer[0] = *value,
+            Vid(value) => NativeEndian::write_u16(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::RouterPortAttr::*;
+        match self {
+            Timer(_) => MDBA_ROUTER_PATTR_TIMER,
+            Type(_) => MDBA_ROUTER_PATTR_TYPE,
+            InetTimer(_) => MDBA_ROUTER_PATTR_INET_TIMER,
+            Inet6Timer(_) => MDBA_ROUTER_PATTR_INET6_TIMER,
+            Vid(_) => MDBA_ROUTER_PATTR_VID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for RouterPortAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::RouterPortAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBA_ROUTER_PATTR_TIMER => {
+                Timer(parse_u32(payload).context("invalid MDBA_ROUTER_PATTR_TIMER value")?)
+            }
+            MDBA_ROUTER_PATTR_TYPE => {
+                Type(parse_u8(payload).context("invalid MDBA_ROUTER_PATTR_TYPE value")?)
+            }
+            MDBA_ROUTER_PATTR_INET_TIMER => {
+                InetTimer(parse_u32(payload).context("invalid MDBA_ROUTER_PATTR_INET_TIMER value")?)
+            }
+            MDBA_ROUTER_PATTR_INET6_TIMER => Inet6Timer(
+                parse_u32(payload).context("invalid MDBA_ROUTER_PATTR_INET6_TIMER value")?,
+            ),
+            MDBA_ROUTER_PATTR_VID => {
+                Vid(parse_u16(payload).context("invalid MDBA_ROUTER_PATTR_VID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use anyhow::Context;
+
+use super::{emit_ip, ip_len};
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_ip, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// `MDBE_ATTR_*`: attributes of an entry being added or removed
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MdbSetAttr {
+    /// Source address of an (S, G) entry
+    Source(IpAddr),
+    /// Sources of a (*, G) entry
+    SourceList(Vec<IpAddr>),
+    /// `MCAST_INCLUDE` or `MCAST_EXCLUDE`
+    GroupMode(u8),
+    /// `RTPROT_*` of the entry creator
+    Protocol(u8),
+    Other(DefaultNla),
+}
+
+struct SourceAddress(IpAddr);
+
+impl Nla for SourceAddress {
+    fn value_len(&self) -> usize {
+        ip_len(&self.0)
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        emit_ip(&self.0, buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBE_SRCATTR_ADDRESS
+    }
+}
+
+struct SourceListEntry(IpAddr);
+
+impl Nla for SourceListEntry {
+    fn value_len(&self) -> usize {
+        [SourceAddress(self.0)].as_slice().buffer_len()
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        [SourceAddress(self.0)].as_slice().emit(buffer)
+    }
+
+    fn kind(&self) -> u16 {
+        MDBE_SRC_LIST_ENTRY | NLA_F_NESTED
+    }
+}
+
+fn source_list(sources: &[IpAddr]) -> Vec<SourceListEntry> {
+    sources.iter().map(|addr| SourceListEntry(*addr)).collect()
+}
+
+impl Nla for MdbSetAttr {
+    fn value_len(&self) -> usize {
+        use self::MdbSetAttr::*;
+        match self {
+            Source(addr) => ip_len(addr),
+            SourceList(sources) => source_list(sources).as_slice().buffer_len(),
+            GroupMode(_) | Protocol(_) => 1,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::MdbSetAttr::*;
+        match self {
+            Source(addr) => emit_ip(addr, buffer),
+            SourceList(sources) => source_list(sources).as_slice().emit(buffer),
+            GroupMode(value) | Protocol(value) => buffer[0] = *value,
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::MdbSetAttr::*;
+        match self {
+            Source(_) => MDBE_ATTR_SOURCE,
+            SourceList(_) => MDBE_ATTR_SRC_LIST | NLA_F_NESTED,
+            GroupMode(_) => MDBE_ATTR_GROUP_MODE,
+            Protocol(_) => MDBE_ATTR_RTPROT,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MdbSetAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::MdbSetAttr::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MDBE_ATTR_SOURCE => {
+                Source(parse_ip(payload).context("invalid MDBE_ATTR_SOURCE value")?)
+            }
+            MDBE_ATTR_SRC_LIST => {
+                let error_msg = "invalid MDBE_ATTR_SRC_LIST value";
+                let mut sources = vec![];
+                for entry in NlasIterator::new(payload) {
+                    let entry = entry.context(error_msg)?;
+                    if entry.kind() != MDBE_SRC_LIST_ENTRY {
+                        continue;
+                    }
+                    for nla in NlasIterator::new(entry.value()) {
+                        let nla = nla.context(error_msg)?;
+                        if nla.kind() == MDBE_SRCATTR_ADDRESS {
+                            sources.push(parse_ip(nla.value()).context(error_msg)?);
+                        }
+                    }
+                }
+                SourceList(sources)
+            }
+            MDBE_ATTR_GROUP_MODE => {
+                GroupMode(parse_u8(payload).context("invalid MDBE_ATTR_GROUP_MODE value")?)
+            }
+            MDBE_ATTR_RTPROT => {
+                Protocol(parse_u8(payload).context("invalid MDBE_ATTR_RTPROT value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
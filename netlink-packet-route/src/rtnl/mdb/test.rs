@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use crate::{
+    constants::*,
+    nlas::mdb::*,
+    traits::{Emitable, Parseable},
+    MdbHeader,
+    MdbMessage,
+    MdbMessageBuffer,
+};
+
+// bridge mdb add dev br0 port veth0 grp 239.1.1.3 permanent \
+//     filter_mode include source_list 10.0.0.2,10.0.0.1
+#[rustfmt::skip]
+static MDB_ENTRY: [u8; 116] = [
+    0x00, // family
+    0x00, // pad1
+    0x00, 0x00, // pad2
+    0x22, 0x00, 0x00, 0x00, // ifindex = 34
+
+    0x6c, 0x00, 0x01, 0x00, // length = 108, type = MDBA_MDB
+        0x68, 0x00, 0x01, 0x00, // length = 104, type = MDBA_MDB_ENTRY
+            0x64, 0x00, 0x01, 0x00, // length = 100, type = MDBA_MDB_ENTRY_INFO
+                0x24, 0x00, 0x00, 0x00, // ifindex = 36
+                0x01, // state = MDB_PERMANENT
+                0x00, // flags
+                0x00, 0x00, // vid
+                0xef, 0x01, 0x01, 0x03, 0x00, 0x00, 0x00, 0x00, // group = 239.1.1.3
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+                0x08, 0x00, // proto = ETH_P_IP
+                0x00, 0x00, // padding
+                0x08, 0x00, 0x01, 0x00, // length = 8, type = MDBA_MDB_EATTR_TIMER
+                    0x00, 0x00, 0x00, 0x00,
+                0x05, 0x00, 0x05, 0x00, // length = 5, type = MDBA_MDB_EATTR_RTPROT
+                    0x04, 0x00, 0x00, 0x00, // RTPROT_STATIC
+                0x2c, 0x00, 0x02, 0x00, // length = 44, type = MDBA_MDB_EATTR_SRC_LIST
+                    0x14, 0x00, 0x01, 0x00, // length = 20, type = MDBA_MDB_SRCLIST_ENTRY
+                        0x08, 0x00, 0x01, 0x00, // length = 8, type = MDBA_MDB_SRCATTR_ADDRESS
+                            0x0a, 0x00, 0x00, 0x02,
+                        0x08, 0x00, 0x02, 0x00, // length = 8, type = MDBA_MDB_SRCATTR_TIMER
+                            0x00, 0x00, 0x00, 0x00,
+                    0x14, 0x00, 0x01, 0x00, // length = 20, type = MDBA_MDB_SRCLIST_ENTRY
+                        0x08, 0x00, 0x01, 0x00, // length = 8, type = MDBA_MDB_SRCATTR_ADDRESS
+                            0x0a, 0x00, 0x00, 0x01,
+                        0x08, 0x00, 0x02, 0x00, // length = 8, type = MDBA_MDB_SRCATTR_TIMER
+                            0x00, 0x00, 0x00, 0x00,
+                0x05, 0x00, 0x03, 0x00, // length = 5, type = MDBA_MDB_EATTR_GROUP_MODE
+                    0x01, 0x00, 0x00, 0x00, // MCAST_INCLUDE
+];
+
+#[test]
+fn parse_mdb_entry() {
+    let source = |addr: [u8; 4]| {
+        MdbSourceEntry::Entry(vec![
+            MdbSourceAttr::Address(Ipv4Addr::from(addr).into()),
+            MdbSourceAttr::Timer(0),
+        ])
+    };
+    let expected = MdbMessage {
+        header: MdbHeader {
+            family: 0,
+            ifindex: 34,
+        },
+        nlas: vec![Nla::Mdb(vec![MdbNla::Entry(vec![MdbEntryNla::Info(
+            MdbEntryInfo {
+                entry: MdbEntry {
+                    ifindex: 36,
+                    state: MDB_PERMANENT,
+                    flags: 0,
+                    vid: 0,
+                    group: MdbGroup::Ipv4(Ipv4Addr::new(239, 1, 1, 3)),
+                },
+                nlas: vec![
+                    MdbEntryAttr::Timer(0),
+                    MdbEntryAttr::Protocol(RTPROT_STATIC),
+                    MdbEntryAttr::SourceList(vec![source([10, 0, 0, 2]), source([10, 0, 0, 1])]),
+                    MdbEntryAttr::GroupMode(MCAST_INCLUDE),
+                ],
+            },
+        )])])],
+    };
+    let actual = MdbMessage::parse(&MdbMessageBuffer::new(&MDB_ENTRY)).unwrap();
+    assert_eq!(actual, expected);
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(&buf[..], &MDB_ENTRY[..]);
+}
+
+// bridge mdb add dev br0 port veth0 grp 01:00:5e:00:00:01 vid 10 temp
+#[test]
+fn emit_set_entry() {
+    let msg = MdbMessage {
+        header: MdbHeader {
+            family: AF_BRIDGE as u8,
+            ifindex: 3,
+        },
+        nlas: vec![
+            Nla::SetEntry(MdbEntry {
+                ifindex: 4,
+                state: MDB_TEMPORARY,
+                flags: 0,
+                vid: 10,
+                group: MdbGroup::L2([0x01, 0x00, 0x5e, 0x00, 0x00, 0x01]),
+            }),
+            Nla::SetEntryAttrs(vec![MdbSetAttr::Protocol(RTPROT_STATIC)]),
+        ],
+    };
+
+    #[rustfmt::skip]
+    let expected = vec![
+        0x07, // family = AF_BRIDGE
+        0x00, // pad1
+        0x00, 0x00, // pad2
+        0x03, 0x00, 0x00, 0x00, // ifindex = 3
+
+        0x20, 0x00, 0x01, 0x00, // length = 32, type = MDBA_SET_ENTRY
+            0x04, 0x00, 0x00, 0x00, // ifindex = 4
+            0x00, // state = MDB_TEMPORARY
+            0x00, // flags
+            0x0a, 0x00, // vid = 10
+            0x01, 0x00, 0x5e, 0x00, 0x00, 0x01, 0x00, 0x00, // group
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+            0x00, 0x00, // proto
+            0x00, 0x00, // padding
+        0x0c, 0x00, 0x02, 0x80, // length = 12, type = MDBA_SET_ENTRY_ATTRS | NLA_F_NESTED
+            0x05, 0x00, 0x04, 0x00, // length = 5, type = MDBE_ATTR_RTPROT
+                0x04, 0x00, 0x00, 0x00, // RTPROT_STATIC
+    ];
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    assert_eq!(buf, expected);
+}
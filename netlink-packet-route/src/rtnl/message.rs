@@ -3,19 +3,29 @@
 use crate::{
     constants::*,
     traits::{Emitable, ParseableParametrized},
+    AddrLabelMessage,
     AddressMessage,
+    BridgeVlanMessage,
+    CacheReportMessage,
     DecodeError,
     LinkMessage,
+    MdbMessage,
+    NdUserOptMessage,
     NeighbourMessage,
     NeighbourTableMessage,
+    NetconfMessage,
     NetlinkDeserializable,
     NetlinkHeader,
     NetlinkPayload,
     NetlinkSerializable,
+    NexthopMessage,
     NsidMessage,
+    PrefixMessage,
     RouteMessage,
     RtnlMessageBuffer,
     RuleMessage,
+    StatsMessage,
+    TcActionMessage,
     TcMessage,
 };
 
@@ -57,6 +67,35 @@ pub enum RtnlMessage {
     NewRule(RuleMessage),
     DelRule(RuleMessage),
     GetRule(RuleMessage),
+    NewNexthop(NexthopMessage),
+    DelNexthop(NexthopMessage),
+    GetNexthop(NexthopMessage),
+    NewNetconf(NetconfMessage),
+    DelNetconf(NetconfMessage),
+    GetNetconf(NetconfMessage),
+    NewStats(StatsMessage),
+    GetStats(StatsMessage),
+    NewMdb(MdbMessage),
+    DelMdb(MdbMessage),
+    GetMdb(MdbMessage),
+    NewBridgeVlan(BridgeVlanMessage),
+    DelBridgeVlan(BridgeVlanMessage),
+    GetBridgeVlan(BridgeVlanMessage),
+    NewTrafficAction(TcActionMessage),
+    DelTrafficAction(TcActionMessage),
+    GetTrafficAction(TcActionMessage),
+    NewAddrLabel(AddrLabelMessage),
+    DelAddrLabel(AddrLabelMessage),
+    GetAddrLabel(AddrLabelMessage),
+    NewMulticastAddress(AddressMessage),
+    DelMulticastAddress(AddressMessage),
+    GetMulticastAddress(AddressMessage),
+    NewAnycastAddress(AddressMessage),
+    DelAnycastAddress(AddressMessage),
+    GetAnycastAddress(AddressMessage),
+    NewPrefix(PrefixMessage),
+    NewNdUserOpt(NdUserOptMessage),
+    NewCacheReport(CacheReportMessage),
 }
 
 impl RtnlMessage {
@@ -196,6 +235,122 @@ impl RtnlMessage {
         matches!(self, RtnlMessage::DelRule(_))
     }
 
+    pub fn is_new_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::NewNexthop(_))
+    }
+
+    pub fn is_del_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::DelNexthop(_))
+    }
+
+    pub fn is_get_nexthop(&self) -> bool {
+        matches!(self, RtnlMessage::GetNexthop(_))
+    }
+
+    pub fn is_new_netconf(&self) -> bool {
+        matches!(self, RtnlMessage::NewNetconf(_))
+    }
+
+    pub fn is_del_netconf(&self) -> bool {
+        matches!(self, RtnlMessage::DelNetconf(_))
+    }
+
+    pub fn is_get_netconf(&self) -> bool {
+        matches!(self, RtnlMessage::GetNetconf(_))
+    }
+
+    pub fn is_new_stats(&self) -> bool {
+        matches!(self, RtnlMessage::NewStats(_))
+    }
+
+    pub fn is_get_stats(&self) -> bool {
+        matches!(self, RtnlMessage::GetStats(_))
+    }
+
+    pub fn is_new_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::NewMdb(_))
+    }
+
+    pub fn is_del_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::DelMdb(_))
+    }
+
+    pub fn is_get_mdb(&self) -> bool {
+        matches!(self, RtnlMessage::GetMdb(_))
+    }
+
+    pub fn is_new_bridge_vlan(&self) -> bool {
+        matches!(self, RtnlMessage::NewBridgeVlan(_))
+    }
+
+    pub fn is_del_bridge_vlan(&self) -> bool {
+        matches!(self, RtnlMessage::DelBridgeVlan(_))
+    }
+
+    pub fn is_get_bridge_vlan(&self) -> bool {
+        matches!(self, RtnlMessage::GetBridgeVlan(_))
+    }
+
+    pub fn is_new_traffic_action(&self) -> bool {
+        matches!(self, RtnlMessage::NewTrafficAction(_))
+    }
+
+    pub fn is_del_traffic_action(&self) -> bool {
+        matches!(self, RtnlMessage::DelTrafficAction(_))
+    }
+
+    pub fn is_get_traffic_action(&self) -> bool {
+        matches!(self, RtnlMessage::GetTrafficAction(_))
+    }
+
+    pub fn is_new_addr_label(&self) -> bool {
+        matches!(self, RtnlMessage::NewAddrLabel(_))
+    }
+
+    pub fn is_del_addr_label(&self) -> bool {
+        matches!(self, RtnlMessage::DelAddrLabel(_))
+    }
+
+    pub fn is_get_addr_label(&self) -> bool {
+        matches!(self, RtnlMessage::GetAddrLabel(_))
+    }
+
+    pub fn is_new_multicast_address(&self) -> bool {
+        matches!(self, RtnlMessage::NewMulticastAddress(_))
+    }
+
+    pub fn is_del_multicast_address(&self) -> bool {
+        matches!(self, RtnlMessage::DelMulticastAddress(_))
+    }
+
+    pub fn is_get_multicast_address(&self) -> bool {
+        matches!(self, RtnlMessage::GetMulticastAddress(_))
+    }
+
+    pub fn is_new_anycast_address(&self) -> bool {
+        matches!(self, RtnlMessage::NewAnycastAddress(_))
+    }
+
+    pub fn is_del_anycast_address(&self) -> bool {
+        matches!(self, RtnlMessage::DelAnycastAddress(_))
+    }
+
+    pub fn is_get_anycast_address(&self) -> bool {
+        matches!(self, RtnlMessage::GetAnycastAddress(_))
+    }
+
+    pub fn is_new_prefix(&self) -> bool {
+        matches!(self, RtnlMessage::NewPrefix(_))
+    }
+
+    pub fn is_new_nd_user_opt(&self) -> bool {
+        matches!(self, RtnlMessage::NewNdUserOpt(_))
+    }
+
+    pub fn is_new_cache_report(&self) -> bool {
+        matches!(self, RtnlMessage::NewCacheReport(_))
+    }
+
     pub fn message_type(&self) -> u16 {
         use self::RtnlMessage::*;
 
@@ -236,6 +391,35 @@ impl RtnlMessage {
             GetRule(_) => RTM_GETRULE,
             NewRule(_) => RTM_NEWRULE,
             DelRule(_) => RTM_DELRULE,
+            NewNexthop(_) => RTM_NEWNEXTHOP,
+            DelNexthop(_) => RTM_DELNEXTHOP,
+            GetNexthop(_) => RTM_GETNEXTHOP,
+            NewNetconf(_) => RTM_NEWNETCONF,
+            DelNetconf(_) => RTM_DELNETCONF,
+            GetNetconf(_) => RTM_GETNETCONF,
+            NewStats(_) => RTM_NEWSTATS,
+            GetStats(_) => RTM_GETSTATS,
+            NewMdb(_) => RTM_NEWMDB,
+            DelMdb(_) => RTM_DELMDB,
+            GetMdb(_) => RTM_GETMDB,
+            NewBridgeVlan(_) => RTM_NEWVLAN,
+            DelBridgeVlan(_) => RTM_DELVLAN,
+            GetBridgeVlan(_) => RTM_GETVLAN,
+            NewTrafficAction(_) => RTM_NEWACTION,
+            DelTrafficAction(_) => RTM_DELACTION,
+            GetTrafficAction(_) => RTM_GETACTION,
+            NewAddrLabel(_) => RTM_NEWADDRLABEL,
+            DelAddrLabel(_) => RTM_DELADDRLABEL,
+            GetAddrLabel(_) => RTM_GETADDRLABEL,
+            NewMulticastAddress(_) => RTM_NEWMULTICAST,
+            DelMulticastAddress(_) => RTM_DELMULTICAST,
+            GetMulticastAddress(_) => RTM_GETMULTICAST,
+            NewAnycastAddress(_) => RTM_NEWANYCAST,
+            DelAnycastAddress(_) => RTM_DELANYCAST,
+            GetAnycastAddress(_) => RTM_GETANYCAST,
+            NewPrefix(_) => RTM_NEWPREFIX,
+            NewNdUserOpt(_) => RTM_NEWNDUSEROPT,
+            NewCacheReport(_) => RTM_NEWCACHEREPORT,
         }
     }
 }
@@ -256,6 +440,12 @@ impl Emitable for RtnlMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            | NewMulticastAddress(ref msg)
+            | DelMulticastAddress(ref msg)
+            | GetMulticastAddress(ref msg)
+            | NewAnycastAddress(ref msg)
+            | DelAnycastAddress(ref msg)
+            | GetAnycastAddress(ref msg)
             => msg.buffer_len(),
 
             | NewNeighbour(ref msg)
@@ -295,7 +485,47 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
-            => msg.buffer_len()
+            => msg.buffer_len(),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            => msg.buffer_len(),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.buffer_len(),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.buffer_len(),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.buffer_len(),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.buffer_len(),
+
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.buffer_len(),
+
+            | NewAddrLabel(ref msg)
+            | DelAddrLabel(ref msg)
+            | GetAddrLabel(ref msg)
+            => msg.buffer_len(),
+
+            NewPrefix(ref msg) => msg.buffer_len(),
+
+            NewNdUserOpt(ref msg) => msg.buffer_len(),
+
+            NewCacheReport(ref msg) => msg.buffer_len()
         }
     }
 
@@ -314,6 +544,12 @@ impl Emitable for RtnlMessage {
             | NewAddress(ref msg)
             | DelAddress(ref msg)
             | GetAddress(ref msg)
+            | NewMulticastAddress(ref msg)
+            | DelMulticastAddress(ref msg)
+            | GetMulticastAddress(ref msg)
+            | NewAnycastAddress(ref msg)
+            | DelAnycastAddress(ref msg)
+            | GetAnycastAddress(ref msg)
             => msg.emit(buffer),
 
             | GetNeighbour(ref msg)
@@ -353,7 +589,47 @@ impl Emitable for RtnlMessage {
             | NewRule(ref msg)
             | DelRule(ref msg)
             | GetRule(ref msg)
-            => msg.emit(buffer)
+            => msg.emit(buffer),
+
+            | NewNexthop(ref msg)
+            | DelNexthop(ref msg)
+            | GetNexthop(ref msg)
+            => msg.emit(buffer),
+
+            | NewNetconf(ref msg)
+            | DelNetconf(ref msg)
+            | GetNetconf(ref msg)
+            => msg.emit(buffer),
+
+            | NewStats(ref msg)
+            | GetStats(ref msg)
+            => msg.emit(buffer),
+
+            | NewMdb(ref msg)
+            | DelMdb(ref msg)
+            | GetMdb(ref msg)
+            => msg.emit(buffer),
+
+            | NewBridgeVlan(ref msg)
+            | DelBridgeVlan(ref msg)
+            | GetBridgeVlan(ref msg)
+            => msg.emit(buffer),
+
+            | NewTrafficAction(ref msg)
+            | DelTrafficAction(ref msg)
+            | GetTrafficAction(ref msg)
+            => msg.emit(buffer),
+
+            | NewAddrLabel(ref msg)
+            | DelAddrLabel(ref msg)
+            | GetAddrLabel(ref msg)
+            => msg.emit(buffer),
+
+            NewPrefix(ref msg) => msg.emit(buffer),
+
+            NewNdUserOpt(ref msg) => msg.emit(buffer),
+
+            NewCacheReport(ref msg) => msg.emit(buffer)
         }
     }
 }
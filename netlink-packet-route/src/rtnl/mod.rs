@@ -3,9 +3,50 @@
 pub mod address;
 pub use address::{AddressHeader, AddressMessage, AddressMessageBuffer, ADDRESS_HEADER_LEN};
 
+pub mod addr_label;
+pub use addr_label::{
+    AddrLabelHeader,
+    AddrLabelMessage,
+    AddrLabelMessageBuffer,
+    ADDR_LABEL_HEADER_LEN,
+};
+
+pub mod bridge_vlan;
+pub use bridge_vlan::{
+    BridgeVlanHeader,
+    BridgeVlanMessage,
+    BridgeVlanMessageBuffer,
+    BRIDGE_VLAN_HEADER_LEN,
+};
+
+pub mod cache_report;
+pub use cache_report::{
+    CacheReportHeader,
+    CacheReportMessage,
+    CacheReportMessageBuffer,
+    CACHE_REPORT_HEADER_LEN,
+};
+
 pub mod link;
 pub use link::{LinkHeader, LinkMessage, LinkMessageBuffer, LINK_HEADER_LEN};
 
+pub mod mdb;
+pub use mdb::{MdbHeader, MdbMessage, MdbMessageBuffer, MDB_HEADER_LEN};
+
+pub mod nd_user_opt;
+pub use nd_user_opt::{
+    Dnssl,
+    NdOption,
+    NdOptionBuffer,
+    NdUserOptHeader,
+    NdUserOptMessage,
+    NdUserOptMessageBuffer,
+    Rdnss,
+    RouteInfo,
+    ND_OPTION_HEADER_LEN,
+    ND_USER_OPT_HEADER_LEN,
+};
+
 pub mod neighbour;
 pub use neighbour::{
     NeighbourHeader,
@@ -22,18 +63,33 @@ pub use neighbour_table::{
     NEIGHBOUR_TABLE_HEADER_LEN,
 };
 
+pub mod netconf;
+pub use netconf::{NetconfHeader, NetconfMessage, NetconfMessageBuffer, NETCONF_HEADER_LEN};
+
+pub mod nexthop;
+pub use nexthop::{NexthopHeader, NexthopMessage, NexthopMessageBuffer, NEXTHOP_HEADER_LEN};
+
 pub mod nsid;
 pub use nsid::{NsidHeader, NsidMessage, NsidMessageBuffer, NSID_HEADER_LEN};
 
+pub mod prefix;
+pub use prefix::{PrefixHeader, PrefixMessage, PrefixMessageBuffer, PREFIX_HEADER_LEN};
+
 pub mod route;
 pub use route::{RouteFlags, RouteHeader, RouteMessage, RouteMessageBuffer, ROUTE_HEADER_LEN};
 
 pub mod rule;
 pub use rule::{RuleHeader, RuleMessage, RuleMessageBuffer, RULE_HEADER_LEN};
 
+pub mod stats;
+pub use stats::{StatsHeader, StatsMessage, StatsMessageBuffer, STATS_HEADER_LEN};
+
 pub mod tc;
 pub use tc::{TcHeader, TcMessage, TcMessageBuffer, TC_HEADER_LEN};
 
+pub mod tc_action;
+pub use tc_action::{TcActionHeader, TcActionMessage, TcActionMessageBuffer, TC_ACTION_HEADER_LEN};
+
 pub mod constants;
 pub use self::constants::*;
 
@@ -45,14 +101,24 @@ pub use self::message::*;
 
 pub mod nlas {
     pub use super::{
+        addr_label::nlas as addr_label,
         address::nlas as address,
+        bridge_vlan::nlas as bridge_vlan,
+        cache_report::nlas as cache_report,
         link::nlas as link,
+        mdb::nlas as mdb,
+        nd_user_opt::nlas as nd_user_opt,
         neighbour::nlas as neighbour,
         neighbour_table::nlas as neighbour_table,
+        netconf::nlas as netconf,
+        nexthop::nlas as nexthop,
         nsid::nlas as nsid,
+        prefix::nlas as prefix,
         route::nlas as route,
         rule::nlas as rule,
+        stats::nlas as stats,
         tc::nlas as tc,
+        tc_action::nlas as tc_action,
     };
     pub use crate::utils::nla::*;
 }
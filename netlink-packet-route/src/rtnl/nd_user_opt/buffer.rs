@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const ND_USER_OPT_HEADER_LEN: usize = 16;
+
+buffer!(NdUserOptMessageBuffer(ND_USER_OPT_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    opts_len: (u16, 2..4),
+    ifindex: (u32, 4..8),
+    icmp_type: (u8, 8),
+    icmp_code: (u8, 9),
+    pad2: (u16, 10..12),
+    pad3: (u32, 12..16),
+    payload: (slice, ND_USER_OPT_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> NdUserOptMessageBuffer<&'a T> {
+    /// The raw neighbour discovery options, as found in the ICMPv6 message
+    pub fn options(&self) -> Result<&'a [u8], DecodeError> {
+        let opts_len = self.opts_len() as usize;
+        let payload = self.payload();
+        if payload.len() < opts_len {
+            return Err(format!(
+                "invalid nduseroptmsg: options length {} exceeds the payload length {}",
+                opts_len,
+                payload.len()
+            )
+            .into());
+        }
+        Ok(&payload[..opts_len])
+    }
+
+    /// The attributes that follow the options
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        let payload = self.payload();
+        let opts_len = (self.opts_len() as usize).min(payload.len());
+        NlasIterator::new(&payload[opts_len..])
+    }
+}
@@ -0,0 +1,48 @@
+// SPDX-License-Identifier: MIT
+
+use super::{NdUserOptMessageBuffer, ND_USER_OPT_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// High level representation of `struct nduseroptmsg`. The length of the
+/// options is computed from the options of the message when it is emitted.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NdUserOptHeader {
+    pub family: u8,
+    /// Index of the interface the ICMPv6 message was received on
+    pub ifindex: u32,
+    /// Type of the ICMPv6 message the options come from, usually
+    /// `ND_ROUTER_ADVERT` (134)
+    pub icmp_type: u8,
+    pub icmp_code: u8,
+}
+
+impl Emitable for NdUserOptHeader {
+    fn buffer_len(&self) -> usize {
+        ND_USER_OPT_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NdUserOptMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_icmp_type(self.icmp_type);
+        packet.set_icmp_code(self.icmp_code);
+        packet.set_pad2(0);
+        packet.set_pad3(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NdUserOptMessageBuffer<T>> for NdUserOptHeader {
+    fn parse(buf: &NdUserOptMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NdUserOptHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+            icmp_type: buf.icmp_type(),
+            icmp_code: buf.icmp_code(),
+        })
+    }
+}
@@ -0,0 +1,201 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::nd_user_opt::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError,
+    NdOption,
+    NdOptionBuffer,
+    NdUserOptHeader,
+    NdUserOptMessageBuffer,
+    ND_OPTION_HEADER_LEN,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NdUserOptMessage {
+    pub header: NdUserOptHeader,
+    pub options: Vec<NdOption>,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptMessageBuffer<&'a T>> for NdUserOptMessage {
+    fn parse(buf: &NdUserOptMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: NdUserOptHeader::parse(buf)
+                .context("failed to parse nd user option message header")?,
+            options: Vec::<NdOption>::parse(buf)
+                .context("failed to parse nd user option message options")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse nd user option message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptMessageBuffer<&'a T>> for Vec<NdOption> {
+    fn parse(buf: &NdUserOptMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut options = vec![];
+        let mut payload = buf.options()?;
+        while !payload.is_empty() {
+            if payload.len() < ND_OPTION_HEADER_LEN {
+                return Err("truncated neighbour discovery option".into());
+            }
+            let len = payload[1] as usize * 8;
+            if len == 0 || len > payload.len() {
+                return Err(format!("invalid neighbour discovery option length {}", len).into());
+            }
+            options.push(NdOption::parse(&NdOptionBuffer::new(&payload[..len]))?);
+            payload = &payload[len..];
+        }
+        Ok(options)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NdUserOptMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &NdUserOptMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for NdUserOptMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.options_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        let options_len = self.options_len();
+        NdUserOptMessageBuffer::new(&mut *buffer).set_opts_len(options_len as u16);
+        let mut offset = self.header.buffer_len();
+        for option in &self.options {
+            option.emit(&mut buffer[offset..]);
+            offset += option.buffer_len();
+        }
+        self.nlas.as_slice().emit(&mut buffer[offset..]);
+    }
+}
+
+impl NdUserOptMessage {
+    fn options_len(&self) -> usize {
+        self.options.iter().map(|option| option.buffer_len()).sum()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv6Addr;
+
+    use crate::{
+        nlas::nd_user_opt::Nla,
+        traits::{Emitable, ParseableParametrized},
+        Dnssl,
+        NdOption,
+        NdUserOptHeader,
+        NdUserOptMessage,
+        NetlinkBuffer,
+        Rdnss,
+        RouteInfo,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_INET6,
+        RTM_NEWNDUSEROPT,
+    };
+
+    // Router advertisement options relayed by the kernel
+    #[rustfmt::skip]
+    static NEW_ND_USER_OPT: [u8; 116] = [
+        0x74, 0x00, 0x00, 0x00, // length = 116
+        0x44, 0x00, // message type = 68 = RTM_NEWNDUSEROPT
+        0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // seq number
+        0x00, 0x00, 0x00, 0x00, // pid
+
+        // nd user option message
+        0x0a, // family = AF_INET6
+        0x00, // pad1
+        0x40, 0x00, // options length = 64
+        0x03, 0x00, 0x00, 0x00, // ifindex = 3
+        0x86, // icmp type = ND_ROUTER_ADVERT
+        0x00, // icmp code
+        0x00, 0x00, // pad2
+        0x00, 0x00, 0x00, 0x00, // pad3
+
+        0x19, 0x03, // type = ND_OPT_RDNSS, length = 3 * 8
+            0x00, 0x00, // reserved
+            0x00, 0x00, 0x07, 0x08, // lifetime = 1800
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, // 2001:db8::53
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x53,
+        0x1f, 0x03, // type = ND_OPT_DNSSL, length = 3 * 8
+            0x00, 0x00, // reserved
+            0x00, 0x00, 0x07, 0x08, // lifetime = 1800
+            0x07, b'e', b'x', b'a', b'm', b'p', b'l', b'e', // example.com
+            0x03, b'c', b'o', b'm', 0x00,
+            0x00, 0x00, 0x00, // padding
+        0x18, 0x02, // type = ND_OPT_ROUTE_INFO, length = 2 * 8
+            0x30, // prefix length = 48
+            0x08, // flags = high preference
+            0x00, 0x00, 0x0e, 0x10, // lifetime = 3600
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x01, 0x00, 0x00, // 2001:db8:1::
+
+        0x14, 0x00, 0x01, 0x00, // length = 20, type = NDUSEROPT_SRCADDR
+            0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // fe80::1
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+
+    fn new_nd_user_opt() -> NdUserOptMessage {
+        NdUserOptMessage {
+            header: NdUserOptHeader {
+                family: AF_INET6 as u8,
+                ifindex: 3,
+                icmp_type: 134,
+                icmp_code: 0,
+            },
+            options: vec![
+                NdOption::Rdnss(Rdnss {
+                    lifetime: 1800,
+                    servers: vec![Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0x53)],
+                }),
+                NdOption::Dnssl(Dnssl {
+                    lifetime: 1800,
+                    domains: vec!["example.com".to_string()],
+                }),
+                NdOption::RouteInfo(RouteInfo {
+                    prefix_len: 48,
+                    flags: 0x08,
+                    lifetime: 3600,
+                    prefix: Ipv6Addr::new(0x2001, 0xdb8, 1, 0, 0, 0, 0, 0),
+                }),
+            ],
+            nlas: vec![Nla::SrcAddr(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 1))],
+        }
+    }
+
+    #[test]
+    fn parse_new_nd_user_opt() {
+        let nl_buffer = NetlinkBuffer::new(&NEW_ND_USER_OPT).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNDUSEROPT).unwrap();
+        assert_eq!(RtnlMessage::NewNdUserOpt(new_nd_user_opt()), actual);
+    }
+
+    #[test]
+    fn emit_new_nd_user_opt() {
+        let msg = new_nd_user_opt();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &NEW_ND_USER_OPT[16..]);
+    }
+
+    #[test]
+    fn route_info_preference() {
+        if let NdOption::RouteInfo(info) = &new_nd_user_opt().options[2] {
+            assert_eq!(info.preference(), 1);
+        } else {
+            unreachable!()
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: MIT
+
+mod buffer;
+mod header;
+mod message;
+pub mod nlas;
+mod option;
+
+pub use self::{buffer::*, header::*, message::*, nlas::*, option::*};
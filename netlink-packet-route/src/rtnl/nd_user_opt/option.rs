@@ -0,0 +1,226 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{
+    constants::*,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const ND_OPTION_HEADER_LEN: usize = 2;
+
+buffer!(NdOptionBuffer(ND_OPTION_HEADER_LEN) {
+    kind: (u8, 0),
+    // in units of 8 bytes, including the header
+    length: (u8, 1),
+    value: (slice, ND_OPTION_HEADER_LEN..),
+});
+
+/// A neighbour discovery option relayed to userspace by the kernel
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum NdOption {
+    /// Recursive DNS servers (RFC 8106)
+    Rdnss(Rdnss),
+    /// DNS search list (RFC 8106)
+    Dnssl(Dnssl),
+    /// More specific route (RFC 4191)
+    RouteInfo(RouteInfo),
+    /// Any other option: its type, and its value without the type and
+    /// length bytes
+    Other(u8, Vec<u8>),
+}
+
+/// Recursive DNS servers option. The lifetime is in seconds.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Rdnss {
+    pub lifetime: u32,
+    pub servers: Vec<Ipv6Addr>,
+}
+
+/// DNS search list option. The lifetime is in seconds.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Dnssl {
+    pub lifetime: u32,
+    /// Domain names, without the trailing dot
+    pub domains: Vec<String>,
+}
+
+/// Route information option. The lifetime is in seconds.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RouteInfo {
+    pub prefix_len: u8,
+    /// The route preference is in bits 3 and 4, see
+    /// [`RouteInfo::preference`]
+    pub flags: u8,
+    pub lifetime: u32,
+    pub prefix: Ipv6Addr,
+}
+
+impl RouteInfo {
+    /// Return the route preference: 1 for high, 0 for medium and -1 for low.
+    /// The reserved value 2 is returned as is.
+    pub fn preference(&self) -> i8 {
+        match (self.flags >> 3) & 0x3 {
+            3 => -1,
+            value => value as i8,
+        }
+    }
+
+    // The prefix is truncated to the number of 8 bytes blocks its length
+    // requires
+    fn prefix_bytes(&self) -> usize {
+        match self.prefix_len {
+            0 => 0,
+            1..=64 => 8,
+            _ => 16,
+        }
+    }
+}
+
+// Encode a domain name in the DNS wire format
+fn emit_domain(domain: &str, buffer: &mut Vec<u8>) {
+    for label in domain.split('.').filter(|label| !label.is_empty()) {
+        buffer.push(label.len() as u8);
+        buffer.extend_from_slice(label.as_bytes());
+    }
+    buffer.push(0);
+}
+
+impl NdOption {
+    fn value(&self) -> Vec<u8> {
+        use self::NdOption::*;
+        let mut value = vec![];
+        match self {
+            Rdnss(rdnss) => {
+                value.resize(6, 0);
+                BigEndian::write_u32(&mut value[2..6], rdnss.lifetime);
+                for server in &rdnss.servers {
+                    value.extend_from_slice(&server.octets());
+                }
+            }
+            Dnssl(dnssl) => {
+                value.resize(6, 0);
+                BigEndian::write_u32(&mut value[2..6], dnssl.lifetime);
+                for domain in &dnssl.domains {
+                    emit_domain(domain, &mut value);
+                }
+            }
+            RouteInfo(info) => {
+                value.resize(6, 0);
+                value[0] = info.prefix_len;
+                value[1] = info.flags;
+                BigEndian::write_u32(&mut value[2..6], info.lifetime);
+                value.extend_from_slice(&info.prefix.octets()[..info.prefix_bytes()]);
+            }
+            Other(_, bytes) => value.extend_from_slice(bytes),
+        }
+        value
+    }
+
+    fn kind(&self) -> u8 {
+        use self::NdOption::*;
+        match self {
+            Rdnss(_) => ND_OPT_RDNSS,
+            Dnssl(_) => ND_OPT_DNSSL,
+            RouteInfo(_) => ND_OPT_ROUTE_INFO,
+            Other(kind, _) => *kind,
+        }
+    }
+}
+
+impl Emitable for NdOption {
+    fn buffer_len(&self) -> usize {
+        // Options are padded to a multiple of 8 bytes
+        (ND_OPTION_HEADER_LEN + self.value().len() + 7) & !7
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let len = self.buffer_len();
+        let value = self.value();
+        let mut packet = NdOptionBuffer::new(&mut buffer[..len]);
+        packet.set_kind(self.kind());
+        packet.set_length((len / 8) as u8);
+        let padded = packet.value_mut();
+        padded[..value.len()].copy_from_slice(&value);
+        for byte in padded[value.len()..].iter_mut() {
+            *byte = 0;
+        }
+    }
+}
+
+fn parse_domains(mut payload: &[u8]) -> Result<Vec<String>, DecodeError> {
+    let mut domains = vec![];
+    // The list ends with zero padding, i.e. empty domain names
+    while !payload.is_empty() && payload[0] != 0 {
+        let mut labels = vec![];
+        loop {
+            let len = *payload
+                .first()
+                .ok_or("truncated domain name in DNSSL option")? as usize;
+            payload = &payload[1..];
+            if len == 0 {
+                break;
+            }
+            if payload.len() < len {
+                return Err("truncated domain name in DNSSL option".into());
+            }
+            labels.push(String::from_utf8_lossy(&payload[..len]).into_owned());
+            payload = &payload[len..];
+        }
+        domains.push(labels.join("."));
+    }
+    Ok(domains)
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NdOptionBuffer<&'a T>> for NdOption {
+    fn parse(buf: &NdOptionBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            ND_OPT_RDNSS => {
+                let servers = payload.get(6..).map(|addresses| addresses.chunks_exact(16));
+                let servers = match servers {
+                    Some(servers) if servers.remainder().is_empty() => servers,
+                    _ => return Err(format!("invalid RDNSS option: {:?}", payload).into()),
+                };
+                NdOption::Rdnss(Rdnss {
+                    lifetime: BigEndian::read_u32(&payload[2..6]),
+                    servers: servers
+                        .map(|chunk| {
+                            let mut octets = [0; 16];
+                            octets.copy_from_slice(chunk);
+                            Ipv6Addr::from(octets)
+                        })
+                        .collect(),
+                })
+            }
+            ND_OPT_DNSSL => {
+                if payload.len() < 6 {
+                    return Err(format!("invalid DNSSL option: {:?}", payload).into());
+                }
+                NdOption::Dnssl(Dnssl {
+                    lifetime: BigEndian::read_u32(&payload[2..6]),
+                    domains: parse_domains(&payload[6..])?,
+                })
+            }
+            ND_OPT_ROUTE_INFO => {
+                if payload.len() < 6 || payload[0] > 128 {
+                    return Err(format!("invalid route information option: {:?}", payload).into());
+                }
+                let mut octets = [0; 16];
+                let prefix = &payload[6..];
+                let prefix_len = prefix.len().min(16);
+                octets[..prefix_len].copy_from_slice(&prefix[..prefix_len]);
+                NdOption::RouteInfo(RouteInfo {
+                    prefix_len: payload[0],
+                    flags: payload[1],
+                    lifetime: BigEndian::read_u32(&payload[2..6]),
+                    prefix: Ipv6Addr::from(octets),
+                })
+            }
+            kind => NdOption::Other(kind, payload.to_vec()),
+        })
+    }
+}
@@ -53,7 +53,8 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<NeighbourMessageBuffer<&'a T>> for Vec<N
 mod test {
     use crate::{
         constants::*,
-        traits::Emitable,
+        neighbour::{FdbExtAttr, Nla},
+        traits::{Emitable, Parseable},
         NeighbourHeader,
         NeighbourMessage,
         NeighbourMessageBuffer,
@@ -119,4 +120,54 @@ mod test {
         assert_eq!(packet.buffer_len(), 12);
         packet.emit(&mut buf[..]);
     }
+
+    // bridge fdb append 00:00:00:00:00:00 dev vxlan0 dst 10.0.0.2 port 4789 vni 42 \
+    //     activity_notify
+    #[rustfmt::skip]
+    static VXLAN_FDB: [u8; 60] = [
+        0x07, // interface family (bridge)
+        0x00, 0x00, 0x00, // padding
+        0x0a, 0x00, 0x00, 0x00, // interface index = 10
+        0xc0, 0x00, // state NUD_NOARP | NUD_PERMANENT
+        0x02, // flags NTF_SELF
+        0x00, // ntype
+
+        0x0a, 0x00, 0x02, 0x00, // length = 10, type = NDA_LLADDR
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = NDA_DST
+            0x0a, 0x00, 0x00, 0x02,
+        0x06, 0x00, 0x06, 0x00, // length = 6, type = NDA_PORT
+            0x12, 0xb5, 0x00, 0x00, // 4789, in network byte order
+        0x08, 0x00, 0x07, 0x00, // length = 8, type = NDA_VNI
+            0x2a, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x0e, 0x80, // length = 12, type = NDA_FDB_EXT_ATTRS | NLA_F_NESTED
+            0x05, 0x00, 0x01, 0x00, // length = 5, type = NFEA_ACTIVITY_NOTIFY
+                0x01, 0x00, 0x00, 0x00, // FDB_NOTIFY_BIT
+    ];
+
+    #[test]
+    fn parse_vxlan_fdb() {
+        let expected = NeighbourMessage {
+            header: NeighbourHeader {
+                family: AF_BRIDGE as u8,
+                ifindex: 10,
+                state: NUD_NOARP | NUD_PERMANENT,
+                flags: NTF_SELF,
+                ntype: 0,
+            },
+            nlas: vec![
+                Nla::LinkLocalAddress(vec![0; 6]),
+                Nla::Destination(vec![10, 0, 0, 2]),
+                Nla::Port(vec![0x12, 0xb5]),
+                Nla::Vni(42),
+                Nla::FdbExtAttrs(vec![FdbExtAttr::ActivityNotify(FDB_NOTIFY_BIT)]),
+            ],
+        };
+        let actual = NeighbourMessage::parse(&NeighbourMessageBuffer::new(&VXLAN_FDB)).unwrap();
+        assert_eq!(actual, expected);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &VXLAN_FDB[..]);
+    }
 }
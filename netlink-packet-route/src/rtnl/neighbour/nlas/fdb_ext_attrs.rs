@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::parse_u8,
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Attributes of `NDA_FDB_EXT_ATTRS`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum FdbExtAttr {
+    /// `FDB_NOTIFY_*` bits: notify userspace when the entry becomes inactive
+    ActivityNotify(u8),
+    /// Do not refresh the activity of the entry on this update
+    DontRefresh,
+    Other(DefaultNla),
+}
+
+impl Nla for FdbExtAttr {
+    fn value_len(&self) -> usize {
+        match self {
+            FdbExtAttr::ActivityNotify(_) => 1,
+            FdbExtAttr::DontRefresh => 0,
+            FdbExtAttr::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            FdbExtAttr::ActivityNotify(value) => buffer[0] = *value,
+            FdbExtAttr::DontRefresh => {}
+            FdbExtAttr::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            FdbExtAttr::ActivityNotify(_) => NFEA_ACTIVITY_NOTIFY,
+            FdbExtAttr::DontRefresh => NFEA_DONT_REFRESH,
+            FdbExtAttr::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for FdbExtAttr {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NFEA_ACTIVITY_NOTIFY => FdbExtAttr::ActivityNotify(
+                parse_u8(payload).context("invalid NFEA_ACTIVITY_NOTIFY value")?,
+            ),
+            NFEA_DONT_REFRESH => FdbExtAttr::DontRefresh,
+            kind => FdbExtAttr::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
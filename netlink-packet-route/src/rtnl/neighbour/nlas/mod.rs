@@ -3,14 +3,17 @@
 mod cache_info;
 pub use self::cache_info::*;
 
+mod fdb_ext_attrs;
+pub use self::fdb_ext_attrs::*;
+
 use anyhow::Context;
 use byteorder::{ByteOrder, NativeEndian};
 
 use crate::{
     constants::*,
-    nlas::{self, DefaultNla, NlaBuffer},
-    parsers::{parse_u16, parse_u32},
-    traits::Parseable,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
     DecodeError,
 };
 
@@ -28,6 +31,17 @@ pub enum Nla {
     Master(Vec<u8>),
     LinkNetNsId(Vec<u8>),
     SourceVni(u32),
+    /// `RTPROT_*` of the entry creator
+    Protocol(u8),
+    /// Nexthop group used to reach the destination
+    NhId(u32),
+    FdbExtAttrs(Vec<FdbExtAttr>),
+    /// `NTF_EXT_*` flags
+    FlagsExt(u32),
+    /// Mask of the `NUD_*` states to match when dumping
+    NdmStateMask(u16),
+    /// Mask of the `NTF_*` flags to match when dumping
+    NdmFlagsMask(u8),
     Other(DefaultNla),
 }
 
@@ -44,10 +58,16 @@ impl nlas::Nla for Nla {
             | Master(ref bytes)
             | CacheInfo(ref bytes)
             | LinkNetNsId(ref bytes) => bytes.len(),
-            Vlan(_) => 2,
+            Vlan(_)
+            | NdmStateMask(_) => 2,
             Vni(_)
             | IfIndex(_)
-            | SourceVni(_) => 4,
+            | SourceVni(_)
+            | NhId(_)
+            | FlagsExt(_) => 4,
+            Protocol(_)
+            | NdmFlagsMask(_) => 1,
+            FdbExtAttrs(ref nlas) => nlas.as_slice().buffer_len(),
             Other(ref attr) => attr.value_len(),
         }
     }
@@ -64,10 +84,16 @@ impl nlas::Nla for Nla {
             | Master(ref bytes)
             | CacheInfo(ref bytes)
             | LinkNetNsId(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Vlan(ref value) => NativeEndian::write_u16(buffer, *value),
+            Vlan(ref value)
+            | NdmStateMask(ref value) => NativeEndian::write_u16(buffer, *value),
             Vni(ref value)
             | IfIndex(ref value)
-            | SourceVni(ref value) => NativeEndian::write_u32(buffer, *value),
+            | SourceVni(ref value)
+            | NhId(ref value)
+            | FlagsExt(ref value) => NativeEndian::write_u32(buffer, *value),
+            Protocol(ref value)
+            | NdmFlagsMask(ref value) => buffer[0] = *value,
+            FdbExtAttrs(ref nlas) => nlas.as_slice().emit(buffer),
             Other(ref attr) => attr.emit_value(buffer),
         }
     }
@@ -87,6 +113,12 @@ impl nlas::Nla for Nla {
             Master(_) => NDA_MASTER,
             LinkNetNsId(_) => NDA_LINK_NETNSID,
             SourceVni(_) => NDA_SRC_VNI,
+            Protocol(_) => NDA_PROTOCOL,
+            NhId(_) => NDA_NH_ID,
+            FdbExtAttrs(_) => NDA_FDB_EXT_ATTRS | NLA_F_NESTED,
+            FlagsExt(_) => NDA_FLAGS_EXT,
+            NdmStateMask(_) => NDA_NDM_STATE_MASK,
+            NdmFlagsMask(_) => NDA_NDM_FLAGS_MASK,
             Other(ref nla) => nla.kind(),
         }
     }
@@ -110,6 +142,24 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             NDA_MASTER => Master(payload.to_vec()),
             NDA_LINK_NETNSID => LinkNetNsId(payload.to_vec()),
             NDA_SRC_VNI => SourceVni(parse_u32(payload)?),
+            NDA_PROTOCOL => Protocol(parse_u8(payload).context("invalid NDA_PROTOCOL value")?),
+            NDA_NH_ID => NhId(parse_u32(payload).context("invalid NDA_NH_ID value")?),
+            NDA_FDB_EXT_ATTRS => {
+                let error_msg = "invalid NDA_FDB_EXT_ATTRS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(FdbExtAttr::parse(nla).context(error_msg)?);
+                }
+                FdbExtAttrs(nlas)
+            }
+            NDA_FLAGS_EXT => FlagsExt(parse_u32(payload).context("invalid NDA_FLAGS_EXT value")?),
+            NDA_NDM_STATE_MASK => {
+                NdmStateMask(parse_u16(payload).context("invalid NDA_NDM_STATE_MASK value")?)
+            }
+            NDA_NDM_FLAGS_MASK => {
+                NdmFlagsMask(parse_u8(payload).context("invalid NDA_NDM_FLAGS_MASK value")?)
+            }
             _ => Other(DefaultNla::parse(buf).context("invalid link NLA value (unknown type)")?),
         })
     }
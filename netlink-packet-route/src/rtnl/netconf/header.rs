@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+use super::{NetconfMessageBuffer, NETCONF_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetconfHeader {
+    pub family: u8,
+}
+
+impl Emitable for NetconfHeader {
+    fn buffer_len(&self) -> usize {
+        NETCONF_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = NetconfMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<NetconfMessageBuffer<T>> for NetconfHeader {
+    fn parse(buf: &NetconfMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(NetconfHeader {
+            family: buf.family(),
+        })
+    }
+}
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::netconf::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError,
+    NetconfHeader,
+    NetconfMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NetconfMessage {
+    pub header: NetconfHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NetconfMessageBuffer<&'a T>> for NetconfMessage {
+    fn parse(buf: &NetconfMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: NetconfHeader::parse(buf).context("failed to parse netconf message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse netconf message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NetconfMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &NetconfMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for NetconfMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl NetconfMessage {
+    /// Return the interface index the configuration applies to. Negative
+    /// values are `NETCONFA_IFINDEX_ALL` and `NETCONFA_IFINDEX_DEFAULT`.
+    pub fn ifindex(&self) -> Option<i32> {
+        self.nlas.iter().find_map(|nla| match nla {
+            Nla::IfIndex(index) => Some(*index),
+            _ => None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        nlas::netconf::Nla,
+        traits::{Emitable, ParseableParametrized},
+        NetconfHeader,
+        NetconfMessage,
+        NetlinkBuffer,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_INET,
+        NETCONFA_IFINDEX_ALL,
+        RTM_NEWNETCONF,
+    };
+
+    #[rustfmt::skip]
+    static NEW_NETCONF: [u8; 76] = [
+        0x4c, 0x00, 0x00, 0x00, // length = 76
+        0x50, 0x00, // message type = 80 = RTM_NEWNETCONF
+        0x02, 0x00, // flags = NLM_F_MULTI
+        0x00, 0x00, 0x00, 0x00, // seq number
+        0x00, 0x00, 0x00, 0x00, // pid
+
+        // netconf message
+        0x02, // family = AF_INET
+        0x00, 0x00, 0x00, // padding
+        0x08, 0x00, 0x01, 0x00, 0xff, 0xff, 0xff, 0xff, // NETCONFA_IFINDEX = -1 (all)
+        0x08, 0x00, 0x02, 0x00, 0x01, 0x00, 0x00, 0x00, // NETCONFA_FORWARDING = 1
+        0x08, 0x00, 0x03, 0x00, 0x02, 0x00, 0x00, 0x00, // NETCONFA_RP_FILTER = 2
+        0x08, 0x00, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, // NETCONFA_MC_FORWARDING = 0
+        0x08, 0x00, 0x05, 0x00, 0x00, 0x00, 0x00, 0x00, // NETCONFA_PROXY_NEIGH = 0
+        0x08, 0x00, 0x06, 0x00, 0x00, 0x00, 0x00, 0x00, // NETCONFA_IGNORE_ROUTES_WITH_LINKDOWN = 0
+        0x08, 0x00, 0x08, 0x00, 0x00, 0x00, 0x00, 0x00, // NETCONFA_BC_FORWARDING = 0
+    ];
+
+    fn new_netconf() -> NetconfMessage {
+        NetconfMessage {
+            header: NetconfHeader {
+                family: AF_INET as u8,
+            },
+            nlas: vec![
+                Nla::IfIndex(NETCONFA_IFINDEX_ALL),
+                Nla::Forwarding(1),
+                Nla::RpFilter(2),
+                Nla::McForwarding(0),
+                Nla::ProxyNeigh(0),
+                Nla::IgnoreRoutesWithLinkdown(0),
+                Nla::BcForwarding(0),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_new_netconf() {
+        let nl_buffer = NetlinkBuffer::new(&NEW_NETCONF).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWNETCONF).unwrap();
+        assert_eq!(RtnlMessage::NewNetconf(new_netconf()), actual);
+        assert_eq!(new_netconf().ifindex(), Some(NETCONFA_IFINDEX_ALL));
+    }
+
+    #[test]
+    fn emit_new_netconf() {
+        let msg = new_netconf();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &NEW_NETCONF[16..]);
+    }
+}
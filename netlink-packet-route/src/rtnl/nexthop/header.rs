@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT
+
+use super::{NexthopMessageBuffer, NEXTHOP_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// High level representation of `RTM_GETNEXTHOP`, `RTM_NEWNEXTHOP` and
+/// `RTM_DELNEXTHOP` messages headers.
+///
+/// These headers have the following structure:
+///
+/// ```no_rust
+/// 0                8                16              24               32
+/// +----------------+----------------+----------------+----------------+
+/// |     family     |     scope      |    protocol    |    reserved    |
+/// +----------------+----------------+----------------+----------------+
+/// |                               flags                               |
+/// +----------------+----------------+----------------+----------------+
+/// ```
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, Default)]
+pub struct NexthopHeader {
+    /// Address family: `AF_INET`, `AF_INET6` or `AF_UNSPEC` for groups and
+    /// blackhole nexthops.
+    pub family: u8,
+    /// Scope of the nexthop. This is only set by the kernel.
+    pub scope: u8,
+    /// Routing protocol that installed the nexthop (`RTPROT_*`)
+    pub protocol: u8,
+    /// Nexthop flags (`RTNH_F_*`)
+    pub flags: u32,
+}
+
+impl<T: AsRef<[u8]>> Parseable<NexthopMessageBuffer<T>> for NexthopHeader {
+    fn parse(buf: &NexthopMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            family: buf.family(),
+            scope: buf.scope(),
+            protocol: buf.protocol(),
+            flags: buf.flags(),
+        })
+    }
+}
+
+impl Emitable for NexthopHeader {
+    fn buffer_len(&self) -> usize {
+        NEXTHOP_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = NexthopMessageBuffer::new(buffer);
+        buffer.set_family(self.family);
+        buffer.set_scope(self.scope);
+        buffer.set_protocol(self.protocol);
+        buffer.set_reserved(0);
+        buffer.set_flags(self.flags);
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::NHA_ENCAP_TYPE,
+    nlas::{nexthop::Nla, route::find_encap_type},
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+    NexthopHeader,
+    NexthopMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct NexthopMessage {
+    pub header: NexthopHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl Emitable for NexthopMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NexthopMessageBuffer<&'a T>> for NexthopMessage {
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(NexthopMessage {
+            header: NexthopHeader::parse(buf).context("failed to parse nexthop message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse nexthop message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<NexthopMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &NexthopMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let encap_type = find_encap_type(NHA_ENCAP_TYPE, buf.nlas());
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse_with_param(&nla_buf?, encap_type)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl NexthopMessage {
+    /// Returns the nexthop id, if present.
+    pub fn id(&self) -> Option<u32> {
+        self.nlas
+            .iter()
+            .find_map(|nla| if let Nla::Id(v) = nla { Some(*v) } else { None })
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: MIT
+
+mod buffer;
+mod header;
+mod message;
+pub mod nlas;
+
+pub use self::{buffer::*, header::*, message::*, nlas::*};
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,178 @@
+// SPDX-License-Identifier: MIT
+
+mod group;
+pub use self::group::*;
+
+mod resilient;
+pub use self::resilient::*;
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator, NLA_F_NESTED},
+    parsers::{parse_u16, parse_u32},
+    route::RouteEncap,
+    traits::{Emitable, Parseable, ParseableParametrized},
+    DecodeError,
+};
+
+/// Netlink attributes for `RTM_NEWNEXTHOP`, `RTM_DELNEXTHOP` and
+/// `RTM_GETNEXTHOP` messages.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// Nexthop id. The kernel allocates one if it is not given on creation.
+    Id(u32),
+    /// Members of a nexthop group
+    Group(Vec<NexthopGroupEntry>),
+    /// Type of nexthop group: `NEXTHOP_GRP_TYPE_MPATH` or
+    /// `NEXTHOP_GRP_TYPE_RES`
+    GroupType(u16),
+    /// The nexthop drops packets
+    Blackhole,
+    /// Output interface index
+    Oif(u32),
+    /// Gateway address: 4 bytes for IPv4, 16 bytes for IPv6
+    Gateway(Vec<u8>),
+    /// Lightweight tunnel encapsulation type (`LWTUNNEL_ENCAP_*`)
+    EncapType(u16),
+    /// Lightweight tunnel encapsulation, whose type is given by `EncapType`
+    Encap(RouteEncap),
+    /// Only dump nexthop groups
+    Groups,
+    /// Only dump nexthops using devices enslaved to this master
+    Master(u32),
+    /// The nexthop is used by FDB entries (VXLAN)
+    Fdb,
+    /// Parameters of a resilient nexthop group
+    ResilientGroup(Vec<ResilientGroup>),
+    /// A bucket of a resilient nexthop group
+    ResilientBucket(Vec<ResilientBucket>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    #[rustfmt::skip]
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes)
+                | Gateway(bytes)
+                => bytes.len(),
+            Encap(encap) => encap.buffer_len(),
+            Group(entries) => entries.len() * NEXTHOP_GROUP_ENTRY_LEN,
+            Blackhole | Groups | Fdb => 0,
+            GroupType(_) | EncapType(_) => 2,
+            Id(_) | Oif(_) | Master(_) => 4,
+            ResilientGroup(nlas) => nlas.as_slice().buffer_len(),
+            ResilientBucket(nlas) => nlas.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes)
+                | Gateway(bytes)
+                => buffer.copy_from_slice(bytes.as_slice()),
+            Encap(encap) => encap.emit(buffer),
+            Group(entries) => {
+                for (i, entry) in entries.iter().enumerate() {
+                    let offset = i * NEXTHOP_GROUP_ENTRY_LEN;
+                    entry.emit(&mut buffer[offset..offset + NEXTHOP_GROUP_ENTRY_LEN]);
+                }
+            }
+            Blackhole | Groups | Fdb => {}
+            GroupType(value) | EncapType(value) => NativeEndian::write_u16(buffer, *value),
+            Id(value) | Oif(value) | Master(value) => NativeEndian::write_u32(buffer, *value),
+            ResilientGroup(nlas) => nlas.as_slice().emit(buffer),
+            ResilientBucket(nlas) => nlas.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => NHA_UNSPEC,
+            Id(_) => NHA_ID,
+            Group(_) => NHA_GROUP,
+            GroupType(_) => NHA_GROUP_TYPE,
+            Blackhole => NHA_BLACKHOLE,
+            Oif(_) => NHA_OIF,
+            Gateway(_) => NHA_GATEWAY,
+            EncapType(_) => NHA_ENCAP_TYPE,
+            Encap(_) => NHA_ENCAP | NLA_F_NESTED,
+            Groups => NHA_GROUPS,
+            Master(_) => NHA_MASTER,
+            Fdb => NHA_FDB,
+            ResilientGroup(_) => NHA_RES_GROUP | NLA_F_NESTED,
+            ResilientBucket(_) => NHA_RES_BUCKET | NLA_F_NESTED,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Self::parse_with_param(buf, LWTUNNEL_ENCAP_NONE)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> for Nla {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, encap_type: u16) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_UNSPEC => Unspec(payload.to_vec()),
+            NHA_ID => Id(parse_u32(payload).context("invalid NHA_ID value")?),
+            NHA_GROUP => {
+                let error_msg = "invalid NHA_GROUP value";
+                let mut entries = vec![];
+                for chunk in payload.chunks(NEXTHOP_GROUP_ENTRY_LEN) {
+                    let buf = NexthopGroupEntryBuffer::new_checked(chunk).context(error_msg)?;
+                    entries.push(NexthopGroupEntry::parse(&buf).context(error_msg)?);
+                }
+                Group(entries)
+            }
+            NHA_GROUP_TYPE => {
+                GroupType(parse_u16(payload).context("invalid NHA_GROUP_TYPE value")?)
+            }
+            NHA_BLACKHOLE => Blackhole,
+            NHA_OIF => Oif(parse_u32(payload).context("invalid NHA_OIF value")?),
+            NHA_GATEWAY => Gateway(payload.to_vec()),
+            NHA_ENCAP_TYPE => {
+                EncapType(parse_u16(payload).context("invalid NHA_ENCAP_TYPE value")?)
+            }
+            NHA_ENCAP => {
+                Encap(RouteEncap::parse(encap_type, payload).context("invalid NHA_ENCAP value")?)
+            }
+            NHA_GROUPS => Groups,
+            NHA_MASTER => Master(parse_u32(payload).context("invalid NHA_MASTER value")?),
+            NHA_FDB => Fdb,
+            NHA_RES_GROUP => {
+                let error_msg = "invalid NHA_RES_GROUP value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(self::ResilientGroup::parse(nla).context(error_msg)?);
+                }
+                ResilientGroup(nlas)
+            }
+            NHA_RES_BUCKET => {
+                let error_msg = "invalid NHA_RES_BUCKET value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(self::ResilientBucket::parse(nla).context(error_msg)?);
+                }
+                ResilientBucket(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
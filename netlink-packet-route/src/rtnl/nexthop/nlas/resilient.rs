@@ -0,0 +1,146 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_u16, parse_u32, parse_u64},
+    traits::Parseable,
+    DecodeError,
+};
+
+/// Parameters of a resilient nexthop group (`NHA_RES_GROUP_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ResilientGroup {
+    /// Number of hash buckets
+    Buckets(u16),
+    /// Time, in clock ticks, after which an idle bucket may be migrated
+    IdleTimer(u32),
+    /// Time, in clock ticks, after which the group is forcibly rebalanced
+    UnbalancedTimer(u32),
+    /// Time, in clock ticks, the group has been unbalanced. Only set by the
+    /// kernel.
+    UnbalancedTime(u64),
+    Other(DefaultNla),
+}
+
+impl Nla for ResilientGroup {
+    fn value_len(&self) -> usize {
+        use self::ResilientGroup::*;
+        match self {
+            Buckets(_) => 2,
+            IdleTimer(_) | UnbalancedTimer(_) => 4,
+            UnbalancedTime(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ResilientGroup::*;
+        match self {
+            Buckets(value) => NativeEndian::write_u16(buffer, *value),
+            IdleTimer(value) | UnbalancedTimer(value) => NativeEndian::write_u32(buffer, *value),
+            UnbalancedTime(value) => NativeEndian::write_u64(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ResilientGroup::*;
+        match self {
+            Buckets(_) => NHA_RES_GROUP_BUCKETS,
+            IdleTimer(_) => NHA_RES_GROUP_IDLE_TIMER,
+            UnbalancedTimer(_) => NHA_RES_GROUP_UNBALANCED_TIMER,
+            UnbalancedTime(_) => NHA_RES_GROUP_UNBALANCED_TIME,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ResilientGroup {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ResilientGroup::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_GROUP_BUCKETS => {
+                Buckets(parse_u16(payload).context("invalid NHA_RES_GROUP_BUCKETS value")?)
+            }
+            NHA_RES_GROUP_IDLE_TIMER => {
+                IdleTimer(parse_u32(payload).context("invalid NHA_RES_GROUP_IDLE_TIMER value")?)
+            }
+            NHA_RES_GROUP_UNBALANCED_TIMER => UnbalancedTimer(
+                parse_u32(payload).context("invalid NHA_RES_GROUP_UNBALANCED_TIMER value")?,
+            ),
+            NHA_RES_GROUP_UNBALANCED_TIME => UnbalancedTime(
+                parse_u64(payload).context("invalid NHA_RES_GROUP_UNBALANCED_TIME value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// A bucket of a resilient nexthop group (`NHA_RES_BUCKET_*`), as returned by
+/// the kernel.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ResilientBucket {
+    /// Index of the bucket in the group
+    Index(u16),
+    /// Time, in clock ticks, the bucket has been idle
+    IdleTime(u64),
+    /// Id of the nexthop the bucket is assigned to
+    NexthopId(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for ResilientBucket {
+    fn value_len(&self) -> usize {
+        use self::ResilientBucket::*;
+        match self {
+            Index(_) => 2,
+            NexthopId(_) => 4,
+            IdleTime(_) => 8,
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::ResilientBucket::*;
+        match self {
+            Index(value) => NativeEndian::write_u16(buffer, *value),
+            NexthopId(value) => NativeEndian::write_u32(buffer, *value),
+            IdleTime(value) => NativeEndian::write_u64(buffer, *value),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::ResilientBucket::*;
+        match self {
+            Index(_) => NHA_RES_BUCKET_INDEX,
+            IdleTime(_) => NHA_RES_BUCKET_IDLE_TIME,
+            NexthopId(_) => NHA_RES_BUCKET_NH_ID,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for ResilientBucket {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::ResilientBucket::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            NHA_RES_BUCKET_INDEX => {
+                Index(parse_u16(payload).context("invalid NHA_RES_BUCKET_INDEX value")?)
+            }
+            NHA_RES_BUCKET_IDLE_TIME => {
+                IdleTime(parse_u64(payload).context("invalid NHA_RES_BUCKET_IDLE_TIME value")?)
+            }
+            NHA_RES_BUCKET_NH_ID => {
+                NexthopId(parse_u32(payload).context("invalid NHA_RES_BUCKET_NH_ID value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,227 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    nlas::{
+        nexthop::{NexthopGroupEntry, Nla, ResilientGroup},
+        route::{MplsIpTunnel, MplsLabel, RouteEncap},
+    },
+    traits::{Emitable, Parseable},
+    NexthopHeader,
+    NexthopMessage,
+    NexthopMessageBuffer,
+};
+
+#[rustfmt::skip]
+static NEXTHOP_GROUP_MSG: [u8; 44] = [
+    0x00, // family (AF_UNSPEC)
+    0x00, // scope
+    0x03, // protocol (boot)
+    0x00, // reserved
+    0x00, 0x00, 0x00, 0x00, // flags
+
+    // NHA_ID
+    0x08, 0x00, // length
+    0x01, 0x00, // type
+    0x0a, 0x00, 0x00, 0x00, // 10
+
+    // NHA_GROUP
+    0x14, 0x00, // length (20)
+    0x02, 0x00, // type
+        0x01, 0x00, 0x00, 0x00, // id 1
+        0x00, // weight 1
+        0x00, 0x00, 0x00, // reserved
+        0x02, 0x00, 0x00, 0x00, // id 2
+        0x04, // weight 5
+        0x00, 0x00, 0x00, // reserved
+
+    // NHA_GROUP_TYPE
+    0x06, 0x00, // length
+    0x03, 0x00, // type
+    0x00, 0x00, // NEXTHOP_GRP_TYPE_MPATH
+    0x00, 0x00, // padding
+];
+
+#[rustfmt::skip]
+static NEXTHOP_GATEWAY_MSG: [u8; 32] = [
+    0x02, // family (AF_INET)
+    0xfd, // scope (link)
+    0x04, // protocol (static)
+    0x00, // reserved
+    0x04, 0x00, 0x00, 0x00, // flags (RTNH_F_ONLINK)
+
+    // NHA_ID
+    0x08, 0x00, // length
+    0x01, 0x00, // type
+    0x01, 0x00, 0x00, 0x00, // 1
+
+    // NHA_OIF
+    0x08, 0x00, // length
+    0x05, 0x00, // type
+    0x02, 0x00, 0x00, 0x00, // 2
+
+    // NHA_GATEWAY
+    0x08, 0x00, // length
+    0x06, 0x00, // type
+    0xc0, 0xa8, 0x01, 0x01, // 192.168.1.1
+];
+
+#[rustfmt::skip]
+static NEXTHOP_RESILIENT_MSG: [u8; 36] = [
+    0x00, // family (AF_UNSPEC)
+    0x00, // scope
+    0x03, // protocol (boot)
+    0x00, // reserved
+    0x00, 0x00, 0x00, 0x00, // flags
+
+    // NHA_GROUP_TYPE
+    0x06, 0x00, // length
+    0x03, 0x00, // type
+    0x01, 0x00, // NEXTHOP_GRP_TYPE_RES
+    0x00, 0x00, // padding
+
+    // NHA_RES_GROUP
+    0x14, 0x00, // length (20)
+    0x0c, 0x80, // type (nested)
+        // NHA_RES_GROUP_BUCKETS
+        0x06, 0x00, // length
+        0x01, 0x00, // type
+        0x80, 0x00, // 128
+        0x00, 0x00, // padding
+        // NHA_RES_GROUP_IDLE_TIMER
+        0x08, 0x00, // length
+        0x02, 0x00, // type
+        0x78, 0x00, 0x00, 0x00, // 120
+];
+
+// ip nexthop add id 1 encap mpls 100/200 ttl 10 via 10.0.0.2 dev eth0
+#[rustfmt::skip]
+static NEXTHOP_MPLS_ENCAP_MSG: [u8; 64] = [
+    0x02, // family (AF_INET)
+    0x00, // scope
+    0x04, // protocol (static)
+    0x00, // reserved
+    0x00, 0x00, 0x00, 0x00, // flags
+
+    // NHA_ID
+    0x08, 0x00, // length
+    0x01, 0x00, // type
+    0x01, 0x00, 0x00, 0x00, // 1
+
+    // NHA_OIF
+    0x08, 0x00, // length
+    0x05, 0x00, // type
+    0x02, 0x00, 0x00, 0x00, // 2
+
+    // NHA_GATEWAY
+    0x08, 0x00, // length
+    0x06, 0x00, // type
+    0x0a, 0x00, 0x00, 0x02, // 10.0.0.2
+
+    // NHA_ENCAP, before its type as the kernel sends it
+    0x18, 0x00, // length (24)
+    0x08, 0x80, // type (nested)
+        0x0c, 0x00, 0x01, 0x00, // length = 12, type = MPLS_IPTUNNEL_DST
+            0x00, 0x06, 0x40, 0x00, // label = 100
+            0x00, 0x0c, 0x81, 0x00, // label = 200, bottom of stack
+        0x05, 0x00, 0x02, 0x00, // length = 5, type = MPLS_IPTUNNEL_TTL
+            0x0a, 0x00, 0x00, 0x00, // 10
+
+    // NHA_ENCAP_TYPE
+    0x06, 0x00, // length
+    0x07, 0x00, // type
+    0x01, 0x00, // LWTUNNEL_ENCAP_MPLS
+    0x00, 0x00, // padding
+];
+
+fn group_message() -> NexthopMessage {
+    NexthopMessage {
+        header: NexthopHeader {
+            family: AF_UNSPEC as u8,
+            scope: 0,
+            protocol: RTPROT_BOOT,
+            flags: 0,
+        },
+        nlas: vec![
+            Nla::Id(10),
+            Nla::Group(vec![
+                NexthopGroupEntry { id: 1, weight: 0 },
+                NexthopGroupEntry { id: 2, weight: 4 },
+            ]),
+            Nla::GroupType(NEXTHOP_GRP_TYPE_MPATH),
+        ],
+    }
+}
+
+#[test]
+fn parse_nexthop_group() {
+    let buf = NexthopMessageBuffer::new(&NEXTHOP_GROUP_MSG);
+    assert_eq!(NexthopMessage::parse(&buf).unwrap(), group_message());
+}
+
+#[test]
+fn emit_nexthop_group() {
+    let msg = group_message();
+    let mut buf = [0; 44];
+    assert_eq!(msg.buffer_len(), 44);
+    msg.emit(&mut buf[..]);
+    assert_eq!(&buf[..], &NEXTHOP_GROUP_MSG[..]);
+}
+
+#[test]
+fn parse_nexthop_gateway() {
+    let buf = NexthopMessageBuffer::new(&NEXTHOP_GATEWAY_MSG);
+    let msg = NexthopMessage::parse(&buf).unwrap();
+    assert_eq!(msg.header.family, AF_INET as u8);
+    assert_eq!(msg.header.scope, RT_SCOPE_LINK);
+    assert_eq!(msg.header.flags, RTNH_F_ONLINK as u32);
+    assert_eq!(msg.id(), Some(1));
+    assert_eq!(
+        msg.nlas,
+        vec![Nla::Id(1), Nla::Oif(2), Nla::Gateway(vec![192, 168, 1, 1]),]
+    );
+}
+
+#[test]
+fn parse_emit_nexthop_resilient_group() {
+    let buf = NexthopMessageBuffer::new(&NEXTHOP_RESILIENT_MSG);
+    let msg = NexthopMessage::parse(&buf).unwrap();
+    assert_eq!(
+        msg.nlas,
+        vec![
+            Nla::GroupType(NEXTHOP_GRP_TYPE_RES),
+            Nla::ResilientGroup(vec![
+                ResilientGroup::Buckets(128),
+                ResilientGroup::IdleTimer(120),
+            ]),
+        ]
+    );
+
+    let mut buf = [0; 36];
+    msg.emit(&mut buf[..]);
+    assert_eq!(&buf[..], &NEXTHOP_RESILIENT_MSG[..]);
+}
+
+#[test]
+fn parse_emit_nexthop_mpls_encap() {
+    let buf = NexthopMessageBuffer::new(&NEXTHOP_MPLS_ENCAP_MSG);
+    let msg = NexthopMessage::parse(&buf).unwrap();
+    assert_eq!(
+        msg.nlas,
+        vec![
+            Nla::Id(1),
+            Nla::Oif(2),
+            Nla::Gateway(vec![10, 0, 0, 2]),
+            Nla::Encap(RouteEncap::Mpls(vec![
+                MplsIpTunnel::Destination(MplsLabel::stack(&[100, 200])),
+                MplsIpTunnel::Ttl(10),
+            ])),
+            Nla::EncapType(LWTUNNEL_ENCAP_MPLS),
+        ]
+    );
+
+    let mut buf = [0; 64];
+    assert_eq!(msg.buffer_len(), 64);
+    msg.emit(&mut buf[..]);
+    assert_eq!(&buf[..], &NEXTHOP_MPLS_ENCAP_MSG[..]);
+}
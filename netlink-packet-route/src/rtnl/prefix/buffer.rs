@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::{NlaBuffer, NlasIterator},
+    DecodeError,
+};
+
+pub const PREFIX_HEADER_LEN: usize = 12;
+
+buffer!(PrefixMessageBuffer(PREFIX_HEADER_LEN) {
+    family: (u8, 0),
+    pad1: (u8, 1),
+    pad2: (u16, 2..4),
+    ifindex: (u32, 4..8),
+    prefix_type: (u8, 8),
+    prefix_len: (u8, 9),
+    flags: (u8, 10),
+    pad3: (u8, 11),
+    payload: (slice, PREFIX_HEADER_LEN..),
+});
+
+impl<'a, T: AsRef<[u8]> + ?Sized> PrefixMessageBuffer<&'a T> {
+    pub fn nlas(&self) -> impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>> {
+        NlasIterator::new(self.payload())
+    }
+}
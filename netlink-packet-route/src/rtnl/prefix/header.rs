@@ -0,0 +1,51 @@
+// SPDX-License-Identifier: MIT
+
+use super::{PrefixMessageBuffer, PREFIX_HEADER_LEN};
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// High level representation of `struct prefixmsg`
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PrefixHeader {
+    pub family: u8,
+    /// Index of the interface the router advertisement was received on
+    pub ifindex: u32,
+    /// Type of the neighbour discovery option the prefix comes from
+    /// (`ND_OPT_PREFIX_INFO`)
+    pub prefix_type: u8,
+    pub prefix_len: u8,
+    /// `IF_PREFIX_*` flags
+    pub flags: u8,
+}
+
+impl Emitable for PrefixHeader {
+    fn buffer_len(&self) -> usize {
+        PREFIX_HEADER_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut packet = PrefixMessageBuffer::new(buffer);
+        packet.set_family(self.family);
+        packet.set_pad1(0);
+        packet.set_pad2(0);
+        packet.set_ifindex(self.ifindex);
+        packet.set_prefix_type(self.prefix_type);
+        packet.set_prefix_len(self.prefix_len);
+        packet.set_flags(self.flags);
+        packet.set_pad3(0);
+    }
+}
+
+impl<T: AsRef<[u8]>> Parseable<PrefixMessageBuffer<T>> for PrefixHeader {
+    fn parse(buf: &PrefixMessageBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(PrefixHeader {
+            family: buf.family(),
+            ifindex: buf.ifindex(),
+            prefix_type: buf.prefix_type(),
+            prefix_len: buf.prefix_len(),
+            flags: buf.flags(),
+        })
+    }
+}
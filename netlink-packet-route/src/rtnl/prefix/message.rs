@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    nlas::prefix::Nla,
+    traits::{Emitable, Parseable},
+    DecodeError,
+    PrefixHeader,
+    PrefixMessageBuffer,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct PrefixMessage {
+    pub header: PrefixHeader,
+    pub nlas: Vec<Nla>,
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<PrefixMessageBuffer<&'a T>> for PrefixMessage {
+    fn parse(buf: &PrefixMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            header: PrefixHeader::parse(buf).context("failed to parse prefix message header")?,
+            nlas: Vec::<Nla>::parse(buf).context("failed to parse prefix message NLAs")?,
+        })
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + 'a> Parseable<PrefixMessageBuffer<&'a T>> for Vec<Nla> {
+    fn parse(buf: &PrefixMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let mut nlas = vec![];
+        for nla_buf in buf.nlas() {
+            nlas.push(Nla::parse(&nla_buf?)?);
+        }
+        Ok(nlas)
+    }
+}
+
+impl Emitable for PrefixMessage {
+    fn buffer_len(&self) -> usize {
+        self.header.buffer_len() + self.nlas.as_slice().buffer_len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        self.header.emit(buffer);
+        self.nlas
+            .as_slice()
+            .emit(&mut buffer[self.header.buffer_len()..]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::net::Ipv6Addr;
+
+    use crate::{
+        nlas::prefix::{Nla, PrefixCacheInfo},
+        traits::{Emitable, ParseableParametrized},
+        NetlinkBuffer,
+        PrefixHeader,
+        PrefixMessage,
+        RtnlMessage,
+        RtnlMessageBuffer,
+        AF_INET6,
+        IF_PREFIX_AUTOCONF,
+        IF_PREFIX_ONLINK,
+        ND_OPT_PREFIX_INFO,
+        RTM_NEWPREFIX,
+    };
+
+    // Prefix information of a router advertisement for 2001:db8::/64
+    #[rustfmt::skip]
+    static NEW_PREFIX: [u8; 60] = [
+        0x3c, 0x00, 0x00, 0x00, // length = 60
+        0x34, 0x00, // message type = 52 = RTM_NEWPREFIX
+        0x00, 0x00, // flags
+        0x00, 0x00, 0x00, 0x00, // seq number
+        0x00, 0x00, 0x00, 0x00, // pid
+
+        // prefix message
+        0x0a, // family = AF_INET6
+        0x00, // pad1
+        0x00, 0x00, // pad2
+        0x03, 0x00, 0x00, 0x00, // ifindex = 3
+        0x03, // prefix type = ND_OPT_PREFIX_INFO
+        0x40, // prefix length = 64
+        0x03, // flags = IF_PREFIX_ONLINK | IF_PREFIX_AUTOCONF
+        0x00, // pad3
+        0x14, 0x00, 0x01, 0x00, // length = 20, type = PREFIX_ADDRESS
+            0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00, // 2001:db8::
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x0c, 0x00, 0x02, 0x00, // length = 12, type = PREFIX_CACHEINFO
+            0x08, 0x07, 0x00, 0x00, // preferred time = 1800
+            0x10, 0x0e, 0x00, 0x00, // valid time = 3600
+    ];
+
+    fn new_prefix() -> PrefixMessage {
+        PrefixMessage {
+            header: PrefixHeader {
+                family: AF_INET6 as u8,
+                ifindex: 3,
+                prefix_type: ND_OPT_PREFIX_INFO,
+                prefix_len: 64,
+                flags: IF_PREFIX_ONLINK | IF_PREFIX_AUTOCONF,
+            },
+            nlas: vec![
+                Nla::Address(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)),
+                Nla::CacheInfo(PrefixCacheInfo {
+                    preferred_time: 1800,
+                    valid_time: 3600,
+                }),
+            ],
+        }
+    }
+
+    #[test]
+    fn parse_new_prefix() {
+        let nl_buffer = NetlinkBuffer::new(&NEW_PREFIX).payload();
+        let rtnl_buffer = RtnlMessageBuffer::new(&nl_buffer);
+        let actual = RtnlMessage::parse_with_param(&rtnl_buffer, RTM_NEWPREFIX).unwrap();
+        assert_eq!(RtnlMessage::NewPrefix(new_prefix()), actual);
+    }
+
+    #[test]
+    fn emit_new_prefix() {
+        let msg = new_prefix();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf);
+        assert_eq!(&buf[..], &NEW_PREFIX[16..]);
+    }
+}
@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{self, DefaultNla, NlaBuffer},
+    parsers::parse_ipv6,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    Address(Ipv6Addr),
+    CacheInfo(PrefixCacheInfo),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => bytes.len(),
+            Address(_) => 16,
+            CacheInfo(ref info) => info.buffer_len(),
+            Other(ref attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match *self {
+            Unspec(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Address(ref addr) => buffer.copy_from_slice(&addr.octets()),
+            CacheInfo(ref info) => info.emit(buffer),
+            Other(ref attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match *self {
+            Unspec(_) => PREFIX_UNSPEC,
+            Address(_) => PREFIX_ADDRESS,
+            CacheInfo(_) => PREFIX_CACHEINFO,
+            Other(ref attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            PREFIX_UNSPEC => Unspec(payload.to_vec()),
+            PREFIX_ADDRESS => Address(Ipv6Addr::from(
+                parse_ipv6(payload).context("invalid PREFIX_ADDRESS")?,
+            )),
+            PREFIX_CACHEINFO => CacheInfo(
+                PrefixCacheInfo::parse(&PrefixCacheInfoBuffer::new_checked(payload)?)
+                    .context("invalid PREFIX_CACHEINFO")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Lifetimes of the prefix, in seconds
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+pub struct PrefixCacheInfo {
+    pub preferred_time: u32,
+    pub valid_time: u32,
+}
+
+pub const PREFIX_CACHE_INFO_LEN: usize = 8;
+buffer!(PrefixCacheInfoBuffer(PREFIX_CACHE_INFO_LEN) {
+    preferred_time: (u32, 0..4),
+    valid_time: (u32, 4..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<PrefixCacheInfoBuffer<T>> for PrefixCacheInfo {
+    fn parse(buf: &PrefixCacheInfoBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(PrefixCacheInfo {
+            preferred_time: buf.preferred_time(),
+            valid_time: buf.valid_time(),
+        })
+    }
+}
+
+impl Emitable for PrefixCacheInfo {
+    fn buffer_len(&self) -> usize {
+        PREFIX_CACHE_INFO_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = PrefixCacheInfoBuffer::new(buffer);
+        buffer.set_preferred_time(self.preferred_time);
+        buffer.set_valid_time(self.valid_time);
+    }
+}
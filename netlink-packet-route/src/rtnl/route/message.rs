@@ -1,8 +1,18 @@
 // SPDX-License-Identifier: MIT
 
 use crate::{
-    nlas::route::Nla,
-    traits::{Emitable, Parseable},
+    constants::{AF_MPLS, RTA_ENCAP_TYPE},
+    nlas::route::{
+        find_encap_type,
+        CacheInfo,
+        Metrics,
+        MfcStats,
+        MplsLabel,
+        NextHop,
+        Nla,
+        RouteEncap,
+    },
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
     RouteHeader,
     RouteMessageBuffer,
@@ -10,6 +20,9 @@ use crate::{
 use anyhow::Context;
 use std::net::IpAddr;
 
+#[cfg(not(feature = "rich_nlas"))]
+use crate::nlas::route::{parse_next_hops, CacheInfoBuffer, MfcStatsBuffer};
+
 #[derive(Debug, PartialEq, Eq, Clone, Default)]
 pub struct RouteMessage {
     pub header: RouteHeader,
@@ -40,9 +53,10 @@ impl<'a, T: AsRef<[u8]> + 'a> Parseable<RouteMessageBuffer<&'a T>> for RouteMess
 
 impl<'a, T: AsRef<[u8]> + 'a> Parseable<RouteMessageBuffer<&'a T>> for Vec<Nla> {
     fn parse(buf: &RouteMessageBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let encap_type = find_encap_type(RTA_ENCAP_TYPE, buf.nlas());
         let mut nlas = vec![];
         for nla_buf in buf.nlas() {
-            nlas.push(Nla::parse(&nla_buf?)?);
+            nlas.push(Nla::parse_with_param(&nla_buf?, encap_type)?);
         }
         Ok(nlas)
     }
@@ -85,8 +99,46 @@ impl RouteMessage {
         })
     }
 
+    /// Returns the next-hops of a multipath route, if present.
+    pub fn multipath(&self) -> Option<Vec<NextHop>> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::MultiPath(v) = nla {
+                #[cfg(not(feature = "rich_nlas"))]
+                return parse_next_hops(v).ok();
+                #[cfg(feature = "rich_nlas")]
+                return Some(v.clone());
+            }
+            None
+        })
+    }
+
+    /// Returns the metrics of the route, if present.
+    pub fn metrics(&self) -> Option<&[Metrics]> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Metrics(metrics) = nla {
+                Some(metrics.as_slice())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the lightweight tunnel encapsulation, if present.
+    pub fn encap(&self) -> Option<&RouteEncap> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Encap(encap) = nla {
+                Some(encap)
+            } else {
+                None
+            }
+        })
+    }
+
     /// Returns the source address prefix, if present.
     pub fn source_prefix(&self) -> Option<(IpAddr, u8)> {
+        if self.is_mpls() {
+            return None;
+        }
         self.nlas.iter().find_map(|nla| {
             if let Nla::Source(v) = nla {
                 octets_to_addr(v)
@@ -98,8 +150,12 @@ impl RouteMessage {
         })
     }
 
-    /// Returns the destination subnet prefix, if present.
+    /// Returns the destination subnet prefix, if present. `AF_MPLS` routes have an incoming
+    /// label instead, see [`RouteMessage::mpls_destination`].
     pub fn destination_prefix(&self) -> Option<(IpAddr, u8)> {
+        if self.is_mpls() {
+            return None;
+        }
         self.nlas.iter().find_map(|nla| {
             if let Nla::Destination(v) = nla {
                 octets_to_addr(v)
@@ -121,4 +177,79 @@ impl RouteMessage {
             }
         })
     }
+
+    /// Returns the preferred source address, if present.
+    pub fn pref_source(&self) -> Option<IpAddr> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::PrefSource(v) = nla {
+                octets_to_addr(v).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the routing table id. Table ids above 255 are only given by the `Table`
+    /// attribute.
+    pub fn table(&self) -> u32 {
+        self.nlas
+            .iter()
+            .find_map(|nla| {
+                if let Nla::Table(v) = nla {
+                    Some(*v)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_else(|| u32::from(self.header.table))
+    }
+
+    /// Returns the cache information of the route, if present.
+    pub fn cache_info(&self) -> Option<CacheInfo> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::CacheInfo(v) = nla {
+                #[cfg(not(feature = "rich_nlas"))]
+                return CacheInfoBuffer::new_checked(&v[..])
+                    .and_then(|buf| CacheInfo::parse(&buf))
+                    .ok();
+                #[cfg(feature = "rich_nlas")]
+                return Some(*v);
+            }
+            None
+        })
+    }
+
+    /// Returns the statistics of a multicast forwarding cache entry, if present.
+    pub fn mfc_stats(&self) -> Option<MfcStats> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::MfcStats(v) = nla {
+                #[cfg(not(feature = "rich_nlas"))]
+                return MfcStatsBuffer::new_checked(&v[..])
+                    .and_then(|buf| MfcStats::parse(&buf))
+                    .ok();
+                #[cfg(feature = "rich_nlas")]
+                return Some(*v);
+            }
+            None
+        })
+    }
+
+    /// Returns the incoming label stack of an `AF_MPLS` route, if present. The kernel only
+    /// supports a single incoming label.
+    pub fn mpls_destination(&self) -> Option<Vec<MplsLabel>> {
+        if !self.is_mpls() {
+            return None;
+        }
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Destination(v) = nla {
+                MplsLabel::parse_stack(v).ok()
+            } else {
+                None
+            }
+        })
+    }
+
+    fn is_mpls(&self) -> bool {
+        u16::from(self.header.address_family) == AF_MPLS
+    }
 }
@@ -0,0 +1,136 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to `LWTUNNEL_ENCAP_BPF`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BpfEncap {
+    /// Program run on packets received through the route
+    In(Vec<BpfProg>),
+    /// Program run on locally generated packets sent through the route
+    Out(Vec<BpfProg>),
+    /// Program run on packets before they are transmitted
+    Xmit(Vec<BpfProg>),
+    /// Headroom the `Xmit` program may need to push headers
+    XmitHeadroom(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for BpfEncap {
+    fn value_len(&self) -> usize {
+        use self::BpfEncap::*;
+        match self {
+            In(prog) | Out(prog) | Xmit(prog) => prog.as_slice().buffer_len(),
+            XmitHeadroom(_) => 4,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BpfEncap::*;
+        match self {
+            In(_) => LWT_BPF_IN,
+            Out(_) => LWT_BPF_OUT,
+            Xmit(_) => LWT_BPF_XMIT,
+            XmitHeadroom(_) => LWT_BPF_XMIT_HEADROOM,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BpfEncap::*;
+        match self {
+            In(prog) | Out(prog) | Xmit(prog) => prog.as_slice().emit(buffer),
+            XmitHeadroom(value) => NativeEndian::write_u32(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BpfEncap {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BpfEncap::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_IN => In(parse_prog(payload).context("invalid LWT_BPF_IN value")?),
+            LWT_BPF_OUT => Out(parse_prog(payload).context("invalid LWT_BPF_OUT value")?),
+            LWT_BPF_XMIT => Xmit(parse_prog(payload).context("invalid LWT_BPF_XMIT value")?),
+            LWT_BPF_XMIT_HEADROOM => {
+                XmitHeadroom(parse_u32(payload).context("invalid LWT_BPF_XMIT_HEADROOM value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
+
+fn parse_prog(payload: &[u8]) -> Result<Vec<BpfProg>, DecodeError> {
+    let mut prog = vec![];
+    for nla in NlasIterator::new(payload) {
+        prog.push(BpfProg::parse(&nla?)?);
+    }
+    Ok(prog)
+}
+
+/// BPF program attached to a route. The kernel requires both the file descriptor and the
+/// name when attaching, and only reports the name.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BpfProg {
+    Fd(u32),
+    Name(String),
+    Other(DefaultNla),
+}
+
+impl Nla for BpfProg {
+    fn value_len(&self) -> usize {
+        use self::BpfProg::*;
+        match self {
+            Fd(_) => 4,
+            Name(name) => name.len() + 1,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BpfProg::*;
+        match self {
+            Fd(_) => LWT_BPF_PROG_FD,
+            Name(_) => LWT_BPF_PROG_NAME,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BpfProg::*;
+        match self {
+            Fd(fd) => NativeEndian::write_u32(buffer, *fd),
+            Name(name) => {
+                buffer[..name.len()].copy_from_slice(name.as_bytes());
+                buffer[name.len()] = 0;
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BpfProg {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BpfProg::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWT_BPF_PROG_FD => Fd(parse_u32(payload).context("invalid LWT_BPF_PROG_FD value")?),
+            LWT_BPF_PROG_NAME => {
+                Name(parse_string(payload).context("invalid LWT_BPF_PROG_NAME value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{NlaBuffer, NlasIterator},
+    route::nlas::{
+        BpfEncap,
+        Ioam6Tunnel,
+        Ip6Tunnel,
+        IpTunnel,
+        MplsIpTunnel,
+        Seg6Local,
+        Seg6Tunnel,
+    },
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Lightweight tunnel encapsulation of a route (`RTA_ENCAP`). The attribute layout depends on
+/// the `RTA_ENCAP_TYPE` of the route.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RouteEncap {
+    Mpls(Vec<MplsIpTunnel>),
+    Ip(Vec<IpTunnel>),
+    Ip6(Vec<Ip6Tunnel>),
+    Seg6(Vec<Seg6Tunnel>),
+    Seg6Local(Vec<Seg6Local>),
+    Bpf(Vec<BpfEncap>),
+    Ioam6(Vec<Ioam6Tunnel>),
+    /// Encapsulation type and raw attributes of an unsupported encapsulation
+    Other(u16, Vec<u8>),
+}
+
+impl RouteEncap {
+    /// Parse the `RTA_ENCAP` payload of a route whose `RTA_ENCAP_TYPE` is `kind`
+    pub fn parse(kind: u16, payload: &[u8]) -> Result<Self, DecodeError> {
+        use self::RouteEncap::*;
+        Ok(match kind {
+            LWTUNNEL_ENCAP_MPLS => Mpls(parse_nlas(payload).context("invalid MPLS encap")?),
+            LWTUNNEL_ENCAP_IP => Ip(parse_nlas(payload).context("invalid IP encap")?),
+            LWTUNNEL_ENCAP_IP6 => Ip6(parse_nlas(payload).context("invalid IP6 encap")?),
+            LWTUNNEL_ENCAP_SEG6 => Seg6(parse_nlas(payload).context("invalid SEG6 encap")?),
+            LWTUNNEL_ENCAP_SEG6_LOCAL => {
+                Seg6Local(parse_nlas(payload).context("invalid SEG6_LOCAL encap")?)
+            }
+            LWTUNNEL_ENCAP_BPF => Bpf(parse_nlas(payload).context("invalid BPF encap")?),
+            LWTUNNEL_ENCAP_IOAM6 => Ioam6(parse_nlas(payload).context("invalid IOAM6 encap")?),
+            _ => Other(kind, payload.to_vec()),
+        })
+    }
+
+    /// Return the `LWTUNNEL_ENCAP_*` type to set in `RTA_ENCAP_TYPE`
+    pub fn kind(&self) -> u16 {
+        use self::RouteEncap::*;
+        match self {
+            Mpls(_) => LWTUNNEL_ENCAP_MPLS,
+            Ip(_) => LWTUNNEL_ENCAP_IP,
+            Ip6(_) => LWTUNNEL_ENCAP_IP6,
+            Seg6(_) => LWTUNNEL_ENCAP_SEG6,
+            Seg6Local(_) => LWTUNNEL_ENCAP_SEG6_LOCAL,
+            Bpf(_) => LWTUNNEL_ENCAP_BPF,
+            Ioam6(_) => LWTUNNEL_ENCAP_IOAM6,
+            Other(kind, _) => *kind,
+        }
+    }
+}
+
+fn parse_nlas<T>(payload: &[u8]) -> Result<Vec<T>, DecodeError>
+where
+    T: for<'a> Parseable<NlaBuffer<&'a [u8]>>,
+{
+    let mut nlas = vec![];
+    for nla in NlasIterator::new(payload) {
+        nlas.push(T::parse(&nla?)?);
+    }
+    Ok(nlas)
+}
+
+impl Emitable for RouteEncap {
+    fn buffer_len(&self) -> usize {
+        use self::RouteEncap::*;
+        match self {
+            Mpls(nlas) => nlas.as_slice().buffer_len(),
+            Ip(nlas) => nlas.as_slice().buffer_len(),
+            Ip6(nlas) => nlas.as_slice().buffer_len(),
+            Seg6(nlas) => nlas.as_slice().buffer_len(),
+            Seg6Local(nlas) => nlas.as_slice().buffer_len(),
+            Bpf(nlas) => nlas.as_slice().buffer_len(),
+            Ioam6(nlas) => nlas.as_slice().buffer_len(),
+            Other(_, bytes) => bytes.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        use self::RouteEncap::*;
+        match self {
+            Mpls(nlas) => nlas.as_slice().emit(buffer),
+            Ip(nlas) => nlas.as_slice().emit(buffer),
+            Ip6(nlas) => nlas.as_slice().emit(buffer),
+            Seg6(nlas) => nlas.as_slice().emit(buffer),
+            Seg6Local(nlas) => nlas.as_slice().emit(buffer),
+            Bpf(nlas) => nlas.as_slice().emit(buffer),
+            Ioam6(nlas) => nlas.as_slice().emit(buffer),
+            Other(_, bytes) => buffer.copy_from_slice(bytes),
+        }
+    }
+}
@@ -0,0 +1,135 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u32, parse_u8},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const IOAM6_TRACE_LEN: usize = 8;
+
+/// IOAM pre-allocated trace header (`struct ioam6_trace_hdr`)
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct Ioam6Trace {
+    pub namespace_id: u16,
+    /// Length of the data of a node, in 4 bytes units. Computed by the kernel from
+    /// `trace_type`.
+    pub node_len: u8,
+    pub overflow: bool,
+    /// Space pre-allocated for the trace data, in 4 bytes units
+    pub remaining_len: u8,
+    /// Bitmap of the data to record, in the 24 most significant bits
+    pub trace_type: u32,
+}
+
+impl Ioam6Trace {
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() != IOAM6_TRACE_LEN {
+            return Err(format!("invalid IOAM6 trace header: {:?}", payload).into());
+        }
+        Ok(Ioam6Trace {
+            namespace_id: BigEndian::read_u16(&payload[0..2]),
+            node_len: payload[2] >> 3,
+            overflow: payload[2] & 0x04 != 0,
+            remaining_len: payload[3] & 0x7f,
+            trace_type: BigEndian::read_u32(&payload[4..8]),
+        })
+    }
+}
+
+impl Emitable for Ioam6Trace {
+    fn buffer_len(&self) -> usize {
+        IOAM6_TRACE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        BigEndian::write_u16(&mut buffer[0..2], self.namespace_id);
+        buffer[2] = (self.node_len << 3) | if self.overflow { 0x04 } else { 0 };
+        buffer[3] = self.remaining_len & 0x7f;
+        BigEndian::write_u32(&mut buffer[4..8], self.trace_type);
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to `LWTUNNEL_ENCAP_IOAM6`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Ioam6Tunnel {
+    /// `IOAM6_IPTUNNEL_MODE_*` mode
+    Mode(u8),
+    /// Tunnel destination, for the encap and auto modes
+    Destination(Ipv6Addr),
+    Trace(Ioam6Trace),
+    /// The trace is inserted in `FreqK` packets out of `FreqN`
+    FreqK(u32),
+    FreqN(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Ioam6Tunnel {
+    fn value_len(&self) -> usize {
+        use self::Ioam6Tunnel::*;
+        match self {
+            Mode(_) => 1,
+            Destination(_) => 16,
+            Trace(trace) => trace.buffer_len(),
+            FreqK(_) | FreqN(_) => 4,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Ioam6Tunnel::*;
+        match self {
+            Mode(_) => IOAM6_IPTUNNEL_MODE,
+            Destination(_) => IOAM6_IPTUNNEL_DST,
+            Trace(_) => IOAM6_IPTUNNEL_TRACE,
+            FreqK(_) => IOAM6_IPTUNNEL_FREQ_K,
+            FreqN(_) => IOAM6_IPTUNNEL_FREQ_N,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Ioam6Tunnel::*;
+        match self {
+            Mode(mode) => buffer[0] = *mode,
+            Destination(addr) => buffer.copy_from_slice(&addr.octets()),
+            Trace(trace) => trace.emit(buffer),
+            FreqK(value) | FreqN(value) => NativeEndian::write_u32(buffer, *value),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Ioam6Tunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Ioam6Tunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IOAM6_IPTUNNEL_MODE => {
+                Mode(parse_u8(payload).context("invalid IOAM6_IPTUNNEL_MODE value")?)
+            }
+            IOAM6_IPTUNNEL_DST => Destination(
+                parse_ipv6(payload)
+                    .context("invalid IOAM6_IPTUNNEL_DST value")?
+                    .into(),
+            ),
+            IOAM6_IPTUNNEL_TRACE => {
+                Trace(Ioam6Trace::parse(payload).context("invalid IOAM6_IPTUNNEL_TRACE value")?)
+            }
+            IOAM6_IPTUNNEL_FREQ_K => {
+                FreqK(parse_u32(payload).context("invalid IOAM6_IPTUNNEL_FREQ_K value")?)
+            }
+            IOAM6_IPTUNNEL_FREQ_N => {
+                FreqN(parse_u32(payload).context("invalid IOAM6_IPTUNNEL_FREQ_N value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
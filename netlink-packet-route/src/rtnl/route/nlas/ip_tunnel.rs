@@ -0,0 +1,182 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u16_be, parse_u32_be, parse_u8},
+    traits::Parseable,
+    DecodeError,
+};
+
+fn parse_u64_be(payload: &[u8]) -> Result<u64, DecodeError> {
+    if payload.len() != 8 {
+        return Err(format!("invalid u64: {:?}", payload).into());
+    }
+    Ok(BigEndian::read_u64(payload))
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to `LWTUNNEL_ENCAP_IP`.
+///
+/// The tunnel metadata is attached to the packets and consumed by a tunnel device in external
+/// (`collect_md`) mode.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum IpTunnel {
+    /// Tunnel key (VNI, GRE key...)
+    Id(u64),
+    Destination(Ipv4Addr),
+    Source(Ipv4Addr),
+    Ttl(u8),
+    Tos(u8),
+    /// `TUNNEL_*` flags, in host byte order
+    Flags(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for IpTunnel {
+    fn value_len(&self) -> usize {
+        use self::IpTunnel::*;
+        match self {
+            Id(_) => 8,
+            Destination(_) | Source(_) => 4,
+            Ttl(_) | Tos(_) => 1,
+            Flags(_) => 2,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::IpTunnel::*;
+        match self {
+            Id(_) => LWTUNNEL_IP_ID,
+            Destination(_) => LWTUNNEL_IP_DST,
+            Source(_) => LWTUNNEL_IP_SRC,
+            Ttl(_) => LWTUNNEL_IP_TTL,
+            Tos(_) => LWTUNNEL_IP_TOS,
+            Flags(_) => LWTUNNEL_IP_FLAGS,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::IpTunnel::*;
+        match self {
+            Id(id) => BigEndian::write_u64(buffer, *id),
+            Destination(addr) | Source(addr) => buffer.copy_from_slice(&addr.octets()),
+            Ttl(value) | Tos(value) => buffer[0] = *value,
+            Flags(flags) => BigEndian::write_u16(buffer, *flags),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for IpTunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::IpTunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP_ID => Id(parse_u64_be(payload).context("invalid LWTUNNEL_IP_ID value")?),
+            LWTUNNEL_IP_DST => Destination(
+                parse_u32_be(payload)
+                    .context("invalid LWTUNNEL_IP_DST value")?
+                    .into(),
+            ),
+            LWTUNNEL_IP_SRC => Source(
+                parse_u32_be(payload)
+                    .context("invalid LWTUNNEL_IP_SRC value")?
+                    .into(),
+            ),
+            LWTUNNEL_IP_TTL => Ttl(parse_u8(payload).context("invalid LWTUNNEL_IP_TTL value")?),
+            LWTUNNEL_IP_TOS => Tos(parse_u8(payload).context("invalid LWTUNNEL_IP_TOS value")?),
+            LWTUNNEL_IP_FLAGS => {
+                Flags(parse_u16_be(payload).context("invalid LWTUNNEL_IP_FLAGS value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to `LWTUNNEL_ENCAP_IP6`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Ip6Tunnel {
+    /// Tunnel key (VNI, GRE key...)
+    Id(u64),
+    Destination(Ipv6Addr),
+    Source(Ipv6Addr),
+    HopLimit(u8),
+    TrafficClass(u8),
+    /// `TUNNEL_*` flags, in host byte order
+    Flags(u16),
+    Other(DefaultNla),
+}
+
+impl Nla for Ip6Tunnel {
+    fn value_len(&self) -> usize {
+        use self::Ip6Tunnel::*;
+        match self {
+            Id(_) => 8,
+            Destination(_) | Source(_) => 16,
+            HopLimit(_) | TrafficClass(_) => 1,
+            Flags(_) => 2,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Ip6Tunnel::*;
+        match self {
+            Id(_) => LWTUNNEL_IP6_ID,
+            Destination(_) => LWTUNNEL_IP6_DST,
+            Source(_) => LWTUNNEL_IP6_SRC,
+            HopLimit(_) => LWTUNNEL_IP6_HOPLIMIT,
+            TrafficClass(_) => LWTUNNEL_IP6_TC,
+            Flags(_) => LWTUNNEL_IP6_FLAGS,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Ip6Tunnel::*;
+        match self {
+            Id(id) => BigEndian::write_u64(buffer, *id),
+            Destination(addr) | Source(addr) => buffer.copy_from_slice(&addr.octets()),
+            HopLimit(value) | TrafficClass(value) => buffer[0] = *value,
+            Flags(flags) => BigEndian::write_u16(buffer, *flags),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Ip6Tunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Ip6Tunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LWTUNNEL_IP6_ID => Id(parse_u64_be(payload).context("invalid LWTUNNEL_IP6_ID value")?),
+            LWTUNNEL_IP6_DST => Destination(
+                parse_ipv6(payload)
+                    .context("invalid LWTUNNEL_IP6_DST value")?
+                    .into(),
+            ),
+            LWTUNNEL_IP6_SRC => Source(
+                parse_ipv6(payload)
+                    .context("invalid LWTUNNEL_IP6_SRC value")?
+                    .into(),
+            ),
+            LWTUNNEL_IP6_HOPLIMIT => {
+                HopLimit(parse_u8(payload).context("invalid LWTUNNEL_IP6_HOPLIMIT value")?)
+            }
+            LWTUNNEL_IP6_TC => {
+                TrafficClass(parse_u8(payload).context("invalid LWTUNNEL_IP6_TC value")?)
+            }
+            LWTUNNEL_IP6_FLAGS => {
+                Flags(parse_u16_be(payload).context("invalid LWTUNNEL_IP6_FLAGS value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
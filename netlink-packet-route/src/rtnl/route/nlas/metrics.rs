@@ -7,18 +7,23 @@ use std::mem::size_of;
 use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer},
-    parsers::parse_u32,
+    parsers::{parse_string, parse_u32},
     traits::Parseable,
     DecodeError,
 };
 
+/// Route metrics, nested in the `RTA_METRICS` attribute.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Metrics {
     Unspec(Vec<u8>),
+    /// Bitmask of the locked metrics (`1 << RTAX_*`), which are not updated from the
+    /// traffic
     Lock(u32),
     Mtu(u32),
     Window(u32),
+    /// Round trip time, in 1/8 milliseconds
     Rtt(u32),
+    /// Round trip time variance, in 1/4 milliseconds
     RttVar(u32),
     SsThresh(u32),
     Cwnd(u32),
@@ -26,11 +31,14 @@ pub enum Metrics {
     Reordering(u32),
     Hoplimit(u32),
     InitCwnd(u32),
+    /// `RTAX_FEATURE_*` flags
     Features(u32),
+    /// Minimum retransmission timeout, in milliseconds
     RtoMin(u32),
     InitRwnd(u32),
     QuickAck(u32),
-    CcAlgo(u32),
+    /// Name of the TCP congestion control algorithm
+    CcAlgo(String),
     FastopenNoCookie(u32),
     Other(DefaultNla),
 }
@@ -56,9 +64,9 @@ impl Nla for Metrics {
                 | RtoMin(_)
                 | InitRwnd(_)
                 | QuickAck(_)
-                | CcAlgo(_)
                 | FastopenNoCookie(_)
                 => size_of::<u32>(),
+            CcAlgo(ref name) => name.len() + 1,
             Other(ref attr) => attr.value_len(),
         }
     }
@@ -84,10 +92,14 @@ impl Nla for Metrics {
                 | RtoMin(value)
                 | InitRwnd(value)
                 | QuickAck(value)
-                | CcAlgo(value)
                 | FastopenNoCookie(value)
                 => NativeEndian::write_u32(buffer, value),
 
+            CcAlgo(ref name) => {
+                buffer[..name.len()].copy_from_slice(name.as_bytes());
+                buffer[name.len()] = 0;
+            }
+
             Other(ref attr) => attr.emit_value(buffer),
         }
     }
@@ -141,7 +153,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Metrics {
             RTAX_RTO_MIN => RtoMin(parse_u32(payload).context("invalid RTAX_RTO_MIN value")?),
             RTAX_INITRWND => InitRwnd(parse_u32(payload).context("invalid RTAX_INITRWND value")?),
             RTAX_QUICKACK => QuickAck(parse_u32(payload).context("invalid RTAX_QUICKACK value")?),
-            RTAX_CC_ALGO => CcAlgo(parse_u32(payload).context("invalid RTAX_CC_ALGO value")?),
+            RTAX_CC_ALGO => CcAlgo(parse_string(payload).context("invalid RTAX_CC_ALGO value")?),
             RTAX_FASTOPEN_NO_COOKIE => FastopenNoCookie(
                 parse_u32(payload).context("invalid RTAX_FASTOPEN_NO_COOKIE value")?,
             ),
@@ -1,8 +1,20 @@
 // SPDX-License-Identifier: MIT
 
+mod bpf_encap;
+pub use self::bpf_encap::*;
+
 mod cache_info;
 pub use self::cache_info::*;
 
+mod encap;
+pub use self::encap::*;
+
+mod ioam6;
+pub use self::ioam6::*;
+
+mod ip_tunnel;
+pub use self::ip_tunnel::*;
+
 mod metrics;
 pub use self::metrics::*;
 
@@ -12,31 +24,34 @@ pub use self::mfc_stats::*;
 mod mpls_ip_tunnel;
 pub use self::mpls_ip_tunnel::*;
 
+mod mpls_label;
+pub use self::mpls_label::*;
+
 mod next_hops;
 pub use self::next_hops::*;
 
+mod seg6;
+pub use self::seg6::*;
+
+mod via;
+pub use self::via::*;
+
 use anyhow::Context;
-use byteorder::{ByteOrder, NativeEndian};
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
 
 use crate::{
     constants::*,
-    nlas::{self, DefaultNla, NlaBuffer},
-    parsers::{parse_u16, parse_u32},
-    traits::Parseable,
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_u16, parse_u16_be, parse_u32, parse_u8},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
-#[cfg(feature = "rich_nlas")]
-use crate::traits::Emitable;
-
 /// Netlink attributes for `RTM_NEWROUTE`, `RTM_DELROUTE`,
 /// `RTM_GETROUTE` messages.
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum Nla {
-    #[cfg(not(feature = "rich_nlas"))]
-    Metrics(Vec<u8>),
-    #[cfg(feature = "rich_nlas")]
-    Metrics(Metrics),
+    Metrics(Vec<Metrics>),
     #[cfg(not(feature = "rich_nlas"))]
     MfcStats(Vec<u8>),
     #[cfg(feature = "rich_nlas")]
@@ -57,15 +72,25 @@ pub enum Nla {
     PrefSource(Vec<u8>),
     Session(Vec<u8>),
     MpAlgo(Vec<u8>),
-    Via(Vec<u8>),
-    NewDestination(Vec<u8>),
+    /// Nexthop in another address family than the route
+    Via(RouteVia),
+    /// Label stack to swap the incoming label with (`AF_MPLS` routes only)
+    NewDestination(Vec<MplsLabel>),
     Pref(Vec<u8>),
-    Encap(Vec<u8>),
+    /// Lightweight tunnel encapsulation, typed after the `EncapType` of the route
+    Encap(RouteEncap),
     Expires(Vec<u8>),
     Pad(Vec<u8>),
-    Uid(Vec<u8>),
+    /// User id of the socket the lookup is made for
+    Uid(u32),
     TtlPropagate(Vec<u8>),
     EncapType(u16),
+    /// Layer 4 protocol of the lookup (`IPPROTO_*`)
+    IpProtocol(u8),
+    /// Layer 4 source port of the lookup
+    SourcePort(u16),
+    /// Layer 4 destination port of the lookup
+    DestinationPort(u16),
     Iif(u32),
     Oif(u32),
     Priority(u32),
@@ -73,6 +98,7 @@ pub enum Nla {
     Flow(u32),
     Table(u32),
     Mark(u32),
+    NexthopId(u32),
     Other(DefaultNla),
 }
 
@@ -88,20 +114,19 @@ impl nlas::Nla for Nla {
                 | PrefSource(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
-                | NewDestination(ref bytes)
                 | Pref(ref bytes)
-                | Encap(ref bytes)
                 | Expires(ref bytes)
                 | Pad(ref bytes)
-                | Uid(ref bytes)
                 | TtlPropagate(ref bytes)
                 => bytes.len(),
 
+            Via(ref via) => via.buffer_len(),
+            Encap(ref encap) => encap.buffer_len(),
+            NewDestination(ref labels) => labels.len() * MPLS_LABEL_LEN,
+
             #[cfg(not(feature = "rich_nlas"))]
             CacheInfo(ref bytes)
                 | MfcStats(ref bytes)
-                | Metrics(ref bytes)
                 | MultiPath(ref bytes)
                 => bytes.len(),
 
@@ -110,11 +135,11 @@ impl nlas::Nla for Nla {
             #[cfg(feature = "rich_nlas")]
             MfcStats(ref stats) => stats.buffer_len(),
             #[cfg(feature = "rich_nlas")]
-            Metrics(ref metrics) => metrics.buffer_len(),
-            #[cfg(feature = "rich_nlas")]
             MultiPath(ref next_hops) => next_hops.iter().map(|nh| nh.buffer_len()).sum(),
+            Metrics(ref metrics) => metrics.as_slice().buffer_len(),
 
-            EncapType(_) => 2,
+            IpProtocol(_) => 1,
+            EncapType(_) | SourcePort(_) | DestinationPort(_) => 2,
             Iif(_)
                 | Oif(_)
                 | Priority(_)
@@ -122,6 +147,8 @@ impl nlas::Nla for Nla {
                 | Flow(_)
                 | Table(_)
                 | Mark(_)
+                | NexthopId(_)
+                | Uid(_)
                 => 4,
 
             Other(ref attr) => attr.value_len(),
@@ -139,21 +166,20 @@ impl nlas::Nla for Nla {
                 | PrefSource(ref bytes)
                 | Session(ref bytes)
                 | MpAlgo(ref bytes)
-                | Via(ref bytes)
-                | NewDestination(ref bytes)
                 | Pref(ref bytes)
-                | Encap(ref bytes)
                 | Expires(ref bytes)
                 | Pad(ref bytes)
-                | Uid(ref bytes)
                 | TtlPropagate(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
 
+            Via(ref via) => via.emit(buffer),
+            Encap(ref encap) => encap.emit(buffer),
+            NewDestination(ref labels) => MplsLabel::emit_stack(labels, buffer),
+
             #[cfg(not(feature = "rich_nlas"))]
-                MultiPath(ref bytes)
-                | CacheInfo(ref bytes)
+                CacheInfo(ref bytes)
                 | MfcStats(ref bytes)
-                | Metrics(ref bytes)
+                | MultiPath(ref bytes)
                 => buffer.copy_from_slice(bytes.as_slice()),
 
             #[cfg(feature = "rich_nlas")]
@@ -161,18 +187,12 @@ impl nlas::Nla for Nla {
             #[cfg(feature = "rich_nlas")]
             MfcStats(ref stats) => stats.emit(buffer),
             #[cfg(feature = "rich_nlas")]
-            Metrics(ref metrics) => metrics.emit(buffer),
-            #[cfg(feature = "rich_nlas")]
-            MultiPath(ref next_hops) => {
-                let mut offset = 0;
-                for nh in next_hops {
-                    let len = nh.buffer_len();
-                    nh.emit(&mut buffer[offset..offset+len]);
-                    offset += len
-                }
-            }
+            MultiPath(ref next_hops) => next_hops::emit_next_hops(next_hops, buffer),
+            Metrics(ref metrics) => metrics.as_slice().emit(buffer),
 
             EncapType(value) => NativeEndian::write_u16(buffer, value),
+            IpProtocol(value) => buffer[0] = value,
+            SourcePort(value) | DestinationPort(value) => BigEndian::write_u16(buffer, value),
             Iif(value)
                 | Oif(value)
                 | Priority(value)
@@ -180,6 +200,8 @@ impl nlas::Nla for Nla {
                 | Flow(value)
                 | Table(value)
                 | Mark(value)
+                | NexthopId(value)
+                | Uid(value)
                 => NativeEndian::write_u32(buffer, value),
             Other(ref attr) => attr.emit_value(buffer),
         }
@@ -214,14 +236,52 @@ impl nlas::Nla for Nla {
             Expires(_) => RTA_EXPIRES,
             Pad(_) => RTA_PAD,
             Uid(_) => RTA_UID,
+            IpProtocol(_) => RTA_IP_PROTO,
+            SourcePort(_) => RTA_SPORT,
+            DestinationPort(_) => RTA_DPORT,
             TtlPropagate(_) => RTA_TTL_PROPAGATE,
+            NexthopId(_) => RTA_NH_ID,
             Other(ref attr) => attr.kind(),
         }
     }
 }
 
+impl Nla {
+    /// Build an `RTA_MULTIPATH` attribute from a list of next-hops, whether or not the
+    /// `rich_nlas` feature is enabled.
+    pub fn multipath(next_hops: Vec<NextHop>) -> Self {
+        #[cfg(not(feature = "rich_nlas"))]
+        {
+            let mut bytes = vec![0; next_hops.iter().map(|nh| nh.buffer_len()).sum()];
+            next_hops::emit_next_hops(&next_hops, &mut bytes);
+            Nla::MultiPath(bytes)
+        }
+        #[cfg(feature = "rich_nlas")]
+        Nla::MultiPath(next_hops)
+    }
+}
+
+/// Return the encapsulation type of a route or nexthop, carried by the `encap_type_kind`
+/// attribute (`RTA_ENCAP_TYPE` or `NHA_ENCAP_TYPE`). It is needed to parse the
+/// encapsulation attribute, which may come first.
+pub(crate) fn find_encap_type<'a>(
+    encap_type_kind: u16,
+    nlas: impl Iterator<Item = Result<NlaBuffer<&'a [u8]>, DecodeError>>,
+) -> u16 {
+    nlas.filter_map(|nla| nla.ok())
+        .find(|nla| nla.kind() == encap_type_kind)
+        .and_then(|nla| parse_u16(nla.value()).ok())
+        .unwrap_or(LWTUNNEL_ENCAP_NONE)
+}
+
 impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
     fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        Self::parse_with_param(buf, LWTUNNEL_ENCAP_NONE)
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> ParseableParametrized<NlaBuffer<&'a T>, u16> for Nla {
+    fn parse_with_param(buf: &NlaBuffer<&'a T>, encap_type: u16) -> Result<Self, DecodeError> {
         use self::Nla::*;
 
         let payload = buf.value();
@@ -233,13 +293,20 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_PREFSRC => PrefSource(payload.to_vec()),
             RTA_SESSION => Session(payload.to_vec()),
             RTA_MP_ALGO => MpAlgo(payload.to_vec()),
-            RTA_VIA => Via(payload.to_vec()),
-            RTA_NEWDST => NewDestination(payload.to_vec()),
+            RTA_VIA => Via(RouteVia::parse(payload).context("invalid RTA_VIA value")?),
+            RTA_NEWDST => {
+                NewDestination(MplsLabel::parse_stack(payload).context("invalid RTA_NEWDST value")?)
+            }
             RTA_PREF => Pref(payload.to_vec()),
-            RTA_ENCAP => Encap(payload.to_vec()),
+            RTA_ENCAP => {
+                Encap(RouteEncap::parse(encap_type, payload).context("invalid RTA_ENCAP value")?)
+            }
             RTA_EXPIRES => Expires(payload.to_vec()),
             RTA_PAD => Pad(payload.to_vec()),
-            RTA_UID => Uid(payload.to_vec()),
+            RTA_UID => Uid(parse_u32(payload).context("invalid RTA_UID value")?),
+            RTA_IP_PROTO => IpProtocol(parse_u8(payload).context("invalid RTA_IP_PROTO value")?),
+            RTA_SPORT => SourcePort(parse_u16_be(payload).context("invalid RTA_SPORT value")?),
+            RTA_DPORT => DestinationPort(parse_u16_be(payload).context("invalid RTA_DPORT value")?),
             RTA_TTL_PROPAGATE => TtlPropagate(payload.to_vec()),
             RTA_ENCAP_TYPE => {
                 EncapType(parse_u16(payload).context("invalid RTA_ENCAP_TYPE value")?)
@@ -253,6 +320,7 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             RTA_FLOW => Flow(parse_u32(payload).context("invalid RTA_FLOW value")?),
             RTA_TABLE => Table(parse_u32(payload).context("invalid RTA_TABLE value")?),
             RTA_MARK => Mark(parse_u32(payload).context("invalid RTA_MARK value")?),
+            RTA_NH_ID => NexthopId(parse_u32(payload).context("invalid RTA_NH_ID value")?),
 
             #[cfg(not(feature = "rich_nlas"))]
             RTA_CACHEINFO => CacheInfo(payload.to_vec()),
@@ -273,34 +341,21 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
                 )
                 .context("invalid RTA_MFC_STATS value")?,
             ),
-            #[cfg(not(feature = "rich_nlas"))]
-            RTA_METRICS => Metrics(payload.to_vec()),
-            #[cfg(feature = "rich_nlas")]
-            RTA_METRICS => Metrics(
-                metrics::Metrics::parse(
-                    &NlaBuffer::new_checked(payload).context("invalid RTA_METRICS value")?,
-                )
-                .context("invalid RTA_METRICS value")?,
-            ),
+            RTA_METRICS => {
+                let mut metrics = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context("invalid RTA_METRICS value")?;
+                    metrics
+                        .push(metrics::Metrics::parse(nla).context("invalid RTA_METRICS value")?);
+                }
+                Metrics(metrics)
+            }
             #[cfg(not(feature = "rich_nlas"))]
             RTA_MULTIPATH => MultiPath(payload.to_vec()),
             #[cfg(feature = "rich_nlas")]
-            RTA_MULTIPATH => {
-                let mut next_hops = vec![];
-                let mut buf = payload;
-                loop {
-                    let nh_buf =
-                        NextHopBuffer::new_checked(&buf).context("invalid RTA_MULTIPATH value")?;
-                    let len = nh_buf.length() as usize;
-                    let nh = NextHop::parse(&nh_buf).context("invalid RTA_MULTIPATH value")?;
-                    next_hops.push(nh);
-                    if buf.len() == len {
-                        break;
-                    }
-                    buf = &buf[len..];
-                }
-                MultiPath(next_hops)
-            }
+            RTA_MULTIPATH => MultiPath(
+                next_hops::parse_next_hops(payload).context("invalid RTA_MULTIPATH value")?,
+            ),
             _ => Other(DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?),
         })
     }
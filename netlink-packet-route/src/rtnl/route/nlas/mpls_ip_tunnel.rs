@@ -6,13 +6,17 @@ use crate::{
     constants::*,
     nlas::{DefaultNla, Nla, NlaBuffer},
     parsers::parse_u8,
+    route::nlas::{MplsLabel, MPLS_LABEL_LEN},
     traits::Parseable,
     DecodeError,
 };
 
 /// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to `LWTUNNEL_ENCAP_MPLS`.
+#[derive(Debug, PartialEq, Eq, Clone)]
 pub enum MplsIpTunnel {
-    Destination(Vec<u8>),
+    /// Labels to push, the bottom of stack bit set on the last one
+    Destination(Vec<MplsLabel>),
+    /// TTL of the pushed labels. The TTL of the IP packet is used otherwise.
     Ttl(u8),
     Other(DefaultNla),
 }
@@ -21,7 +25,7 @@ impl Nla for MplsIpTunnel {
     fn value_len(&self) -> usize {
         use self::MplsIpTunnel::*;
         match self {
-            Destination(bytes) => bytes.len(),
+            Destination(labels) => labels.len() * MPLS_LABEL_LEN,
             Ttl(_) => 1,
             Other(attr) => attr.value_len(),
         }
@@ -39,7 +43,7 @@ impl Nla for MplsIpTunnel {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::MplsIpTunnel::*;
         match self {
-            Destination(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Destination(labels) => MplsLabel::emit_stack(labels, buffer),
             Ttl(ttl) => buffer[0] = *ttl,
             Other(attr) => attr.emit_value(buffer),
         }
@@ -51,7 +55,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MplsIpTunnel {
         use self::MplsIpTunnel::*;
         let payload = buf.value();
         Ok(match buf.kind() {
-            MPLS_IPTUNNEL_DST => Destination(payload.to_vec()),
+            MPLS_IPTUNNEL_DST => Destination(
+                MplsLabel::parse_stack(payload).context("invalid MPLS_IPTUNNEL_DST value")?,
+            ),
             MPLS_IPTUNNEL_TTL => Ttl(parse_u8(payload).context("invalid MPLS_IPTUNNEL_TTL value")?),
             _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
         })
@@ -0,0 +1,83 @@
+// SPDX-License-Identifier: MIT
+
+use byteorder::{BigEndian, ByteOrder};
+
+use crate::DecodeError;
+
+pub const MPLS_LABEL_LEN: usize = 4;
+
+const MPLS_LS_LABEL_SHIFT: u32 = 12;
+const MPLS_LS_TC_SHIFT: u32 = 9;
+const MPLS_LS_S_SHIFT: u32 = 8;
+
+/// A label stack entry, as found in `RTA_DST` and `RTA_NEWDST` for `AF_MPLS`
+/// routes, and in `MPLS_IPTUNNEL_DST`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct MplsLabel {
+    /// 20 bits label value
+    pub label: u32,
+    /// 3 bits traffic class
+    pub traffic_class: u8,
+    /// Whether this is the last entry of the stack
+    pub bottom_of_stack: bool,
+    pub ttl: u8,
+}
+
+impl MplsLabel {
+    /// Create a label stack entry with the given label value. The other
+    /// fields are cleared.
+    pub fn new(label: u32) -> Self {
+        MplsLabel {
+            label,
+            ..Default::default()
+        }
+    }
+
+    /// Build a label stack from the given label values, with the bottom of
+    /// stack bit set on the last entry, as expected by the kernel.
+    pub fn stack(labels: &[u32]) -> Vec<MplsLabel> {
+        let mut stack: Vec<MplsLabel> = labels.iter().map(|label| MplsLabel::new(*label)).collect();
+        if let Some(last) = stack.last_mut() {
+            last.bottom_of_stack = true;
+        }
+        stack
+    }
+
+    /// Decode a label stack
+    pub fn parse_stack(payload: &[u8]) -> Result<Vec<MplsLabel>, DecodeError> {
+        let entries = payload.chunks_exact(MPLS_LABEL_LEN);
+        if !entries.remainder().is_empty() {
+            return Err(format!("invalid MPLS label stack: {:?}", payload).into());
+        }
+        Ok(entries
+            .map(|entry| MplsLabel::from(BigEndian::read_u32(entry)))
+            .collect())
+    }
+
+    /// Encode a label stack
+    pub fn emit_stack(stack: &[MplsLabel], buffer: &mut [u8]) {
+        for (label, entry) in stack.iter().zip(buffer.chunks_mut(MPLS_LABEL_LEN)) {
+            BigEndian::write_u32(entry, u32::from(*label));
+        }
+    }
+}
+
+impl From<u32> for MplsLabel {
+    fn from(entry: u32) -> Self {
+        MplsLabel {
+            label: entry >> MPLS_LS_LABEL_SHIFT,
+            traffic_class: ((entry >> MPLS_LS_TC_SHIFT) & 0x7) as u8,
+            bottom_of_stack: (entry >> MPLS_LS_S_SHIFT) & 0x1 == 1,
+            ttl: (entry & 0xff) as u8,
+        }
+    }
+}
+
+impl From<MplsLabel> for u32 {
+    fn from(label: MplsLabel) -> Self {
+        ((label.label & 0xfffff) << MPLS_LS_LABEL_SHIFT)
+            | ((label.traffic_class as u32 & 0x7) << MPLS_LS_TC_SHIFT)
+            | ((label.bottom_of_stack as u32) << MPLS_LS_S_SHIFT)
+            | label.ttl as u32
+    }
+}
@@ -7,8 +7,8 @@ use crate::{
     constants,
     nlas::{NlaBuffer, NlasIterator},
     parsers::parse_ip,
-    route::nlas::Nla,
-    traits::{Emitable, Parseable},
+    route::nlas::{find_encap_type, Nla, RouteEncap, RouteVia},
+    traits::{Emitable, Parseable, ParseableParametrized},
     DecodeError,
 };
 
@@ -48,6 +48,14 @@ impl<T: AsRef<[u8]>> NextHopBuffer<T> {
                 format!("invalid NextHopBuffer: length {} < {}", len, PAYLOAD_OFFSET).into(),
             );
         }
+        if (self.length() as usize) < PAYLOAD_OFFSET {
+            return Err(format!(
+                "invalid NextHopBuffer: length field {} < {}",
+                self.length(),
+                PAYLOAD_OFFSET
+            )
+            .into());
+        }
         if len < self.length() as usize {
             return Err(format!(
                 "invalid NextHopBuffer: length {} < {}",
@@ -96,9 +104,10 @@ impl<'a, T: AsRef<[u8]>> Parseable<NextHopBuffer<&'a T>> for NextHop {
 
 impl<'a, T: AsRef<[u8]> + 'a> Parseable<NextHopBuffer<&'a T>> for Vec<Nla> {
     fn parse(buf: &NextHopBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let encap_type = find_encap_type(constants::RTA_ENCAP_TYPE, buf.nlas());
         let mut nlas = vec![];
         for nla_buf in buf.nlas() {
-            nlas.push(Nla::parse(&nla_buf?)?);
+            nlas.push(Nla::parse_with_param(&nla_buf?, encap_type)?);
         }
         Ok(nlas)
     }
@@ -131,4 +140,55 @@ impl NextHop {
             }
         })
     }
+
+    /// Gateway in another address family than the route (encoded as an `RTA_VIA` nla)
+    pub fn via(&self) -> Option<&RouteVia> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Via(via) = nla {
+                Some(via)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Lightweight tunnel encapsulation of the packets sent through this next-hop
+    pub fn encap(&self) -> Option<&RouteEncap> {
+        self.nlas.iter().find_map(|nla| {
+            if let Nla::Encap(encap) = nla {
+                Some(encap)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Weight of the next-hop, from 1 to 256 (`hops` + 1)
+    pub fn weight(&self) -> u16 {
+        u16::from(self.hops) + 1
+    }
+}
+
+/// Parse the payload of an `RTA_MULTIPATH` attribute
+pub(crate) fn parse_next_hops(payload: &[u8]) -> Result<Vec<NextHop>, DecodeError> {
+    let mut next_hops = vec![];
+    let mut buf = payload;
+    while !buf.is_empty() {
+        let nh_buf = NextHopBuffer::new_checked(&buf)?;
+        // next-hops are aligned on 4 bytes
+        let len = (nh_buf.length() as usize + 3) & !3;
+        next_hops.push(NextHop::parse(&nh_buf)?);
+        buf = &buf[len.min(buf.len())..];
+    }
+    Ok(next_hops)
+}
+
+/// Emit next-hops as the payload of an `RTA_MULTIPATH` attribute
+pub(crate) fn emit_next_hops(next_hops: &[NextHop], buffer: &mut [u8]) {
+    let mut offset = 0;
+    for nh in next_hops {
+        let len = nh.buffer_len();
+        nh.emit(&mut buffer[offset..offset + len]);
+        offset += len;
+    }
 }
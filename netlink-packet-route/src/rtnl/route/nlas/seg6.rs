@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use anyhow::Context;
+use byteorder::{BigEndian, ByteOrder, NativeEndian};
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer},
+    parsers::{parse_ipv6, parse_u32, parse_u32_be},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+const SRH_HEADER_LEN: usize = 8;
+const SEGMENT_LEN: usize = 16;
+
+/// IPv6 Segment Routing Header (`struct ipv6_sr_hdr`), as carried by `SEG6_IPTUNNEL_SRH` and
+/// `SEG6_LOCAL_SRH`.
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct SegmentRoutingHeader {
+    /// Index of the next segment to visit in `segments`
+    pub segments_left: u8,
+    /// `SR6_FLAG1_*` flags
+    pub flags: u8,
+    pub tag: u16,
+    /// Segment list in header order: the last segment to visit comes first.
+    pub segments: Vec<Ipv6Addr>,
+    /// Raw TLVs following the segment list (HMAC, padding...), padded to a multiple of 8 bytes
+    pub tlvs: Vec<u8>,
+}
+
+impl SegmentRoutingHeader {
+    /// Build a header visiting the given segments in order, the first one being the next
+    /// destination of the packet.
+    pub fn new(segments: &[Ipv6Addr]) -> Self {
+        SegmentRoutingHeader {
+            segments_left: segments.len().saturating_sub(1) as u8,
+            segments: segments.iter().rev().cloned().collect(),
+            ..Default::default()
+        }
+    }
+
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < SRH_HEADER_LEN {
+            return Err(format!("invalid segment routing header: {:?}", payload).into());
+        }
+        let len = (payload[1] as usize + 1) * 8;
+        let segments_len = (payload[4] as usize + 1) * SEGMENT_LEN;
+        if payload.len() < len || len < SRH_HEADER_LEN + segments_len {
+            return Err(format!("invalid segment routing header: {:?}", payload).into());
+        }
+        let segments = payload[SRH_HEADER_LEN..SRH_HEADER_LEN + segments_len]
+            .chunks_exact(SEGMENT_LEN)
+            .map(|segment| Ipv6Addr::from(parse_ipv6(segment).unwrap()))
+            .collect();
+        Ok(SegmentRoutingHeader {
+            segments_left: payload[3],
+            flags: payload[5],
+            tag: BigEndian::read_u16(&payload[6..8]),
+            segments,
+            tlvs: payload[SRH_HEADER_LEN + segments_len..len].to_vec(),
+        })
+    }
+}
+
+impl Emitable for SegmentRoutingHeader {
+    fn buffer_len(&self) -> usize {
+        SRH_HEADER_LEN + self.segments.len() * SEGMENT_LEN + self.tlvs.len()
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        // the next header is filled in by the kernel
+        buffer[0] = 0;
+        buffer[1] = (self.buffer_len() / 8 - 1) as u8;
+        buffer[2] = IPV6_SRCRT_TYPE_4;
+        buffer[3] = self.segments_left;
+        buffer[4] = self.segments.len().saturating_sub(1) as u8;
+        buffer[5] = self.flags;
+        BigEndian::write_u16(&mut buffer[6..8], self.tag);
+        let mut offset = SRH_HEADER_LEN;
+        for segment in &self.segments {
+            buffer[offset..offset + SEGMENT_LEN].copy_from_slice(&segment.octets());
+            offset += SEGMENT_LEN;
+        }
+        buffer[offset..offset + self.tlvs.len()].copy_from_slice(&self.tlvs);
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to `LWTUNNEL_ENCAP_SEG6`.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Seg6Tunnel {
+    /// Segment routing header to insert or encapsulate with, according to the
+    /// `SEG6_IPTUN_MODE_*` mode.
+    Srh(u32, SegmentRoutingHeader),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6Tunnel {
+    fn value_len(&self) -> usize {
+        use self::Seg6Tunnel::*;
+        match self {
+            Srh(_, srh) => 4 + srh.buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Seg6Tunnel::*;
+        match self {
+            Srh(_, _) => SEG6_IPTUNNEL_SRH,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Seg6Tunnel::*;
+        match self {
+            Srh(mode, srh) => {
+                NativeEndian::write_u32(&mut buffer[..4], *mode);
+                srh.emit(&mut buffer[4..]);
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6Tunnel {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Seg6Tunnel::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_IPTUNNEL_SRH => {
+                if payload.len() < 4 {
+                    return Err(format!("invalid SEG6_IPTUNNEL_SRH value: {:?}", payload).into());
+                }
+                Srh(
+                    NativeEndian::read_u32(&payload[..4]),
+                    SegmentRoutingHeader::parse(&payload[4..])
+                        .context("invalid SEG6_IPTUNNEL_SRH value")?,
+                )
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
+
+/// Netlink attributes for `RTA_ENCAP` with `RTA_ENCAP_TYPE` set to
+/// `LWTUNNEL_ENCAP_SEG6_LOCAL`. Which attributes are required depends on the action.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Seg6Local {
+    /// `SEG6_LOCAL_ACTION_*` behavior
+    Action(u32),
+    /// Segment routing header for the `End.B6` and `End.B6.Encaps` behaviors
+    Srh(SegmentRoutingHeader),
+    /// Table of the lookup for `End.T` and `End.DT6`
+    Table(u32),
+    /// IPv4 nexthop of `End.DX4`
+    Nh4(Ipv4Addr),
+    /// IPv6 nexthop of `End.X` and `End.DX6`
+    Nh6(Ipv6Addr),
+    Iif(u32),
+    /// Output interface of `End.DX2`
+    Oif(u32),
+    /// VRF table of the lookup for `End.DT4`, `End.DT6` and `End.DT46`
+    VrfTable(u32),
+    Other(DefaultNla),
+}
+
+impl Nla for Seg6Local {
+    fn value_len(&self) -> usize {
+        use self::Seg6Local::*;
+        match self {
+            Srh(srh) => srh.buffer_len(),
+            Nh6(_) => 16,
+            Action(_) | Table(_) | Nh4(_) | Iif(_) | Oif(_) | VrfTable(_) => 4,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Seg6Local::*;
+        match self {
+            Action(_) => SEG6_LOCAL_ACTION,
+            Srh(_) => SEG6_LOCAL_SRH,
+            Table(_) => SEG6_LOCAL_TABLE,
+            Nh4(_) => SEG6_LOCAL_NH4,
+            Nh6(_) => SEG6_LOCAL_NH6,
+            Iif(_) => SEG6_LOCAL_IIF,
+            Oif(_) => SEG6_LOCAL_OIF,
+            VrfTable(_) => SEG6_LOCAL_VRFTABLE,
+            Other(attr) => attr.kind(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Seg6Local::*;
+        match self {
+            Srh(srh) => srh.emit(buffer),
+            Nh4(addr) => buffer.copy_from_slice(&addr.octets()),
+            Nh6(addr) => buffer.copy_from_slice(&addr.octets()),
+            Action(value) | Table(value) | Iif(value) | Oif(value) | VrfTable(value) => {
+                NativeEndian::write_u32(buffer, *value)
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Seg6Local {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Seg6Local::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            SEG6_LOCAL_ACTION => {
+                Action(parse_u32(payload).context("invalid SEG6_LOCAL_ACTION value")?)
+            }
+            SEG6_LOCAL_SRH => {
+                Srh(SegmentRoutingHeader::parse(payload).context("invalid SEG6_LOCAL_SRH value")?)
+            }
+            SEG6_LOCAL_TABLE => {
+                Table(parse_u32(payload).context("invalid SEG6_LOCAL_TABLE value")?)
+            }
+            SEG6_LOCAL_NH4 => Nh4(parse_u32_be(payload)
+                .context("invalid SEG6_LOCAL_NH4 value")?
+                .into()),
+            SEG6_LOCAL_NH6 => Nh6(parse_ipv6(payload)
+                .context("invalid SEG6_LOCAL_NH6 value")?
+                .into()),
+            SEG6_LOCAL_IIF => Iif(parse_u32(payload).context("invalid SEG6_LOCAL_IIF value")?),
+            SEG6_LOCAL_OIF => Oif(parse_u32(payload).context("invalid SEG6_LOCAL_OIF value")?),
+            SEG6_LOCAL_VRFTABLE => {
+                VrfTable(parse_u32(payload).context("invalid SEG6_LOCAL_VRFTABLE value")?)
+            }
+            _ => Other(DefaultNla::parse(buf).context("invalid NLA value (unknown type) value")?),
+        })
+    }
+}
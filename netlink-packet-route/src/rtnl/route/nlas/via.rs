@@ -0,0 +1,62 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    constants::{AF_INET, AF_INET6},
+    traits::Emitable,
+    DecodeError,
+};
+
+/// Nexthop of a route in a different address family than the route, carried
+/// by `RTA_VIA` (`struct rtvia`). MPLS routes always use it.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum RouteVia {
+    Inet(Ipv4Addr),
+    Inet6(Ipv6Addr),
+    /// Any other address family, and the raw address
+    Other(u16, Vec<u8>),
+}
+
+impl RouteVia {
+    pub fn parse(payload: &[u8]) -> Result<Self, DecodeError> {
+        if payload.len() < 2 {
+            return Err(format!("invalid RTA_VIA value: {:?}", payload).into());
+        }
+        let family = NativeEndian::read_u16(&payload[..2]);
+        let address = &payload[2..];
+        Ok(match (family, address.len()) {
+            (AF_INET, 4) => RouteVia::Inet(Ipv4Addr::new(
+                address[0], address[1], address[2], address[3],
+            )),
+            (AF_INET6, 16) => {
+                let mut octets = [0; 16];
+                octets.copy_from_slice(address);
+                RouteVia::Inet6(Ipv6Addr::from(octets))
+            }
+            _ => RouteVia::Other(family, address.to_vec()),
+        })
+    }
+}
+
+impl Emitable for RouteVia {
+    fn buffer_len(&self) -> usize {
+        2 + match self {
+            RouteVia::Inet(_) => 4,
+            RouteVia::Inet6(_) => 16,
+            RouteVia::Other(_, address) => address.len(),
+        }
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let (family, address) = match self {
+            RouteVia::Inet(address) => (AF_INET, address.octets().to_vec()),
+            RouteVia::Inet6(address) => (AF_INET6, address.octets().to_vec()),
+            RouteVia::Other(family, address) => (*family, address.clone()),
+        };
+        NativeEndian::write_u16(&mut buffer[..2], family);
+        buffer[2..2 + address.len()].copy_from_slice(&address);
+    }
+}
@@ -123,3 +123,588 @@ mod test_rich_nlas {
         assert_eq!(buf, ROUTE_MSG);
     }
 }
+
+mod test_mpls {
+    use std::net::Ipv4Addr;
+
+    use crate::{
+        constants::*,
+        nlas::NlasIterator,
+        rtnl::route::{
+            nlas::{MplsIpTunnel, MplsLabel, Nla, RouteVia},
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+        NetlinkMessage,
+        NetlinkPayload,
+        RtnlMessage,
+    };
+
+    // ip -f mpls route add 100 as 200/300 via inet 10.0.0.2 dev eth0
+    #[rustfmt::skip]
+    static MPLS_ROUTE_MSG: [u8; 52] = [
+        0x1c, // address family = AF_MPLS
+        0x14, // length of destination = 20
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x03, // routing protocol (boot)
+        0x00, // route origin (global)
+        0x01, // gateway or direct route
+        0x00, 0x00, 0x00, 0x00,
+
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+                0x00, 0x06, 0x41, 0x00, // label = 100, bottom of stack
+
+            0x0c, 0x00, 0x13, 0x00, // length = 12, type = RTA_NEWDST
+                0x00, 0x0c, 0x80, 0x00, // label = 200
+                0x00, 0x12, 0xc1, 0x00, // label = 300, bottom of stack
+
+            0x0a, 0x00, 0x12, 0x00, // length = 10, type = RTA_VIA
+                0x02, 0x00, // family = AF_INET
+                0x0a, 0x00, 0x00, 0x02, // 10.0.0.2
+                0x00, 0x00, // padding
+
+            0x08, 0x00, 0x04, 0x00, // length = 8, type = RTA_OIF
+                0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    fn mpls_route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_MPLS as u8;
+        msg.header.destination_prefix_length = 20;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_BOOT;
+        msg.header.kind = RTN_UNICAST;
+        let mut destination = vec![0; 4];
+        MplsLabel::emit_stack(&MplsLabel::stack(&[100]), &mut destination);
+        msg.nlas = vec![
+            Nla::Destination(destination),
+            Nla::NewDestination(MplsLabel::stack(&[200, 300])),
+            Nla::Via(RouteVia::Inet(Ipv4Addr::new(10, 0, 0, 2))),
+            Nla::Oif(2),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_mpls_route() {
+        let actual =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&MPLS_ROUTE_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, mpls_route_message());
+    }
+
+    #[test]
+    fn emit_mpls_route() {
+        let msg = mpls_route_message();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &MPLS_ROUTE_MSG[..]);
+    }
+
+    // RTM_NEWROUTE reply to `ip -f mpls route show`, for the route above
+    #[rustfmt::skip]
+    static MPLS_ROUTE_DUMP: [u8; 68] = [
+        0x44, 0x00, 0x00, 0x00, // length = 68
+        0x18, 0x00, // message type = RTM_NEWROUTE
+        0x02, 0x00, // flags = NLM_F_MULTI
+        0x01, 0x00, 0x00, 0x00, // seq id
+        0xd2, 0x04, 0x00, 0x00, // pid
+        0x1c, // address family = AF_MPLS
+        0x14, // length of destination = 20
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x03, // routing protocol (boot)
+        0x00, // route origin (global)
+        0x01, // gateway or direct route
+        0x00, 0x00, 0x00, 0x00,
+
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+                0x00, 0x06, 0x41, 0x00, // label = 100, bottom of stack
+
+            0x0c, 0x00, 0x13, 0x00, // length = 12, type = RTA_NEWDST
+                0x00, 0x0c, 0x80, 0x00, // label = 200
+                0x00, 0x12, 0xc1, 0x00, // label = 300, bottom of stack
+
+            0x0a, 0x00, 0x12, 0x00, // length = 10, type = RTA_VIA
+                0x02, 0x00, // family = AF_INET
+                0x0a, 0x00, 0x00, 0x02, // 10.0.0.2
+                0x00, 0x00, // padding
+
+            0x08, 0x00, 0x04, 0x00, // length = 8, type = RTA_OIF
+                0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    #[test]
+    fn parse_mpls_route_dump() {
+        let msg = NetlinkMessage::<RtnlMessage>::deserialize(&MPLS_ROUTE_DUMP[..]).unwrap();
+        let route = match msg.payload {
+            NetlinkPayload::InnerMessage(RtnlMessage::NewRoute(route)) => route,
+            payload => panic!("unexpected payload {:?}", payload),
+        };
+        assert_eq!(route, mpls_route_message());
+        assert_eq!(route.mpls_destination(), Some(MplsLabel::stack(&[100])));
+        assert_eq!(route.destination_prefix(), None);
+        assert_eq!(route.output_interface(), Some(2));
+
+        // The destination of IP routes is not a label
+        let mut ip_route = route;
+        ip_route.header.address_family = AF_INET as u8;
+        assert_eq!(ip_route.mpls_destination(), None);
+    }
+
+    #[test]
+    fn mpls_label_fields() {
+        let label = MplsLabel {
+            label: 0xfffff,
+            traffic_class: 5,
+            bottom_of_stack: true,
+            ttl: 64,
+        };
+        assert_eq!(u32::from(label), 0xffff_fb40);
+        assert_eq!(MplsLabel::from(0xffff_fb40), label);
+    }
+
+    // ip route add 10.1.0.0/16 encap mpls 100/200 ttl 10 via 10.0.0.2
+    #[rustfmt::skip]
+    static MPLS_ENCAP: [u8; 20] = [
+        0x0c, 0x00, 0x01, 0x00, // length = 12, type = MPLS_IPTUNNEL_DST
+            0x00, 0x06, 0x40, 0x00, // label = 100
+            0x00, 0x0c, 0x81, 0x00, // label = 200, bottom of stack
+        0x05, 0x00, 0x02, 0x00, // length = 5, type = MPLS_IPTUNNEL_TTL
+            0x0a, 0x00, 0x00, 0x00, // 10
+    ];
+
+    #[test]
+    fn parse_emit_mpls_encap() {
+        let expected = vec![
+            MplsIpTunnel::Destination(MplsLabel::stack(&[100, 200])),
+            MplsIpTunnel::Ttl(10),
+        ];
+        let actual = NlasIterator::new(&MPLS_ENCAP[..])
+            .map(|nla| MplsIpTunnel::parse(&nla.unwrap()).unwrap())
+            .collect::<Vec<_>>();
+        assert_eq!(actual, expected);
+
+        let mut buf = vec![0; expected.as_slice().buffer_len()];
+        expected.as_slice().emit(&mut buf);
+        assert_eq!(&buf[..], &MPLS_ENCAP[..]);
+    }
+}
+
+mod test_encap {
+    use std::net::Ipv6Addr;
+
+    use crate::{
+        constants::*,
+        rtnl::route::{
+            nlas::{Nla, RouteEncap, Seg6Local, Seg6Tunnel, SegmentRoutingHeader},
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+
+    // ip -6 route add 2001:db8::/64 encap seg6 mode encap segs fc00::1,fc00::2 dev eth0
+    #[rustfmt::skip]
+    static SEG6_ROUTE_MSG: [u8; 100] = [
+        0x0a, // address family = AF_INET6
+        0x40, // length of destination = 64
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x03, // routing protocol (boot)
+        0x00, // route origin (global)
+        0x01, // gateway or direct route
+        0x00, 0x00, 0x00, 0x00,
+
+            0x14, 0x00, 0x01, 0x00, // length = 20, type = RTA_DST
+                0x20, 0x01, 0x0d, 0xb8, 0x00, 0x00, 0x00, 0x00,
+                0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+
+            // the encapsulation comes before its type in kernel dumps
+            0x34, 0x00, 0x16, 0x00, // length = 52, type = RTA_ENCAP
+                0x30, 0x00, 0x01, 0x00, // length = 48, type = SEG6_IPTUNNEL_SRH
+                    0x01, 0x00, 0x00, 0x00, // mode = SEG6_IPTUN_MODE_ENCAP
+                    0x00, // next header
+                    0x04, // header length = 40 bytes
+                    0x04, // routing type = 4
+                    0x01, // segments left
+                    0x01, // last entry
+                    0x00, // flags
+                    0x00, 0x00, // tag
+                    0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // fc00::2
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x02,
+                    0xfc, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // fc00::1
+                    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+
+            0x06, 0x00, 0x15, 0x00, // length = 6, type = RTA_ENCAP_TYPE
+                0x05, 0x00, // LWTUNNEL_ENCAP_SEG6
+                0x00, 0x00, // padding
+
+            0x08, 0x00, 0x04, 0x00, // length = 8, type = RTA_OIF
+                0x02, 0x00, 0x00, 0x00, // 2
+    ];
+
+    fn seg6_route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_INET6 as u8;
+        msg.header.destination_prefix_length = 64;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_BOOT;
+        msg.header.kind = RTN_UNICAST;
+        let srh = SegmentRoutingHeader::new(&[
+            "fc00::1".parse::<Ipv6Addr>().unwrap(),
+            "fc00::2".parse::<Ipv6Addr>().unwrap(),
+        ]);
+        msg.nlas = vec![
+            Nla::Destination("2001:db8::".parse::<Ipv6Addr>().unwrap().octets().to_vec()),
+            Nla::Encap(RouteEncap::Seg6(vec![Seg6Tunnel::Srh(
+                SEG6_IPTUN_MODE_ENCAP,
+                srh,
+            )])),
+            Nla::EncapType(LWTUNNEL_ENCAP_SEG6),
+            Nla::Oif(2),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_seg6_route() {
+        let actual =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&SEG6_ROUTE_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, seg6_route_message());
+    }
+
+    #[test]
+    fn emit_seg6_route() {
+        let msg = seg6_route_message();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &SEG6_ROUTE_MSG[..]);
+    }
+
+    // ip -6 route add fc00::100 encap seg6local action End.DT6 vrftable 100 dev vrf100
+    #[rustfmt::skip]
+    static SEG6_LOCAL_ENCAP: [u8; 16] = [
+        0x08, 0x00, 0x01, 0x00, // length = 8, type = SEG6_LOCAL_ACTION
+            0x07, 0x00, 0x00, 0x00, // SEG6_LOCAL_ACTION_END_DT6
+        0x08, 0x00, 0x09, 0x00, // length = 8, type = SEG6_LOCAL_VRFTABLE
+            0x64, 0x00, 0x00, 0x00, // 100
+    ];
+
+    #[test]
+    fn parse_emit_seg6_local_encap() {
+        let expected = RouteEncap::Seg6Local(vec![
+            Seg6Local::Action(SEG6_LOCAL_ACTION_END_DT6),
+            Seg6Local::VrfTable(100),
+        ]);
+        let actual = RouteEncap::parse(LWTUNNEL_ENCAP_SEG6_LOCAL, &SEG6_LOCAL_ENCAP).unwrap();
+        assert_eq!(actual, expected);
+
+        let mut buf = vec![0; expected.buffer_len()];
+        expected.emit(&mut buf);
+        assert_eq!(&buf[..], &SEG6_LOCAL_ENCAP[..]);
+    }
+}
+
+mod test_multipath {
+    use std::net::{Ipv4Addr, Ipv6Addr};
+
+    use crate::{
+        constants::*,
+        rtnl::route::{
+            nlas::{NextHop, NextHopFlags, Nla, RouteVia},
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+
+    // ip route add 10.1.2.0/24 proto static \
+    //     nexthop via 10.0.0.1 dev eth0 weight 2 onlink \
+    //     nexthop via inet6 fe80::1 dev eth1
+    #[rustfmt::skip]
+    static MULTIPATH_ROUTE_MSG: [u8; 72] = [
+        0x02, // address family = AF_INET
+        0x18, // length of destination = 24
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x04, // routing protocol (static)
+        0x00, // route origin (global)
+        0x01, // gateway or direct route
+        0x00, 0x00, 0x00, 0x00,
+
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+                0x0a, 0x01, 0x02, 0x00, // 10.1.2.0
+
+            0x34, 0x00, 0x09, 0x00, // length = 52, type = RTA_MULTIPATH
+                0x10, 0x00, // next-hop length = 16
+                0x04, // flags = RTNH_F_ONLINK
+                0x01, // hops = weight - 1
+                0x02, 0x00, 0x00, 0x00, // interface index = 2
+                    0x08, 0x00, 0x05, 0x00, // length = 8, type = RTA_GATEWAY
+                        0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+
+                0x20, 0x00, // next-hop length = 32
+                0x00, // flags
+                0x00, // hops
+                0x03, 0x00, 0x00, 0x00, // interface index = 3
+                    0x16, 0x00, 0x12, 0x00, // length = 22, type = RTA_VIA
+                        0x0a, 0x00, // family = AF_INET6
+                        0xfe, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // fe80::1
+                        0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+                        0x00, 0x00, // padding
+    ];
+
+    fn multipath_route_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_INET as u8;
+        msg.header.destination_prefix_length = 24;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_STATIC;
+        msg.header.kind = RTN_UNICAST;
+        msg.nlas = vec![
+            Nla::Destination(vec![10, 1, 2, 0]),
+            Nla::multipath(vec![
+                NextHop {
+                    flags: NextHopFlags::RTNH_F_ONLINK,
+                    hops: 1,
+                    interface_id: 2,
+                    nlas: vec![Nla::Gateway(Ipv4Addr::new(10, 0, 0, 1).octets().to_vec())],
+                },
+                NextHop {
+                    flags: NextHopFlags::empty(),
+                    hops: 0,
+                    interface_id: 3,
+                    nlas: vec![Nla::Via(RouteVia::Inet6(
+                        "fe80::1".parse::<Ipv6Addr>().unwrap(),
+                    ))],
+                },
+            ]),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_multipath_route() {
+        let actual = RouteMessage::parse(
+            &RouteMessageBuffer::new_checked(&&MULTIPATH_ROUTE_MSG[..]).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(actual, multipath_route_message());
+        let nexthops = actual.multipath().unwrap();
+        assert_eq!(nexthops[0].weight(), 2);
+        assert_eq!(
+            nexthops[0].gateway(),
+            Some(Ipv4Addr::new(10, 0, 0, 1).into())
+        );
+        assert!(nexthops[1].via().is_some());
+    }
+
+    #[test]
+    fn emit_multipath_route() {
+        let msg = multipath_route_message();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &MULTIPATH_ROUTE_MSG[..]);
+    }
+}
+
+mod test_lookup {
+    use crate::{
+        constants::*,
+        rtnl::route::{nlas::Nla, RouteFlags, RouteMessage, RouteMessageBuffer},
+        utils::{Emitable, Parseable},
+    };
+
+    // ip route get 10.71.1.1 ipproto tcp sport 1234 dport 8080 uid 1000
+    #[rustfmt::skip]
+    static LOOKUP_MSG: [u8; 52] = [
+        0x02, // address family = AF_INET
+        0x20, // length of destination = 32
+        0x00, // length of source
+        0x00, // TOS
+        0x00, // routing table id = RT_TABLE_UNSPEC
+        0x00, // routing protocol
+        0x00, // route origin (global)
+        0x00, // route type
+        0x00, 0x10, 0x00, 0x00, // flags = RTM_F_LOOKUP_TABLE
+
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+                0x0a, 0x47, 0x01, 0x01, // 10.71.1.1
+
+            0x05, 0x00, 0x1b, 0x00, // length = 5, type = RTA_IP_PROTO
+                0x06, 0x00, 0x00, 0x00, // IPPROTO_TCP
+
+            0x06, 0x00, 0x1c, 0x00, // length = 6, type = RTA_SPORT
+                0x04, 0xd2, 0x00, 0x00, // 1234, network byte order
+
+            0x06, 0x00, 0x1d, 0x00, // length = 6, type = RTA_DPORT
+                0x1f, 0x90, 0x00, 0x00, // 8080, network byte order
+
+            0x08, 0x00, 0x19, 0x00, // length = 8, type = RTA_UID
+                0xe8, 0x03, 0x00, 0x00, // 1000
+    ];
+
+    fn lookup_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_INET as u8;
+        msg.header.destination_prefix_length = 32;
+        msg.header.flags = RouteFlags::RTM_F_LOOKUP_TABLE;
+        msg.nlas = vec![
+            Nla::Destination(vec![10, 71, 1, 1]),
+            Nla::IpProtocol(6),
+            Nla::SourcePort(1234),
+            Nla::DestinationPort(8080),
+            Nla::Uid(1000),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_lookup() {
+        let actual =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&LOOKUP_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, lookup_message());
+    }
+
+    #[test]
+    fn emit_lookup() {
+        let msg = lookup_message();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &LOOKUP_MSG[..]);
+    }
+}
+
+mod test_metrics {
+    use crate::{
+        constants::*,
+        rtnl::route::{
+            nlas::{Metrics, Nla},
+            RouteMessage,
+            RouteMessageBuffer,
+        },
+        utils::{Emitable, Parseable},
+    };
+
+    // ip route add 10.0.0.0/8 mtu lock 1400 features ecn congctl cubic dev lo
+    #[rustfmt::skip]
+    static METRICS_MSG: [u8; 60] = [
+        0x02, // address family = AF_INET
+        0x08, // length of destination = 8
+        0x00, // length of source
+        0x00, // TOS
+        0xfe, // routing table id = RT_TABLE_MAIN
+        0x04, // routing protocol = RTPROT_STATIC
+        0x00, // route origin = RT_SCOPE_UNIVERSE
+        0x01, // route type = RTN_UNICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+                0x0a, 0x00, 0x00, 0x00, // 10.0.0.0
+
+            0x28, 0x00, 0x08, 0x00, // length = 40, type = RTA_METRICS
+                0x08, 0x00, 0x01, 0x00, // length = 8, type = RTAX_LOCK
+                    0x04, 0x00, 0x00, 0x00, // 1 << RTAX_MTU
+                0x08, 0x00, 0x02, 0x00, // length = 8, type = RTAX_MTU
+                    0x78, 0x05, 0x00, 0x00, // 1400
+                0x08, 0x00, 0x0c, 0x00, // length = 8, type = RTAX_FEATURES
+                    0x01, 0x00, 0x00, 0x00, // RTAX_FEATURE_ECN
+                0x0a, 0x00, 0x10, 0x00, // length = 10, type = RTAX_CC_ALGO
+                    0x63, 0x75, 0x62, 0x69, 0x63, 0x00, // "cubic"
+                    0x00, 0x00, // padding
+    ];
+
+    fn metrics_message() -> RouteMessage {
+        let mut msg = RouteMessage::default();
+        msg.header.address_family = AF_INET as u8;
+        msg.header.destination_prefix_length = 8;
+        msg.header.table = RT_TABLE_MAIN;
+        msg.header.protocol = RTPROT_STATIC;
+        msg.header.scope = RT_SCOPE_UNIVERSE;
+        msg.header.kind = RTN_UNICAST;
+        msg.nlas = vec![
+            Nla::Destination(vec![10, 0, 0, 0]),
+            Nla::Metrics(vec![
+                Metrics::Lock(1 << RTAX_MTU),
+                Metrics::Mtu(1400),
+                Metrics::Features(RTAX_FEATURE_ECN),
+                Metrics::CcAlgo("cubic".to_string()),
+            ]),
+        ];
+        msg
+    }
+
+    #[test]
+    fn parse_metrics() {
+        let actual =
+            RouteMessage::parse(&RouteMessageBuffer::new_checked(&&METRICS_MSG[..]).unwrap())
+                .unwrap();
+        assert_eq!(actual, metrics_message());
+    }
+
+    #[test]
+    fn emit_metrics() {
+        let msg = metrics_message();
+        let mut buf = vec![0; msg.buffer_len()];
+        msg.emit(&mut buf[..]);
+        assert_eq!(&buf[..], &METRICS_MSG[..]);
+    }
+}
+
+mod test_mfc_stats {
+    use crate::{
+        constants::*,
+        rtnl::route::{nlas::MfcStats, RouteMessage, RouteMessageBuffer},
+        utils::Parseable,
+    };
+
+    // ip mroute show, with a (10.0.0.1, 239.1.1.1) entry
+    #[rustfmt::skip]
+    static MROUTE_MSG: [u8; 56] = [
+        0x80, // address family = RTNL_FAMILY_IPMR
+        0x20, // length of destination = 32
+        0x20, // length of source = 32
+        0x00, // TOS
+        0xfd, // routing table id = RT_TABLE_DEFAULT
+        0x11, // routing protocol = RTPROT_MROUTED
+        0x00, // route origin = RT_SCOPE_UNIVERSE
+        0x05, // route type = RTN_MULTICAST
+        0x00, 0x00, 0x00, 0x00, // flags
+
+            0x08, 0x00, 0x02, 0x00, // length = 8, type = RTA_SRC
+                0x0a, 0x00, 0x00, 0x01, // 10.0.0.1
+
+            0x08, 0x00, 0x01, 0x00, // length = 8, type = RTA_DST
+                0xef, 0x01, 0x01, 0x01, // 239.1.1.1
+
+            0x1c, 0x00, 0x11, 0x00, // length = 28, type = RTA_MFC_STATS
+                0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 3 packets
+                0x54, 0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 340 bytes
+                0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 1 packet on a wrong interface
+    ];
+
+    #[test]
+    fn parse_mfc_stats() {
+        let msg = RouteMessage::parse(&RouteMessageBuffer::new_checked(&&MROUTE_MSG[..]).unwrap())
+            .unwrap();
+        assert_eq!(msg.header.address_family, RTNL_FAMILY_IPMR);
+        assert_eq!(
+            msg.mfc_stats(),
+            Some(MfcStats {
+                packets: 3,
+                bytes: 340,
+                wrong_if: 1,
+            })
+        );
+        assert_eq!(RouteMessage::default().mfc_stats(), None);
+    }
+}
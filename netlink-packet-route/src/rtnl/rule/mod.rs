@@ -5,6 +5,9 @@ pub mod header;
 pub mod message;
 pub mod nlas;
 
+#[cfg(test)]
+mod test;
+
 pub use buffer::*;
 pub use header::*;
 pub use message::*;
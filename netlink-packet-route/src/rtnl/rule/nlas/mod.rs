@@ -1,12 +1,19 @@
 // SPDX-License-Identifier: MIT
 
+mod port_range;
+pub use self::port_range::*;
+
+mod uid_range;
+pub use self::uid_range::*;
+
 use crate::{
     nlas,
     nlas::DefaultNla,
     utils::{
-        byteorder::{ByteOrder, NativeEndian},
+        byteorder::{BigEndian, ByteOrder, NativeEndian},
         nla::NlaBuffer,
-        parsers::{parse_string, parse_u32, parse_u8},
+        parsers::{parse_string, parse_u32, parse_u64, parse_u8},
+        Emitable,
         Parseable,
     },
     DecodeError,
@@ -50,7 +57,9 @@ pub enum Nla {
     FwMask(u32),
     /// flow class id,
     Flow(u32),
-    TunId(u32),
+    /// tunnel id, matched against the metadata of the packets received through a
+    /// collect-metadata tunnel
+    TunId(u64),
     SuppressIfGroup(u32),
     SuppressPrefixLen(u32),
     Table(u32),
@@ -59,13 +68,13 @@ pub enum Nla {
     Pad(Vec<u8>),
     /// iif or oif is l3mdev goto its table
     L3MDev(u8),
-    UidRange(Vec<u8>),
+    UidRange(UidRange),
     /// RTPROT_*
     Protocol(u8),
-    /// AF_*
+    /// IPPROTO_*
     IpProto(u8),
-    SourcePortRange(Vec<u8>),
-    DestinationPortRange(Vec<u8>),
+    SourcePortRange(PortRange),
+    DestinationPortRange(PortRange),
     Other(DefaultNla),
 }
 
@@ -73,16 +82,15 @@ impl nlas::Nla for Nla {
     fn value_len(&self) -> usize {
         use self::Nla::*;
         match self {
-            Unspec(ref bytes)
-            | Destination(ref bytes)
-            | Source(ref bytes)
-            | Pad(ref bytes)
-            | UidRange(ref bytes)
-            | SourcePortRange(ref bytes)
-            | DestinationPortRange(ref bytes) => bytes.len(),
+            Unspec(ref bytes) | Destination(ref bytes) | Source(ref bytes) | Pad(ref bytes) => {
+                bytes.len()
+            }
             Iifname(ref s) | OifName(ref s) => s.as_bytes().len() + 1,
-            Priority(_) | FwMark(_) | FwMask(_) | Flow(_) | TunId(_) | Goto(_)
-            | SuppressIfGroup(_) | SuppressPrefixLen(_) | Table(_) => 4,
+            Priority(_) | FwMark(_) | FwMask(_) | Flow(_) | Goto(_) | SuppressIfGroup(_)
+            | SuppressPrefixLen(_) | Table(_) => 4,
+            TunId(_) => 8,
+            UidRange(ref range) => range.buffer_len(),
+            SourcePortRange(ref range) | DestinationPortRange(ref range) => range.buffer_len(),
             L3MDev(_) | Protocol(_) | IpProto(_) => 1,
             Other(attr) => attr.value_len(),
         }
@@ -119,25 +127,26 @@ impl nlas::Nla for Nla {
     fn emit_value(&self, buffer: &mut [u8]) {
         use self::Nla::*;
         match self {
-            Unspec(ref bytes)
-            | Destination(ref bytes)
-            | Source(ref bytes)
-            | Pad(ref bytes)
-            | UidRange(ref bytes)
-            | SourcePortRange(ref bytes)
-            | DestinationPortRange(ref bytes) => buffer.copy_from_slice(bytes.as_slice()),
-            Iifname(ref s) | OifName(ref s) => buffer.copy_from_slice(s.as_bytes()),
+            Unspec(ref bytes) | Destination(ref bytes) | Source(ref bytes) | Pad(ref bytes) => {
+                buffer.copy_from_slice(bytes.as_slice())
+            }
+            Iifname(ref s) | OifName(ref s) => {
+                buffer[..s.len()].copy_from_slice(s.as_bytes());
+                buffer[s.len()] = 0;
+            }
 
             Priority(value)
             | FwMark(value)
             | FwMask(value)
             | Flow(value)
-            | TunId(value)
             | Goto(value)
             | SuppressIfGroup(value)
             | SuppressPrefixLen(value)
             | Table(value) => NativeEndian::write_u32(buffer, *value),
+            TunId(value) => BigEndian::write_u64(buffer, *value),
             L3MDev(value) | Protocol(value) | IpProto(value) => buffer[0] = *value,
+            UidRange(ref range) => range.emit(buffer),
+            SourcePortRange(ref range) | DestinationPortRange(ref range) => range.emit(buffer),
             Other(attr) => attr.emit_value(buffer),
         }
     }
@@ -158,7 +167,9 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             FRA_PRIORITY => Priority(parse_u32(payload).context("invalid FRA_PRIORITY value")?),
             FRA_FWMARK => FwMark(parse_u32(payload).context("invalid FRA_FWMARK value")?),
             FRA_FLOW => Flow(parse_u32(payload).context("invalid FRA_FLOW value")?),
-            FRA_TUN_ID => TunId(parse_u32(payload).context("invalid FRA_TUN_ID value")?),
+            FRA_TUN_ID => TunId(u64::from_be(
+                parse_u64(payload).context("invalid FRA_TUN_ID value")?,
+            )),
             FRA_SUPPRESS_IFGROUP => {
                 SuppressIfGroup(parse_u32(payload).context("invalid FRA_SUPPRESS_IFGROUP value")?)
             }
@@ -170,11 +181,23 @@ impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
             FRA_OIFNAME => OifName(parse_string(payload).context("invalid FRA_OIFNAME value")?),
             FRA_PAD => Pad(payload.to_vec()),
             FRA_L3MDEV => L3MDev(parse_u8(payload).context("invalid FRA_L3MDEV value")?),
-            FRA_UID_RANGE => UidRange(payload.to_vec()),
+            FRA_UID_RANGE => UidRange(
+                UidRangeBuffer::new_checked(payload)
+                    .and_then(|buf| self::UidRange::parse(&buf))
+                    .context("invalid FRA_UID_RANGE value")?,
+            ),
             FRA_PROTOCOL => Protocol(parse_u8(payload).context("invalid FRA_PROTOCOL value")?),
             FRA_IP_PROTO => IpProto(parse_u8(payload).context("invalid FRA_IP_PROTO value")?),
-            FRA_SPORT_RANGE => SourcePortRange(payload.to_vec()),
-            FRA_DPORT_RANGE => DestinationPortRange(payload.to_vec()),
+            FRA_SPORT_RANGE => SourcePortRange(
+                PortRangeBuffer::new_checked(payload)
+                    .and_then(|buf| PortRange::parse(&buf))
+                    .context("invalid FRA_SPORT_RANGE value")?,
+            ),
+            FRA_DPORT_RANGE => DestinationPortRange(
+                PortRangeBuffer::new_checked(payload)
+                    .and_then(|buf| PortRange::parse(&buf))
+                    .context("invalid FRA_DPORT_RANGE value")?,
+            ),
             _ => Other(DefaultNla::parse(buf).context("invalid NLA (unknown kind)")?),
         })
     }
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Range of layer 4 ports matched by a rule (`FRA_SPORT_RANGE` and `FRA_DPORT_RANGE`),
+/// bounds included
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct PortRange {
+    pub start: u16,
+    pub end: u16,
+}
+
+pub const PORT_RANGE_LEN: usize = 4;
+
+buffer!(PortRangeBuffer(PORT_RANGE_LEN) {
+    start: (u16, 0..2),
+    end: (u16, 2..4),
+});
+
+impl<T: AsRef<[u8]>> Parseable<PortRangeBuffer<T>> for PortRange {
+    fn parse(buf: &PortRangeBuffer<T>) -> Result<PortRange, DecodeError> {
+        Ok(PortRange {
+            start: buf.start(),
+            end: buf.end(),
+        })
+    }
+}
+
+impl Emitable for PortRange {
+    fn buffer_len(&self) -> usize {
+        PORT_RANGE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = PortRangeBuffer::new(buffer);
+        buffer.set_start(self.start);
+        buffer.set_end(self.end);
+    }
+}
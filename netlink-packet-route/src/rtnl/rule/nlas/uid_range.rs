@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Range of user ids matched by a rule (`FRA_UID_RANGE`), bounds included
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub struct UidRange {
+    pub start: u32,
+    pub end: u32,
+}
+
+pub const UID_RANGE_LEN: usize = 8;
+
+buffer!(UidRangeBuffer(UID_RANGE_LEN) {
+    start: (u32, 0..4),
+    end: (u32, 4..8),
+});
+
+impl<T: AsRef<[u8]>> Parseable<UidRangeBuffer<T>> for UidRange {
+    fn parse(buf: &UidRangeBuffer<T>) -> Result<UidRange, DecodeError> {
+        Ok(UidRange {
+            start: buf.start(),
+            end: buf.end(),
+        })
+    }
+}
+
+impl Emitable for UidRange {
+    fn buffer_len(&self) -> usize {
+        UID_RANGE_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = UidRangeBuffer::new(buffer);
+        buffer.set_start(self.start);
+        buffer.set_end(self.end);
+    }
+}
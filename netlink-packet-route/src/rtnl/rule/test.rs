@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    rtnl::rule::{
+        nlas::{Nla, PortRange, UidRange},
+        RuleMessage,
+        RuleMessageBuffer,
+    },
+    utils::{Emitable, Parseable},
+};
+
+// ip rule add pref 100 iif lo fwmark 0x10/0xff ipproto tcp dport 1000-2000 \
+//     uidrange 1000-1999 tun_id 42 table 1000
+#[rustfmt::skip]
+static RULE_MSG: [u8; 92] = [
+    0x02, // address family = AF_INET
+    0x00, // length of destination
+    0x00, // length of source
+    0x00, // TOS
+    0x00, // routing table id = RT_TABLE_UNSPEC
+    0x00, 0x00, // reserved
+    0x01, // action = FR_ACT_TO_TBL
+    0x00, 0x00, 0x00, 0x00, // flags
+
+        0x08, 0x00, 0x0f, 0x00, // length = 8, type = FRA_TABLE
+            0xe8, 0x03, 0x00, 0x00, // 1000
+
+        0x08, 0x00, 0x06, 0x00, // length = 8, type = FRA_PRIORITY
+            0x64, 0x00, 0x00, 0x00, // 100
+
+        0x07, 0x00, 0x03, 0x00, // length = 7, type = FRA_IIFNAME
+            0x6c, 0x6f, 0x00, // "lo"
+            0x00, // padding
+
+        0x08, 0x00, 0x0a, 0x00, // length = 8, type = FRA_FWMARK
+            0x10, 0x00, 0x00, 0x00, // 0x10
+
+        0x08, 0x00, 0x10, 0x00, // length = 8, type = FRA_FWMASK
+            0xff, 0x00, 0x00, 0x00, // 0xff
+
+        0x05, 0x00, 0x16, 0x00, // length = 5, type = FRA_IP_PROTO
+            0x06, 0x00, 0x00, 0x00, // IPPROTO_TCP
+
+        0x08, 0x00, 0x18, 0x00, // length = 8, type = FRA_DPORT_RANGE
+            0xe8, 0x03, // start = 1000
+            0xd0, 0x07, // end = 2000
+
+        0x0c, 0x00, 0x14, 0x00, // length = 12, type = FRA_UID_RANGE
+            0xe8, 0x03, 0x00, 0x00, // start = 1000
+            0xcf, 0x07, 0x00, 0x00, // end = 1999
+
+        0x0c, 0x00, 0x0c, 0x00, // length = 12, type = FRA_TUN_ID
+            0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x2a, // 42, network byte order
+];
+
+fn rule_message() -> RuleMessage {
+    let mut msg = RuleMessage::default();
+    msg.header.family = AF_INET as u8;
+    msg.header.table = RT_TABLE_UNSPEC;
+    msg.header.action = FR_ACT_TO_TBL;
+    msg.nlas = vec![
+        Nla::Table(1000),
+        Nla::Priority(100),
+        Nla::Iifname("lo".to_string()),
+        Nla::FwMark(0x10),
+        Nla::FwMask(0xff),
+        Nla::IpProto(6),
+        Nla::DestinationPortRange(PortRange {
+            start: 1000,
+            end: 2000,
+        }),
+        Nla::UidRange(UidRange {
+            start: 1000,
+            end: 1999,
+        }),
+        Nla::TunId(42),
+    ];
+    msg
+}
+
+#[test]
+fn parse_rule() {
+    let actual =
+        RuleMessage::parse(&RuleMessageBuffer::new_checked(&&RULE_MSG[..]).unwrap()).unwrap();
+    assert_eq!(actual, rule_message());
+}
+
+#[test]
+fn emit_rule() {
+    let msg = rule_message();
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf[..]);
+    assert_eq!(&buf[..], &RULE_MSG[..]);
+}
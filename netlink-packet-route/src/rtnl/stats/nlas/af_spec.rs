@@ -0,0 +1,184 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Protocol specific statistics of a link, by address family
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AfSpecStats {
+    Mpls(Vec<MplsStats>),
+    Other(DefaultNla),
+}
+
+impl Nla for AfSpecStats {
+    fn value_len(&self) -> usize {
+        match self {
+            AfSpecStats::Mpls(nlas) => nlas.as_slice().buffer_len(),
+            AfSpecStats::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            AfSpecStats::Mpls(nlas) => nlas.as_slice().emit(buffer),
+            AfSpecStats::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            AfSpecStats::Mpls(_) => AF_MPLS,
+            AfSpecStats::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for AfSpecStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            AF_MPLS => {
+                let error_msg = "invalid AF_MPLS statistics";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(MplsStats::parse(nla).context(error_msg)?);
+                }
+                AfSpecStats::Mpls(nlas)
+            }
+            kind => AfSpecStats::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+/// `MPLS_STATS_*`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MplsStats {
+    Unspec(Vec<u8>),
+    Link(MplsLinkStats),
+    Other(DefaultNla),
+}
+
+impl Nla for MplsStats {
+    fn value_len(&self) -> usize {
+        match self {
+            MplsStats::Unspec(bytes) => bytes.len(),
+            MplsStats::Link(stats) => stats.buffer_len(),
+            MplsStats::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            MplsStats::Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            MplsStats::Link(stats) => stats.emit(buffer),
+            MplsStats::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            MplsStats::Unspec(_) => MPLS_STATS_UNSPEC,
+            MplsStats::Link(_) => MPLS_STATS_LINK,
+            MplsStats::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for MplsStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            MPLS_STATS_UNSPEC => MplsStats::Unspec(payload.to_vec()),
+            MPLS_STATS_LINK => MplsStats::Link(
+                MplsLinkStats::parse(
+                    &MplsLinkStatsBuffer::new_checked(payload)
+                        .context("invalid MPLS_STATS_LINK value")?,
+                )
+                .context("invalid MPLS_STATS_LINK value")?,
+            ),
+            kind => MplsStats::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+pub const MPLS_LINK_STATS_LEN: usize = 72;
+buffer!(MplsLinkStatsBuffer(MPLS_LINK_STATS_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    rx_noroute: (u64, 64..72),
+});
+
+/// MPLS counters of a link (`struct mpls_link_stats`)
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct MplsLinkStats {
+    /// total packets received
+    pub rx_packets: u64,
+    /// total packets transmitted
+    pub tx_packets: u64,
+    /// total bytes received
+    pub rx_bytes: u64,
+    /// total bytes transmitted
+    pub tx_bytes: u64,
+    /// bad packets received
+    pub rx_errors: u64,
+    /// packet transmit problems
+    pub tx_errors: u64,
+    /// packets dropped on receive
+    pub rx_dropped: u64,
+    /// packets dropped on transmit
+    pub tx_dropped: u64,
+    /// packets received with an unknown label
+    pub rx_noroute: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<MplsLinkStatsBuffer<T>> for MplsLinkStats {
+    fn parse(buf: &MplsLinkStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            rx_noroute: buf.rx_noroute(),
+        })
+    }
+}
+
+impl Emitable for MplsLinkStats {
+    fn buffer_len(&self) -> usize {
+        MPLS_LINK_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = MplsLinkStatsBuffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_rx_noroute(self.rx_noroute);
+    }
+}
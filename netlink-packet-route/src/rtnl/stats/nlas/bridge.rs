@@ -0,0 +1,288 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+pub const BRIDGE_VLAN_XSTATS_LEN: usize = 40;
+buffer!(BridgeVlanXstatsBuffer(BRIDGE_VLAN_XSTATS_LEN) {
+    rx_bytes: (u64, 0..8),
+    rx_packets: (u64, 8..16),
+    tx_bytes: (u64, 16..24),
+    tx_packets: (u64, 24..32),
+    vid: (u16, 32..34),
+    flags: (u16, 34..36),
+    pad2: (u32, 36..40),
+});
+
+/// Per VLAN counters of a bridge or bridge port (`struct bridge_vlan_xstats`)
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct BridgeVlanXstats {
+    pub rx_bytes: u64,
+    pub rx_packets: u64,
+    pub tx_bytes: u64,
+    pub tx_packets: u64,
+    /// VLAN id
+    pub vid: u16,
+    /// `BRIDGE_VLAN_INFO_*` flags
+    pub flags: u16,
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeVlanXstatsBuffer<T>> for BridgeVlanXstats {
+    fn parse(buf: &BridgeVlanXstatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_bytes: buf.rx_bytes(),
+            rx_packets: buf.rx_packets(),
+            tx_bytes: buf.tx_bytes(),
+            tx_packets: buf.tx_packets(),
+            vid: buf.vid(),
+            flags: buf.flags(),
+        })
+    }
+}
+
+impl Emitable for BridgeVlanXstats {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_VLAN_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = BridgeVlanXstatsBuffer::new(buffer);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_vid(self.vid);
+        buffer.set_flags(self.flags);
+        buffer.set_pad2(0);
+    }
+}
+
+pub const BRIDGE_MCAST_STATS_LEN: usize = 240;
+buffer!(BridgeMcastStatsBuffer(BRIDGE_MCAST_STATS_LEN) {
+    igmp_v1queries_rx: (u64, 0..8),
+    igmp_v1queries_tx: (u64, 8..16),
+    igmp_v2queries_rx: (u64, 16..24),
+    igmp_v2queries_tx: (u64, 24..32),
+    igmp_v3queries_rx: (u64, 32..40),
+    igmp_v3queries_tx: (u64, 40..48),
+    igmp_leaves_rx: (u64, 48..56),
+    igmp_leaves_tx: (u64, 56..64),
+    igmp_v1reports_rx: (u64, 64..72),
+    igmp_v1reports_tx: (u64, 72..80),
+    igmp_v2reports_rx: (u64, 80..88),
+    igmp_v2reports_tx: (u64, 88..96),
+    igmp_v3reports_rx: (u64, 96..104),
+    igmp_v3reports_tx: (u64, 104..112),
+    igmp_parse_errors: (u64, 112..120),
+    mld_v1queries_rx: (u64, 120..128),
+    mld_v1queries_tx: (u64, 128..136),
+    mld_v2queries_rx: (u64, 136..144),
+    mld_v2queries_tx: (u64, 144..152),
+    mld_leaves_rx: (u64, 152..160),
+    mld_leaves_tx: (u64, 160..168),
+    mld_v1reports_rx: (u64, 168..176),
+    mld_v1reports_tx: (u64, 176..184),
+    mld_v2reports_rx: (u64, 184..192),
+    mld_v2reports_tx: (u64, 192..200),
+    mld_parse_errors: (u64, 200..208),
+    mcast_bytes_rx: (u64, 208..216),
+    mcast_bytes_tx: (u64, 216..224),
+    mcast_packets_rx: (u64, 224..232),
+    mcast_packets_tx: (u64, 232..240),
+});
+
+/// Multicast snooping counters of a bridge or bridge port (`struct br_mcast_stats`)
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct BridgeMcastStats {
+    /// IGMPv1 queries received
+    pub igmp_v1queries_rx: u64,
+    /// IGMPv1 queries sent
+    pub igmp_v1queries_tx: u64,
+    /// IGMPv2 queries received
+    pub igmp_v2queries_rx: u64,
+    /// IGMPv2 queries sent
+    pub igmp_v2queries_tx: u64,
+    /// IGMPv3 queries received
+    pub igmp_v3queries_rx: u64,
+    /// IGMPv3 queries sent
+    pub igmp_v3queries_tx: u64,
+    /// IGMP leave messages received
+    pub igmp_leaves_rx: u64,
+    /// IGMP leave messages sent
+    pub igmp_leaves_tx: u64,
+    /// IGMPv1 reports received
+    pub igmp_v1reports_rx: u64,
+    /// IGMPv1 reports sent
+    pub igmp_v1reports_tx: u64,
+    /// IGMPv2 reports received
+    pub igmp_v2reports_rx: u64,
+    /// IGMPv2 reports sent
+    pub igmp_v2reports_tx: u64,
+    /// IGMPv3 reports received
+    pub igmp_v3reports_rx: u64,
+    /// IGMPv3 reports sent
+    pub igmp_v3reports_tx: u64,
+    /// IGMP packets that could not be parsed
+    pub igmp_parse_errors: u64,
+    /// MLDv1 queries received
+    pub mld_v1queries_rx: u64,
+    /// MLDv1 queries sent
+    pub mld_v1queries_tx: u64,
+    /// MLDv2 queries received
+    pub mld_v2queries_rx: u64,
+    /// MLDv2 queries sent
+    pub mld_v2queries_tx: u64,
+    /// MLD done messages received
+    pub mld_leaves_rx: u64,
+    /// MLD done messages sent
+    pub mld_leaves_tx: u64,
+    /// MLDv1 reports received
+    pub mld_v1reports_rx: u64,
+    /// MLDv1 reports sent
+    pub mld_v1reports_tx: u64,
+    /// MLDv2 reports received
+    pub mld_v2reports_rx: u64,
+    /// MLDv2 reports sent
+    pub mld_v2reports_tx: u64,
+    /// MLD packets that could not be parsed
+    pub mld_parse_errors: u64,
+    /// multicast bytes received
+    pub mcast_bytes_rx: u64,
+    /// multicast bytes sent
+    pub mcast_bytes_tx: u64,
+    /// multicast packets received
+    pub mcast_packets_rx: u64,
+    /// multicast packets sent
+    pub mcast_packets_tx: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeMcastStatsBuffer<T>> for BridgeMcastStats {
+    fn parse(buf: &BridgeMcastStatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            igmp_v1queries_rx: buf.igmp_v1queries_rx(),
+            igmp_v1queries_tx: buf.igmp_v1queries_tx(),
+            igmp_v2queries_rx: buf.igmp_v2queries_rx(),
+            igmp_v2queries_tx: buf.igmp_v2queries_tx(),
+            igmp_v3queries_rx: buf.igmp_v3queries_rx(),
+            igmp_v3queries_tx: buf.igmp_v3queries_tx(),
+            igmp_leaves_rx: buf.igmp_leaves_rx(),
+            igmp_leaves_tx: buf.igmp_leaves_tx(),
+            igmp_v1reports_rx: buf.igmp_v1reports_rx(),
+            igmp_v1reports_tx: buf.igmp_v1reports_tx(),
+            igmp_v2reports_rx: buf.igmp_v2reports_rx(),
+            igmp_v2reports_tx: buf.igmp_v2reports_tx(),
+            igmp_v3reports_rx: buf.igmp_v3reports_rx(),
+            igmp_v3reports_tx: buf.igmp_v3reports_tx(),
+            igmp_parse_errors: buf.igmp_parse_errors(),
+            mld_v1queries_rx: buf.mld_v1queries_rx(),
+            mld_v1queries_tx: buf.mld_v1queries_tx(),
+            mld_v2queries_rx: buf.mld_v2queries_rx(),
+            mld_v2queries_tx: buf.mld_v2queries_tx(),
+            mld_leaves_rx: buf.mld_leaves_rx(),
+            mld_leaves_tx: buf.mld_leaves_tx(),
+            mld_v1reports_rx: buf.mld_v1reports_rx(),
+            mld_v1reports_tx: buf.mld_v1reports_tx(),
+            mld_v2reports_rx: buf.mld_v2reports_rx(),
+            mld_v2reports_tx: buf.mld_v2reports_tx(),
+            mld_parse_errors: buf.mld_parse_errors(),
+            mcast_bytes_rx: buf.mcast_bytes_rx(),
+            mcast_bytes_tx: buf.mcast_bytes_tx(),
+            mcast_packets_rx: buf.mcast_packets_rx(),
+            mcast_packets_tx: buf.mcast_packets_tx(),
+        })
+    }
+}
+
+impl Emitable for BridgeMcastStats {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_MCAST_STATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = BridgeMcastStatsBuffer::new(buffer);
+        buffer.set_igmp_v1queries_rx(self.igmp_v1queries_rx);
+        buffer.set_igmp_v1queries_tx(self.igmp_v1queries_tx);
+        buffer.set_igmp_v2queries_rx(self.igmp_v2queries_rx);
+        buffer.set_igmp_v2queries_tx(self.igmp_v2queries_tx);
+        buffer.set_igmp_v3queries_rx(self.igmp_v3queries_rx);
+        buffer.set_igmp_v3queries_tx(self.igmp_v3queries_tx);
+        buffer.set_igmp_leaves_rx(self.igmp_leaves_rx);
+        buffer.set_igmp_leaves_tx(self.igmp_leaves_tx);
+        buffer.set_igmp_v1reports_rx(self.igmp_v1reports_rx);
+        buffer.set_igmp_v1reports_tx(self.igmp_v1reports_tx);
+        buffer.set_igmp_v2reports_rx(self.igmp_v2reports_rx);
+        buffer.set_igmp_v2reports_tx(self.igmp_v2reports_tx);
+        buffer.set_igmp_v3reports_rx(self.igmp_v3reports_rx);
+        buffer.set_igmp_v3reports_tx(self.igmp_v3reports_tx);
+        buffer.set_igmp_parse_errors(self.igmp_parse_errors);
+        buffer.set_mld_v1queries_rx(self.mld_v1queries_rx);
+        buffer.set_mld_v1queries_tx(self.mld_v1queries_tx);
+        buffer.set_mld_v2queries_rx(self.mld_v2queries_rx);
+        buffer.set_mld_v2queries_tx(self.mld_v2queries_tx);
+        buffer.set_mld_leaves_rx(self.mld_leaves_rx);
+        buffer.set_mld_leaves_tx(self.mld_leaves_tx);
+        buffer.set_mld_v1reports_rx(self.mld_v1reports_rx);
+        buffer.set_mld_v1reports_tx(self.mld_v1reports_tx);
+        buffer.set_mld_v2reports_rx(self.mld_v2reports_rx);
+        buffer.set_mld_v2reports_tx(self.mld_v2reports_tx);
+        buffer.set_mld_parse_errors(self.mld_parse_errors);
+        buffer.set_mcast_bytes_rx(self.mcast_bytes_rx);
+        buffer.set_mcast_bytes_tx(self.mcast_bytes_tx);
+        buffer.set_mcast_packets_rx(self.mcast_packets_rx);
+        buffer.set_mcast_packets_tx(self.mcast_packets_tx);
+    }
+}
+
+pub const BRIDGE_STP_XSTATS_LEN: usize = 48;
+buffer!(BridgeStpXstatsBuffer(BRIDGE_STP_XSTATS_LEN) {
+    transition_blk: (u64, 0..8),
+    transition_fwd: (u64, 8..16),
+    rx_bpdu: (u64, 16..24),
+    tx_bpdu: (u64, 24..32),
+    rx_tcn: (u64, 32..40),
+    tx_tcn: (u64, 40..48),
+});
+
+/// STP counters of a bridge port (`struct bridge_stp_xstats`)
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct BridgeStpXstats {
+    /// transitions to the blocking state
+    pub transition_blk: u64,
+    /// transitions to the forwarding state
+    pub transition_fwd: u64,
+    pub rx_bpdu: u64,
+    pub tx_bpdu: u64,
+    pub rx_tcn: u64,
+    pub tx_tcn: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<BridgeStpXstatsBuffer<T>> for BridgeStpXstats {
+    fn parse(buf: &BridgeStpXstatsBuffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            transition_blk: buf.transition_blk(),
+            transition_fwd: buf.transition_fwd(),
+            rx_bpdu: buf.rx_bpdu(),
+            tx_bpdu: buf.tx_bpdu(),
+            rx_tcn: buf.rx_tcn(),
+            tx_tcn: buf.tx_tcn(),
+        })
+    }
+}
+
+impl Emitable for BridgeStpXstats {
+    fn buffer_len(&self) -> usize {
+        BRIDGE_STP_XSTATS_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = BridgeStpXstatsBuffer::new(buffer);
+        buffer.set_transition_blk(self.transition_blk);
+        buffer.set_transition_fwd(self.transition_fwd);
+        buffer.set_rx_bpdu(self.rx_bpdu);
+        buffer.set_tx_bpdu(self.tx_bpdu);
+        buffer.set_rx_tcn(self.rx_tcn);
+        buffer.set_tx_tcn(self.tx_tcn);
+    }
+}
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+mod af_spec;
+pub use self::af_spec::*;
+
+mod bridge;
+pub use self::bridge::*;
+
+mod offload;
+pub use self::offload::*;
+
+mod xstats;
+pub use self::xstats::*;
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    link::nlas::{Stats64, Stats64Buffer},
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    /// The kernel uses this attribute to align the 64 bits counters
+    Unspec(Vec<u8>),
+    Link64(Stats64),
+    /// Extended statistics of the link itself, e.g. of a bridge or a bond
+    LinkXstats(Vec<LinkXstats>),
+    /// Extended statistics of the link as a port of its master, e.g. of a
+    /// bridge port or of a bond slave
+    LinkXstatsSlave(Vec<LinkXstats>),
+    LinkOffloadXstats(Vec<OffloadXstats>),
+    AfSpec(Vec<AfSpecStats>),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Link64(stats) => stats.buffer_len(),
+            LinkXstats(nlas) | LinkXstatsSlave(nlas) => nlas.as_slice().buffer_len(),
+            LinkOffloadXstats(nlas) => nlas.as_slice().buffer_len(),
+            AfSpec(nlas) => nlas.as_slice().buffer_len(),
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Link64(stats) => stats.emit(buffer),
+            LinkXstats(nlas) | LinkXstatsSlave(nlas) => nlas.as_slice().emit(buffer),
+            LinkOffloadXstats(nlas) => nlas.as_slice().emit(buffer),
+            AfSpec(nlas) => nlas.as_slice().emit(buffer),
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => IFLA_STATS_UNSPEC,
+            Link64(_) => IFLA_STATS_LINK_64,
+            LinkXstats(_) => IFLA_STATS_LINK_XSTATS,
+            LinkXstatsSlave(_) => IFLA_STATS_LINK_XSTATS_SLAVE,
+            LinkOffloadXstats(_) => IFLA_STATS_LINK_OFFLOAD_XSTATS,
+            AfSpec(_) => IFLA_STATS_AF_SPEC,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_STATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_STATS_LINK_64 => Link64(
+                Stats64::parse(
+                    &Stats64Buffer::new_checked(payload)
+                        .context("invalid IFLA_STATS_LINK_64 value")?,
+                )
+                .context("invalid IFLA_STATS_LINK_64 value")?,
+            ),
+            IFLA_STATS_LINK_XSTATS => {
+                LinkXstats(parse_xstats(payload).context("invalid IFLA_STATS_LINK_XSTATS value")?)
+            }
+            IFLA_STATS_LINK_XSTATS_SLAVE => LinkXstatsSlave(
+                parse_xstats(payload).context("invalid IFLA_STATS_LINK_XSTATS_SLAVE value")?,
+            ),
+            IFLA_STATS_LINK_OFFLOAD_XSTATS => {
+                let error_msg = "invalid IFLA_STATS_LINK_OFFLOAD_XSTATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(OffloadXstats::parse(nla).context(error_msg)?);
+                }
+                LinkOffloadXstats(nlas)
+            }
+            IFLA_STATS_AF_SPEC => {
+                let error_msg = "invalid IFLA_STATS_AF_SPEC value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(AfSpecStats::parse(nla).context(error_msg)?);
+                }
+                AfSpec(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+fn parse_xstats(payload: &[u8]) -> Result<Vec<LinkXstats>, DecodeError> {
+    let mut nlas = vec![];
+    for nla in NlasIterator::new(payload) {
+        nlas.push(LinkXstats::parse(&nla?)?);
+    }
+    Ok(nlas)
+}
@@ -0,0 +1,260 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+
+use crate::{
+    constants::*,
+    link::nlas::{Stats64, Stats64Buffer},
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u8,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Statistics of traffic handled by the hardware or by the CPU on behalf of
+/// an offloading device (`IFLA_OFFLOAD_XSTATS_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum OffloadXstats {
+    Unspec(Vec<u8>),
+    /// Packets that hit the CPU instead of being forwarded by the hardware
+    CpuHit(Stats64),
+    /// Which hardware statistics are requested and in use, by type
+    HwStatsInfo(Vec<HwStatsInfo>),
+    /// Layer 3 hardware statistics
+    L3Stats(HwStats64),
+    Other(DefaultNla),
+}
+
+impl Nla for OffloadXstats {
+    fn value_len(&self) -> usize {
+        use self::OffloadXstats::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            CpuHit(stats) => stats.buffer_len(),
+            HwStatsInfo(nlas) => nlas.as_slice().buffer_len(),
+            L3Stats(stats) => stats.buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::OffloadXstats::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            CpuHit(stats) => stats.emit(buffer),
+            HwStatsInfo(nlas) => nlas.as_slice().emit(buffer),
+            L3Stats(stats) => stats.emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::OffloadXstats::*;
+        match self {
+            Unspec(_) => IFLA_OFFLOAD_XSTATS_UNSPEC,
+            CpuHit(_) => IFLA_OFFLOAD_XSTATS_CPU_HIT,
+            HwStatsInfo(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO,
+            L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for OffloadXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::OffloadXstats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_UNSPEC => Unspec(payload.to_vec()),
+            IFLA_OFFLOAD_XSTATS_CPU_HIT => CpuHit(
+                Stats64::parse(
+                    &Stats64Buffer::new_checked(payload)
+                        .context("invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value")?,
+                )
+                .context("invalid IFLA_OFFLOAD_XSTATS_CPU_HIT value")?,
+            ),
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO => {
+                let error_msg = "invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(self::HwStatsInfo::parse(nla).context(error_msg)?);
+                }
+                HwStatsInfo(nlas)
+            }
+            IFLA_OFFLOAD_XSTATS_L3_STATS => L3Stats(
+                HwStats64::parse(
+                    &HwStats64Buffer::new_checked(payload)
+                        .context("invalid IFLA_OFFLOAD_XSTATS_L3_STATS value")?,
+                )
+                .context("invalid IFLA_OFFLOAD_XSTATS_L3_STATS value")?,
+            ),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// State of one kind of hardware statistics, keyed by the
+/// `IFLA_OFFLOAD_XSTATS_*` attribute carrying them
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HwStatsInfo {
+    L3Stats(Vec<HwStatsUsage>),
+    Other(DefaultNla),
+}
+
+impl Nla for HwStatsInfo {
+    fn value_len(&self) -> usize {
+        match self {
+            HwStatsInfo::L3Stats(nlas) => nlas.as_slice().buffer_len(),
+            HwStatsInfo::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HwStatsInfo::L3Stats(nlas) => nlas.as_slice().emit(buffer),
+            HwStatsInfo::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HwStatsInfo::L3Stats(_) => IFLA_OFFLOAD_XSTATS_L3_STATS,
+            HwStatsInfo::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for HwStatsInfo {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_L3_STATS => {
+                let error_msg = "invalid IFLA_OFFLOAD_XSTATS_L3_STATS value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(HwStatsUsage::parse(nla).context(error_msg)?);
+                }
+                HwStatsInfo::L3Stats(nlas)
+            }
+            kind => HwStatsInfo::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+/// `IFLA_OFFLOAD_XSTATS_HW_S_INFO_*`
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum HwStatsUsage {
+    /// Whether the statistics have been requested by the user
+    Request(bool),
+    /// Whether the device actually collects them
+    Used(bool),
+    Other(DefaultNla),
+}
+
+impl Nla for HwStatsUsage {
+    fn value_len(&self) -> usize {
+        match self {
+            HwStatsUsage::Request(_) | HwStatsUsage::Used(_) => 1,
+            HwStatsUsage::Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        match self {
+            HwStatsUsage::Request(value) | HwStatsUsage::Used(value) => buffer[0] = *value as u8,
+            HwStatsUsage::Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        match self {
+            HwStatsUsage::Request(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST,
+            HwStatsUsage::Used(_) => IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED,
+            HwStatsUsage::Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for HwStatsUsage {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST => HwStatsUsage::Request(
+                parse_u8(payload).context("invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO_REQUEST value")?
+                    != 0,
+            ),
+            IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED => HwStatsUsage::Used(
+                parse_u8(payload).context("invalid IFLA_OFFLOAD_XSTATS_HW_S_INFO_USED value")? != 0,
+            ),
+            kind => HwStatsUsage::Other(
+                DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?,
+            ),
+        })
+    }
+}
+
+pub const HW_STATS64_LEN: usize = 72;
+buffer!(HwStats64Buffer(HW_STATS64_LEN) {
+    rx_packets: (u64, 0..8),
+    tx_packets: (u64, 8..16),
+    rx_bytes: (u64, 16..24),
+    tx_bytes: (u64, 24..32),
+    rx_errors: (u64, 32..40),
+    tx_errors: (u64, 40..48),
+    rx_dropped: (u64, 48..56),
+    tx_dropped: (u64, 56..64),
+    multicast: (u64, 64..72),
+});
+
+/// Counters maintained by the hardware (`struct rtnl_hw_stats64`)
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq)]
+pub struct HwStats64 {
+    pub rx_packets: u64,
+    pub tx_packets: u64,
+    pub rx_bytes: u64,
+    pub tx_bytes: u64,
+    pub rx_errors: u64,
+    pub tx_errors: u64,
+    pub rx_dropped: u64,
+    pub tx_dropped: u64,
+    pub multicast: u64,
+}
+
+impl<T: AsRef<[u8]>> Parseable<HwStats64Buffer<T>> for HwStats64 {
+    fn parse(buf: &HwStats64Buffer<T>) -> Result<Self, DecodeError> {
+        Ok(Self {
+            rx_packets: buf.rx_packets(),
+            tx_packets: buf.tx_packets(),
+            rx_bytes: buf.rx_bytes(),
+            tx_bytes: buf.tx_bytes(),
+            rx_errors: buf.rx_errors(),
+            tx_errors: buf.tx_errors(),
+            rx_dropped: buf.rx_dropped(),
+            tx_dropped: buf.tx_dropped(),
+            multicast: buf.multicast(),
+        })
+    }
+}
+
+impl Emitable for HwStats64 {
+    fn buffer_len(&self) -> usize {
+        HW_STATS64_LEN
+    }
+
+    fn emit(&self, buffer: &mut [u8]) {
+        let mut buffer = HwStats64Buffer::new(buffer);
+        buffer.set_rx_packets(self.rx_packets);
+        buffer.set_tx_packets(self.tx_packets);
+        buffer.set_rx_bytes(self.rx_bytes);
+        buffer.set_tx_bytes(self.tx_bytes);
+        buffer.set_rx_errors(self.rx_errors);
+        buffer.set_tx_errors(self.tx_errors);
+        buffer.set_rx_dropped(self.rx_dropped);
+        buffer.set_tx_dropped(self.tx_dropped);
+        buffer.set_multicast(self.multicast);
+    }
+}
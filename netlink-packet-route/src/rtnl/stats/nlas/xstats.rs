@@ -0,0 +1,326 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use super::{
+    BridgeMcastStats,
+    BridgeMcastStatsBuffer,
+    BridgeStpXstats,
+    BridgeStpXstatsBuffer,
+    BridgeVlanXstats,
+    BridgeVlanXstatsBuffer,
+};
+use crate::{
+    constants::*,
+    nlas::{DefaultNla, Nla, NlaBuffer, NlasIterator},
+    parsers::parse_u64,
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// Extended statistics of a link or of a port, by link type
+/// (`LINK_XSTATS_TYPE_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum LinkXstats {
+    Bridge(Vec<BridgeXstats>),
+    Bond(Vec<BondXstats>),
+    Other(DefaultNla),
+}
+
+impl Nla for LinkXstats {
+    fn value_len(&self) -> usize {
+        use self::LinkXstats::*;
+        match self {
+            Bridge(nlas) => nlas.as_slice().buffer_len(),
+            Bond(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::LinkXstats::*;
+        match self {
+            Bridge(nlas) => nlas.as_slice().emit(buffer),
+            Bond(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::LinkXstats::*;
+        match self {
+            Bridge(_) => LINK_XSTATS_TYPE_BRIDGE,
+            Bond(_) => LINK_XSTATS_TYPE_BOND,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for LinkXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::LinkXstats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            LINK_XSTATS_TYPE_BRIDGE => {
+                let error_msg = "invalid LINK_XSTATS_TYPE_BRIDGE value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(BridgeXstats::parse(nla).context(error_msg)?);
+                }
+                Bridge(nlas)
+            }
+            LINK_XSTATS_TYPE_BOND => {
+                let error_msg = "invalid LINK_XSTATS_TYPE_BOND value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(BondXstats::parse(nla).context(error_msg)?);
+                }
+                Bond(nlas)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Bridge extended statistics (`BRIDGE_XSTATS_*`). A bridge reports one
+/// `Vlan` entry per VLAN, and multicast counters if enabled. A bridge port
+/// also reports STP counters.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BridgeXstats {
+    Unspec(Vec<u8>),
+    Vlan(BridgeVlanXstats),
+    Mcast(BridgeMcastStats),
+    Stp(BridgeStpXstats),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for BridgeXstats {
+    fn value_len(&self) -> usize {
+        use self::BridgeXstats::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Vlan(stats) => stats.buffer_len(),
+            Mcast(stats) => stats.buffer_len(),
+            Stp(stats) => stats.buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BridgeXstats::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Vlan(stats) => stats.emit(buffer),
+            Mcast(stats) => stats.emit(buffer),
+            Stp(stats) => stats.emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BridgeXstats::*;
+        match self {
+            Unspec(_) => BRIDGE_XSTATS_UNSPEC,
+            Vlan(_) => BRIDGE_XSTATS_VLAN,
+            Mcast(_) => BRIDGE_XSTATS_MCAST,
+            Stp(_) => BRIDGE_XSTATS_STP,
+            Pad(_) => BRIDGE_XSTATS_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BridgeXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BridgeXstats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BRIDGE_XSTATS_UNSPEC => Unspec(payload.to_vec()),
+            BRIDGE_XSTATS_VLAN => Vlan(
+                BridgeVlanXstats::parse(
+                    &BridgeVlanXstatsBuffer::new_checked(payload)
+                        .context("invalid BRIDGE_XSTATS_VLAN value")?,
+                )
+                .context("invalid BRIDGE_XSTATS_VLAN value")?,
+            ),
+            BRIDGE_XSTATS_MCAST => Mcast(
+                BridgeMcastStats::parse(
+                    &BridgeMcastStatsBuffer::new_checked(payload)
+                        .context("invalid BRIDGE_XSTATS_MCAST value")?,
+                )
+                .context("invalid BRIDGE_XSTATS_MCAST value")?,
+            ),
+            BRIDGE_XSTATS_STP => Stp(BridgeStpXstats::parse(
+                &BridgeStpXstatsBuffer::new_checked(payload)
+                    .context("invalid BRIDGE_XSTATS_STP value")?,
+            )
+            .context("invalid BRIDGE_XSTATS_STP value")?),
+            BRIDGE_XSTATS_PAD => Pad(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// Bonding extended statistics (`BOND_XSTATS_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum BondXstats {
+    Unspec(Vec<u8>),
+    /// 802.3ad (LACP) counters
+    Lacp(Vec<Bond3adStats>),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for BondXstats {
+    fn value_len(&self) -> usize {
+        use self::BondXstats::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => bytes.len(),
+            Lacp(nlas) => nlas.as_slice().buffer_len(),
+            Other(nla) => nla.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::BondXstats::*;
+        match self {
+            Unspec(bytes) | Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Lacp(nlas) => nlas.as_slice().emit(buffer),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::BondXstats::*;
+        match self {
+            Unspec(_) => BOND_XSTATS_UNSPEC,
+            Lacp(_) => BOND_XSTATS_3AD,
+            Pad(_) => BOND_XSTATS_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for BondXstats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::BondXstats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_XSTATS_UNSPEC => Unspec(payload.to_vec()),
+            BOND_XSTATS_3AD => {
+                let error_msg = "invalid BOND_XSTATS_3AD value";
+                let mut nlas = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    nlas.push(Bond3adStats::parse(nla).context(error_msg)?);
+                }
+                Lacp(nlas)
+            }
+            BOND_XSTATS_PAD => Pad(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
+
+/// 802.3ad counters of a bond or bond port (`BOND_3AD_STAT_*`)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Bond3adStats {
+    LacpduRx(u64),
+    LacpduTx(u64),
+    LacpduUnknownRx(u64),
+    LacpduIllegalRx(u64),
+    MarkerRx(u64),
+    MarkerTx(u64),
+    MarkerRespRx(u64),
+    MarkerRespTx(u64),
+    MarkerUnknownRx(u64),
+    Pad(Vec<u8>),
+    Other(DefaultNla),
+}
+
+impl Nla for Bond3adStats {
+    fn value_len(&self) -> usize {
+        use self::Bond3adStats::*;
+        match self {
+            Pad(bytes) => bytes.len(),
+            Other(nla) => nla.value_len(),
+            _ => 8,
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Bond3adStats::*;
+        match self {
+            LacpduRx(value)
+            | LacpduTx(value)
+            | LacpduUnknownRx(value)
+            | LacpduIllegalRx(value)
+            | MarkerRx(value)
+            | MarkerTx(value)
+            | MarkerRespRx(value)
+            | MarkerRespTx(value)
+            | MarkerUnknownRx(value) => NativeEndian::write_u64(buffer, *value),
+            Pad(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Other(nla) => nla.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Bond3adStats::*;
+        match self {
+            LacpduRx(_) => BOND_3AD_STAT_LACPDU_RX,
+            LacpduTx(_) => BOND_3AD_STAT_LACPDU_TX,
+            LacpduUnknownRx(_) => BOND_3AD_STAT_LACPDU_UNKNOWN_RX,
+            LacpduIllegalRx(_) => BOND_3AD_STAT_LACPDU_ILLEGAL_RX,
+            MarkerRx(_) => BOND_3AD_STAT_MARKER_RX,
+            MarkerTx(_) => BOND_3AD_STAT_MARKER_TX,
+            MarkerRespRx(_) => BOND_3AD_STAT_MARKER_RESP_RX,
+            MarkerRespTx(_) => BOND_3AD_STAT_MARKER_RESP_TX,
+            MarkerUnknownRx(_) => BOND_3AD_STAT_MARKER_UNKNOWN_RX,
+            Pad(_) => BOND_3AD_STAT_PAD,
+            Other(nla) => nla.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Bond3adStats {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Bond3adStats::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            BOND_3AD_STAT_LACPDU_RX => {
+                LacpduRx(parse_u64(payload).context("invalid BOND_3AD_STAT_LACPDU_RX value")?)
+            }
+            BOND_3AD_STAT_LACPDU_TX => {
+                LacpduTx(parse_u64(payload).context("invalid BOND_3AD_STAT_LACPDU_TX value")?)
+            }
+            BOND_3AD_STAT_LACPDU_UNKNOWN_RX => LacpduUnknownRx(
+                parse_u64(payload).context("invalid BOND_3AD_STAT_LACPDU_UNKNOWN_RX value")?,
+            ),
+            BOND_3AD_STAT_LACPDU_ILLEGAL_RX => LacpduIllegalRx(
+                parse_u64(payload).context("invalid BOND_3AD_STAT_LACPDU_ILLEGAL_RX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_RX => {
+                MarkerRx(parse_u64(payload).context("invalid BOND_3AD_STAT_MARKER_RX value")?)
+            }
+            BOND_3AD_STAT_MARKER_TX => {
+                MarkerTx(parse_u64(payload).context("invalid BOND_3AD_STAT_MARKER_TX value")?)
+            }
+            BOND_3AD_STAT_MARKER_RESP_RX => MarkerRespRx(
+                parse_u64(payload).context("invalid BOND_3AD_STAT_MARKER_RESP_RX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_RESP_TX => MarkerRespTx(
+                parse_u64(payload).context("invalid BOND_3AD_STAT_MARKER_RESP_TX value")?,
+            ),
+            BOND_3AD_STAT_MARKER_UNKNOWN_RX => MarkerUnknownRx(
+                parse_u64(payload).context("invalid BOND_3AD_STAT_MARKER_UNKNOWN_RX value")?,
+            ),
+            BOND_3AD_STAT_PAD => Pad(payload.to_vec()),
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,141 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    constants::*,
+    link::nlas::Stats64,
+    nlas::stats::*,
+    traits::{Emitable, Parseable},
+    StatsHeader,
+    StatsMessage,
+    StatsMessageBuffer,
+};
+
+#[rustfmt::skip]
+static BOND_XSTATS: [u8; 52] = [
+    0x00, // family
+    0x00, // pad1
+    0x00, 0x00, // pad2
+    0x05, 0x00, 0x00, 0x00, // ifindex = 5
+    0x04, 0x00, 0x00, 0x00, // filter mask = IFLA_STATS_FILTER_BIT_LINK_XSTATS_SLAVE
+
+    0x28, 0x00, 0x03, 0x00, // length = 40, type = IFLA_STATS_LINK_XSTATS_SLAVE
+        0x24, 0x00, 0x02, 0x00, // length = 36, type = LINK_XSTATS_TYPE_BOND
+            0x20, 0x00, 0x01, 0x00, // length = 32, type = BOND_XSTATS_3AD
+                0x04, 0x00, 0x09, 0x00, // length = 4, type = BOND_3AD_STAT_PAD
+                0x0c, 0x00, 0x00, 0x00, // length = 12, type = BOND_3AD_STAT_LACPDU_RX
+                    0x05, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 5
+                0x0c, 0x00, 0x01, 0x00, // length = 12, type = BOND_3AD_STAT_LACPDU_TX
+                    0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // 7
+];
+
+#[test]
+fn parse_bond_xstats() {
+    let expected = StatsMessage {
+        header: StatsHeader {
+            family: 0,
+            ifindex: 5,
+            filter_mask: IFLA_STATS_FILTER_BIT_LINK_XSTATS_SLAVE,
+        },
+        nlas: vec![Nla::LinkXstatsSlave(vec![LinkXstats::Bond(vec![
+            BondXstats::Lacp(vec![
+                Bond3adStats::Pad(vec![]),
+                Bond3adStats::LacpduRx(5),
+                Bond3adStats::LacpduTx(7),
+            ]),
+        ])])],
+    };
+    let actual = StatsMessage::parse(&StatsMessageBuffer::new(&BOND_XSTATS)).unwrap();
+    assert_eq!(actual, expected);
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(&buf[..], &BOND_XSTATS[..]);
+}
+
+#[test]
+fn emit_parse_stats() {
+    let link64 = Stats64 {
+        rx_packets: 1,
+        tx_packets: 2,
+        rx_bytes: 3,
+        tx_bytes: 4,
+        rx_errors: 0,
+        tx_errors: 0,
+        rx_dropped: 5,
+        tx_dropped: 0,
+        multicast: 6,
+        collisions: 0,
+        rx_length_errors: 0,
+        rx_over_errors: 0,
+        rx_crc_errors: 0,
+        rx_frame_errors: 0,
+        rx_fifo_errors: 0,
+        rx_missed_errors: 0,
+        tx_aborted_errors: 0,
+        tx_carrier_errors: 0,
+        tx_fifo_errors: 0,
+        tx_heartbeat_errors: 0,
+        tx_window_errors: 0,
+        rx_compressed: 0,
+        tx_compressed: 0,
+        rx_nohandler: 7,
+    };
+    let msg = StatsMessage {
+        header: StatsHeader {
+            family: 0,
+            ifindex: 3,
+            filter_mask: IFLA_STATS_FILTER_BIT_LINK_64
+                | IFLA_STATS_FILTER_BIT_LINK_XSTATS
+                | IFLA_STATS_FILTER_BIT_LINK_OFFLOAD_XSTATS
+                | IFLA_STATS_FILTER_BIT_AF_SPEC,
+        },
+        nlas: vec![
+            Nla::Unspec(vec![]),
+            Nla::Link64(link64),
+            Nla::LinkXstats(vec![LinkXstats::Bridge(vec![
+                BridgeXstats::Vlan(BridgeVlanXstats {
+                    rx_bytes: 100,
+                    rx_packets: 1,
+                    tx_bytes: 200,
+                    tx_packets: 2,
+                    vid: 10,
+                    flags: 0,
+                }),
+                BridgeXstats::Mcast(BridgeMcastStats {
+                    igmp_v2reports_rx: 3,
+                    mld_v2reports_tx: 4,
+                    mcast_packets_rx: 7,
+                    ..Default::default()
+                }),
+                BridgeXstats::Stp(BridgeStpXstats {
+                    transition_fwd: 1,
+                    tx_bpdu: 12,
+                    ..Default::default()
+                }),
+            ])]),
+            Nla::LinkOffloadXstats(vec![
+                OffloadXstats::CpuHit(link64),
+                OffloadXstats::HwStatsInfo(vec![HwStatsInfo::L3Stats(vec![
+                    HwStatsUsage::Request(true),
+                    HwStatsUsage::Used(false),
+                ])]),
+                OffloadXstats::L3Stats(HwStats64 {
+                    rx_packets: 10,
+                    tx_bytes: 1500,
+                    ..Default::default()
+                }),
+            ]),
+            Nla::AfSpec(vec![AfSpecStats::Mpls(vec![MplsStats::Link(
+                MplsLinkStats {
+                    rx_packets: 9,
+                    rx_noroute: 1,
+                    ..Default::default()
+                },
+            )])]),
+        ],
+    };
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    let parsed = StatsMessage::parse(&StatsMessageBuffer::new(&buf)).unwrap();
+    assert_eq!(parsed, msg);
+}
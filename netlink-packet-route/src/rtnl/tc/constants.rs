@@ -81,6 +81,19 @@ pub const TC_ACT_JUMP: i32 = 0x10000000;
 pub const TCA_ACT_TAB: u16 = 1; // TCA_ROOT_TAB
 pub const TCAA_MAX: u16 = 1;
 
+/// Attributes of `RTM_*ACTION` messages
+pub const TCA_ROOT_UNSPEC: u16 = 0;
+pub const TCA_ROOT_TAB: u16 = 1;
+pub const TCA_ROOT_FLAGS: u16 = 2;
+pub const TCA_ROOT_COUNT: u16 = 3;
+pub const TCA_ROOT_TIME_DELTA: u16 = 4;
+pub const TCA_ROOT_EXT_WARN_MSG: u16 = 5;
+
+/// `TCA_ROOT_FLAGS` bits
+pub const TCA_FLAG_LARGE_DUMP_ON: u32 = 1;
+pub const TCA_ACT_FLAG_LARGE_DUMP_ON: u32 = TCA_FLAG_LARGE_DUMP_ON;
+pub const TCA_ACT_FLAG_TERSE_DUMP: u32 = 2;
+
 /// Mirred action attr
 pub const TCA_MIRRED_UNSPEC: u16 = 0;
 pub const TCA_MIRRED_TM: u16 = 1;
@@ -0,0 +1,127 @@
+// SPDX-License-Identifier: MIT
+
+use anyhow::Context;
+use byteorder::{ByteOrder, NativeEndian};
+
+use crate::{
+    nlas::{self, DefaultNla, NlaBuffer, NlasIterator},
+    parsers::{parse_string, parse_u32},
+    tc::{constants::*, Action},
+    traits::{Emitable, Parseable},
+    DecodeError,
+};
+
+/// `struct nla_bitfield32` carried by `TCA_ROOT_FLAGS`: only the bits set in
+/// `selector` are taken into account by the kernel.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct RootFlags {
+    /// `TCA_ACT_FLAG_*` bits
+    pub value: u32,
+    pub selector: u32,
+}
+
+impl RootFlags {
+    pub fn new(flags: u32) -> Self {
+        RootFlags {
+            value: flags,
+            selector: flags,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Nla {
+    Unspec(Vec<u8>),
+    /// The actions. The `tab` of each action is its position in the list:
+    /// requests number them from 1, while dumps from the kernel start at 0.
+    Actions(Vec<Action>),
+    /// Dump flags, only used in requests
+    Flags(RootFlags),
+    /// Number of actions in a dump reply
+    Count(u32),
+    /// Only dump the actions used in the last given number of milliseconds
+    TimeDelta(u32),
+    ExtWarnMsg(String),
+    Other(DefaultNla),
+}
+
+impl nlas::Nla for Nla {
+    fn value_len(&self) -> usize {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => bytes.len(),
+            Actions(actions) => actions.as_slice().buffer_len(),
+            Flags(_) => 8,
+            Count(_) | TimeDelta(_) => 4,
+            ExtWarnMsg(string) => string.len() + 1,
+            Other(attr) => attr.value_len(),
+        }
+    }
+
+    fn emit_value(&self, buffer: &mut [u8]) {
+        use self::Nla::*;
+        match self {
+            Unspec(bytes) => buffer.copy_from_slice(bytes.as_slice()),
+            Actions(actions) => actions.as_slice().emit(buffer),
+            Flags(flags) => {
+                NativeEndian::write_u32(&mut buffer[0..4], flags.value);
+                NativeEndian::write_u32(&mut buffer[4..8], flags.selector);
+            }
+            Count(value) | TimeDelta(value) => NativeEndian::write_u32(buffer, *value),
+            ExtWarnMsg(string) => {
+                buffer[..string.len()].copy_from_slice(string.as_bytes());
+                buffer[string.len()] = 0;
+            }
+            Other(attr) => attr.emit_value(buffer),
+        }
+    }
+
+    fn kind(&self) -> u16 {
+        use self::Nla::*;
+        match self {
+            Unspec(_) => TCA_ROOT_UNSPEC,
+            Actions(_) => TCA_ROOT_TAB,
+            Flags(_) => TCA_ROOT_FLAGS,
+            Count(_) => TCA_ROOT_COUNT,
+            TimeDelta(_) => TCA_ROOT_TIME_DELTA,
+            ExtWarnMsg(_) => TCA_ROOT_EXT_WARN_MSG,
+            Other(attr) => attr.kind(),
+        }
+    }
+}
+
+impl<'a, T: AsRef<[u8]> + ?Sized> Parseable<NlaBuffer<&'a T>> for Nla {
+    fn parse(buf: &NlaBuffer<&'a T>) -> Result<Self, DecodeError> {
+        use self::Nla::*;
+        let payload = buf.value();
+        Ok(match buf.kind() {
+            TCA_ROOT_UNSPEC => Unspec(payload.to_vec()),
+            TCA_ROOT_TAB => {
+                let error_msg = "invalid TCA_ROOT_TAB value";
+                let mut actions = vec![];
+                for nla in NlasIterator::new(payload) {
+                    let nla = &nla.context(error_msg)?;
+                    actions.push(Action::parse(nla).context(error_msg)?);
+                }
+                Actions(actions)
+            }
+            TCA_ROOT_FLAGS => {
+                if payload.len() != 8 {
+                    return Err(format!("invalid TCA_ROOT_FLAGS value: {:?}", payload).into());
+                }
+                Flags(RootFlags {
+                    value: NativeEndian::read_u32(&payload[0..4]),
+                    selector: NativeEndian::read_u32(&payload[4..8]),
+                })
+            }
+            TCA_ROOT_COUNT => Count(parse_u32(payload).context("invalid TCA_ROOT_COUNT value")?),
+            TCA_ROOT_TIME_DELTA => {
+                TimeDelta(parse_u32(payload).context("invalid TCA_ROOT_TIME_DELTA value")?)
+            }
+            TCA_ROOT_EXT_WARN_MSG => {
+                ExtWarnMsg(parse_string(payload).context("invalid TCA_ROOT_EXT_WARN_MSG value")?)
+            }
+            kind => Other(DefaultNla::parse(buf).context(format!("unknown NLA type {}", kind))?),
+        })
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    nlas::tc_action::*,
+    tc::{self, constants::*},
+    traits::{Emitable, Parseable},
+    TcActionHeader,
+    TcActionMessage,
+    TcActionMessageBuffer,
+};
+
+// tc -brief actions ls action mirred
+#[rustfmt::skip]
+static TERSE_DUMP: [u8; 40] = [
+    0x00, // family = AF_UNSPEC
+    0x00, // pad1
+    0x00, 0x00, // pad2
+
+    0x08, 0x00, 0x03, 0x00, // length = 8, type = TCA_ROOT_COUNT
+        0x01, 0x00, 0x00, 0x00, // 1
+
+    0x1c, 0x00, 0x01, 0x00, // length = 28, type = TCA_ROOT_TAB
+        0x18, 0x00, 0x00, 0x00, // length = 24, order = 0
+            0x0b, 0x00, 0x01, 0x00, // length = 11, type = TCA_ACT_KIND
+                0x6d, 0x69, 0x72, 0x72, 0x65, 0x64, 0x00, // "mirred"
+                0x00, // padding
+            0x08, 0x00, 0x03, 0x00, // length = 8, type = TCA_ACT_INDEX
+                0x07, 0x00, 0x00, 0x00, // 7
+];
+
+#[test]
+fn parse_terse_dump() {
+    let expected = TcActionMessage {
+        header: TcActionHeader { family: 0 },
+        nlas: vec![
+            Nla::Count(1),
+            Nla::Actions(vec![tc::Action {
+                tab: 0,
+                nlas: vec![
+                    tc::ActNla::Kind(tc::mirred::KIND.to_string()),
+                    tc::ActNla::Index(7),
+                ],
+            }]),
+        ],
+    };
+    let actual = TcActionMessage::parse(&TcActionMessageBuffer::new(&TERSE_DUMP)).unwrap();
+    assert_eq!(actual, expected);
+
+    let mut buf = vec![0; expected.buffer_len()];
+    expected.emit(&mut buf);
+    assert_eq!(&buf[..], &TERSE_DUMP[..]);
+}
+
+#[test]
+fn emit_parse_dump_request() {
+    let msg = TcActionMessage {
+        header: TcActionHeader::default(),
+        nlas: vec![
+            Nla::Actions(vec![tc::Action {
+                tab: 1,
+                nlas: vec![tc::ActNla::Kind(tc::mirred::KIND.to_string())],
+            }]),
+            Nla::Flags(RootFlags::new(
+                TCA_ACT_FLAG_LARGE_DUMP_ON | TCA_ACT_FLAG_TERSE_DUMP,
+            )),
+            Nla::TimeDelta(1000),
+        ],
+    };
+
+    let mut buf = vec![0; msg.buffer_len()];
+    msg.emit(&mut buf);
+    let parsed = TcActionMessage::parse(&TcActionMessageBuffer::new(&buf)).unwrap();
+    assert_eq!(parsed, msg);
+}
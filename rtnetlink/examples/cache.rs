@@ -0,0 +1,41 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use rtnetlink::{new_connection, CacheEvent, CacheObject};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let (connection, handle, _) = new_connection().map_err(|e| format!("{}", e))?;
+    tokio::spawn(connection);
+
+    let cache = handle
+        .cache()
+        .all()
+        .execute()
+        .await
+        .map_err(|e| format!("{}", e))?;
+
+    match cache.link_by_name("lo") {
+        Some(lo) => println!(
+            "lo has {} address(es)",
+            cache.addresses_by_index(lo.header.index).len()
+        ),
+        None => println!("no loopback interface"),
+    }
+    println!("{} route(s) in the cache", cache.routes().len());
+
+    let mut changes = cache.subscribe();
+    while let Some(change) = changes.next().await {
+        match change {
+            CacheEvent::Added(CacheObject::Link(link)) => {
+                println!("new link {}", link.header.index)
+            }
+            CacheEvent::Removed(CacheObject::Link(link)) => {
+                println!("link {} removed", link.header.index)
+            }
+            CacheEvent::Resynced => println!("cache reloaded"),
+            change => println!("{:?}", change),
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use rtnetlink::{new_connection, MonitorEvent};
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+    let (connection, handle, _) = new_connection().map_err(|e| format!("{}", e))?;
+    tokio::spawn(connection);
+
+    let mut events = handle
+        .monitor()
+        .link()
+        .address()
+        .route()
+        .execute()
+        .map_err(|e| format!("{}", e))?;
+
+    while let Some(event) = events.next().await {
+        match event {
+            MonitorEvent::LinkAdded(link) => println!("new link {}", link.header.index),
+            MonitorEvent::LinkChanged(link) => println!("link {} changed", link.header.index),
+            MonitorEvent::LinkRemoved(link) => println!("link {} removed", link.header.index),
+            event => println!("{:?}", event),
+        }
+    }
+    Ok(())
+}
@@ -4,12 +4,19 @@ use futures::stream::StreamExt;
 use std::net::{IpAddr, Ipv4Addr};
 
 use netlink_packet_route::{
-    nlas::address::Nla,
+    nlas::address::{CacheInfo, Nla},
     AddressMessage,
     NetlinkMessage,
     RtnlMessage,
     AF_INET,
     AF_INET6,
+    IFA_F_HOMEADDRESS,
+    IFA_F_MANAGETEMPADDR,
+    IFA_F_MCAUTOJOIN,
+    IFA_F_NODAD,
+    IFA_F_NOPREFIXROUTE,
+    IFA_F_OPTIMISTIC,
+    INFINITY_LIFE_TIME,
     NLM_F_ACK,
     NLM_F_CREATE,
     NLM_F_EXCL,
@@ -23,7 +30,10 @@ use crate::{try_nl, Error, Handle};
 pub struct AddressAddRequest {
     handle: Handle,
     message: AddressMessage,
-    replace: bool,
+    // NLM_F_EXCL | NLM_F_CREATE, NLM_F_REPLACE | NLM_F_CREATE or NLM_F_REPLACE (change)
+    flags: u16,
+    // Whether the preferred lifetime was given, it follows the valid lifetime otherwise
+    preferred_lifetime_set: bool,
 }
 
 impl AddressAddRequest {
@@ -73,28 +83,201 @@ impl AddressAddRequest {
         AddressAddRequest {
             handle,
             message,
-            replace: false,
+            flags: NLM_F_EXCL | NLM_F_CREATE,
+            preferred_lifetime_set: false,
         }
     }
 
     /// Replace existing matching address.
     pub fn replace(self) -> Self {
         Self {
-            replace: true,
+            flags: NLM_F_REPLACE | NLM_F_CREATE,
             ..self
         }
     }
 
+    /// Update an existing address, for instance to renew its lifetimes. This is equivalent
+    /// to `ip address change`: like it, the kernel still adds the address if it is missing.
+    pub fn change(self) -> Self {
+        Self {
+            flags: NLM_F_REPLACE,
+            ..self
+        }
+    }
+
+    // Return the lifetimes of the address, which never expire by default
+    fn cache_info_mut(&mut self) -> &mut CacheInfo {
+        let index = match self
+            .message
+            .nlas
+            .iter()
+            .position(|nla| matches!(nla, Nla::CacheInfo(_)))
+        {
+            Some(index) => index,
+            None => {
+                self.message.nlas.push(Nla::CacheInfo(CacheInfo {
+                    ifa_preferred: INFINITY_LIFE_TIME,
+                    ifa_valid: INFINITY_LIFE_TIME,
+                    cstamp: 0,
+                    tstamp: 0,
+                }));
+                self.message.nlas.len() - 1
+            }
+        };
+        match self.message.nlas[index] {
+            Nla::CacheInfo(ref mut cache_info) => cache_info,
+            _ => unreachable!(),
+        }
+    }
+
+    /// Sets the valid lifetime of the address in seconds, after which it is removed. Unless
+    /// it is set, the preferred lifetime is the same, like with `ip address add`.
+    pub fn valid_lifetime(mut self, seconds: u32) -> Self {
+        let preferred_lifetime_set = self.preferred_lifetime_set;
+        let cache_info = self.cache_info_mut();
+        cache_info.ifa_valid = seconds;
+        if !preferred_lifetime_set {
+            cache_info.ifa_preferred = seconds;
+        }
+        self
+    }
+
+    /// Sets the preferred lifetime of the address in seconds, after which it is deprecated.
+    /// It can't be longer than the valid lifetime, otherwise executing the request fails with
+    /// [`Error::InvalidArgument`].
+    pub fn preferred_lifetime(mut self, seconds: u32) -> Self {
+        self.cache_info_mut().ifa_preferred = seconds;
+        self.preferred_lifetime_set = true;
+        self
+    }
+
+    // Set an IFA_F_* flag, in the header when it fits and in IFA_FLAGS
+    fn set_flag(mut self, flag: u32) -> Self {
+        if flag < 256 {
+            self.message.header.flags |= flag as u8;
+        }
+        match self.message.nlas.iter_mut().find_map(|nla| match nla {
+            Nla::Flags(flags) => Some(flags),
+            _ => None,
+        }) {
+            Some(flags) => *flags |= flag,
+            None => self.message.nlas.push(Nla::Flags(flag)),
+        }
+        self
+    }
+
+    /// Skip duplicate address detection for an IPv6 address.
+    pub fn nodad(self) -> Self {
+        self.set_flag(IFA_F_NODAD)
+    }
+
+    /// Don't add a prefix route for the network of the address.
+    pub fn noprefixroute(self) -> Self {
+        self.set_flag(IFA_F_NOPREFIXROUTE)
+    }
+
+    /// Let the kernel create and manage temporary addresses from this IPv6 address.
+    pub fn manage_temp_addr(self) -> Self {
+        self.set_flag(IFA_F_MANAGETEMPADDR)
+    }
+
+    /// Join or leave the multicast group of a multicast address.
+    pub fn mcautojoin(self) -> Self {
+        self.set_flag(IFA_F_MCAUTOJOIN)
+    }
+
+    /// Use the IPv6 address before duplicate address detection completes.
+    pub fn optimistic(self) -> Self {
+        self.set_flag(IFA_F_OPTIMISTIC)
+    }
+
+    /// Mark the IPv6 address as the home address of the host, for Mobile IPv6.
+    pub fn home_address(self) -> Self {
+        self.set_flag(IFA_F_HOMEADDRESS)
+    }
+
+    /// Sets the address of the remote end of a point-to-point link. The prefix length then
+    /// applies to the peer address.
+    pub fn peer(mut self, peer: IpAddr) -> Self {
+        let peer = match peer {
+            IpAddr::V4(addr) => addr.octets().to_vec(),
+            IpAddr::V6(addr) => addr.octets().to_vec(),
+        };
+        let has_local = self
+            .message
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, Nla::Local(_)));
+        let mut nlas = Vec::with_capacity(self.message.nlas.len() + 1);
+        for nla in self.message.nlas.drain(..) {
+            match nla {
+                // the local address moves to IFA_LOCAL and IFA_ADDRESS is the peer
+                Nla::Address(local) => {
+                    if !has_local {
+                        nlas.push(Nla::Local(local));
+                    }
+                    nlas.push(Nla::Address(peer.clone()));
+                }
+                // the broadcast address derived from the prefix length makes no sense here
+                Nla::Broadcast(_) => {}
+                nla => nlas.push(nla),
+            }
+        }
+        self.message.nlas = nlas;
+        self
+    }
+
+    /// Sets the broadcast address of an IPv4 address.
+    pub fn broadcast(mut self, broadcast: Ipv4Addr) -> Self {
+        self.message
+            .nlas
+            .retain(|nla| !matches!(nla, Nla::Broadcast(_)));
+        self.message
+            .nlas
+            .push(Nla::Broadcast(broadcast.octets().to_vec()));
+        self
+    }
+
+    /// Sets the label of an IPv4 address. The label must start with the name of the
+    /// interface, like `eth0:1`.
+    pub fn label(mut self, label: String) -> Self {
+        self.message.nlas.push(Nla::Label(label));
+        self
+    }
+
+    /// Sets the metric of the prefix route added for the address.
+    pub fn priority(mut self, metric: u32) -> Self {
+        self.message.nlas.push(Nla::RtPriority(metric));
+        self
+    }
+
+    /// Sets the protocol which added the address (`IFAPROT_*`).
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::Protocol(protocol));
+        self
+    }
+
     /// Execute the request.
     pub async fn execute(self) -> Result<(), Error> {
         let AddressAddRequest {
             mut handle,
             message,
-            replace,
+            flags,
+            ..
         } = self;
+        if let Some(cache_info) = message.nlas.iter().find_map(|nla| match nla {
+            Nla::CacheInfo(cache_info) => Some(cache_info),
+            _ => None,
+        }) {
+            if cache_info.ifa_preferred > cache_info.ifa_valid {
+                return Err(Error::InvalidArgument(format!(
+                    "preferred lifetime {} is longer than the valid lifetime {}",
+                    cache_info.ifa_preferred, cache_info.ifa_valid
+                )));
+            }
+        }
         let mut req = NetlinkMessage::from(RtnlMessage::NewAddress(message));
-        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | flags;
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -108,3 +291,69 @@ impl AddressAddRequest {
         &mut self.message
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::net::{IpAddr, Ipv4Addr};
+    use tokio::runtime::Runtime;
+
+    use netlink_packet_route::nlas::address::Nla;
+
+    use super::AddressAddRequest;
+    use crate::{new_connection, Error};
+
+    const ADDRESS: IpAddr = IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1));
+
+    // Return the preferred and valid lifetimes of the request
+    fn lifetimes(request: &mut AddressAddRequest) -> (u32, u32) {
+        request
+            .message_mut()
+            .nlas
+            .iter()
+            .find_map(|nla| match nla {
+                Nla::CacheInfo(cache_info) => {
+                    Some((cache_info.ifa_preferred, cache_info.ifa_valid))
+                }
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    async fn test_async_lifetimes() {
+        let (connection, handle, _) = new_connection().unwrap();
+        tokio::spawn(connection);
+
+        // The preferred lifetime follows the valid lifetime unless it is set
+        let mut request = handle.address().add(1, ADDRESS, 24).valid_lifetime(300);
+        assert_eq!(lifetimes(&mut request), (300, 300));
+
+        let mut request = handle
+            .address()
+            .add(1, ADDRESS, 24)
+            .preferred_lifetime(100)
+            .valid_lifetime(300);
+        assert_eq!(lifetimes(&mut request), (100, 300));
+
+        let mut request = handle
+            .address()
+            .add(1, ADDRESS, 24)
+            .valid_lifetime(300)
+            .preferred_lifetime(100);
+        assert_eq!(lifetimes(&mut request), (100, 300));
+
+        // Rejected before reaching the kernel
+        let result = handle
+            .address()
+            .add(1, ADDRESS, 24)
+            .valid_lifetime(300)
+            .preferred_lifetime(600)
+            .execute()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidArgument(_))));
+    }
+
+    #[test]
+    fn test_lifetimes() {
+        Runtime::new().unwrap().block_on(test_async_lifetimes());
+    }
+}
@@ -0,0 +1,299 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{self, Stream, StreamExt, TryStream, TryStreamExt},
+    FutureExt,
+};
+use nix::errno::Errno;
+use std::{
+    convert::TryFrom,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use netlink_packet_route::{
+    nlas::address::{CacheInfo, Nla},
+    AddressMessage,
+    NetlinkMessage,
+    NetlinkPayload,
+    RtnlMessage,
+    AF_INET,
+    AF_INET6,
+    NLM_F_DUMP,
+    NLM_F_REQUEST,
+};
+
+use crate::{Error, Handle};
+
+/// A multicast group joined by an interface, or an IPv6 anycast address of an
+/// interface.
+///
+/// The kernel does not report how many users joined a group: an entry is
+/// only reported once per interface.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct AddressGroup {
+    /// Index of the interface
+    pub index: u32,
+    pub address: IpAddr,
+    /// `cstamp` and `tstamp` are the times the group was joined and last
+    /// updated, in hundredths of seconds since boot. The lifetimes are
+    /// always infinite.
+    pub cache_info: Option<CacheInfo>,
+}
+
+impl TryFrom<AddressMessage> for AddressGroup {
+    type Error = Error;
+
+    fn try_from(message: AddressMessage) -> Result<Self, Self::Error> {
+        let mut address = None;
+        let mut cache_info = None;
+        for nla in message.nlas {
+            match nla {
+                Nla::Multicast(bytes) | Nla::Anycast(bytes) => {
+                    address = Some(match bytes.len() {
+                        4 => IpAddr::V4(Ipv4Addr::new(bytes[0], bytes[1], bytes[2], bytes[3])),
+                        16 => {
+                            let mut octets = [0; 16];
+                            octets.copy_from_slice(&bytes);
+                            IpAddr::V6(Ipv6Addr::from(octets))
+                        }
+                        _ => return Err(Error::InvalidIp(bytes)),
+                    });
+                }
+                Nla::CacheInfo(info) => cache_info = Some(info),
+                _ => {}
+            }
+        }
+        Ok(AddressGroup {
+            index: message.header.index,
+            address: address.ok_or_else(|| Error::InvalidIp(vec![]))?,
+            cache_info,
+        })
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum GroupKind {
+    Multicast,
+    Anycast,
+}
+
+pub struct AddressGroupGetRequest {
+    handle: Handle,
+    kind: GroupKind,
+    families: Vec<u8>,
+    // Whether to skip the IPv4 groups on kernels that cannot dump them
+    ipv4_optional: bool,
+    index: Option<u32>,
+}
+
+impl AddressGroupGetRequest {
+    pub(crate) fn multicast(handle: Handle) -> Self {
+        AddressGroupGetRequest {
+            handle,
+            kind: GroupKind::Multicast,
+            families: vec![AF_INET as u8, AF_INET6 as u8],
+            ipv4_optional: true,
+            index: None,
+        }
+    }
+
+    pub(crate) fn anycast(handle: Handle) -> Self {
+        AddressGroupGetRequest {
+            handle,
+            kind: GroupKind::Anycast,
+            families: vec![AF_INET6 as u8],
+            ipv4_optional: false,
+            index: None,
+        }
+    }
+
+    /// Only dump the IPv4 multicast groups. Dumping them requires Linux 6.13
+    /// or later: the request fails on older kernels. Without this, the IPv4
+    /// groups are silently skipped on these kernels.
+    pub fn ipv4(mut self) -> Self {
+        self.families = vec![AF_INET as u8];
+        self.ipv4_optional = false;
+        self
+    }
+
+    /// Only dump the IPv6 groups
+    pub fn ipv6(mut self) -> Self {
+        self.families = vec![AF_INET6 as u8];
+        self.ipv4_optional = false;
+        self
+    }
+
+    /// Return only the groups of the given interface.
+    pub fn set_link_index_filter(mut self, index: u32) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Execute the request. One dump is issued per address family.
+    pub fn execute(self) -> impl TryStream<Ok = AddressGroup, Error = Error> {
+        let AddressGroupGetRequest {
+            mut handle,
+            kind,
+            families,
+            ipv4_optional,
+            index,
+        } = self;
+
+        let mut responses = vec![];
+        for family in families {
+            let mut message = AddressMessage::default();
+            message.header.family = family;
+            let mut req = NetlinkMessage::from(match kind {
+                GroupKind::Multicast => RtnlMessage::GetMulticastAddress(message),
+                GroupKind::Anycast => RtnlMessage::GetAnycastAddress(message),
+            });
+            req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+            match handle.request(req) {
+                Ok(response) => responses.push((family, response)),
+                Err(e) => {
+                    return Either::Right(future::err::<AddressGroup, Error>(e).into_stream())
+                }
+            }
+        }
+
+        Either::Left(address_groups(responses, kind, ipv4_optional, index))
+    }
+}
+
+/// Chain the dumps of each address family. If `ipv4_optional` is set, the
+/// error returned by kernels that cannot dump the IPv4 groups is dropped,
+/// and the other dumps go on.
+fn address_groups<S>(
+    responses: Vec<(u8, S)>,
+    kind: GroupKind,
+    ipv4_optional: bool,
+    index: Option<u32>,
+) -> impl Stream<Item = Result<AddressGroup, Error>>
+where
+    S: Stream<Item = NetlinkMessage<RtnlMessage>>,
+{
+    stream::iter(responses)
+        .map(move |(family, response)| {
+            response
+                .map(move |msg| group_message(msg, kind))
+                .filter(move |result| {
+                    let unsupported = match result {
+                        Err(Error::NetlinkError(err)) => {
+                            err.code == -(Errno::EOPNOTSUPP as i32)
+                                || err.code == -(Errno::EINVAL as i32)
+                        }
+                        _ => false,
+                    };
+                    future::ready(!(ipv4_optional && family == AF_INET as u8 && unsupported))
+                })
+        })
+        .flatten()
+        .and_then(|msg| future::ready(AddressGroup::try_from(msg)))
+        .try_filter(move |group| {
+            future::ready(index.map(|index| group.index == index).unwrap_or(true))
+        })
+}
+
+// The kernel answers the dumps with RTM_GETMULTICAST and RTM_GETANYCAST
+// messages
+fn group_message(
+    msg: NetlinkMessage<RtnlMessage>,
+    kind: GroupKind,
+) -> Result<AddressMessage, Error> {
+    let (header, payload) = msg.into_parts();
+    match (payload, kind) {
+        (
+            NetlinkPayload::InnerMessage(RtnlMessage::GetMulticastAddress(msg)),
+            GroupKind::Multicast,
+        )
+        | (NetlinkPayload::InnerMessage(RtnlMessage::GetAnycastAddress(msg)), GroupKind::Anycast) => {
+            Ok(msg)
+        }
+        (NetlinkPayload::Error(err), _) => Err(Error::NetlinkError(err)),
+        (payload, _) => Err(Error::UnexpectedMessage(NetlinkMessage::new(
+            header, payload,
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use futures::{executor::block_on, stream, Stream, TryStreamExt};
+    use std::net::{IpAddr, Ipv6Addr};
+
+    use netlink_packet_route::{
+        nlas::address::Nla,
+        AddressMessage,
+        ErrorMessage,
+        NetlinkMessage,
+        NetlinkPayload,
+        RtnlMessage,
+        AF_INET,
+        AF_INET6,
+    };
+
+    use super::{address_groups, AddressGroup, GroupKind};
+    use crate::Error;
+
+    const ALL_NODES: Ipv6Addr = Ipv6Addr::new(0xff02, 0, 0, 0, 0, 0, 0, 1);
+
+    fn error(code: i32) -> NetlinkMessage<RtnlMessage> {
+        NetlinkMessage::new(
+            Default::default(),
+            NetlinkPayload::Error(ErrorMessage {
+                code,
+                header: vec![],
+            }),
+        )
+    }
+
+    fn group(index: u32, address: Ipv6Addr) -> NetlinkMessage<RtnlMessage> {
+        let mut message = AddressMessage::default();
+        message.header.family = AF_INET6 as u8;
+        message.header.index = index;
+        message.nlas.push(Nla::Multicast(address.octets().to_vec()));
+        NetlinkMessage::from(RtnlMessage::GetMulticastAddress(message))
+    }
+
+    // What kernels older than 6.13 answer to the dumps of both families
+    fn responses(ipv4_error: i32) -> Vec<(u8, impl Stream<Item = NetlinkMessage<RtnlMessage>>)> {
+        vec![
+            (AF_INET as u8, stream::iter(vec![error(ipv4_error)])),
+            (
+                AF_INET6 as u8,
+                stream::iter(vec![group(1, ALL_NODES), group(2, ALL_NODES)]),
+            ),
+        ]
+    }
+
+    #[test]
+    fn test_ipv4_multicast_unsupported() {
+        for code in [-95, -22] {
+            let groups: Vec<AddressGroup> = block_on(
+                address_groups(responses(code), GroupKind::Multicast, true, None).try_collect(),
+            )
+            .unwrap();
+            assert_eq!(
+                groups
+                    .iter()
+                    .map(|g| (g.index, g.address))
+                    .collect::<Vec<_>>(),
+                vec![(1, IpAddr::V6(ALL_NODES)), (2, IpAddr::V6(ALL_NODES))]
+            );
+        }
+    }
+
+    #[test]
+    fn test_ipv4_multicast_required() {
+        // Explicitly requested IPv4 groups, or any other error, still fail
+        let result: Result<Vec<AddressGroup>, Error> = block_on(
+            address_groups(responses(-95), GroupKind::Multicast, false, None).try_collect(),
+        );
+        assert!(matches!(result, Err(Error::NetlinkError(err)) if err.code == -95));
+        let result: Result<Vec<AddressGroup>, Error> =
+            block_on(address_groups(responses(-1), GroupKind::Multicast, true, None).try_collect());
+        assert!(matches!(result, Err(Error::NetlinkError(err)) if err.code == -1));
+    }
+}
@@ -2,7 +2,7 @@
 
 use std::net::IpAddr;
 
-use super::{AddressAddRequest, AddressDelRequest, AddressGetRequest};
+use super::{AddressAddRequest, AddressDelRequest, AddressGetRequest, AddressGroupGetRequest};
 use crate::Handle;
 
 use netlink_packet_route::AddressMessage;
@@ -19,6 +19,17 @@ impl AddressHandle {
         AddressGetRequest::new(self.0.clone())
     }
 
+    /// Retrieve the IPv4 and IPv6 multicast groups joined by the interfaces (equivalent to
+    /// `ip maddr show`)
+    pub fn multicast(&self) -> AddressGroupGetRequest {
+        AddressGroupGetRequest::multicast(self.0.clone())
+    }
+
+    /// Retrieve the IPv6 anycast addresses of the interfaces
+    pub fn anycast(&self) -> AddressGroupGetRequest {
+        AddressGroupGetRequest::anycast(self.0.clone())
+    }
+
     /// Add an ip address on an interface (equivalent to `ip addr add`)
     pub fn add(&self, index: u32, address: IpAddr, prefix_len: u8) -> AddressAddRequest {
         AddressAddRequest::new(self.0.clone(), index, address, prefix_len)
@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::Ipv6Addr;
+
+use netlink_packet_route::{
+    nlas::addr_label::Nla,
+    AddrLabelMessage,
+    NetlinkMessage,
+    RtnlMessage,
+    AF_INET6,
+    NLM_F_ACK,
+    NLM_F_CREATE,
+    NLM_F_EXCL,
+    NLM_F_REPLACE,
+    NLM_F_REQUEST,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to create a new address label. This is equivalent to the `ip addrlabel add`
+/// commands.
+pub struct AddrLabelAddRequest {
+    handle: Handle,
+    message: AddrLabelMessage,
+    replace: bool,
+}
+
+impl AddrLabelAddRequest {
+    pub(crate) fn new(handle: Handle, prefix: Ipv6Addr, prefix_len: u8, label: u32) -> Self {
+        let mut message = AddrLabelMessage::default();
+        message.header.family = AF_INET6 as u8;
+        message.header.prefix_len = prefix_len;
+        message.nlas.push(Nla::Address(prefix));
+        message.nlas.push(Nla::Label(label));
+
+        AddrLabelAddRequest {
+            handle,
+            message,
+            replace: false,
+        }
+    }
+
+    /// Only apply the label to the addresses of the interface with the given index
+    pub fn index(mut self, index: u32) -> Self {
+        self.message.header.index = index;
+        self
+    }
+
+    /// Replace the label of the prefix if it already exists.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let AddrLabelAddRequest {
+            mut handle,
+            message,
+            replace,
+        } = self;
+        let mut req = NetlinkMessage::from(RtnlMessage::NewAddrLabel(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut AddrLabelMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::Ipv6Addr;
+
+use netlink_packet_route::{
+    nlas::addr_label::Nla,
+    AddrLabelMessage,
+    NetlinkMessage,
+    RtnlMessage,
+    AF_INET6,
+    NLM_F_ACK,
+    NLM_F_REQUEST,
+};
+
+use crate::{try_nl, Error, Handle};
+
+pub struct AddrLabelDelRequest {
+    handle: Handle,
+    message: AddrLabelMessage,
+}
+
+impl AddrLabelDelRequest {
+    pub(crate) fn new(handle: Handle, prefix: Ipv6Addr, prefix_len: u8) -> Self {
+        let mut message = AddrLabelMessage::default();
+        message.header.family = AF_INET6 as u8;
+        message.header.prefix_len = prefix_len;
+        message.nlas.push(Nla::Address(prefix));
+        // The kernel requires a label, but does not use it to find the entry
+        message.nlas.push(Nla::Label(0));
+        AddrLabelDelRequest { handle, message }
+    }
+
+    /// Remove the label restricted to the interface with the given index
+    pub fn index(mut self, index: u32) -> Self {
+        self.message.header.index = index;
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let AddrLabelDelRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelAddrLabel(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(msg) = response.next().await {
+            try_nl!(msg);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut AddrLabelMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::{StreamExt, TryStreamExt};
+
+use netlink_packet_route::{
+    AddrLabelMessage,
+    NetlinkMessage,
+    RtnlMessage,
+    NLM_F_ACK,
+    NLM_F_REQUEST,
+};
+
+use crate::{try_nl, AddrLabelGetRequest, Error, Handle};
+
+pub struct AddrLabelFlushRequest {
+    handle: Handle,
+}
+
+impl AddrLabelFlushRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        AddrLabelFlushRequest { handle }
+    }
+
+    /// Execute the request. The kernel has no flush operation for address
+    /// labels, so they are dumped and deleted one by one.
+    pub async fn execute(self) -> Result<(), Error> {
+        let AddrLabelFlushRequest { mut handle } = self;
+
+        let labels: Vec<AddrLabelMessage> = AddrLabelGetRequest::new(handle.clone())
+            .execute()
+            .try_collect()
+            .await?;
+
+        for message in labels {
+            let mut req = NetlinkMessage::from(RtnlMessage::DelAddrLabel(message));
+            req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+            let mut response = handle.request(req)?;
+            while let Some(msg) = response.next().await {
+                try_nl!(msg);
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    AddrLabelMessage,
+    NetlinkMessage,
+    RtnlMessage,
+    AF_INET6,
+    NLM_F_DUMP,
+    NLM_F_REQUEST,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct AddrLabelGetRequest {
+    handle: Handle,
+    message: AddrLabelMessage,
+}
+
+impl AddrLabelGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = AddrLabelMessage::default();
+        message.header.family = AF_INET6 as u8;
+        AddrLabelGetRequest { handle, message }
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = AddrLabelMessage, Error = Error> {
+        let AddrLabelGetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetAddrLabel(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewAddrLabel))))
+            }
+            Err(e) => Either::Right(future::err::<AddrLabelMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut AddrLabelMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv6Addr;
+
+use crate::{
+    AddrLabelAddRequest,
+    AddrLabelDelRequest,
+    AddrLabelFlushRequest,
+    AddrLabelGetRequest,
+    Handle,
+};
+
+pub struct AddrLabelHandle(Handle);
+
+impl AddrLabelHandle {
+    pub fn new(handle: Handle) -> Self {
+        AddrLabelHandle(handle)
+    }
+
+    /// Retrieve the IPv6 address labels (equivalent to `ip addrlabel list`)
+    pub fn get(&self) -> AddrLabelGetRequest {
+        AddrLabelGetRequest::new(self.0.clone())
+    }
+
+    /// Assign `label` to the addresses of the given prefix (equivalent to
+    /// `ip addrlabel add prefix PREFIX label LABEL`)
+    pub fn add(&self, prefix: Ipv6Addr, prefix_len: u8, label: u32) -> AddrLabelAddRequest {
+        AddrLabelAddRequest::new(self.0.clone(), prefix, prefix_len, label)
+    }
+
+    /// Remove the label of the given prefix (equivalent to
+    /// `ip addrlabel del prefix PREFIX`)
+    pub fn del(&self, prefix: Ipv6Addr, prefix_len: u8) -> AddrLabelDelRequest {
+        AddrLabelDelRequest::new(self.0.clone(), prefix, prefix_len)
+    }
+
+    /// Remove all the labels, including the default ones (equivalent to
+    /// `ip addrlabel flush`)
+    pub fn flush(&self) -> AddrLabelFlushRequest {
+        AddrLabelFlushRequest::new(self.0.clone())
+    }
+}
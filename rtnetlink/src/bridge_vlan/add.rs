@@ -0,0 +1,158 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::{
+        bridge_vlan::{Nla, VlanEntryNla, VlanTunnelNla},
+        link::BridgeVlanInfo,
+    },
+    BridgeVlanMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use super::compress_ranges;
+use crate::{try_nl, Error, Handle};
+
+/// A request to add VLANs to a bridge or bridge port, or to change their
+/// options. This is equivalent to the `bridge vlan add` and `bridge vlan set`
+/// commands.
+///
+/// The VLANs given with [`vid`](Self::vid), [`range`](Self::range) and
+/// [`vids`](Self::vids) are merged into as few ranges as possible, and the
+/// flags and options apply to all of them.
+pub struct BridgeVlanAddRequest {
+    handle: Handle,
+    message: BridgeVlanMessage,
+    ranges: Vec<(u16, u16)>,
+    flags: u16,
+    options: Vec<VlanEntryNla>,
+}
+
+impl BridgeVlanAddRequest {
+    pub(crate) fn new(handle: Handle, index: u32) -> Self {
+        let mut message = BridgeVlanMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+
+        BridgeVlanAddRequest {
+            handle,
+            message,
+            ranges: vec![],
+            flags: 0,
+            options: vec![],
+        }
+    }
+
+    /// Add the given VLAN
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.ranges.push((vid, vid));
+        self
+    }
+
+    /// Add all the VLANs from `start` to `end`, inclusive
+    pub fn range(mut self, start: u16, end: u16) -> Self {
+        self.ranges.push((start, end));
+        self
+    }
+
+    /// Add the given VLANs
+    pub fn vids<I>(mut self, vids: I) -> Self
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        self.ranges.extend(vids.into_iter().map(|vid| (vid, vid)));
+        self
+    }
+
+    /// Make the VLAN the PVID: untagged ingress traffic is assigned to it. This can only be
+    /// used with a single VLAN.
+    pub fn pvid(mut self) -> Self {
+        self.flags |= BRIDGE_VLAN_INFO_PVID;
+        self
+    }
+
+    /// Send the egress traffic of the VLANs untagged
+    pub fn untagged(mut self) -> Self {
+        self.flags |= BRIDGE_VLAN_INFO_UNTAGGED;
+        self
+    }
+
+    /// Only change the options of existing VLANs, without touching their flags (equivalent to
+    /// `bridge vlan set`)
+    pub fn options_only(mut self) -> Self {
+        self.flags |= BRIDGE_VLAN_INFO_ONLY_OPTS;
+        self
+    }
+
+    /// Set the STP state (`BR_STATE_*`) of the VLANs
+    pub fn state(mut self, state: u8) -> Self {
+        self.options.push(VlanEntryNla::State(state));
+        self
+    }
+
+    /// Set the multicast router mode (`MDB_RTR_TYPE_*`) of the port in the VLANs
+    pub fn mcast_router(mut self, mode: u8) -> Self {
+        self.options.push(VlanEntryNla::McastRouter(mode));
+        self
+    }
+
+    /// Set the maximum number of multicast groups the port can join in each VLAN. 0 means no
+    /// limit.
+    pub fn mcast_max_groups(mut self, max: u32) -> Self {
+        self.options.push(VlanEntryNla::McastMaxGroups(max));
+        self
+    }
+
+    /// Enable or disable ARP and ND suppression on the port in the VLANs
+    pub fn neigh_suppress(mut self, enable: bool) -> Self {
+        self.options.push(VlanEntryNla::NeighSuppress(enable));
+        self
+    }
+
+    /// Map the VLANs to tunnel IDs, starting with `id` for the first VLAN of each range. This
+    /// requires a port with `vlan_tunnel` enabled.
+    pub fn tunnel_id(mut self, id: u32) -> Self {
+        self.options.push(VlanEntryNla::TunnelInfo(vec![
+            VlanTunnelNla::Id(id),
+            VlanTunnelNla::Cmd(RTM_SETLINK as u32),
+        ]));
+        self
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanAddRequest {
+            mut handle,
+            mut message,
+            ranges,
+            flags,
+            options,
+        } = self;
+
+        for (start, end) in compress_ranges(ranges) {
+            let mut entry = vec![VlanEntryNla::Info(BridgeVlanInfo { flags, vid: start })];
+            if end > start {
+                entry.push(VlanEntryNla::Range(end));
+            }
+            entry.extend(options.iter().cloned());
+            message.nlas.push(Nla::Entry(entry));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewBridgeVlan(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut BridgeVlanMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,91 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::{
+        bridge_vlan::{Nla, VlanEntryNla},
+        link::BridgeVlanInfo,
+    },
+    BridgeVlanMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use super::compress_ranges;
+use crate::{try_nl, Error, Handle};
+
+pub struct BridgeVlanDelRequest {
+    handle: Handle,
+    message: BridgeVlanMessage,
+    ranges: Vec<(u16, u16)>,
+}
+
+impl BridgeVlanDelRequest {
+    pub(crate) fn new(handle: Handle, index: u32) -> Self {
+        let mut message = BridgeVlanMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+
+        BridgeVlanDelRequest {
+            handle,
+            message,
+            ranges: vec![],
+        }
+    }
+
+    /// Remove the given VLAN
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.ranges.push((vid, vid));
+        self
+    }
+
+    /// Remove all the VLANs from `start` to `end`, inclusive
+    pub fn range(mut self, start: u16, end: u16) -> Self {
+        self.ranges.push((start, end));
+        self
+    }
+
+    /// Remove the given VLANs
+    pub fn vids<I>(mut self, vids: I) -> Self
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        self.ranges.extend(vids.into_iter().map(|vid| (vid, vid)));
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanDelRequest {
+            mut handle,
+            mut message,
+            ranges,
+        } = self;
+
+        for (start, end) in compress_ranges(ranges) {
+            let mut entry = vec![VlanEntryNla::Info(BridgeVlanInfo {
+                flags: 0,
+                vid: start,
+            })];
+            if end > start {
+                entry.push(VlanEntryNla::Range(end));
+            }
+            message.nlas.push(Nla::Entry(entry));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelBridgeVlan(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(msg) = response.next().await {
+            try_nl!(msg);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut BridgeVlanMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::bridge_vlan::Nla,
+    BridgeVlanMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct BridgeVlanGetRequest {
+    handle: Handle,
+    message: BridgeVlanMessage,
+    dump_flags: u32,
+}
+
+impl BridgeVlanGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = BridgeVlanMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        BridgeVlanGetRequest {
+            handle,
+            message,
+            dump_flags: 0,
+        }
+    }
+
+    /// Only retrieve the VLANs of the bridge or bridge port with the given index
+    pub fn match_index(mut self, index: u32) -> Self {
+        self.message.header.ifindex = index;
+        self
+    }
+
+    /// Include the per-VLAN statistics (equivalent to `bridge -s vlan show`). Entries with
+    /// statistics are never merged into ranges.
+    pub fn stats(mut self) -> Self {
+        self.dump_flags |= BRIDGE_VLANDB_DUMPF_STATS;
+        self
+    }
+
+    /// Retrieve the bridge-wide VLAN options instead of the per-port entries (equivalent to
+    /// `bridge vlan global show`)
+    pub fn global(mut self) -> Self {
+        self.dump_flags |= BRIDGE_VLANDB_DUMPF_GLOBAL;
+        self
+    }
+
+    /// Execute the request. The kernel sends one message per bridge or bridge port, with one
+    /// `Entry` attribute per VLAN range sharing the same flags and options.
+    pub fn execute(self) -> impl TryStream<Ok = BridgeVlanMessage, Error = Error> {
+        let BridgeVlanGetRequest {
+            mut handle,
+            mut message,
+            dump_flags,
+        } = self;
+
+        if dump_flags != 0 {
+            message.nlas.push(Nla::DumpFlags(dump_flags));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetBridgeVlan(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewBridgeVlan))),
+            ),
+            Err(e) => Either::Right(future::err::<BridgeVlanMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut BridgeVlanMessage {
+        &mut self.message
+    }
+}
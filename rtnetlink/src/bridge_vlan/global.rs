@@ -0,0 +1,169 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::bridge_vlan::{Nla, VlanGlobalNla},
+    BridgeVlanMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use super::compress_ranges;
+use crate::{try_nl, Error, Handle};
+
+/// A request to change the bridge-wide options of VLANs. This is equivalent
+/// to the `bridge vlan global set` command. The intervals are in hundredths
+/// of seconds.
+pub struct BridgeVlanGlobalRequest {
+    handle: Handle,
+    message: BridgeVlanMessage,
+    ranges: Vec<(u16, u16)>,
+    options: Vec<VlanGlobalNla>,
+}
+
+impl BridgeVlanGlobalRequest {
+    pub(crate) fn new(handle: Handle, bridge: u32) -> Self {
+        let mut message = BridgeVlanMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge;
+
+        BridgeVlanGlobalRequest {
+            handle,
+            message,
+            ranges: vec![],
+            options: vec![],
+        }
+    }
+
+    /// Change the options of the given VLAN
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.ranges.push((vid, vid));
+        self
+    }
+
+    /// Change the options of all the VLANs from `start` to `end`, inclusive
+    pub fn range(mut self, start: u16, end: u16) -> Self {
+        self.ranges.push((start, end));
+        self
+    }
+
+    /// Change the options of the given VLANs
+    pub fn vids<I>(mut self, vids: I) -> Self
+    where
+        I: IntoIterator<Item = u16>,
+    {
+        self.ranges.extend(vids.into_iter().map(|vid| (vid, vid)));
+        self
+    }
+
+    /// Enable or disable multicast snooping in the VLANs
+    pub fn mcast_snooping(mut self, enable: bool) -> Self {
+        self.options
+            .push(VlanGlobalNla::McastSnooping(enable as u8));
+        self
+    }
+
+    pub fn mcast_igmp_version(mut self, version: u8) -> Self {
+        self.options.push(VlanGlobalNla::McastIgmpVersion(version));
+        self
+    }
+
+    pub fn mcast_mld_version(mut self, version: u8) -> Self {
+        self.options.push(VlanGlobalNla::McastMldVersion(version));
+        self
+    }
+
+    pub fn mcast_last_member_count(mut self, count: u32) -> Self {
+        self.options.push(VlanGlobalNla::McastLastMemberCnt(count));
+        self
+    }
+
+    pub fn mcast_startup_query_count(mut self, count: u32) -> Self {
+        self.options
+            .push(VlanGlobalNla::McastStartupQueryCnt(count));
+        self
+    }
+
+    pub fn mcast_last_member_interval(mut self, interval: u64) -> Self {
+        self.options
+            .push(VlanGlobalNla::McastLastMemberIntvl(interval));
+        self
+    }
+
+    pub fn mcast_membership_interval(mut self, interval: u64) -> Self {
+        self.options
+            .push(VlanGlobalNla::McastMembershipIntvl(interval));
+        self
+    }
+
+    pub fn mcast_querier_interval(mut self, interval: u64) -> Self {
+        self.options
+            .push(VlanGlobalNla::McastQuerierIntvl(interval));
+        self
+    }
+
+    pub fn mcast_query_interval(mut self, interval: u64) -> Self {
+        self.options.push(VlanGlobalNla::McastQueryIntvl(interval));
+        self
+    }
+
+    pub fn mcast_query_response_interval(mut self, interval: u64) -> Self {
+        self.options
+            .push(VlanGlobalNla::McastQueryResponseIntvl(interval));
+        self
+    }
+
+    pub fn mcast_startup_query_interval(mut self, interval: u64) -> Self {
+        self.options
+            .push(VlanGlobalNla::McastStartupQueryIntvl(interval));
+        self
+    }
+
+    /// Enable or disable the multicast querier of the bridge in the VLANs
+    pub fn mcast_querier(mut self, enable: bool) -> Self {
+        self.options.push(VlanGlobalNla::McastQuerier(enable as u8));
+        self
+    }
+
+    /// Assign the VLANs to a multiple spanning tree instance. This requires a
+    /// bridge with `mst_enabled`.
+    pub fn msti(mut self, msti: u16) -> Self {
+        self.options.push(VlanGlobalNla::Msti(msti));
+        self
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let BridgeVlanGlobalRequest {
+            mut handle,
+            mut message,
+            ranges,
+            options,
+        } = self;
+
+        for (start, end) in compress_ranges(ranges) {
+            let mut nlas = vec![VlanGlobalNla::Id(start)];
+            if end > start {
+                nlas.push(VlanGlobalNla::Range(end));
+            }
+            nlas.extend(options.iter().cloned());
+            message.nlas.push(Nla::GlobalOptions(nlas));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewBridgeVlan(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut BridgeVlanMessage {
+        &mut self.message
+    }
+}
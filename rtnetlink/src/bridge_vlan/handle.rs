@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{
+    BridgeVlanAddRequest,
+    BridgeVlanDelRequest,
+    BridgeVlanGetRequest,
+    BridgeVlanGlobalRequest,
+    Handle,
+};
+
+pub struct BridgeVlanHandle(Handle);
+
+impl BridgeVlanHandle {
+    pub fn new(handle: Handle) -> Self {
+        BridgeVlanHandle(handle)
+    }
+
+    /// Retrieve the VLANs of all the bridges and bridge ports (equivalent to `bridge vlan show`)
+    pub fn get(&self) -> BridgeVlanGetRequest {
+        BridgeVlanGetRequest::new(self.0.clone())
+    }
+
+    /// Add VLANs to the bridge or bridge port with index `index`, or change their options
+    /// (equivalent to `bridge vlan add dev DEV` and `bridge vlan set dev DEV`)
+    pub fn add(&self, index: u32) -> BridgeVlanAddRequest {
+        BridgeVlanAddRequest::new(self.0.clone(), index)
+    }
+
+    /// Remove VLANs from the bridge or bridge port with index `index` (equivalent to
+    /// `bridge vlan del dev DEV`)
+    pub fn del(&self, index: u32) -> BridgeVlanDelRequest {
+        BridgeVlanDelRequest::new(self.0.clone(), index)
+    }
+
+    /// Change the bridge-wide options of VLANs of the bridge with index `bridge` (equivalent to
+    /// `bridge vlan global set dev BRIDGE`)
+    pub fn global(&self, bridge: u32) -> BridgeVlanGlobalRequest {
+        BridgeVlanGlobalRequest::new(self.0.clone(), bridge)
+    }
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT
+
+mod handle;
+pub use self::handle::*;
+
+mod add;
+pub use self::add::*;
+
+mod del;
+pub use self::del::*;
+
+mod get;
+pub use self::get::*;
+
+mod global;
+pub use self::global::*;
+
+/// Merge overlapping and adjacent VLAN ranges, so that they can be sent as
+/// few entries as possible
+fn compress_ranges(mut ranges: Vec<(u16, u16)>) -> Vec<(u16, u16)> {
+    ranges.sort_unstable();
+    let mut compressed: Vec<(u16, u16)> = vec![];
+    for (start, end) in ranges {
+        match compressed.last_mut() {
+            Some(last) if start as u32 <= last.1 as u32 + 1 => last.1 = last.1.max(end),
+            _ => compressed.push((start, end)),
+        }
+    }
+    compressed
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT
+
+use super::compress_ranges;
+
+#[test]
+fn test_compress_ranges() {
+    assert_eq!(compress_ranges(vec![]), vec![]);
+    assert_eq!(
+        compress_ranges(vec![
+            (5, 5),
+            (1, 1),
+            (2, 3),
+            (4, 4),
+            (10, 20),
+            (15, 30),
+            (4094, 4094)
+        ]),
+        vec![(1, 5), (10, 30), (4094, 4094)]
+    );
+}
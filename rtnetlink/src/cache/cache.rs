@@ -0,0 +1,292 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    net::IpAddr,
+    pin::Pin,
+    sync::{Arc, Mutex, RwLock, Weak},
+};
+
+use futures::{
+    channel::{
+        mpsc::{unbounded, UnboundedReceiver, UnboundedSender},
+        oneshot,
+    },
+    future::{self, Either},
+    Stream,
+    StreamExt,
+    TryStreamExt,
+};
+use log::{error, warn};
+
+use netlink_packet_route::{AddressMessage, LinkMessage, NeighbourMessage, RouteMessage};
+
+use super::state::CacheState;
+use crate::{CacheEvent, Error, Handle, IpVersion, MonitorEvent, MonitorRequest};
+
+type Events = Pin<Box<dyn Stream<Item = MonitorEvent> + Send>>;
+
+/// The kinds of objects a [`Cache`] keeps track of
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CacheKinds {
+    pub(crate) link: bool,
+    pub(crate) address: bool,
+    pub(crate) route: bool,
+    pub(crate) neighbour: bool,
+}
+
+impl CacheKinds {
+    fn monitor(&self) -> MonitorRequest {
+        let mut request = MonitorRequest::new();
+        if self.link {
+            request = request.link();
+        }
+        if self.address {
+            request = request.address();
+        }
+        if self.route {
+            request = request.route();
+        }
+        if self.neighbour {
+            request = request.neighbour();
+        }
+        request
+    }
+}
+
+#[derive(Debug)]
+struct Shared {
+    state: RwLock<CacheState>,
+    subscribers: Mutex<Vec<UnboundedSender<CacheEvent>>>,
+    // Never used to send anything: dropping it along with the last `Cache`
+    // wakes the background task up so that it stops.
+    _closed: oneshot::Sender<()>,
+}
+
+impl Shared {
+    fn notify(&self, events: Vec<CacheEvent>) {
+        if events.is_empty() {
+            return;
+        }
+        let mut subscribers = self.subscribers.lock().unwrap();
+        subscribers.retain(|tx| {
+            events
+                .iter()
+                .all(|event| tx.unbounded_send(event.clone()).is_ok())
+        });
+    }
+}
+
+/// An in-memory copy of the kernel's links, addresses, routes and neighbours,
+/// kept up to date from multicast notifications.
+///
+/// The cache is first seeded with dumps, then a background task applies every
+/// notification received on a dedicated monitoring socket (see
+/// [`MonitorRequest`]). If that socket overruns, the task reloads the whole
+/// cache and subscribers receive [`CacheEvent::Resynced`]. If reloading
+/// fails, the task stops and all the subscription streams end.
+///
+/// `Cache` is cheap to clone; all the clones share the same data. The
+/// background task stops once every clone has been dropped.
+#[derive(Debug, Clone)]
+pub struct Cache {
+    shared: Arc<Shared>,
+}
+
+impl Cache {
+    pub(crate) async fn new(handle: Handle, kinds: CacheKinds) -> Result<Self, Error> {
+        let (state, events) = sync(&handle, kinds).await?;
+        let (closed_tx, closed_rx) = oneshot::channel();
+        let shared = Arc::new(Shared {
+            state: RwLock::new(state),
+            subscribers: Mutex::new(vec![]),
+            _closed: closed_tx,
+        });
+        crate::monitor::spawn(run(
+            handle,
+            kinds,
+            Arc::downgrade(&shared),
+            events,
+            closed_rx,
+        ));
+        Ok(Cache { shared })
+    }
+
+    /// Return a stream of the changes applied to the cache from now on
+    pub fn subscribe(&self) -> UnboundedReceiver<CacheEvent> {
+        let (tx, rx) = unbounded();
+        self.shared.subscribers.lock().unwrap().push(tx);
+        rx
+    }
+
+    /// Look up a link by index
+    pub fn link(&self, index: u32) -> Option<LinkMessage> {
+        self.shared.state.read().unwrap().link(index).cloned()
+    }
+
+    /// Look up a link by name or alternative name
+    pub fn link_by_name(&self, name: &str) -> Option<LinkMessage> {
+        self.shared
+            .state
+            .read()
+            .unwrap()
+            .link_by_name(name)
+            .cloned()
+    }
+
+    /// Return all the links, ordered by index
+    pub fn links(&self) -> Vec<LinkMessage> {
+        self.shared.state.read().unwrap().links().cloned().collect()
+    }
+
+    /// Return all the addresses
+    pub fn addresses(&self) -> Vec<AddressMessage> {
+        self.shared
+            .state
+            .read()
+            .unwrap()
+            .addresses()
+            .cloned()
+            .collect()
+    }
+
+    /// Return the addresses assigned to the link with the given index
+    pub fn addresses_by_index(&self, index: u32) -> Vec<AddressMessage> {
+        self.shared
+            .state
+            .read()
+            .unwrap()
+            .addresses_by_index(index)
+            .cloned()
+            .collect()
+    }
+
+    /// Return all the routes
+    pub fn routes(&self) -> Vec<RouteMessage> {
+        self.shared
+            .state
+            .read()
+            .unwrap()
+            .routes()
+            .cloned()
+            .collect()
+    }
+
+    /// Return the routes to exactly the given prefix, from all the tables.
+    /// Use `0.0.0.0/0` or `::/0` for default routes.
+    pub fn routes_by_prefix(&self, destination: IpAddr, prefix_len: u8) -> Vec<RouteMessage> {
+        self.shared
+            .state
+            .read()
+            .unwrap()
+            .routes_by_prefix(destination, prefix_len)
+            .cloned()
+            .collect()
+    }
+
+    /// Return all the neighbour entries
+    pub fn neighbours(&self) -> Vec<NeighbourMessage> {
+        self.shared
+            .state
+            .read()
+            .unwrap()
+            .neighbours()
+            .cloned()
+            .collect()
+    }
+
+    /// Look up the neighbour entry for `destination` on the link with the
+    /// given index
+    pub fn neighbour(&self, index: u32, destination: IpAddr) -> Option<NeighbourMessage> {
+        self.shared
+            .state
+            .read()
+            .unwrap()
+            .neighbour(index, destination)
+            .cloned()
+    }
+}
+
+/// Subscribe to the notifications, then dump the current state. Subscribing
+/// first guarantees that no change happening during the dump gets lost.
+async fn sync(handle: &Handle, kinds: CacheKinds) -> Result<(CacheState, Events), Error> {
+    let events = Box::pin(kinds.monitor().execute()?);
+    let mut state = CacheState::default();
+
+    if kinds.link {
+        let mut links = handle.link().get().execute();
+        while let Some(msg) = links.try_next().await? {
+            state.insert_link(msg);
+        }
+    }
+    if kinds.address {
+        let mut addresses = handle.address().get().execute();
+        while let Some(msg) = addresses.try_next().await? {
+            state.insert_address(msg);
+        }
+    }
+    if kinds.route {
+        for ip_version in [IpVersion::V4, IpVersion::V6] {
+            let mut routes = handle.route().get(ip_version).execute();
+            while let Some(msg) = routes.try_next().await? {
+                state.insert_route(msg);
+            }
+        }
+    }
+    if kinds.neighbour {
+        let mut neighbours = handle.neighbours().get().execute();
+        while let Some(msg) = neighbours.try_next().await? {
+            state.insert_neighbour(msg);
+        }
+    }
+    Ok((state, events))
+}
+
+async fn run(
+    handle: Handle,
+    kinds: CacheKinds,
+    shared: Weak<Shared>,
+    mut events: Events,
+    mut closed: oneshot::Receiver<()>,
+) {
+    loop {
+        loop {
+            let event = match future::select(events.next(), &mut closed).await {
+                Either::Left((Some(event), _)) => event,
+                Either::Left((None, _)) => break,
+                // Every `Cache` has been dropped
+                Either::Right(_) => return,
+            };
+            let shared = match shared.upgrade() {
+                Some(shared) => shared,
+                None => return,
+            };
+            let changes = shared.state.write().unwrap().apply(event);
+            shared.notify(changes);
+        }
+
+        // The monitoring socket is gone, most likely because it overran and
+        // some notifications were dropped: start over from fresh dumps.
+        if shared.strong_count() == 0 {
+            return;
+        }
+        warn!("cache monitoring socket closed, resynchronizing");
+        events = match sync(&handle, kinds).await {
+            Ok((state, events)) => {
+                let shared = match shared.upgrade() {
+                    Some(shared) => shared,
+                    None => return,
+                };
+                *shared.state.write().unwrap() = state;
+                shared.notify(vec![CacheEvent::Resynced]);
+                events
+            }
+            Err(e) => {
+                error!("failed to resynchronize cache: {}", e);
+                if let Some(shared) = shared.upgrade() {
+                    shared.subscribers.lock().unwrap().clear();
+                }
+                return;
+            }
+        };
+    }
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::{AddressMessage, LinkMessage, NeighbourMessage, RouteMessage};
+
+/// An object held by a [`Cache`](crate::Cache)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CacheObject {
+    Link(LinkMessage),
+    Address(AddressMessage),
+    Route(RouteMessage),
+    Neighbour(NeighbourMessage),
+}
+
+/// A change applied to a [`Cache`](crate::Cache)
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum CacheEvent {
+    /// An object that was not in the cache has been inserted
+    Added(CacheObject),
+    /// An object already in the cache has been updated. This holds the new
+    /// version of the object.
+    Changed(CacheObject),
+    /// An object has been removed from the cache
+    Removed(CacheObject),
+    /// Notifications were lost (typically because the monitoring socket
+    /// overran) and the whole cache has been reloaded from fresh dumps.
+    /// Subscribers should re-read whatever they are interested in.
+    Resynced,
+}
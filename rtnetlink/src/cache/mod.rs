@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod event;
+pub use self::event::*;
+
+mod state;
+
+mod cache;
+pub use self::cache::*;
+
+mod request;
+pub use self::request::*;
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,53 @@
+// SPDX-License-Identifier: MIT
+
+use super::cache::CacheKinds;
+use crate::{Cache, Error, Handle};
+
+/// Build a [`Cache`] of the requested kinds of objects
+pub struct CacheRequest {
+    handle: Handle,
+    kinds: CacheKinds,
+}
+
+impl CacheRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        CacheRequest {
+            handle,
+            kinds: CacheKinds::default(),
+        }
+    }
+
+    /// Keep track of links
+    pub fn link(mut self) -> Self {
+        self.kinds.link = true;
+        self
+    }
+
+    /// Keep track of IPv4 and IPv6 addresses
+    pub fn address(mut self) -> Self {
+        self.kinds.address = true;
+        self
+    }
+
+    /// Keep track of IPv4 and IPv6 routes, from all the tables
+    pub fn route(mut self) -> Self {
+        self.kinds.route = true;
+        self
+    }
+
+    /// Keep track of neighbour entries
+    pub fn neighbour(mut self) -> Self {
+        self.kinds.neighbour = true;
+        self
+    }
+
+    /// Keep track of links, addresses, routes and neighbours
+    pub fn all(self) -> Self {
+        self.link().address().route().neighbour()
+    }
+
+    /// Seed the cache from dumps and start following the notifications
+    pub async fn execute(self) -> Result<Cache, Error> {
+        Cache::new(self.handle, self.kinds).await
+    }
+}
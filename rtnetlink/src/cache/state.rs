@@ -0,0 +1,426 @@
+// SPDX-License-Identifier: MIT
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    net::IpAddr,
+};
+
+use netlink_packet_route::{
+    address,
+    constants::*,
+    link::nlas::{Nla as LinkNla, Prop},
+    neighbour,
+    route,
+    AddressMessage,
+    LinkMessage,
+    NeighbourMessage,
+    RouteMessage,
+};
+
+use crate::{CacheEvent, CacheObject, MonitorEvent};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct AddressKey {
+    index: u32,
+    family: u8,
+    prefix_len: u8,
+    address: Vec<u8>,
+}
+
+impl AddressKey {
+    fn new(msg: &AddressMessage) -> Self {
+        // IFA_LOCAL is the address of the interface, IFA_ADDRESS is the peer
+        // address on point-to-point links. Prefer the former when present.
+        let mut address = vec![];
+        for nla in msg.nlas.iter() {
+            match nla {
+                address::Nla::Local(bytes) => {
+                    address = bytes.clone();
+                    break;
+                }
+                address::Nla::Address(bytes) => address = bytes.clone(),
+                _ => {}
+            }
+        }
+        AddressKey {
+            index: msg.header.index,
+            family: msg.header.family,
+            prefix_len: msg.header.prefix_len,
+            address,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct RouteKey {
+    family: u8,
+    destination: Vec<u8>,
+    destination_prefix_length: u8,
+    table: u32,
+    tos: u8,
+    priority: u32,
+    source: Vec<u8>,
+    source_prefix_length: u8,
+    // Routes to the same destination with the same metric can still differ
+    // by their type and next-hops, for instance link local routes on several
+    // interfaces or routes added with NLM_F_APPEND.
+    kind: u8,
+    output_interface: u32,
+    gateway: Vec<u8>,
+    next_hops: Vec<(u32, Vec<u8>)>,
+}
+
+impl RouteKey {
+    fn new(msg: &RouteMessage) -> Self {
+        let mut key = RouteKey {
+            family: msg.header.address_family,
+            destination: vec![],
+            destination_prefix_length: msg.header.destination_prefix_length,
+            table: msg.header.table as u32,
+            tos: msg.header.tos,
+            priority: 0,
+            source: vec![],
+            source_prefix_length: msg.header.source_prefix_length,
+            kind: msg.header.kind,
+            output_interface: 0,
+            gateway: gateway(&msg.nlas),
+            next_hops: vec![],
+        };
+        for nla in msg.nlas.iter() {
+            match nla {
+                route::Nla::Destination(bytes) => key.destination = bytes.clone(),
+                route::Nla::Source(bytes) => key.source = bytes.clone(),
+                route::Nla::Table(table) => key.table = *table,
+                route::Nla::Priority(priority) => key.priority = *priority,
+                route::Nla::Oif(index) => key.output_interface = *index,
+                _ => {}
+            }
+        }
+        if let Some(next_hops) = msg.multipath() {
+            key.next_hops = next_hops
+                .iter()
+                .map(|nh| (nh.interface_id, gateway(&nh.nlas)))
+                .collect();
+        }
+        key
+    }
+
+    // Whether the kernel would replace one of the routes with the other on
+    // NLM_F_REPLACE: same destination, table, TOS, metric and source.
+    fn same_slot(&self, other: &RouteKey) -> bool {
+        self.family == other.family
+            && self.destination == other.destination
+            && self.destination_prefix_length == other.destination_prefix_length
+            && self.table == other.table
+            && self.tos == other.tos
+            && self.priority == other.priority
+            && self.source == other.source
+            && self.source_prefix_length == other.source_prefix_length
+    }
+}
+
+// Address of the next router, given by RTA_GATEWAY or, for a gateway in
+// another address family, by RTA_VIA
+fn gateway(nlas: &[route::Nla]) -> Vec<u8> {
+    nlas.iter()
+        .find_map(|nla| match nla {
+            route::Nla::Gateway(bytes) => Some(bytes.clone()),
+            route::Nla::Via(route::RouteVia::Inet(ip)) => Some(ip.octets().to_vec()),
+            route::Nla::Via(route::RouteVia::Inet6(ip)) => Some(ip.octets().to_vec()),
+            route::Nla::Via(route::RouteVia::Other(_, bytes)) => Some(bytes.clone()),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+struct NeighbourKey {
+    index: u32,
+    family: u8,
+    destination: Vec<u8>,
+}
+
+impl NeighbourKey {
+    fn new(msg: &NeighbourMessage) -> Self {
+        // Bridge FDB entries have no IP destination and are identified by
+        // their link layer address instead.
+        let mut destination = vec![];
+        for nla in msg.nlas.iter() {
+            match nla {
+                neighbour::Nla::Destination(bytes) => {
+                    destination = bytes.clone();
+                    break;
+                }
+                neighbour::Nla::LinkLocalAddress(bytes) => destination = bytes.clone(),
+                _ => {}
+            }
+        }
+        NeighbourKey {
+            index: msg.header.ifindex,
+            family: msg.header.family,
+            destination,
+        }
+    }
+}
+
+fn link_names(msg: &LinkMessage) -> Vec<String> {
+    let mut names = vec![];
+    for nla in msg.nlas.iter() {
+        match nla {
+            LinkNla::IfName(name) | LinkNla::AltIfName(name) => names.push(name.clone()),
+            LinkNla::PropList(props) => {
+                for prop in props.iter() {
+                    if let Prop::AltIfName(name) = prop {
+                        names.push(name.clone());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    names
+}
+
+fn ip_octets(addr: IpAddr) -> (u8, Vec<u8>) {
+    match addr {
+        IpAddr::V4(ip) => (AF_INET as u8, ip.octets().to_vec()),
+        IpAddr::V6(ip) => (AF_INET6 as u8, ip.octets().to_vec()),
+    }
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct CacheState {
+    links: BTreeMap<u32, LinkMessage>,
+    names: HashMap<String, u32>,
+    addresses: BTreeMap<AddressKey, AddressMessage>,
+    routes: BTreeMap<RouteKey, RouteMessage>,
+    neighbours: BTreeMap<NeighbourKey, NeighbourMessage>,
+}
+
+impl CacheState {
+    pub(crate) fn link(&self, index: u32) -> Option<&LinkMessage> {
+        self.links.get(&index)
+    }
+
+    pub(crate) fn link_by_name(&self, name: &str) -> Option<&LinkMessage> {
+        self.names.get(name).and_then(|index| self.links.get(index))
+    }
+
+    pub(crate) fn links(&self) -> impl Iterator<Item = &LinkMessage> {
+        self.links.values()
+    }
+
+    pub(crate) fn addresses(&self) -> impl Iterator<Item = &AddressMessage> {
+        self.addresses.values()
+    }
+
+    pub(crate) fn addresses_by_index(&self, index: u32) -> impl Iterator<Item = &AddressMessage> {
+        let start = AddressKey {
+            index,
+            family: 0,
+            prefix_len: 0,
+            address: vec![],
+        };
+        self.addresses
+            .range(start..)
+            .take_while(move |(key, _)| key.index == index)
+            .map(|(_, msg)| msg)
+    }
+
+    pub(crate) fn routes(&self) -> impl Iterator<Item = &RouteMessage> {
+        self.routes.values()
+    }
+
+    pub(crate) fn routes_by_prefix(
+        &self,
+        destination: IpAddr,
+        prefix_len: u8,
+    ) -> impl Iterator<Item = &RouteMessage> {
+        let (family, mut octets) = ip_octets(destination);
+        // The kernel omits RTA_DST for default routes
+        if prefix_len == 0 {
+            octets.clear();
+        }
+        let start = RouteKey {
+            family,
+            destination: octets,
+            destination_prefix_length: prefix_len,
+            table: 0,
+            tos: 0,
+            priority: 0,
+            source: vec![],
+            source_prefix_length: 0,
+            kind: 0,
+            output_interface: 0,
+            gateway: vec![],
+            next_hops: vec![],
+        };
+        let matches = start.clone();
+        self.routes
+            .range(start..)
+            .take_while(move |(key, _)| {
+                key.family == matches.family
+                    && key.destination == matches.destination
+                    && key.destination_prefix_length == matches.destination_prefix_length
+            })
+            .map(|(_, msg)| msg)
+    }
+
+    pub(crate) fn neighbours(&self) -> impl Iterator<Item = &NeighbourMessage> {
+        self.neighbours.values()
+    }
+
+    pub(crate) fn neighbour(&self, index: u32, destination: IpAddr) -> Option<&NeighbourMessage> {
+        let (family, destination) = ip_octets(destination);
+        self.neighbours.get(&NeighbourKey {
+            index,
+            family,
+            destination,
+        })
+    }
+
+    pub(crate) fn insert_link(&mut self, msg: LinkMessage) -> CacheEvent {
+        let index = msg.header.index;
+        self.names.retain(|_, i| *i != index);
+        for name in link_names(&msg) {
+            self.names.insert(name, index);
+        }
+        match self.links.insert(index, msg.clone()) {
+            Some(_) => CacheEvent::Changed(CacheObject::Link(msg)),
+            None => CacheEvent::Added(CacheObject::Link(msg)),
+        }
+    }
+
+    pub(crate) fn insert_address(&mut self, msg: AddressMessage) -> CacheEvent {
+        match self.addresses.insert(AddressKey::new(&msg), msg.clone()) {
+            Some(_) => CacheEvent::Changed(CacheObject::Address(msg)),
+            None => CacheEvent::Added(CacheObject::Address(msg)),
+        }
+    }
+
+    pub(crate) fn insert_route(&mut self, msg: RouteMessage) -> CacheEvent {
+        match self.routes.insert(RouteKey::new(&msg), msg.clone()) {
+            Some(_) => CacheEvent::Changed(CacheObject::Route(msg)),
+            None => CacheEvent::Added(CacheObject::Route(msg)),
+        }
+    }
+
+    pub(crate) fn insert_neighbour(&mut self, msg: NeighbourMessage) -> CacheEvent {
+        match self.neighbours.insert(NeighbourKey::new(&msg), msg.clone()) {
+            Some(_) => CacheEvent::Changed(CacheObject::Neighbour(msg)),
+            None => CacheEvent::Added(CacheObject::Neighbour(msg)),
+        }
+    }
+
+    fn remove_link(&mut self, index: u32) -> Vec<CacheEvent> {
+        let mut events = vec![];
+        self.names.retain(|_, i| *i != index);
+        if let Some(msg) = self.links.remove(&index) {
+            events.push(CacheEvent::Removed(CacheObject::Link(msg)));
+        }
+        // The kernel does not always send a notification for every object
+        // that goes away with the link, so drop them here.
+        let addresses: Vec<_> = self
+            .addresses
+            .keys()
+            .filter(|key| key.index == index)
+            .cloned()
+            .collect();
+        for key in addresses {
+            if let Some(msg) = self.addresses.remove(&key) {
+                events.push(CacheEvent::Removed(CacheObject::Address(msg)));
+            }
+        }
+        events.extend(self.remove_routes_via(index, None));
+        let neighbours: Vec<_> = self
+            .neighbours
+            .keys()
+            .filter(|key| key.index == index)
+            .cloned()
+            .collect();
+        for key in neighbours {
+            if let Some(msg) = self.neighbours.remove(&key) {
+                events.push(CacheEvent::Removed(CacheObject::Neighbour(msg)));
+            }
+        }
+        events
+    }
+
+    /// Remove the routes going out through the given interface, optionally
+    /// restricted to one address family.
+    fn remove_routes_via(&mut self, index: u32, family: Option<u8>) -> Vec<CacheEvent> {
+        let keys: Vec<_> = self
+            .routes
+            .iter()
+            .filter(|(key, msg)| {
+                family.map(|f| f == key.family).unwrap_or(true)
+                    && msg.output_interface() == Some(index)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        keys.into_iter()
+            .filter_map(|key| self.routes.remove(&key))
+            .map(|msg| CacheEvent::Removed(CacheObject::Route(msg)))
+            .collect()
+    }
+
+    /// Apply a notification and return the resulting changes. Notifications
+    /// about objects the cache does not track are ignored.
+    pub(crate) fn apply(&mut self, event: MonitorEvent) -> Vec<CacheEvent> {
+        use self::MonitorEvent::*;
+        match event {
+            LinkAdded(msg) | LinkChanged(msg) => {
+                let index = msg.header.index;
+                let down = msg.header.flags & IFF_UP == 0;
+                let mut events = vec![self.insert_link(msg)];
+                // IPv4 routes through a link that goes down are flushed
+                // without any notification.
+                if down {
+                    events.extend(self.remove_routes_via(index, Some(AF_INET as u8)));
+                }
+                events
+            }
+            LinkRemoved(msg) => self.remove_link(msg.header.index),
+            AddressAdded(msg) => vec![self.insert_address(msg)],
+            AddressRemoved(msg) => self
+                .addresses
+                .remove(&AddressKey::new(&msg))
+                .map(|msg| CacheEvent::Removed(CacheObject::Address(msg)))
+                .into_iter()
+                .collect(),
+            RouteAdded(msg) => vec![self.insert_route(msg)],
+            RouteReplaced(msg) => {
+                // The kernel does not notify the removal of the route that
+                // was replaced, which may have other next-hops.
+                let key = RouteKey::new(&msg);
+                let replaced: Vec<_> = self
+                    .routes
+                    .keys()
+                    .filter(|k| k.same_slot(&key) && **k != key)
+                    .cloned()
+                    .collect();
+                let mut events: Vec<_> = replaced
+                    .into_iter()
+                    .filter_map(|k| self.routes.remove(&k))
+                    .map(|msg| CacheEvent::Removed(CacheObject::Route(msg)))
+                    .collect();
+                events.push(self.insert_route(msg));
+                events
+            }
+            RouteRemoved(msg) => self
+                .routes
+                .remove(&RouteKey::new(&msg))
+                .map(|msg| CacheEvent::Removed(CacheObject::Route(msg)))
+                .into_iter()
+                .collect(),
+            NeighbourAdded(msg) => vec![self.insert_neighbour(msg)],
+            NeighbourRemoved(msg) => self
+                .neighbours
+                .remove(&NeighbourKey::new(&msg))
+                .map(|msg| CacheEvent::Removed(CacheObject::Neighbour(msg)))
+                .into_iter()
+                .collect(),
+            _ => vec![],
+        }
+    }
+}
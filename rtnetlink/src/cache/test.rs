@@ -0,0 +1,332 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use netlink_packet_route::{
+    address,
+    constants::*,
+    link::nlas::Nla as LinkNla,
+    neighbour,
+    route,
+    AddressMessage,
+    LinkMessage,
+    NeighbourMessage,
+    RouteMessage,
+};
+
+use super::state::CacheState;
+use crate::{CacheEvent, CacheObject, MonitorEvent};
+
+fn link(index: u32, name: &str, up: bool) -> LinkMessage {
+    let mut msg = LinkMessage::default();
+    msg.header.index = index;
+    msg.header.flags = if up { IFF_UP } else { 0 };
+    msg.nlas.push(LinkNla::IfName(name.to_string()));
+    msg
+}
+
+fn address(index: u32, addr: IpAddr, prefix_len: u8) -> AddressMessage {
+    let mut msg = AddressMessage::default();
+    msg.header.index = index;
+    msg.header.prefix_len = prefix_len;
+    let octets = match addr {
+        IpAddr::V4(ip) => {
+            msg.header.family = AF_INET as u8;
+            ip.octets().to_vec()
+        }
+        IpAddr::V6(ip) => {
+            msg.header.family = AF_INET6 as u8;
+            ip.octets().to_vec()
+        }
+    };
+    msg.nlas.push(address::Nla::Address(octets.clone()));
+    msg.nlas.push(address::Nla::Local(octets));
+    msg
+}
+
+fn route(index: u32, destination: IpAddr, prefix_len: u8, metric: u32) -> RouteMessage {
+    let mut msg = RouteMessage::default();
+    msg.header.table = RT_TABLE_MAIN;
+    msg.header.destination_prefix_length = prefix_len;
+    let octets = match destination {
+        IpAddr::V4(ip) => {
+            msg.header.address_family = AF_INET as u8;
+            ip.octets().to_vec()
+        }
+        IpAddr::V6(ip) => {
+            msg.header.address_family = AF_INET6 as u8;
+            ip.octets().to_vec()
+        }
+    };
+    // The kernel omits RTA_DST for default routes
+    if prefix_len != 0 {
+        msg.nlas.push(route::Nla::Destination(octets));
+    }
+    msg.nlas.push(route::Nla::Table(RT_TABLE_MAIN as u32));
+    msg.nlas.push(route::Nla::Priority(metric));
+    msg.nlas.push(route::Nla::Oif(index));
+    msg
+}
+
+fn neighbour(index: u32, destination: Ipv4Addr, lladdr: [u8; 6]) -> NeighbourMessage {
+    let mut msg = NeighbourMessage::default();
+    msg.header.family = AF_INET as u8;
+    msg.header.ifindex = index;
+    msg.header.state = NUD_REACHABLE;
+    msg.nlas
+        .push(neighbour::Nla::Destination(destination.octets().to_vec()));
+    msg.nlas
+        .push(neighbour::Nla::LinkLocalAddress(lladdr.to_vec()));
+    msg
+}
+
+#[test]
+fn test_apply_link() {
+    let mut state = CacheState::default();
+
+    let eth0 = link(2, "eth0", true);
+    assert_eq!(
+        state.apply(MonitorEvent::LinkAdded(eth0.clone())),
+        vec![CacheEvent::Added(CacheObject::Link(eth0.clone()))]
+    );
+    assert_eq!(state.link(2), Some(&eth0));
+    assert_eq!(state.link_by_name("eth0"), Some(&eth0));
+
+    // A renamed link is no longer found under its old name
+    let wan = link(2, "wan", true);
+    assert_eq!(
+        state.apply(MonitorEvent::LinkChanged(wan.clone())),
+        vec![CacheEvent::Changed(CacheObject::Link(wan.clone()))]
+    );
+    assert_eq!(state.link_by_name("eth0"), None);
+    assert_eq!(state.link_by_name("wan"), Some(&wan));
+
+    assert_eq!(
+        state.apply(MonitorEvent::LinkRemoved(wan.clone())),
+        vec![CacheEvent::Removed(CacheObject::Link(wan))]
+    );
+    assert_eq!(state.link(2), None);
+    assert_eq!(state.link_by_name("wan"), None);
+    assert_eq!(state.apply(MonitorEvent::LinkRemoved(eth0)), vec![]);
+}
+
+#[test]
+fn test_apply_address() {
+    let mut state = CacheState::default();
+
+    let v4 = address(2, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), 24);
+    let v6 = address(
+        2,
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 1)),
+        64,
+    );
+    assert_eq!(
+        state.apply(MonitorEvent::AddressAdded(v4.clone())),
+        vec![CacheEvent::Added(CacheObject::Address(v4.clone()))]
+    );
+    assert_eq!(
+        state.apply(MonitorEvent::AddressAdded(v6.clone())),
+        vec![CacheEvent::Added(CacheObject::Address(v6.clone()))]
+    );
+    // The kernel announces refreshed lifetimes as new addresses
+    assert_eq!(
+        state.apply(MonitorEvent::AddressAdded(v4.clone())),
+        vec![CacheEvent::Changed(CacheObject::Address(v4.clone()))]
+    );
+    assert_eq!(state.addresses_by_index(2).count(), 2);
+    assert_eq!(state.addresses_by_index(3).count(), 0);
+
+    assert_eq!(
+        state.apply(MonitorEvent::AddressRemoved(v4.clone())),
+        vec![CacheEvent::Removed(CacheObject::Address(v4.clone()))]
+    );
+    assert_eq!(state.apply(MonitorEvent::AddressRemoved(v4)), vec![]);
+    assert_eq!(state.addresses().collect::<Vec<_>>(), vec![&v6]);
+}
+
+#[test]
+fn test_apply_route() {
+    let mut state = CacheState::default();
+
+    let default = route(2, IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0, 100);
+    let backup = route(3, IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0, 200);
+    let subnet = route(2, IpAddr::V4(Ipv4Addr::new(198, 51, 100, 0)), 24, 0);
+    for msg in [&default, &backup, &subnet] {
+        assert_eq!(
+            state.apply(MonitorEvent::RouteAdded(msg.clone())),
+            vec![CacheEvent::Added(CacheObject::Route(msg.clone()))]
+        );
+    }
+    assert_eq!(
+        state
+            .routes_by_prefix(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)
+            .collect::<Vec<_>>(),
+        vec![&default, &backup]
+    );
+
+    // Same route with another protocol: it is changed in place
+    let mut changed = default.clone();
+    changed.header.protocol = RTPROT_STATIC;
+    assert_eq!(
+        state.apply(MonitorEvent::RouteReplaced(changed.clone())),
+        vec![CacheEvent::Changed(CacheObject::Route(changed.clone()))]
+    );
+
+    // Same destination, table and metric through another interface: the
+    // previous route is gone
+    let replaced = route(4, IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0, 100);
+    assert_eq!(
+        state.apply(MonitorEvent::RouteReplaced(replaced.clone())),
+        vec![
+            CacheEvent::Removed(CacheObject::Route(changed)),
+            CacheEvent::Added(CacheObject::Route(replaced.clone())),
+        ]
+    );
+    assert_eq!(
+        state
+            .routes_by_prefix(IpAddr::V4(Ipv4Addr::UNSPECIFIED), 0)
+            .collect::<Vec<_>>(),
+        vec![&replaced, &backup]
+    );
+
+    assert_eq!(
+        state.apply(MonitorEvent::RouteRemoved(subnet.clone())),
+        vec![CacheEvent::Removed(CacheObject::Route(subnet.clone()))]
+    );
+    assert_eq!(state.apply(MonitorEvent::RouteRemoved(subnet)), vec![]);
+    assert_eq!(
+        state
+            .routes_by_prefix(IpAddr::V4(Ipv4Addr::new(198, 51, 100, 0)), 24)
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_apply_route_same_prefix_different_interfaces() {
+    let mut state = CacheState::default();
+
+    let link_local = IpAddr::V6(Ipv6Addr::new(0xfe80, 0, 0, 0, 0, 0, 0, 0));
+    let eth0 = route(2, link_local, 64, 256);
+    let eth1 = route(3, link_local, 64, 256);
+    for msg in [&eth0, &eth1] {
+        assert_eq!(
+            state.apply(MonitorEvent::RouteAdded(msg.clone())),
+            vec![CacheEvent::Added(CacheObject::Route(msg.clone()))]
+        );
+    }
+    assert_eq!(
+        state.routes_by_prefix(link_local, 64).collect::<Vec<_>>(),
+        vec![&eth0, &eth1]
+    );
+
+    assert_eq!(
+        state.apply(MonitorEvent::RouteRemoved(eth1.clone())),
+        vec![CacheEvent::Removed(CacheObject::Route(eth1))]
+    );
+    assert_eq!(state.routes().collect::<Vec<_>>(), vec![&eth0]);
+
+    // Routes with the same destination and metric through different
+    // gateways, as added with NLM_F_APPEND
+    let destination = IpAddr::V4(Ipv4Addr::new(203, 0, 113, 0));
+    let mut via_first = route(2, destination, 24, 0);
+    via_first.nlas.push(route::Nla::Gateway(vec![192, 0, 2, 1]));
+    let mut via_second = route(2, destination, 24, 0);
+    via_second
+        .nlas
+        .push(route::Nla::Gateway(vec![192, 0, 2, 2]));
+    for msg in [&via_first, &via_second] {
+        state.apply(MonitorEvent::RouteAdded(msg.clone()));
+    }
+    assert_eq!(
+        state.routes_by_prefix(destination, 24).collect::<Vec<_>>(),
+        vec![&via_first, &via_second]
+    );
+}
+
+#[test]
+fn test_apply_neighbour() {
+    let mut state = CacheState::default();
+    let destination = Ipv4Addr::new(192, 0, 2, 2);
+
+    let entry = neighbour(2, destination, [0x52, 0x54, 0, 0x12, 0x34, 0x56]);
+    assert_eq!(
+        state.apply(MonitorEvent::NeighbourAdded(entry.clone())),
+        vec![CacheEvent::Added(CacheObject::Neighbour(entry.clone()))]
+    );
+    let moved = neighbour(2, destination, [0x52, 0x54, 0, 0xab, 0xcd, 0xef]);
+    assert_eq!(
+        state.apply(MonitorEvent::NeighbourAdded(moved.clone())),
+        vec![CacheEvent::Changed(CacheObject::Neighbour(moved.clone()))]
+    );
+    assert_eq!(state.neighbour(2, IpAddr::V4(destination)), Some(&moved));
+    assert_eq!(state.neighbour(3, IpAddr::V4(destination)), None);
+
+    assert_eq!(
+        state.apply(MonitorEvent::NeighbourRemoved(entry)),
+        vec![CacheEvent::Removed(CacheObject::Neighbour(moved))]
+    );
+    assert_eq!(state.neighbours().count(), 0);
+}
+
+#[test]
+fn test_apply_link_down_and_removed() {
+    let mut state = CacheState::default();
+
+    let eth0 = link(2, "eth0", true);
+    let v4 = address(2, IpAddr::V4(Ipv4Addr::new(192, 0, 2, 1)), 24);
+    let route_v4 = route(2, IpAddr::V4(Ipv4Addr::new(198, 51, 100, 0)), 24, 0);
+    let route_v6 = route(
+        2,
+        IpAddr::V6(Ipv6Addr::new(0x2001, 0xdb8, 0, 0, 0, 0, 0, 0)),
+        64,
+        256,
+    );
+    let other = route(3, IpAddr::V4(Ipv4Addr::new(203, 0, 113, 0)), 24, 0);
+    let entry = neighbour(
+        2,
+        Ipv4Addr::new(192, 0, 2, 2),
+        [0x52, 0x54, 0, 0x12, 0x34, 0x56],
+    );
+    state.apply(MonitorEvent::LinkAdded(eth0));
+    state.apply(MonitorEvent::AddressAdded(v4.clone()));
+    for msg in [&route_v4, &route_v6, &other] {
+        state.apply(MonitorEvent::RouteAdded(msg.clone()));
+    }
+    state.apply(MonitorEvent::NeighbourAdded(entry.clone()));
+
+    // The kernel silently flushes the IPv4 routes through a link going down
+    let down = link(2, "eth0", false);
+    assert_eq!(
+        state.apply(MonitorEvent::LinkChanged(down.clone())),
+        vec![
+            CacheEvent::Changed(CacheObject::Link(down.clone())),
+            CacheEvent::Removed(CacheObject::Route(route_v4)),
+        ]
+    );
+    assert_eq!(state.routes().collect::<Vec<_>>(), vec![&other, &route_v6]);
+
+    // Everything attached to a removed link goes away with it
+    assert_eq!(
+        state.apply(MonitorEvent::LinkRemoved(down.clone())),
+        vec![
+            CacheEvent::Removed(CacheObject::Link(down)),
+            CacheEvent::Removed(CacheObject::Address(v4)),
+            CacheEvent::Removed(CacheObject::Route(route_v6)),
+            CacheEvent::Removed(CacheObject::Neighbour(entry)),
+        ]
+    );
+    assert_eq!(state.routes().collect::<Vec<_>>(), vec![&other]);
+    assert_eq!(state.addresses().count(), 0);
+    assert_eq!(state.neighbours().count(), 0);
+}
+
+#[test]
+fn test_apply_ignored() {
+    let mut state = CacheState::default();
+    assert_eq!(
+        state.apply(MonitorEvent::RuleAdded(Default::default())),
+        vec![]
+    );
+    assert_eq!(state.links().count(), 0);
+}
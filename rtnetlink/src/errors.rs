@@ -18,6 +18,12 @@ pub enum Error {
     #[error("Namespace error {0}")]
     NamespaceError(String),
 
+    #[error("Failed to set up a netlink socket: {0}")]
+    SocketError(String),
+
+    #[error("Invalid argument: {0}")]
+    InvalidArgument(String),
+
     #[error(
         "Received a link message (RTM_GETLINK, RTM_NEWLINK, RTM_SETLINK or RTMGETLINK) with an invalid hardware address attribute: {0:?}."
     )]
@@ -0,0 +1,180 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    neighbour::{FdbExtAttr, NeighbourMessage, Nla},
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to add a forwarding database entry to a bridge, bridge port or
+/// VXLAN device. This is equivalent to the `bridge fdb add`, `bridge fdb
+/// append` and `bridge fdb replace` commands.
+///
+/// Like `bridge fdb`, the entry is added to the device itself (`self`) unless
+/// [`master`](Self::master) is used, and is permanent unless another state is
+/// set.
+pub struct FdbAddRequest {
+    handle: Handle,
+    message: NeighbourMessage,
+    ext_attrs: Vec<FdbExtAttr>,
+    mode: u16,
+}
+
+impl FdbAddRequest {
+    pub(crate) fn new(handle: Handle, index: u32, lladdr: [u8; 6], mode: u16) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        message.header.state = NUD_NOARP | NUD_PERMANENT;
+        message.nlas.push(Nla::LinkLocalAddress(lladdr.to_vec()));
+
+        FdbAddRequest {
+            handle,
+            message,
+            ext_attrs: vec![],
+            mode,
+        }
+    }
+
+    /// Add the entry to the forwarding database of the bridge the link is enslaved to
+    pub fn master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
+    /// Add the entry to the forwarding database of the link itself. This is the default, unless
+    /// [`master`](Self::master) is used.
+    pub fn self_(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Make the entry permanent. The bridge treats it as a local address and does not forward
+    /// the matching traffic. This is the default.
+    pub fn permanent(mut self) -> Self {
+        self.message.header.state = NUD_NOARP | NUD_PERMANENT;
+        self
+    }
+
+    /// Make the entry static: it never ages out, and the matching traffic is forwarded
+    pub fn static_(mut self) -> Self {
+        self.message.header.state = NUD_NOARP | NUD_REACHABLE;
+        self
+    }
+
+    /// Make the entry dynamic: it ages out like a learned entry
+    pub fn dynamic(mut self) -> Self {
+        self.message.header.state = NUD_REACHABLE;
+        self
+    }
+
+    /// Mark the entry as learned by an external control plane, such as an EVPN controller
+    pub fn extern_learn(mut self) -> Self {
+        self.message.header.flags |= NTF_EXT_LEARNED;
+        self
+    }
+
+    /// Prevent the entry from moving to another port when the address is seen there
+    pub fn sticky(mut self) -> Self {
+        self.message.header.flags |= NTF_STICKY;
+        self
+    }
+
+    /// Only match the traffic of the given VLAN
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.message.nlas.push(Nla::Vlan(vlan));
+        self
+    }
+
+    /// Set the VXLAN network identifier used to reach the remote destination
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::Vni(vni));
+        self
+    }
+
+    /// Set the source VXLAN network identifier of the entry, for devices in collect metadata
+    /// mode
+    pub fn src_vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::SourceVni(vni));
+        self
+    }
+
+    /// Set the IP address of the remote VXLAN tunnel endpoint
+    pub fn dst(mut self, dst: IpAddr) -> Self {
+        let dst = match dst {
+            IpAddr::V4(v4) => v4.octets().to_vec(),
+            IpAddr::V6(v6) => v6.octets().to_vec(),
+        };
+        self.message.nlas.push(Nla::Destination(dst));
+        self
+    }
+
+    /// Set the UDP destination port of the remote VXLAN tunnel endpoint
+    pub fn port(mut self, port: u16) -> Self {
+        self.message
+            .nlas
+            .push(Nla::Port(port.to_be_bytes().to_vec()));
+        self
+    }
+
+    /// Reach the remote VXLAN tunnel endpoint through the link with the given index
+    pub fn via(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::IfIndex(index));
+        self
+    }
+
+    /// Reach the remote VXLAN tunnel endpoints through the given nexthop group
+    pub fn nhid(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::NhId(id));
+        self
+    }
+
+    /// Set the `FDB_NOTIFY_*` bits, to get notified when the entry becomes inactive
+    pub fn activity_notify(mut self, bits: u8) -> Self {
+        self.ext_attrs.push(FdbExtAttr::ActivityNotify(bits));
+        self
+    }
+
+    /// Do not mark the entry as active on this update
+    pub fn dont_refresh(mut self) -> Self {
+        self.ext_attrs.push(FdbExtAttr::DontRefresh);
+        self
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let FdbAddRequest {
+            mut handle,
+            mut message,
+            ext_attrs,
+            mode,
+        } = self;
+
+        if message.header.flags & (NTF_SELF | NTF_MASTER) == 0 {
+            message.header.flags |= NTF_SELF;
+        }
+        if !ext_attrs.is_empty() {
+            message.nlas.push(Nla::FdbExtAttrs(ext_attrs));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewNeighbour(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | mode | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    neighbour::{NeighbourMessage, Nla},
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+pub struct FdbDelRequest {
+    handle: Handle,
+    message: NeighbourMessage,
+}
+
+impl FdbDelRequest {
+    pub(crate) fn new(handle: Handle, index: u32, lladdr: [u8; 6]) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = index;
+        message.nlas.push(Nla::LinkLocalAddress(lladdr.to_vec()));
+
+        FdbDelRequest { handle, message }
+    }
+
+    /// Remove the entry from the forwarding database of the bridge the link is enslaved to
+    pub fn master(mut self) -> Self {
+        self.message.header.flags |= NTF_MASTER;
+        self
+    }
+
+    /// Remove the entry from the forwarding database of the link itself. This is the default,
+    /// unless [`master`](Self::master) is used.
+    pub fn self_(mut self) -> Self {
+        self.message.header.flags |= NTF_SELF;
+        self
+    }
+
+    /// Remove the entry of the given VLAN
+    pub fn vlan(mut self, vlan: u16) -> Self {
+        self.message.nlas.push(Nla::Vlan(vlan));
+        self
+    }
+
+    /// Only remove the remote destination with the given VXLAN network identifier
+    pub fn vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::Vni(vni));
+        self
+    }
+
+    /// Remove the entry with the given source VXLAN network identifier
+    pub fn src_vni(mut self, vni: u32) -> Self {
+        self.message.nlas.push(Nla::SourceVni(vni));
+        self
+    }
+
+    /// Only remove the given remote VXLAN tunnel endpoint from the entry
+    pub fn dst(mut self, dst: IpAddr) -> Self {
+        let dst = match dst {
+            IpAddr::V4(v4) => v4.octets().to_vec(),
+            IpAddr::V6(v6) => v6.octets().to_vec(),
+        };
+        self.message.nlas.push(Nla::Destination(dst));
+        self
+    }
+
+    /// Only remove the remote destination with the given UDP port
+    pub fn port(mut self, port: u16) -> Self {
+        self.message
+            .nlas
+            .push(Nla::Port(port.to_be_bytes().to_vec()));
+        self
+    }
+
+    /// Only remove the remote destination reached through the link with the given index
+    pub fn via(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::IfIndex(index));
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let FdbDelRequest {
+            mut handle,
+            mut message,
+        } = self;
+
+        if message.header.flags & (NTF_SELF | NTF_MASTER) == 0 {
+            message.header.flags |= NTF_SELF;
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelNeighbour(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(msg) = response.next().await {
+            try_nl!(msg);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+}
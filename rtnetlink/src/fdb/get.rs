@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream, TryStreamExt},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    constants::*,
+    neighbour::{NeighbourMessage, Nla},
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct FdbGetRequest {
+    handle: Handle,
+    message: NeighbourMessage,
+    bridge: Option<u32>,
+    port: Option<u32>,
+}
+
+impl FdbGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = NeighbourMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        FdbGetRequest {
+            handle,
+            message,
+            bridge: None,
+            port: None,
+        }
+    }
+
+    /// Only list the entries of the bridge with the given index, including the entries of the
+    /// bridge device itself (equivalent to `bridge fdb show br BRIDGE`)
+    pub fn bridge(mut self, index: u32) -> Self {
+        self.bridge = Some(index);
+        self
+    }
+
+    /// Only list the entries of the link with the given index (equivalent to
+    /// `bridge fdb show brport PORT`)
+    pub fn port(mut self, index: u32) -> Self {
+        self.port = Some(index);
+        self
+    }
+
+    /// Execute the request. VXLAN entries with several remote destinations
+    /// are reported as one message per destination.
+    pub fn execute(self) -> impl TryStream<Ok = NeighbourMessage, Error = Error> {
+        let FdbGetRequest {
+            mut handle,
+            mut message,
+            bridge,
+            port,
+        } = self;
+
+        // The kernel only honors these filters on sockets with strict
+        // checking enabled, so the entries are filtered here as well.
+        if let Some(index) = port {
+            message.header.ifindex = index;
+        }
+        if let Some(index) = bridge {
+            message.nlas.push(Nla::Master(index.to_ne_bytes().to_vec()));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNeighbour(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewNeighbour)))
+                    .try_filter(move |msg| future::ready(matches(msg, bridge, port))),
+            ),
+            Err(e) => Either::Right(future::err::<NeighbourMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NeighbourMessage {
+        &mut self.message
+    }
+}
+
+fn matches(msg: &NeighbourMessage, bridge: Option<u32>, port: Option<u32>) -> bool {
+    if port
+        .map(|index| msg.header.ifindex != index)
+        .unwrap_or(false)
+    {
+        return false;
+    }
+    match bridge {
+        Some(index) if msg.header.ifindex != index => msg
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, Nla::Master(master) if master[..] == index.to_ne_bytes())),
+        _ => true,
+    }
+}
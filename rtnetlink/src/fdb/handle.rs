@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::constants::*;
+
+use crate::{FdbAddRequest, FdbDelRequest, FdbGetRequest, Handle};
+
+pub struct FdbHandle(Handle);
+
+impl FdbHandle {
+    pub fn new(handle: Handle) -> Self {
+        FdbHandle(handle)
+    }
+
+    /// List the forwarding database entries of all the bridges, bridge ports and VXLAN devices
+    /// (equivalent to `bridge fdb show`)
+    pub fn get(&self) -> FdbGetRequest {
+        FdbGetRequest::new(self.0.clone())
+    }
+
+    /// Add a new entry for the given link layer address on the link with index `index`. This
+    /// fails if the entry already exists (equivalent to `bridge fdb add`).
+    pub fn add(&self, index: u32, lladdr: [u8; 6]) -> FdbAddRequest {
+        FdbAddRequest::new(self.0.clone(), index, lladdr, NLM_F_EXCL)
+    }
+
+    /// Add a remote destination to an entry of a VXLAN device, creating it if necessary
+    /// (equivalent to `bridge fdb append`). Use the all-zeros address to build the list of
+    /// remotes the broadcast, unknown unicast and multicast traffic is flooded to.
+    pub fn append(&self, index: u32, lladdr: [u8; 6]) -> FdbAddRequest {
+        FdbAddRequest::new(self.0.clone(), index, lladdr, NLM_F_APPEND)
+    }
+
+    /// Add a new entry, or replace the existing one (equivalent to `bridge fdb replace`)
+    pub fn replace(&self, index: u32, lladdr: [u8; 6]) -> FdbAddRequest {
+        FdbAddRequest::new(self.0.clone(), index, lladdr, NLM_F_REPLACE)
+    }
+
+    /// Delete an entry (equivalent to `bridge fdb del`). On VXLAN devices, set the destination
+    /// to only remove one remote from the entry.
+    pub fn del(&self, index: u32, lladdr: [u8; 6]) -> FdbDelRequest {
+        FdbDelRequest::new(self.0.clone(), index, lladdr)
+    }
+}
@@ -4,19 +4,31 @@ use futures::Stream;
 
 use crate::{
     packet::{NetlinkMessage, RtnlMessage},
+    AddrLabelHandle,
     AddressHandle,
+    BridgeVlanHandle,
     Error,
+    FdbHandle,
     LinkHandle,
+    MdbHandle,
+    MulticastRouteHandle,
     NeighbourHandle,
+    NetconfHandle,
+    NexthopHandle,
     QDiscHandle,
     RouteHandle,
     RuleHandle,
+    StatsHandle,
+    TrafficActionHandle,
     TrafficChainHandle,
     TrafficClassHandle,
     TrafficFilterHandle,
 };
 use netlink_proto::{sys::SocketAddr, ConnectionHandle};
 
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+use crate::{CacheRequest, MonitorRequest};
+
 #[derive(Clone, Debug)]
 pub struct Handle(ConnectionHandle<RtnlMessage>);
 
@@ -51,11 +63,23 @@ impl Handle {
         AddressHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for IPv6 address label requests (equivalent to
+    /// `ip addrlabel` commands)
+    pub fn addr_label(&self) -> AddrLabelHandle {
+        AddrLabelHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for routing table requests (equivalent to `ip route` commands)
     pub fn route(&self) -> RouteHandle {
         RouteHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for multicast forwarding cache requests (equivalent to
+    /// `ip mroute` commands)
+    pub fn multicast_route(&self) -> MulticastRouteHandle {
+        MulticastRouteHandle::new(self.clone())
+    }
+
     /// Create a new handle, specifically for routing rule requests (equivalent to `ip rule` commands)
     pub fn rule(&self) -> RuleHandle {
         RuleHandle::new(self.clone())
@@ -66,6 +90,56 @@ impl Handle {
         NeighbourHandle::new(self.clone())
     }
 
+    /// Create a new handle, specifically for nexthop object requests (equivalent to
+    /// `ip nexthop` commands)
+    pub fn nexthop(&self) -> NexthopHandle {
+        NexthopHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for per-interface configuration requests
+    /// (equivalent to `ip netconf` commands)
+    pub fn netconf(&self) -> NetconfHandle {
+        NetconfHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for link statistics requests (equivalent to
+    /// `ip stats` commands)
+    pub fn stats(&self) -> StatsHandle {
+        StatsHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for bridge multicast database requests (equivalent to
+    /// `bridge mdb` commands)
+    pub fn mdb(&self) -> MdbHandle {
+        MdbHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for bridge VLAN requests (equivalent to `bridge vlan`
+    /// commands)
+    pub fn bridge_vlan(&self) -> BridgeVlanHandle {
+        BridgeVlanHandle::new(self.clone())
+    }
+
+    /// Create a new handle, specifically for bridge forwarding database requests (equivalent to
+    /// `bridge fdb` commands)
+    pub fn fdb(&self) -> FdbHandle {
+        FdbHandle::new(self.clone())
+    }
+
+    /// Create a new request to subscribe to change notifications (equivalent to `ip monitor`
+    /// commands)
+    #[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+    pub fn monitor(&self) -> MonitorRequest {
+        MonitorRequest::new()
+    }
+
+    /// Create a new request to build a cache of links, addresses, routes and neighbours that
+    /// is kept up to date from change notifications
+    #[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+    pub fn cache(&self) -> CacheRequest {
+        CacheRequest::new(self.clone())
+    }
+
     /// Create a new handle, specifically for traffic control qdisc requests
     /// (equivalent to `tc qdisc show` commands)
     pub fn qdisc(&self) -> QDiscHandle {
@@ -89,4 +163,10 @@ impl Handle {
     pub fn traffic_chain(&self, ifindex: i32) -> TrafficChainHandle {
         TrafficChainHandle::new(self.clone(), ifindex)
     }
+
+    /// Create a new handle, specifically for standalone traffic control action requests
+    /// (equivalent to `tc actions` commands)
+    pub fn traffic_action(&self) -> TrafficActionHandle {
+        TrafficActionHandle::new(self.clone())
+    }
 }
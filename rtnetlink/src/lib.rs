@@ -35,6 +35,38 @@ pub use crate::traffic_control::*;
 mod neighbour;
 pub use crate::neighbour::*;
 
+mod nexthop;
+pub use crate::nexthop::*;
+
+mod netconf;
+pub use crate::netconf::*;
+
+mod stats;
+pub use crate::stats::*;
+
+mod mdb;
+pub use crate::mdb::*;
+
+mod bridge_vlan;
+pub use crate::bridge_vlan::*;
+
+mod fdb;
+pub use crate::fdb::*;
+
+mod addr_label;
+pub use crate::addr_label::*;
+
+mod multicast_route;
+pub use crate::multicast_route::*;
+
+mod monitor;
+pub use crate::monitor::*;
+
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+mod cache;
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+pub use crate::cache::*;
+
 pub mod constants;
 
 pub use netlink_packet_route as packet;
@@ -1,30 +1,41 @@
 // SPDX-License-Identifier: MIT
 
 use futures::stream::StreamExt;
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 use crate::{
     packet::{
         nlas::link::{
             Info,
+            InfoBareUdp,
             InfoBond,
             InfoData,
+            InfoGeneve,
+            InfoGre,
+            InfoIpTunnel,
             InfoKind,
+            InfoMacSec,
             InfoMacVlan,
             InfoVlan,
+            InfoVti,
             InfoVxlan,
+            InfoXfrm,
             Nla,
             VethInfo,
         },
         LinkMessage,
         NetlinkMessage,
         RtnlMessage,
+        GRE_CSUM,
+        GRE_KEY,
+        GRE_SEQ,
         IFF_UP,
         NLM_F_ACK,
         NLM_F_CREATE,
         NLM_F_EXCL,
         NLM_F_REPLACE,
         NLM_F_REQUEST,
+        SIT_ISATAP,
     },
     try_nl,
     Error,
@@ -493,6 +504,784 @@ impl VxlanAddRequest {
     }
 }
 
+/// A request to create a new GENEVE link.
+/// This is equivalent to `ip link add NAME type geneve id VNI ...` commands.
+pub struct GeneveAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoGeneve>,
+}
+
+impl GeneveAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let s = self
+            .request
+            .link_info(InfoKind::Geneve, Some(InfoData::Geneve(self.info_data)));
+        s.execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Sets the IPv4 address of the remote tunnel endpoint.
+    /// This is equivalent to `ip link add NAME type geneve id VNI remote ADDR`.
+    pub fn remote(mut self, addr: Ipv4Addr) -> Self {
+        self.info_data.push(InfoGeneve::Remote(addr));
+        self
+    }
+
+    /// Sets the IPv6 address of the remote tunnel endpoint.
+    /// This is equivalent to `ip link add NAME type geneve id VNI remote ADDR`.
+    pub fn remote6(mut self, addr: Ipv6Addr) -> Self {
+        self.info_data.push(InfoGeneve::Remote6(addr));
+        self
+    }
+
+    /// Sets the TTL of the sent packets.
+    /// This is equivalent to `ip link add NAME type geneve id VNI ttl TTL`.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoGeneve::Ttl(ttl));
+        self
+    }
+
+    /// Inherit the TTL of the sent packets from the inner packet.
+    /// This is equivalent to `ip link add NAME type geneve id VNI ttl inherit`.
+    pub fn ttl_inherit(mut self) -> Self {
+        self.info_data.push(InfoGeneve::TtlInherit(1));
+        self
+    }
+
+    /// Sets the TOS of the sent packets. 1 inherits it from the inner packet.
+    /// This is equivalent to `ip link add NAME type geneve id VNI tos TOS`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoGeneve::Tos(tos));
+        self
+    }
+
+    /// Sets the flow label of the packets sent over IPv6.
+    /// This is equivalent to `ip link add NAME type geneve id VNI flowlabel LABEL`.
+    pub fn label(mut self, label: u32) -> Self {
+        self.info_data.push(InfoGeneve::Label(label));
+        self
+    }
+
+    /// Sets the UDP destination port, 6081 by default.
+    /// This is equivalent to `ip link add NAME type geneve id VNI dstport PORT`.
+    pub fn port(mut self, port: u16) -> Self {
+        self.info_data.push(InfoGeneve::Port(port));
+        self
+    }
+
+    /// Let lightweight tunnel routes and BPF programs set the VNI and remote address, which
+    /// must then be left unset (the VNI given to [`LinkAddRequest::geneve`] must be 0).
+    /// This is equivalent to `ip link add NAME type geneve external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoGeneve::CollectMetadata);
+        self
+    }
+
+    /// Checksum the UDP packets sent over IPv4 or not.
+    /// This is equivalent to `ip link add NAME type geneve id VNI [no]udpcsum`.
+    pub fn udp_csum(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoGeneve::UdpCsum(enable as u8));
+        self
+    }
+
+    /// Don't checksum the UDP packets sent over IPv6.
+    /// This is equivalent to `ip link add NAME type geneve id VNI udp6zerocsumtx`.
+    pub fn udp_zero_csum6_tx(mut self, enable: bool) -> Self {
+        self.info_data
+            .push(InfoGeneve::UdpZeroCsum6Tx(enable as u8));
+        self
+    }
+
+    /// Accept the UDP packets received over IPv6 without checksum.
+    /// This is equivalent to `ip link add NAME type geneve id VNI udp6zerocsumrx`.
+    pub fn udp_zero_csum6_rx(mut self, enable: bool) -> Self {
+        self.info_data
+            .push(InfoGeneve::UdpZeroCsum6Rx(enable as u8));
+        self
+    }
+
+    /// Sets the DF bit (`GENEVE_DF_*`) of the packets sent over IPv4.
+    /// This is equivalent to `ip link add NAME type geneve id VNI df {unset|set|inherit}`.
+    pub fn df(mut self, df: u8) -> Self {
+        self.info_data.push(InfoGeneve::Df(df));
+        self
+    }
+
+    /// Carry layer 3 packets instead of ethernet frames, their protocol being given by the
+    /// GENEVE header. The link then has no ethernet header.
+    /// This is equivalent to `ip link add NAME type geneve id VNI innerprotoinherit`.
+    pub fn inner_proto_inherit(mut self) -> Self {
+        self.info_data.push(InfoGeneve::InnerProtoInherit);
+        self
+    }
+}
+
+/// A request to create a new BAREUDP link.
+/// This is equivalent to `ip link add NAME type bareudp dstport PORT ethertype PROTO ...`
+/// commands.
+pub struct BareUdpAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoBareUdp>,
+}
+
+impl BareUdpAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let s = self
+            .request
+            .link_info(InfoKind::BareUdp, Some(InfoData::BareUdp(self.info_data)));
+        s.execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Sets the lowest UDP source port of the sent packets.
+    /// This is equivalent to `ip link add NAME type bareudp ... srcportmin PORT`.
+    pub fn source_port_min(mut self, port: u16) -> Self {
+        self.info_data.push(InfoBareUdp::SourcePortMin(port));
+        self
+    }
+
+    /// Also carry multicast MPLS packets on an unicast MPLS link, or IPv6 packets on an IPv4
+    /// link.
+    /// This is equivalent to `ip link add NAME type bareudp ... multiproto`.
+    pub fn multiproto(mut self) -> Self {
+        self.info_data.push(InfoBareUdp::MultiprotoMode);
+        self
+    }
+}
+
+/// A request to create a new GRE, GRETAP, IP6GRE, IP6GRETAP, ERSPAN or IP6ERSPAN link.
+/// This is equivalent to `ip link add NAME type gre ...` commands.
+pub struct GreAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    data: fn(Vec<InfoGre>) -> InfoData,
+    info_data: Vec<InfoGre>,
+}
+
+impl GreAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let data = (self.data)(self.info_data);
+        self.request
+            .link_info(self.kind, Some(data))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    // Add GRE_* flags to the received and sent packets
+    fn flags(mut self, iflags: u16, oflags: u16) -> Self {
+        let (mut has_iflags, mut has_oflags) = (false, false);
+        for nla in self.info_data.iter_mut() {
+            match nla {
+                InfoGre::IFlags(value) => {
+                    *value |= iflags;
+                    has_iflags = true;
+                }
+                InfoGre::OFlags(value) => {
+                    *value |= oflags;
+                    has_oflags = true;
+                }
+                _ => {}
+            }
+        }
+        if !has_iflags && iflags != 0 {
+            self.info_data.push(InfoGre::IFlags(iflags));
+        }
+        if !has_oflags && oflags != 0 {
+            self.info_data.push(InfoGre::OFlags(oflags));
+        }
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add NAME type gre ... dev LINK`, but instead of
+    /// specifying a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoGre::Link(index));
+        self
+    }
+
+    /// Sets the local address of the tunnel, IPv4 for GRE, GRETAP and ERSPAN links and IPv6
+    /// for the others.
+    /// This is equivalent to `ip link add NAME type gre local ADDR`.
+    pub fn local(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoGre::Local(addr));
+        self
+    }
+
+    /// Sets the remote address of the tunnel.
+    /// This is equivalent to `ip link add NAME type gre remote ADDR`.
+    pub fn remote(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoGre::Remote(addr));
+        self
+    }
+
+    /// Sets the GRE key of the received and sent packets.
+    /// This is equivalent to `ip link add NAME type gre key KEY`.
+    pub fn key(self, key: u32) -> Self {
+        self.ikey(key).okey(key)
+    }
+
+    /// Sets the GRE key of the received packets.
+    /// This is equivalent to `ip link add NAME type gre ikey KEY`.
+    pub fn ikey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoGre::IKey(key));
+        self.flags(GRE_KEY, 0)
+    }
+
+    /// Sets the GRE key of the sent packets.
+    /// This is equivalent to `ip link add NAME type gre okey KEY`.
+    pub fn okey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoGre::OKey(key));
+        self.flags(0, GRE_KEY)
+    }
+
+    /// Number the sent packets and drop the received packets which are out of order.
+    /// This is equivalent to `ip link add NAME type gre seq`.
+    pub fn sequence(self) -> Self {
+        self.flags(GRE_SEQ, GRE_SEQ)
+    }
+
+    /// Checksum the sent packets and drop the received packets without a valid checksum.
+    /// This is equivalent to `ip link add NAME type gre csum`.
+    pub fn checksum(self) -> Self {
+        self.flags(GRE_CSUM, GRE_CSUM)
+    }
+
+    /// Sets the TTL, or hop limit, of the tunneled packets. 0 inherits it from the inner
+    /// packet.
+    /// This is equivalent to `ip link add NAME type gre ttl TTL`.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoGre::Ttl(ttl));
+        self
+    }
+
+    /// Sets the TOS of the tunneled packets. 1 inherits it from the inner packet.
+    /// This is equivalent to `ip link add NAME type gre tos TOS`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoGre::Tos(tos));
+        self
+    }
+
+    /// Enable or disable path MTU discovery on the tunnel.
+    /// This is equivalent to `ip link add NAME type gre [no]pmtudisc`.
+    pub fn pmtudisc(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoGre::PathMtuDiscovery(enable as u8));
+        self
+    }
+
+    /// Don't set the DF bit of the tunneled packets.
+    /// This is equivalent to `ip link add NAME type gretap ignore-df`.
+    pub fn ignore_df(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoGre::IgnoreDf(enable as u8));
+        self
+    }
+
+    /// Sets the tunnel encapsulation limit of an IPv6 tunnel.
+    /// This is equivalent to `ip link add NAME type ip6gre encaplimit LIMIT`.
+    pub fn encap_limit(mut self, limit: u8) -> Self {
+        self.info_data.push(InfoGre::EncapLimit(limit));
+        self
+    }
+
+    /// Sets the flow label and traffic class of an IPv6 tunnel.
+    pub fn flowinfo(mut self, flowinfo: u32) -> Self {
+        self.info_data.push(InfoGre::FlowInfo(flowinfo));
+        self
+    }
+
+    /// Sets the `IP6_TNL_F_*` flags of an IPv6 tunnel.
+    pub fn ip6_flags(mut self, flags: u32) -> Self {
+        self.info_data.push(InfoGre::Flags(flags));
+        self
+    }
+
+    /// Encapsulate the GRE packets in UDP (`TUNNEL_ENCAP_FOU` or `TUNNEL_ENCAP_GUE`).
+    /// This is equivalent to `ip link add NAME type gre encap fou encap-sport SPORT
+    /// encap-dport DPORT`. A source port of 0 lets the kernel choose it per flow.
+    pub fn encap(mut self, kind: u16, source_port: u16, destination_port: u16) -> Self {
+        self.info_data.push(InfoGre::EncapType(kind));
+        self.info_data.push(InfoGre::EncapSourcePort(source_port));
+        self.info_data
+            .push(InfoGre::EncapDestinationPort(destination_port));
+        self
+    }
+
+    /// Sets the `TUNNEL_ENCAP_FLAG_*` flags of the UDP encapsulation.
+    pub fn encap_flags(mut self, flags: u16) -> Self {
+        self.info_data.push(InfoGre::EncapFlags(flags));
+        self
+    }
+
+    /// Let lightweight tunnel routes and BPF programs set the remote address and key.
+    /// This is equivalent to `ip link add NAME type gretap external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoGre::CollectMetadata);
+        self
+    }
+
+    /// Sets the firewall mark of the tunneled packets.
+    /// This is equivalent to `ip link add NAME type gre fwmark MARK`.
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.info_data.push(InfoGre::FwMark(mark));
+        self
+    }
+
+    /// Sets the port index of an ERSPAN version 1 tunnel.
+    /// This is equivalent to `ip link add NAME type erspan erspan INDEX`.
+    pub fn erspan_index(mut self, index: u32) -> Self {
+        self.info_data.push(InfoGre::ErspanIndex(index));
+        self
+    }
+
+    /// Sets the ERSPAN version, 1 or 2.
+    /// This is equivalent to `ip link add NAME type erspan erspan_ver VERSION`.
+    pub fn erspan_version(mut self, version: u8) -> Self {
+        self.info_data.push(InfoGre::ErspanVersion(version));
+        self
+    }
+
+    /// Sets the direction (`ERSPAN_DIR_*`) of the traffic mirrored by an ERSPAN version 2
+    /// tunnel.
+    /// This is equivalent to `ip link add NAME type erspan erspan_dir DIR`.
+    pub fn erspan_direction(mut self, direction: u8) -> Self {
+        self.info_data.push(InfoGre::ErspanDirection(direction));
+        self
+    }
+
+    /// Sets the hardware id of an ERSPAN version 2 tunnel.
+    /// This is equivalent to `ip link add NAME type erspan erspan_hwid HWID`.
+    pub fn erspan_hwid(mut self, hwid: u16) -> Self {
+        self.info_data.push(InfoGre::ErspanHwid(hwid));
+        self
+    }
+}
+
+/// A request to create a new IPIP, SIT or IP6TNL link.
+/// This is equivalent to `ip link add NAME type ipip ...` commands.
+pub struct IpTunnelAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    data: fn(Vec<InfoIpTunnel>) -> InfoData,
+    info_data: Vec<InfoIpTunnel>,
+}
+
+impl IpTunnelAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let data = (self.data)(self.info_data);
+        self.request
+            .link_info(self.kind, Some(data))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add NAME type ipip ... dev LINK`, but instead of
+    /// specifying a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoIpTunnel::Link(index));
+        self
+    }
+
+    /// Sets the local address of the tunnel, IPv4 for IPIP and SIT links and IPv6 for IP6TNL
+    /// links.
+    /// This is equivalent to `ip link add NAME type ipip local ADDR`.
+    pub fn local(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoIpTunnel::Local(addr));
+        self
+    }
+
+    /// Sets the remote address of the tunnel.
+    /// This is equivalent to `ip link add NAME type ipip remote ADDR`.
+    pub fn remote(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoIpTunnel::Remote(addr));
+        self
+    }
+
+    /// Sets the TTL, or hop limit, of the tunneled packets. 0 inherits it from the inner
+    /// packet.
+    /// This is equivalent to `ip link add NAME type ipip ttl TTL`.
+    pub fn ttl(mut self, ttl: u8) -> Self {
+        self.info_data.push(InfoIpTunnel::Ttl(ttl));
+        self
+    }
+
+    /// Sets the TOS of the tunneled packets. 1 inherits it from the inner packet.
+    /// This is equivalent to `ip link add NAME type ipip tos TOS`.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.info_data.push(InfoIpTunnel::Tos(tos));
+        self
+    }
+
+    /// Enable or disable path MTU discovery on the tunnel.
+    /// This is equivalent to `ip link add NAME type ipip [no]pmtudisc`.
+    pub fn pmtudisc(mut self, enable: bool) -> Self {
+        self.info_data
+            .push(InfoIpTunnel::PathMtuDiscovery(enable as u8));
+        self
+    }
+
+    /// Sets the protocol of the tunneled packets: 41 (`IPPROTO_IPV6`) for the ip6ip (SIT) and
+    /// ip6ip6 (IP6TNL) modes, 4 (`IPPROTO_IPIP`) for the ipip (IPIP, SIT) and ipip6 (IP6TNL)
+    /// modes, 137 (`IPPROTO_MPLS`) for the mplsip mode and 0 for any of them.
+    /// This is equivalent to `ip link add NAME type ip6tnl mode MODE`.
+    pub fn proto(mut self, proto: u8) -> Self {
+        self.info_data.push(InfoIpTunnel::Protocol(proto));
+        self
+    }
+
+    /// Sets the tunnel encapsulation limit of an IP6TNL link.
+    /// This is equivalent to `ip link add NAME type ip6tnl encaplimit LIMIT`.
+    pub fn encap_limit(mut self, limit: u8) -> Self {
+        self.info_data.push(InfoIpTunnel::EncapLimit(limit));
+        self
+    }
+
+    /// Sets the flow label and traffic class of an IP6TNL link.
+    pub fn flowinfo(mut self, flowinfo: u32) -> Self {
+        self.info_data.push(InfoIpTunnel::FlowInfo(flowinfo));
+        self
+    }
+
+    /// Sets the `IP6_TNL_F_*` flags of an IP6TNL link.
+    pub fn ip6_flags(mut self, flags: u32) -> Self {
+        self.info_data.push(InfoIpTunnel::Flags(flags));
+        self
+    }
+
+    /// Make a SIT link an ISATAP (Intra-Site Automatic Tunnel Addressing Protocol) router.
+    /// This is equivalent to `ip link add NAME type sit isatap`.
+    pub fn isatap(mut self) -> Self {
+        self.info_data.push(InfoIpTunnel::SitFlags(SIT_ISATAP));
+        self
+    }
+
+    /// Sets the IPv6 rapid deployment (6rd) prefix and relay prefix of a SIT link.
+    /// This is equivalent to `ip link add NAME type sit 6rd-prefix PREFIX/PREFIX_LEN
+    /// 6rd-relay_prefix RELAY_PREFIX/RELAY_PREFIX_LEN`.
+    pub fn ip6rd(
+        mut self,
+        prefix: Ipv6Addr,
+        prefix_len: u16,
+        relay_prefix: Ipv4Addr,
+        relay_prefix_len: u16,
+    ) -> Self {
+        self.info_data.push(InfoIpTunnel::SixRdPrefix(prefix));
+        self.info_data
+            .push(InfoIpTunnel::SixRdPrefixLen(prefix_len));
+        self.info_data
+            .push(InfoIpTunnel::SixRdRelayPrefix(relay_prefix));
+        self.info_data
+            .push(InfoIpTunnel::SixRdRelayPrefixLen(relay_prefix_len));
+        self
+    }
+
+    /// Encapsulate the tunneled packets in UDP (`TUNNEL_ENCAP_FOU` or `TUNNEL_ENCAP_GUE`).
+    /// This is equivalent to `ip link add NAME type ipip encap fou encap-sport SPORT
+    /// encap-dport DPORT`. A source port of 0 lets the kernel choose it per flow.
+    pub fn encap(mut self, kind: u16, source_port: u16, destination_port: u16) -> Self {
+        self.info_data.push(InfoIpTunnel::EncapType(kind));
+        self.info_data
+            .push(InfoIpTunnel::EncapSourcePort(source_port));
+        self.info_data
+            .push(InfoIpTunnel::EncapDestinationPort(destination_port));
+        self
+    }
+
+    /// Sets the `TUNNEL_ENCAP_FLAG_*` flags of the UDP encapsulation.
+    pub fn encap_flags(mut self, flags: u16) -> Self {
+        self.info_data.push(InfoIpTunnel::EncapFlags(flags));
+        self
+    }
+
+    /// Let lightweight tunnel routes and BPF programs set the remote address.
+    /// This is equivalent to `ip link add NAME type ipip external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoIpTunnel::CollectMetadata);
+        self
+    }
+
+    /// Sets the firewall mark of the tunneled packets.
+    /// This is equivalent to `ip link add NAME type ipip fwmark MARK`.
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.info_data.push(InfoIpTunnel::FwMark(mark));
+        self
+    }
+}
+
+/// A request to create a new VTI or VTI6 link.
+/// This is equivalent to `ip link add NAME type vti ...` commands.
+pub struct VtiAddRequest {
+    request: LinkAddRequest,
+    kind: InfoKind,
+    data: fn(Vec<InfoVti>) -> InfoData,
+    info_data: Vec<InfoVti>,
+}
+
+impl VtiAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let data = (self.data)(self.info_data);
+        self.request
+            .link_info(self.kind, Some(data))
+            .execute()
+            .await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the tunnel
+    /// This is equivalent to `ip link add NAME type vti ... dev LINK`, but instead of
+    /// specifying a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoVti::Link(index));
+        self
+    }
+
+    /// Sets the local address of the tunnel, IPv4 for VTI links and IPv6 for VTI6 links.
+    /// This is equivalent to `ip link add NAME type vti local ADDR`.
+    pub fn local(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoVti::Local(addr));
+        self
+    }
+
+    /// Sets the remote address of the tunnel.
+    /// This is equivalent to `ip link add NAME type vti remote ADDR`.
+    pub fn remote(mut self, addr: IpAddr) -> Self {
+        self.info_data.push(InfoVti::Remote(addr));
+        self
+    }
+
+    /// Sets the key of the received and sent packets, the mark of the IPsec policies
+    /// protecting the tunnel.
+    /// This is equivalent to `ip link add NAME type vti key KEY`.
+    pub fn key(self, key: u32) -> Self {
+        self.ikey(key).okey(key)
+    }
+
+    /// Sets the key of the received packets.
+    /// This is equivalent to `ip link add NAME type vti ikey KEY`.
+    pub fn ikey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoVti::IKey(key));
+        self
+    }
+
+    /// Sets the key of the sent packets.
+    /// This is equivalent to `ip link add NAME type vti okey KEY`.
+    pub fn okey(mut self, key: u32) -> Self {
+        self.info_data.push(InfoVti::OKey(key));
+        self
+    }
+
+    /// Sets the firewall mark of the tunneled packets.
+    /// This is equivalent to `ip link add NAME type vti fwmark MARK`.
+    pub fn fwmark(mut self, mark: u32) -> Self {
+        self.info_data.push(InfoVti::FwMark(mark));
+        self
+    }
+}
+
+/// A request to create a new XFRM link.
+/// This is equivalent to `ip link add NAME type xfrm ...` commands.
+pub struct XfrmAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoXfrm>,
+}
+
+impl XfrmAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let s = self
+            .request
+            .link_info(InfoKind::Xfrm, Some(InfoData::Xfrm(self.info_data)));
+        s.execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Adds the `dev` attribute to the interface
+    /// This is equivalent to `ip link add NAME type xfrm dev LINK`, but instead of
+    /// specifying a link name (`LINK`), we specify a link index.
+    pub fn link(mut self, index: u32) -> Self {
+        self.info_data.push(InfoXfrm::Link(index));
+        self
+    }
+
+    /// Sets the id of the interface, which the IPsec states and policies routing through it
+    /// are created with. It must not be 0.
+    /// This is equivalent to `ip link add NAME type xfrm if_id IF_ID`.
+    pub fn if_id(mut self, if_id: u32) -> Self {
+        self.info_data.push(InfoXfrm::IfId(if_id));
+        self
+    }
+
+    /// Let lightweight tunnel routes and BPF programs set the interface id. Neither a link
+    /// nor an interface id can be given then.
+    /// This is equivalent to `ip link add NAME type xfrm external`.
+    pub fn collect_metadata(mut self) -> Self {
+        self.info_data.push(InfoXfrm::CollectMetadata);
+        self
+    }
+}
+
+/// A request to create a new MACSEC link.
+/// This is equivalent to `ip link add link LINK NAME type macsec ...` commands.
+pub struct MacSecAddRequest {
+    request: LinkAddRequest,
+    info_data: Vec<InfoMacSec>,
+}
+
+impl MacSecAddRequest {
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let s = self
+            .request
+            .link_info(InfoKind::MacSec, Some(InfoData::MacSec(self.info_data)));
+        s.execute().await
+    }
+
+    /// Sets the interface up
+    /// This is equivalent to `ip link set up dev NAME`.
+    pub fn up(mut self) -> Self {
+        self.request = self.request.up();
+        self
+    }
+
+    /// Sets the secure channel identifier of the transmit secure channel, the MAC address
+    /// followed by the port (e.g. `0x5254_0012_3456_0001`).
+    /// This is equivalent to `ip link add link LINK NAME type macsec sci SCI`.
+    pub fn sci(mut self, sci: u64) -> Self {
+        self.info_data.push(InfoMacSec::Sci(sci));
+        self
+    }
+
+    /// Sets the port of the SCI, the MAC address of the underlying link being used.
+    /// This is equivalent to `ip link add link LINK NAME type macsec port PORT`.
+    pub fn port(mut self, port: u16) -> Self {
+        self.info_data.push(InfoMacSec::Port(port));
+        self
+    }
+
+    /// Sets the `MACSEC_CIPHER_ID_*` cipher suite.
+    /// This is equivalent to `ip link add link LINK NAME type macsec cipher CIPHER`.
+    pub fn cipher_suite(mut self, cipher_suite: u64) -> Self {
+        self.info_data.push(InfoMacSec::CipherSuite(cipher_suite));
+        self
+    }
+
+    /// Sets the length of the integrity check value, 16 bytes by default.
+    /// This is equivalent to `ip link add link LINK NAME type macsec icvlen LEN`.
+    pub fn icv_len(mut self, icv_len: u8) -> Self {
+        self.info_data.push(InfoMacSec::IcvLen(icv_len));
+        self
+    }
+
+    /// Sets the association number of the secure association used to send the packets.
+    /// This is equivalent to `ip link add link LINK NAME type macsec encodingsa AN`.
+    pub fn encoding_sa(mut self, an: u8) -> Self {
+        self.info_data.push(InfoMacSec::EncodingSa(an));
+        self
+    }
+
+    /// Encrypt the sent packets or only authenticate them.
+    /// This is equivalent to `ip link add link LINK NAME type macsec encrypt {on|off}`.
+    pub fn encrypt(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoMacSec::Encrypt(enable as u8));
+        self
+    }
+
+    /// Protect the sent packets or send them in clear.
+    /// This is equivalent to `ip link add link LINK NAME type macsec protect {on|off}`.
+    pub fn protect(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoMacSec::Protect(enable as u8));
+        self
+    }
+
+    /// Include the SCI in the sent packets.
+    /// This is equivalent to `ip link add link LINK NAME type macsec send_sci {on|off}`.
+    pub fn send_sci(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoMacSec::IncSci(enable as u8));
+        self
+    }
+
+    /// Sets the end station bit of the sent packets.
+    /// This is equivalent to `ip link add link LINK NAME type macsec end_station {on|off}`.
+    pub fn end_station(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoMacSec::Es(enable as u8));
+        self
+    }
+
+    /// Sets the single copy broadcast bit of the sent packets.
+    /// This is equivalent to `ip link add link LINK NAME type macsec scb {on|off}`.
+    pub fn scb(mut self, enable: bool) -> Self {
+        self.info_data.push(InfoMacSec::Scb(enable as u8));
+        self
+    }
+
+    /// Drop the received packets which are more than `window` packets out of order.
+    /// This is equivalent to `ip link add link LINK NAME type macsec replay on window
+    /// WINDOW`.
+    pub fn replay_protect(mut self, window: u32) -> Self {
+        self.info_data.push(InfoMacSec::ReplayProtect(1));
+        self.info_data.push(InfoMacSec::Window(window));
+        self
+    }
+
+    /// Sets the `MACSEC_VALIDATE_*` validation of the received packets.
+    /// This is equivalent to `ip link add link LINK NAME type macsec validate
+    /// {strict|check|disabled}`.
+    pub fn validation(mut self, validation: u8) -> Self {
+        self.info_data.push(InfoMacSec::Validation(validation));
+        self
+    }
+
+    /// Sets the `MACSEC_OFFLOAD_*` offload of the link.
+    /// This is equivalent to `ip link add link LINK NAME type macsec offload {off|phy|mac}`.
+    pub fn offload(mut self, offload: u8) -> Self {
+        self.info_data.push(InfoMacSec::Offload(offload));
+        self
+    }
+}
+
 /// A request to create a new link. This is equivalent to the `ip link add` commands.
 ///
 /// A few methods for common actions (creating a veth pair, creating a vlan interface, etc.) are
@@ -622,6 +1411,164 @@ impl LinkAddRequest {
         }
     }
 
+    /// Create a GENEVE link.
+    /// This is equivalent to `ip link add NAME type geneve id VNI`, it returns a
+    /// GeneveAddRequest to further customize the link.
+    pub fn geneve(self, name: String, vni: u32) -> GeneveAddRequest {
+        GeneveAddRequest {
+            request: self.name(name),
+            info_data: vec![InfoGeneve::Id(vni)],
+        }
+    }
+
+    /// Create a BAREUDP link, which tunnels the packets of the `ethertype` protocol (e.g.
+    /// `ETH_P_MPLS_UC`, `ETH_P_IP`) to the `port` UDP port, without any additional header.
+    /// This is equivalent to `ip link add NAME type bareudp dstport PORT ethertype PROTO`.
+    pub fn bareudp(self, name: String, port: u16, ethertype: u16) -> BareUdpAddRequest {
+        BareUdpAddRequest {
+            request: self.name(name),
+            info_data: vec![InfoBareUdp::Port(port), InfoBareUdp::EtherType(ethertype)],
+        }
+    }
+
+    fn gre_link(
+        self,
+        name: String,
+        kind: InfoKind,
+        data: fn(Vec<InfoGre>) -> InfoData,
+    ) -> GreAddRequest {
+        GreAddRequest {
+            request: self.name(name),
+            kind,
+            data,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a layer 3 GRE tunnel over IPv4.
+    /// This is equivalent to `ip link add NAME type gre`, it returns a GreAddRequest to
+    /// further customize the tunnel.
+    pub fn gre(self, name: String) -> GreAddRequest {
+        self.gre_link(name, InfoKind::GreTun, InfoData::GreTun)
+    }
+
+    /// Create an ethernet GRE tunnel over IPv4.
+    /// This is equivalent to `ip link add NAME type gretap`.
+    pub fn gretap(self, name: String) -> GreAddRequest {
+        self.gre_link(name, InfoKind::GreTap, InfoData::GreTap)
+    }
+
+    /// Create a layer 3 GRE tunnel over IPv6.
+    /// This is equivalent to `ip link add NAME type ip6gre`.
+    pub fn ip6gre(self, name: String) -> GreAddRequest {
+        self.gre_link(name, InfoKind::GreTun6, InfoData::GreTun6)
+    }
+
+    /// Create an ethernet GRE tunnel over IPv6.
+    /// This is equivalent to `ip link add NAME type ip6gretap`.
+    pub fn ip6gretap(self, name: String) -> GreAddRequest {
+        self.gre_link(name, InfoKind::GreTap6, InfoData::GreTap6)
+    }
+
+    /// Create an ERSPAN tunnel over IPv4, to mirror traffic to a remote analyzer. The
+    /// ERSPAN session id is given by [`GreAddRequest::key`].
+    /// This is equivalent to `ip link add NAME type erspan seq key KEY`.
+    pub fn erspan(self, name: String) -> GreAddRequest {
+        self.gre_link(name, InfoKind::Erspan, InfoData::Erspan)
+            .sequence()
+    }
+
+    /// Create an ERSPAN tunnel over IPv6.
+    /// This is equivalent to `ip link add NAME type ip6erspan seq key KEY`.
+    pub fn ip6erspan(self, name: String) -> GreAddRequest {
+        self.gre_link(name, InfoKind::Ip6Erspan, InfoData::Ip6Erspan)
+            .sequence()
+    }
+
+    fn ip_tunnel_link(
+        self,
+        name: String,
+        kind: InfoKind,
+        data: fn(Vec<InfoIpTunnel>) -> InfoData,
+    ) -> IpTunnelAddRequest {
+        IpTunnelAddRequest {
+            request: self.name(name),
+            kind,
+            data,
+            info_data: vec![],
+        }
+    }
+
+    /// Create an IPv4 over IPv4 tunnel.
+    /// This is equivalent to `ip link add NAME type ipip`, it returns an IpTunnelAddRequest
+    /// to further customize the tunnel.
+    pub fn ipip(self, name: String) -> IpTunnelAddRequest {
+        self.ip_tunnel_link(name, InfoKind::IpTun, InfoData::IpTun)
+    }
+
+    /// Create an IPv6 (or IPv4, or MPLS) over IPv4 tunnel, optionally an ISATAP or 6rd one.
+    /// This is equivalent to `ip link add NAME type sit`.
+    pub fn sit(self, name: String) -> IpTunnelAddRequest {
+        self.ip_tunnel_link(name, InfoKind::SitTun, InfoData::SitTun)
+    }
+
+    /// Create an IPv6 or IPv4 over IPv6 tunnel, see [`IpTunnelAddRequest::proto`] for the
+    /// ip6ip6 and ipip6 modes.
+    /// This is equivalent to `ip link add NAME type ip6tnl`.
+    pub fn ip6tnl(self, name: String) -> IpTunnelAddRequest {
+        self.ip_tunnel_link(name, InfoKind::IpTun6, InfoData::IpTun6)
+    }
+
+    fn vti_link(
+        self,
+        name: String,
+        kind: InfoKind,
+        data: fn(Vec<InfoVti>) -> InfoData,
+    ) -> VtiAddRequest {
+        VtiAddRequest {
+            request: self.name(name),
+            kind,
+            data,
+            info_data: vec![],
+        }
+    }
+
+    /// Create a virtual tunnel interface over IPv4, the route based end of IPsec tunnels.
+    /// This is equivalent to `ip link add NAME type vti`, it returns a VtiAddRequest to
+    /// further customize the tunnel.
+    pub fn vti(self, name: String) -> VtiAddRequest {
+        self.vti_link(name, InfoKind::Vti, InfoData::Vti)
+    }
+
+    /// Create a virtual tunnel interface over IPv6.
+    /// This is equivalent to `ip link add NAME type vti6`.
+    pub fn vti6(self, name: String) -> VtiAddRequest {
+        self.vti_link(name, InfoKind::Vti6, InfoData::Vti6)
+    }
+
+    /// Create an XFRM interface, which sends its packets through the IPsec states and
+    /// policies of its interface id.
+    /// This is equivalent to `ip link add NAME type xfrm`, it returns an XfrmAddRequest to
+    /// further customize the interface.
+    pub fn xfrm(self, name: String) -> XfrmAddRequest {
+        XfrmAddRequest {
+            request: self.name(name),
+            info_data: vec![],
+        }
+    }
+
+    /// Create a MACSEC link on a link. Its secure channels and associations are then
+    /// managed over the `macsec` generic netlink family.
+    /// This is equivalent to `ip link add link LINK NAME type macsec`, but instead of
+    /// specifying a link name (`LINK`), we specify a link index. It returns a
+    /// MacSecAddRequest to further customize the link.
+    pub fn macsec(self, name: String, index: u32) -> MacSecAddRequest {
+        MacSecAddRequest {
+            request: self.name(name).append_nla(Nla::Link(index)),
+            info_data: vec![],
+        }
+    }
+
     /// Create a new bond.
     /// This is equivalent to `ip link add link NAME type bond`.
     pub fn bond(self, name: String) -> BondAddRequest {
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::mdb::{MdbEntry, MdbGroup, MdbSetAttr, Nla},
+    MdbMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to add a port to a multicast group of a bridge. This is
+/// equivalent to the `bridge mdb add` command.
+pub struct MdbAddRequest {
+    handle: Handle,
+    message: MdbMessage,
+    entry: MdbEntry,
+    attrs: Vec<MdbSetAttr>,
+    replace: bool,
+}
+
+impl MdbAddRequest {
+    pub(crate) fn new(handle: Handle, bridge: u32, port: u32, group: MdbGroup) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge;
+
+        MdbAddRequest {
+            handle,
+            message,
+            entry: MdbEntry {
+                ifindex: port,
+                state: MDB_TEMPORARY,
+                group,
+                ..Default::default()
+            },
+            attrs: vec![],
+            replace: false,
+        }
+    }
+
+    /// Only add the port to the group in the given VLAN
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.entry.vid = vid;
+        self
+    }
+
+    /// Make the entry permanent: it does not expire and must be removed explicitly
+    pub fn permanent(mut self) -> Self {
+        self.entry.state = MDB_PERMANENT;
+        self
+    }
+
+    /// Make the entry temporary: it expires unless refreshed by the multicast
+    /// membership reports. This is the default.
+    pub fn temporary(mut self) -> Self {
+        self.entry.state = MDB_TEMPORARY;
+        self
+    }
+
+    /// Only forward the traffic sent by `source` (an (S, G) entry)
+    pub fn source(mut self, source: IpAddr) -> Self {
+        self.attrs.push(MdbSetAttr::Source(source));
+        self
+    }
+
+    /// Set the source list of a (*, G) entry. The sources are included or
+    /// excluded depending on the filter mode.
+    pub fn source_list(mut self, sources: Vec<IpAddr>) -> Self {
+        self.attrs.push(MdbSetAttr::SourceList(sources));
+        self
+    }
+
+    /// Only forward the traffic sent by the sources of the source list
+    pub fn include(mut self) -> Self {
+        self.attrs.push(MdbSetAttr::GroupMode(MCAST_INCLUDE));
+        self
+    }
+
+    /// Forward the traffic sent by all but the sources of the source list
+    pub fn exclude(mut self) -> Self {
+        self.attrs.push(MdbSetAttr::GroupMode(MCAST_EXCLUDE));
+        self
+    }
+
+    /// Sets the protocol (`RTPROT_*`) of the entry creator
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.attrs.push(MdbSetAttr::Protocol(protocol));
+        self
+    }
+
+    /// Replace the entry if it already exists
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let MdbAddRequest {
+            mut handle,
+            mut message,
+            entry,
+            attrs,
+            replace,
+        } = self;
+
+        message.nlas.push(Nla::SetEntry(entry));
+        if !attrs.is_empty() {
+            message.nlas.push(Nla::SetEntryAttrs(attrs));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewMdb(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
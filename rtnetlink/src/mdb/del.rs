@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::mdb::{MdbEntry, MdbGroup, MdbSetAttr, Nla},
+    MdbMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+pub struct MdbDelRequest {
+    handle: Handle,
+    message: MdbMessage,
+    entry: MdbEntry,
+    attrs: Vec<MdbSetAttr>,
+}
+
+impl MdbDelRequest {
+    pub(crate) fn new(handle: Handle, bridge: u32, port: u32, group: MdbGroup) -> Self {
+        let mut message = MdbMessage::default();
+        message.header.family = AF_BRIDGE as u8;
+        message.header.ifindex = bridge;
+
+        MdbDelRequest {
+            handle,
+            message,
+            entry: MdbEntry {
+                ifindex: port,
+                group,
+                ..Default::default()
+            },
+            attrs: vec![],
+        }
+    }
+
+    /// Remove the entry of the given VLAN
+    pub fn vid(mut self, vid: u16) -> Self {
+        self.entry.vid = vid;
+        self
+    }
+
+    /// Remove the (S, G) entry of the given source
+    pub fn source(mut self, source: IpAddr) -> Self {
+        self.attrs.push(MdbSetAttr::Source(source));
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let MdbDelRequest {
+            mut handle,
+            mut message,
+            entry,
+            attrs,
+        } = self;
+
+        message.nlas.push(Nla::SetEntry(entry));
+        if !attrs.is_empty() {
+            message.nlas.push(Nla::SetEntryAttrs(attrs));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelMdb(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(msg) = response.next().await {
+            try_nl!(msg);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut MdbMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::nlas::mdb::MdbGroup;
+
+use crate::{Handle, MdbAddRequest, MdbDelRequest, MdbGetRequest};
+
+pub struct MdbHandle(Handle);
+
+impl MdbHandle {
+    pub fn new(handle: Handle) -> Self {
+        MdbHandle(handle)
+    }
+
+    /// Retrieve the multicast database of all the bridges (equivalent to `bridge mdb show`)
+    pub fn get(&self) -> MdbGetRequest {
+        MdbGetRequest::new(self.0.clone())
+    }
+
+    /// Add `port` to the multicast `group` on the bridge with index `bridge` (equivalent to
+    /// `bridge mdb add dev BRIDGE port PORT grp GROUP`). Use the index of the bridge itself as
+    /// `port` for a host join.
+    pub fn add(&self, bridge: u32, port: u32, group: MdbGroup) -> MdbAddRequest {
+        MdbAddRequest::new(self.0.clone(), bridge, port, group)
+    }
+
+    /// Remove `port` from the multicast `group` on the bridge with index `bridge` (equivalent
+    /// to `bridge mdb del dev BRIDGE port PORT grp GROUP`)
+    pub fn del(&self, bridge: u32, port: u32, group: MdbGroup) -> MdbDelRequest {
+        MdbDelRequest::new(self.0.clone(), bridge, port, group)
+    }
+}
@@ -0,0 +1,164 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::{
+    constants::*,
+    AddressMessage,
+    BridgeVlanMessage,
+    CacheReportMessage,
+    LinkMessage,
+    MdbMessage,
+    NdUserOptMessage,
+    NeighbourMessage,
+    NetconfMessage,
+    NetlinkMessage,
+    NetlinkPayload,
+    NexthopMessage,
+    NsidMessage,
+    PrefixMessage,
+    RouteMessage,
+    RtnlMessage,
+    RuleMessage,
+    TcMessage,
+};
+
+/// The kernel sets the whole change mask when it announces a link for the
+/// first time, i.e. right after it has been registered.
+const IFI_CHANGE_NEW: u32 = 0xffff_ffff;
+
+/// A change notification received from the kernel
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum MonitorEvent {
+    /// A link has been created
+    LinkAdded(LinkMessage),
+    /// An existing link has been modified
+    LinkChanged(LinkMessage),
+    /// A link has been deleted
+    LinkRemoved(LinkMessage),
+    /// An address has been added. The kernel also sends this notification
+    /// when the lifetimes of an existing address are refreshed.
+    AddressAdded(AddressMessage),
+    /// An address has been removed
+    AddressRemoved(AddressMessage),
+    /// A route has been added
+    RouteAdded(RouteMessage),
+    /// An existing route has been replaced (`NLM_F_REPLACE` notification)
+    RouteReplaced(RouteMessage),
+    /// A route has been removed
+    RouteRemoved(RouteMessage),
+    /// A neighbour entry has been added, or its state has changed. The kernel
+    /// does not tell these two cases apart.
+    NeighbourAdded(NeighbourMessage),
+    /// A neighbour entry has been removed
+    NeighbourRemoved(NeighbourMessage),
+    /// A routing rule has been added
+    RuleAdded(RuleMessage),
+    /// A routing rule has been removed
+    RuleRemoved(RuleMessage),
+    /// A qdisc has been added or changed
+    QdiscAdded(TcMessage),
+    /// A qdisc has been removed
+    QdiscRemoved(TcMessage),
+    /// A traffic class has been added or changed
+    TrafficClassAdded(TcMessage),
+    /// A traffic class has been removed
+    TrafficClassRemoved(TcMessage),
+    /// A traffic filter has been added or changed
+    TrafficFilterAdded(TcMessage),
+    /// A traffic filter has been removed
+    TrafficFilterRemoved(TcMessage),
+    /// A traffic chain has been added
+    TrafficChainAdded(TcMessage),
+    /// A traffic chain has been removed
+    TrafficChainRemoved(TcMessage),
+    /// A network namespace id has been assigned
+    NsIdAdded(NsidMessage),
+    /// A network namespace id has been released
+    NsIdRemoved(NsidMessage),
+    /// A nexthop object has been added
+    NexthopAdded(NexthopMessage),
+    /// An existing nexthop object has been replaced (`NLM_F_REPLACE` notification)
+    NexthopReplaced(NexthopMessage),
+    /// A nexthop object has been removed
+    NexthopRemoved(NexthopMessage),
+    /// The configuration of an interface has changed, or an interface has been
+    /// created. Only the attributes that changed may be present.
+    NetconfChanged(NetconfMessage),
+    /// An interface and its configuration have been removed
+    NetconfRemoved(NetconfMessage),
+    /// A port has joined a multicast group of a bridge, or a router port has
+    /// been added
+    MdbAdded(MdbMessage),
+    /// A port has left a multicast group of a bridge, or a router port has
+    /// been removed
+    MdbRemoved(MdbMessage),
+    /// VLANs have been added to a bridge or bridge port, or their options
+    /// have changed
+    BridgeVlanAdded(BridgeVlanMessage),
+    /// VLANs have been removed from a bridge or bridge port
+    BridgeVlanRemoved(BridgeVlanMessage),
+    /// An IPv6 prefix has been received in a router advertisement
+    PrefixAdded(PrefixMessage),
+    /// Neighbour discovery options the kernel does not handle itself, such
+    /// as RDNSS and DNSSL, have been received in a router advertisement
+    NdUserOpt(NdUserOptMessage),
+    /// The multicast routing code needs the attention of the multicast
+    /// routing daemon, e.g. because a packet matched no forwarding cache
+    /// entry (`IGMPMSG_NOCACHE`, `MRT6MSG_NOCACHE`)
+    CacheReport(CacheReportMessage),
+    /// Any other rtnetlink notification
+    Other(RtnlMessage),
+}
+
+impl MonitorEvent {
+    /// Turn a netlink message received on a multicast group into an event.
+    /// Messages that do not carry an rtnetlink payload (acks, errors, etc.)
+    /// are ignored and `None` is returned.
+    pub fn from_message(message: NetlinkMessage<RtnlMessage>) -> Option<Self> {
+        let (header, payload) = message.into_parts();
+        let message = match payload {
+            NetlinkPayload::InnerMessage(message) => message,
+            _ => return None,
+        };
+        let replace = header.flags & NLM_F_REPLACE != 0;
+
+        use self::MonitorEvent::*;
+        let event = match message {
+            RtnlMessage::NewLink(msg) if msg.header.change_mask == IFI_CHANGE_NEW => LinkAdded(msg),
+            RtnlMessage::NewLink(msg) => LinkChanged(msg),
+            RtnlMessage::DelLink(msg) => LinkRemoved(msg),
+            RtnlMessage::NewAddress(msg) => AddressAdded(msg),
+            RtnlMessage::DelAddress(msg) => AddressRemoved(msg),
+            RtnlMessage::NewRoute(msg) if replace => RouteReplaced(msg),
+            RtnlMessage::NewRoute(msg) => RouteAdded(msg),
+            RtnlMessage::DelRoute(msg) => RouteRemoved(msg),
+            RtnlMessage::NewNeighbour(msg) => NeighbourAdded(msg),
+            RtnlMessage::DelNeighbour(msg) => NeighbourRemoved(msg),
+            RtnlMessage::NewRule(msg) => RuleAdded(msg),
+            RtnlMessage::DelRule(msg) => RuleRemoved(msg),
+            RtnlMessage::NewQueueDiscipline(msg) => QdiscAdded(msg),
+            RtnlMessage::DelQueueDiscipline(msg) => QdiscRemoved(msg),
+            RtnlMessage::NewTrafficClass(msg) => TrafficClassAdded(msg),
+            RtnlMessage::DelTrafficClass(msg) => TrafficClassRemoved(msg),
+            RtnlMessage::NewTrafficFilter(msg) => TrafficFilterAdded(msg),
+            RtnlMessage::DelTrafficFilter(msg) => TrafficFilterRemoved(msg),
+            RtnlMessage::NewTrafficChain(msg) => TrafficChainAdded(msg),
+            RtnlMessage::DelTrafficChain(msg) => TrafficChainRemoved(msg),
+            RtnlMessage::NewNsId(msg) => NsIdAdded(msg),
+            RtnlMessage::DelNsId(msg) => NsIdRemoved(msg),
+            RtnlMessage::NewNexthop(msg) if replace => NexthopReplaced(msg),
+            RtnlMessage::NewNexthop(msg) => NexthopAdded(msg),
+            RtnlMessage::DelNexthop(msg) => NexthopRemoved(msg),
+            RtnlMessage::NewNetconf(msg) => NetconfChanged(msg),
+            RtnlMessage::DelNetconf(msg) => NetconfRemoved(msg),
+            RtnlMessage::NewMdb(msg) => MdbAdded(msg),
+            RtnlMessage::DelMdb(msg) => MdbRemoved(msg),
+            RtnlMessage::NewBridgeVlan(msg) => BridgeVlanAdded(msg),
+            RtnlMessage::DelBridgeVlan(msg) => BridgeVlanRemoved(msg),
+            RtnlMessage::NewPrefix(msg) => PrefixAdded(msg),
+            RtnlMessage::NewNdUserOpt(msg) => NdUserOpt(msg),
+            RtnlMessage::NewCacheReport(msg) => CacheReport(msg),
+            msg => Other(msg),
+        };
+        Some(event)
+    }
+}
@@ -0,0 +1,90 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::constants::*;
+
+/// Multicast group a monitor can subscribe to
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum MonitorGroup {
+    /// Link notifications (`RTNLGRP_LINK`)
+    Link,
+    /// Neighbour notifications (`RTNLGRP_NEIGH`)
+    Neighbour,
+    /// Traffic control notifications: qdiscs, classes, filters and chains (`RTNLGRP_TC`)
+    TrafficControl,
+    /// IPv4 address notifications (`RTNLGRP_IPV4_IFADDR`)
+    Ipv4Address,
+    /// IPv6 address notifications (`RTNLGRP_IPV6_IFADDR`)
+    Ipv6Address,
+    /// IPv4 route notifications (`RTNLGRP_IPV4_ROUTE`)
+    Ipv4Route,
+    /// IPv6 route notifications (`RTNLGRP_IPV6_ROUTE`)
+    Ipv6Route,
+    /// MPLS route notifications (`RTNLGRP_MPLS_ROUTE`)
+    MplsRoute,
+    /// IPv4 rule notifications (`RTNLGRP_IPV4_RULE`)
+    Ipv4Rule,
+    /// IPv6 rule notifications (`RTNLGRP_IPV6_RULE`)
+    Ipv6Rule,
+    /// Network namespace id notifications (`RTNLGRP_NSID`)
+    NsId,
+    /// Nexthop object notifications (`RTNLGRP_NEXTHOP`)
+    Nexthop,
+    /// IPv4 per-interface configuration notifications (`RTNLGRP_IPV4_NETCONF`)
+    Ipv4Netconf,
+    /// IPv6 per-interface configuration notifications (`RTNLGRP_IPV6_NETCONF`)
+    Ipv6Netconf,
+    /// MPLS per-interface configuration notifications (`RTNLGRP_MPLS_NETCONF`)
+    MplsNetconf,
+    /// Bridge multicast database notifications (`RTNLGRP_MDB`)
+    Mdb,
+    /// Bridge VLAN notifications (`RTNLGRP_BRVLAN`)
+    BridgeVlan,
+    /// IPv6 prefixes learnt from router advertisements (`RTNLGRP_IPV6_PREFIX`)
+    Ipv6Prefix,
+    /// Neighbour discovery options relayed to userspace (`RTNLGRP_ND_USEROPT`)
+    NdUserOpt,
+    /// IPv4 multicast forwarding cache notifications (`RTNLGRP_IPV4_MROUTE`)
+    Ipv4Mroute,
+    /// IPv6 multicast forwarding cache notifications (`RTNLGRP_IPV6_MROUTE`)
+    Ipv6Mroute,
+    /// IPv4 multicast routing cache reports (`RTNLGRP_IPV4_MROUTE_R`)
+    Ipv4MrouteReport,
+    /// IPv6 multicast routing cache reports (`RTNLGRP_IPV6_MROUTE_R`)
+    Ipv6MrouteReport,
+    /// Any other `RTNLGRP_*` group
+    Other(u32),
+}
+
+impl MonitorGroup {
+    /// Return the `RTNLGRP_*` number of this group, as expected by
+    /// `NETLINK_ADD_MEMBERSHIP`
+    pub fn group_id(&self) -> u32 {
+        use self::MonitorGroup::*;
+        match self {
+            Link => RTNLGRP_LINK,
+            Neighbour => RTNLGRP_NEIGH,
+            TrafficControl => RTNLGRP_TC,
+            Ipv4Address => RTNLGRP_IPV4_IFADDR,
+            Ipv6Address => RTNLGRP_IPV6_IFADDR,
+            Ipv4Route => RTNLGRP_IPV4_ROUTE,
+            Ipv6Route => RTNLGRP_IPV6_ROUTE,
+            MplsRoute => RTNLGRP_MPLS_ROUTE,
+            Ipv4Rule => RTNLGRP_IPV4_RULE,
+            Ipv6Rule => RTNLGRP_IPV6_RULE,
+            NsId => RTNLGRP_NSID,
+            Nexthop => RTNLGRP_NEXTHOP,
+            Ipv4Netconf => RTNLGRP_IPV4_NETCONF,
+            Ipv6Netconf => RTNLGRP_IPV6_NETCONF,
+            MplsNetconf => RTNLGRP_MPLS_NETCONF,
+            Mdb => RTNLGRP_MDB,
+            BridgeVlan => RTNLGRP_BRVLAN,
+            Ipv6Prefix => RTNLGRP_IPV6_PREFIX,
+            NdUserOpt => RTNLGRP_ND_USEROPT,
+            Ipv4Mroute => RTNLGRP_IPV4_MROUTE,
+            Ipv6Mroute => RTNLGRP_IPV6_MROUTE,
+            Ipv4MrouteReport => RTNLGRP_IPV4_MROUTE_R,
+            Ipv6MrouteReport => RTNLGRP_IPV6_MROUTE_R,
+            Other(group) => *group,
+        }
+    }
+}
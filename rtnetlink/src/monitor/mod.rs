@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: MIT
+
+mod group;
+pub use self::group::*;
+
+mod event;
+pub use self::event::*;
+
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+mod request;
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+pub use self::request::*;
+
+#[cfg(test)]
+mod test;
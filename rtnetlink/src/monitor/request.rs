@@ -0,0 +1,213 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, AbortHandle},
+    FutureExt,
+    Stream,
+    StreamExt,
+};
+
+use netlink_packet_route::RtnlMessage;
+use netlink_proto::sys::{protocols::NETLINK_ROUTE, AsyncSocket};
+
+use crate::{Error, MonitorEvent, MonitorGroup};
+
+// if "only" smol or smol+tokio were enabled, we use smol because
+// it doesn't require an active tokio runtime - just to be sure.
+#[cfg(feature = "smol_socket")]
+type MonitorSocket = netlink_proto::sys::SmolSocket;
+
+#[cfg(all(not(feature = "smol_socket"), feature = "tokio_socket"))]
+type MonitorSocket = netlink_proto::sys::TokioSocket;
+
+#[cfg(feature = "smol_socket")]
+pub(crate) fn spawn<F>(fut: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    async_global_executor::spawn(fut).detach();
+}
+
+#[cfg(all(not(feature = "smol_socket"), feature = "tokio_socket"))]
+pub(crate) fn spawn<F>(fut: F)
+where
+    F: std::future::Future<Output = ()> + Send + 'static,
+{
+    tokio::spawn(fut);
+}
+
+/// Stop the connection of the monitoring socket along with the event stream.
+/// Otherwise the connection only notices that the stream is gone when the
+/// next notification arrives, which may never happen.
+struct ConnectionGuard(AbortHandle);
+
+impl Drop for ConnectionGuard {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Subscribe to rtnetlink notifications (equivalent to `ip monitor`).
+///
+/// Multicast group membership belongs to a socket, so the monitor opens and
+/// drives its own netlink connection. The returned stream ends when that
+/// socket fails, for instance when the kernel reports an overrun (`ENOBUFS`)
+/// because events were not consumed fast enough.
+pub struct MonitorRequest {
+    groups: Vec<MonitorGroup>,
+}
+
+impl MonitorRequest {
+    pub(crate) fn new() -> Self {
+        MonitorRequest { groups: vec![] }
+    }
+
+    /// Subscribe to the given multicast group
+    pub fn group(mut self, group: MonitorGroup) -> Self {
+        if !self.groups.contains(&group) {
+            self.groups.push(group);
+        }
+        self
+    }
+
+    /// Subscribe to link notifications (equivalent to `ip monitor link`)
+    pub fn link(self) -> Self {
+        self.group(MonitorGroup::Link)
+    }
+
+    /// Subscribe to IPv4 and IPv6 address notifications (equivalent to `ip monitor address`)
+    pub fn address(self) -> Self {
+        self.group(MonitorGroup::Ipv4Address)
+            .group(MonitorGroup::Ipv6Address)
+    }
+
+    /// Subscribe to IPv4 and IPv6 route notifications (equivalent to `ip monitor route`)
+    pub fn route(self) -> Self {
+        self.group(MonitorGroup::Ipv4Route)
+            .group(MonitorGroup::Ipv6Route)
+    }
+
+    /// Subscribe to neighbour notifications (equivalent to `ip monitor neigh`)
+    pub fn neighbour(self) -> Self {
+        self.group(MonitorGroup::Neighbour)
+    }
+
+    /// Subscribe to IPv4 and IPv6 rule notifications (equivalent to `ip monitor rule`)
+    pub fn rule(self) -> Self {
+        self.group(MonitorGroup::Ipv4Rule)
+            .group(MonitorGroup::Ipv6Rule)
+    }
+
+    /// Subscribe to qdisc, class, filter and chain notifications (equivalent to `tc monitor`)
+    pub fn qdisc(self) -> Self {
+        self.group(MonitorGroup::TrafficControl)
+    }
+
+    /// Subscribe to network namespace id notifications (equivalent to `ip monitor nsid`)
+    pub fn nsid(self) -> Self {
+        self.group(MonitorGroup::NsId)
+    }
+
+    /// Subscribe to nexthop object notifications (equivalent to `ip monitor nexthop`)
+    pub fn nexthop(self) -> Self {
+        self.group(MonitorGroup::Nexthop)
+    }
+
+    /// Subscribe to IPv4, IPv6 and MPLS per-interface configuration notifications (equivalent
+    /// to `ip monitor netconf`)
+    pub fn netconf(self) -> Self {
+        self.group(MonitorGroup::Ipv4Netconf)
+            .group(MonitorGroup::Ipv6Netconf)
+            .group(MonitorGroup::MplsNetconf)
+    }
+
+    /// Subscribe to bridge multicast database notifications (equivalent to `bridge monitor mdb`)
+    pub fn mdb(self) -> Self {
+        self.group(MonitorGroup::Mdb)
+    }
+
+    /// Subscribe to bridge VLAN notifications (equivalent to `bridge monitor vlan`)
+    pub fn bridge_vlan(self) -> Self {
+        self.group(MonitorGroup::BridgeVlan)
+    }
+
+    /// Subscribe to the IPv6 prefixes received in router advertisements
+    /// (equivalent to `ip monitor prefix`)
+    pub fn prefix(self) -> Self {
+        self.group(MonitorGroup::Ipv6Prefix)
+    }
+
+    /// Subscribe to the neighbour discovery options received in router
+    /// advertisements, e.g. the DNS servers (RDNSS) and search list (DNSSL)
+    pub fn nd_user_opt(self) -> Self {
+        self.group(MonitorGroup::NdUserOpt)
+    }
+
+    /// Subscribe to IPv4 and IPv6 multicast forwarding cache notifications (equivalent to
+    /// `ip monitor mroute`). The entries are reported as routes of the `RTNL_FAMILY_IPMR` and
+    /// `RTNL_FAMILY_IP6MR` families.
+    pub fn mroute(self) -> Self {
+        self.group(MonitorGroup::Ipv4Mroute)
+            .group(MonitorGroup::Ipv6Mroute)
+    }
+
+    /// Subscribe to the IPv4 and IPv6 multicast routing cache reports. The kernel only sends them
+    /// while a multicast routing daemon has initialized the multicast routing socket of the
+    /// table. This group is not part of [`MonitorRequest::all`].
+    pub fn mroute_report(self) -> Self {
+        self.group(MonitorGroup::Ipv4MrouteReport)
+            .group(MonitorGroup::Ipv6MrouteReport)
+    }
+
+    /// Subscribe to all the groups above, except the multicast routing cache reports (equivalent
+    /// to `ip monitor all`)
+    pub fn all(self) -> Self {
+        self.link()
+            .address()
+            .route()
+            .neighbour()
+            .rule()
+            .qdisc()
+            .nsid()
+            .nexthop()
+            .netconf()
+            .mdb()
+            .bridge_vlan()
+            .prefix()
+            .nd_user_opt()
+            .mroute()
+    }
+
+    /// Open the monitoring socket, join the requested groups and return the
+    /// stream of events.
+    pub fn execute(self) -> Result<impl Stream<Item = MonitorEvent>, Error> {
+        let (mut conn, _, messages) =
+            netlink_proto::new_connection_with_socket::<RtnlMessage, MonitorSocket>(NETLINK_ROUTE)
+                .map_err(|e| Error::SocketError(e.to_string()))?;
+
+        // The kernel does not deliver multicast messages to sockets that
+        // still have port id 0, so bind before joining the groups.
+        let socket = conn.socket_mut().socket_mut();
+        socket
+            .bind_auto()
+            .map_err(|e| Error::SocketError(e.to_string()))?;
+        for group in self.groups {
+            socket
+                .add_membership(group.group_id())
+                .map_err(|e| Error::SocketError(e.to_string()))?;
+        }
+        let (conn, abort_handle) = future::abortable(conn);
+        spawn(conn.map(|_| ()));
+        let guard = ConnectionGuard(abort_handle);
+
+        Ok(messages.filter_map(move |(message, _)| {
+            let _guard = &guard;
+            future::ready(MonitorEvent::from_message(message))
+        }))
+    }
+
+    /// Return the groups this request subscribes to
+    pub fn groups(&self) -> &[MonitorGroup] {
+        &self.groups
+    }
+}
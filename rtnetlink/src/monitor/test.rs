@@ -0,0 +1,133 @@
+// SPDX-License-Identifier: MIT
+
+use netlink_packet_route::{
+    constants::*,
+    AddressMessage,
+    ErrorMessage,
+    LinkMessage,
+    NetlinkMessage,
+    NetlinkPayload,
+    NexthopMessage,
+    NsidMessage,
+    RouteMessage,
+    RtnlMessage,
+};
+
+use super::MonitorEvent;
+
+fn notification(message: RtnlMessage, flags: u16) -> NetlinkMessage<RtnlMessage> {
+    let mut message = NetlinkMessage::from(message);
+    message.header.flags = flags;
+    message
+}
+
+fn event(message: RtnlMessage, flags: u16) -> MonitorEvent {
+    MonitorEvent::from_message(notification(message, flags)).unwrap()
+}
+
+#[test]
+fn test_route_events() {
+    let route = RouteMessage::default();
+    // `ip route add` notifications carry NLM_F_CREATE and NLM_F_EXCL, `ip route append` only
+    // NLM_F_CREATE and kernel generated routes no flag at all
+    for flags in [0, NLM_F_CREATE, NLM_F_CREATE | NLM_F_EXCL, NLM_F_APPEND] {
+        assert_eq!(
+            event(RtnlMessage::NewRoute(route.clone()), flags),
+            MonitorEvent::RouteAdded(route.clone())
+        );
+    }
+    // `ip route replace` of an existing route, or `ip route change`
+    for flags in [NLM_F_REPLACE, NLM_F_CREATE | NLM_F_REPLACE] {
+        assert_eq!(
+            event(RtnlMessage::NewRoute(route.clone()), flags),
+            MonitorEvent::RouteReplaced(route.clone())
+        );
+    }
+    for flags in [0, NLM_F_REPLACE] {
+        assert_eq!(
+            event(RtnlMessage::DelRoute(route.clone()), flags),
+            MonitorEvent::RouteRemoved(route.clone())
+        );
+    }
+}
+
+#[test]
+fn test_nexthop_events() {
+    let nexthop = NexthopMessage::default();
+    for flags in [0, NLM_F_CREATE, NLM_F_CREATE | NLM_F_EXCL] {
+        assert_eq!(
+            event(RtnlMessage::NewNexthop(nexthop.clone()), flags),
+            MonitorEvent::NexthopAdded(nexthop.clone())
+        );
+    }
+    for flags in [NLM_F_REPLACE, NLM_F_CREATE | NLM_F_REPLACE] {
+        assert_eq!(
+            event(RtnlMessage::NewNexthop(nexthop.clone()), flags),
+            MonitorEvent::NexthopReplaced(nexthop.clone())
+        );
+    }
+    assert_eq!(
+        event(RtnlMessage::DelNexthop(nexthop.clone()), NLM_F_REPLACE),
+        MonitorEvent::NexthopRemoved(nexthop)
+    );
+}
+
+#[test]
+fn test_link_events() {
+    let mut link = LinkMessage::default();
+    link.header.change_mask = 0xffff_ffff;
+    // The change mask tells a new link apart, whatever the flags
+    for flags in [0, NLM_F_CREATE, NLM_F_REPLACE] {
+        assert_eq!(
+            event(RtnlMessage::NewLink(link.clone()), flags),
+            MonitorEvent::LinkAdded(link.clone())
+        );
+    }
+    link.header.change_mask = IFF_UP;
+    for flags in [0, NLM_F_CREATE, NLM_F_REPLACE] {
+        assert_eq!(
+            event(RtnlMessage::NewLink(link.clone()), flags),
+            MonitorEvent::LinkChanged(link.clone())
+        );
+    }
+    assert_eq!(
+        event(RtnlMessage::DelLink(link.clone()), 0),
+        MonitorEvent::LinkRemoved(link)
+    );
+}
+
+#[test]
+fn test_address_events() {
+    let address = AddressMessage::default();
+    // The kernel does not tell a new address apart from refreshed lifetimes
+    for flags in [0, NLM_F_CREATE, NLM_F_REPLACE, NLM_F_CREATE | NLM_F_REPLACE] {
+        assert_eq!(
+            event(RtnlMessage::NewAddress(address.clone()), flags),
+            MonitorEvent::AddressAdded(address.clone())
+        );
+    }
+    assert_eq!(
+        event(RtnlMessage::DelAddress(address.clone()), 0),
+        MonitorEvent::AddressRemoved(address)
+    );
+}
+
+#[test]
+fn test_other_messages() {
+    let nsid = NsidMessage::default();
+    assert_eq!(
+        event(RtnlMessage::NewNsId(nsid.clone()), NLM_F_REPLACE),
+        MonitorEvent::NsIdAdded(nsid)
+    );
+    let get = RtnlMessage::GetLink(LinkMessage::default());
+    assert_eq!(event(get.clone(), 0), MonitorEvent::Other(get));
+
+    let error = ErrorMessage {
+        code: -2,
+        header: vec![],
+    };
+    let message = NetlinkMessage::new(Default::default(), NetlinkPayload::Error(error));
+    assert_eq!(MonitorEvent::from_message(message), None);
+    let message = NetlinkMessage::new(Default::default(), NetlinkPayload::Done);
+    assert_eq!(MonitorEvent::from_message(message), None);
+}
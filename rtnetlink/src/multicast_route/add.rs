@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::Ipv4Addr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::{NextHop, NextHopFlags, Nla},
+    NetlinkMessage,
+    RouteMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+// TTL threshold of the virtual interfaces the traffic is not forwarded to
+const TTL_DISABLED: u8 = 255;
+
+/// A request to add or update an IPv4 multicast forwarding cache entry. The
+/// kernel updates the entry if it already exists.
+///
+/// The input and output interfaces must have been registered as virtual
+/// interfaces (VIFs) by the multicast routing daemon beforehand.
+pub struct MulticastRouteAddRequest {
+    handle: Handle,
+    message: RouteMessage,
+    ttls: Vec<u8>,
+}
+
+impl MulticastRouteAddRequest {
+    pub(crate) fn new(handle: Handle, source: Ipv4Addr, group: Ipv4Addr) -> Self {
+        let mut message = RouteMessage::default();
+        message.header.address_family = RTNL_FAMILY_IPMR;
+        message.header.table = RT_TABLE_DEFAULT;
+        message.header.protocol = RTPROT_STATIC;
+        message.header.scope = RT_SCOPE_UNIVERSE;
+        message.header.kind = RTN_MULTICAST;
+        message.header.source_prefix_length = 32;
+        message.header.destination_prefix_length = 32;
+        message.nlas.push(Nla::Source(source.octets().to_vec()));
+        message.nlas.push(Nla::Destination(group.octets().to_vec()));
+
+        MulticastRouteAddRequest {
+            handle,
+            message,
+            ttls: vec![],
+        }
+    }
+
+    /// Sets the index of the interface the traffic is expected on.
+    pub fn input_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Iif(index));
+        self
+    }
+
+    /// Forward the traffic to the virtual interface `vif`, if the TTL of the
+    /// packets is greater than `ttl`.
+    ///
+    /// Note that outputs are identified by their VIF number, while the
+    /// entries returned by [`MulticastRouteHandle::get`] report the index of
+    /// the interfaces instead.
+    ///
+    /// [`MulticastRouteHandle::get`]: crate::MulticastRouteHandle::get
+    pub fn output(mut self, vif: u16, ttl: u8) -> Self {
+        let vif = vif as usize;
+        if vif < MAXVIFS {
+            if self.ttls.len() <= vif {
+                self.ttls.resize(vif + 1, TTL_DISABLED);
+            }
+            self.ttls[vif] = ttl;
+        }
+        self
+    }
+
+    /// Sets the multicast routing table. The default table is used
+    /// otherwise.
+    pub fn table(mut self, table: u32) -> Self {
+        self.message.nlas.push(Nla::Table(table));
+        self
+    }
+
+    /// Sets the protocol of the entry, either `RTPROT_STATIC` or
+    /// `RTPROT_MROUTED`. Entries added with `RTPROT_MROUTED` belong to the
+    /// multicast routing daemon and are removed when its socket is closed.
+    ///
+    /// Default is static route protocol.
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let MulticastRouteAddRequest {
+            mut handle,
+            mut message,
+            ttls,
+        } = self;
+
+        // The kernel reads the TTL threshold of each virtual interface from
+        // the `rtnh_hops` field of the nexthop at the same position
+        if !ttls.is_empty() {
+            let nexthops = ttls
+                .into_iter()
+                .map(|ttl| NextHop {
+                    flags: NextHopFlags::empty(),
+                    hops: ttl,
+                    interface_id: 0,
+                    nlas: vec![],
+                })
+                .collect();
+            message.nlas.push(Nla::multipath(nexthops));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewRoute(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | NLM_F_CREATE | NLM_F_REPLACE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message. The outputs are
+    /// added to the message when the request is executed.
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+}
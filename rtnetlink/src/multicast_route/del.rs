@@ -0,0 +1,63 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::Ipv4Addr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::Nla,
+    NetlinkMessage,
+    RouteMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+pub struct MulticastRouteDelRequest {
+    handle: Handle,
+    message: RouteMessage,
+}
+
+impl MulticastRouteDelRequest {
+    pub(crate) fn new(handle: Handle, source: Ipv4Addr, group: Ipv4Addr) -> Self {
+        let mut message = RouteMessage::default();
+        message.header.address_family = RTNL_FAMILY_IPMR;
+        message.header.table = RT_TABLE_DEFAULT;
+        message.header.protocol = RTPROT_STATIC;
+        message.header.scope = RT_SCOPE_UNIVERSE;
+        message.header.kind = RTN_MULTICAST;
+        message.header.source_prefix_length = 32;
+        message.header.destination_prefix_length = 32;
+        message.nlas.push(Nla::Source(source.octets().to_vec()));
+        message.nlas.push(Nla::Destination(group.octets().to_vec()));
+
+        MulticastRouteDelRequest { handle, message }
+    }
+
+    /// Sets the multicast routing table of the entry.
+    pub fn table(mut self, table: u32) -> Self {
+        self.message.nlas.push(Nla::Table(table));
+        self
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let MulticastRouteDelRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelRoute(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK;
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,148 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream, TryStreamExt},
+    FutureExt,
+};
+use std::{
+    convert::TryFrom,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+};
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::{MfcStats, Nla},
+    NetlinkMessage,
+    RouteMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle, IpVersion};
+
+/// An interface a multicast forwarding cache entry forwards the traffic to
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MulticastRouteOutput {
+    /// Index of the interface
+    pub index: u32,
+    /// Only the packets with a TTL greater than this threshold are forwarded
+    pub ttl: u8,
+}
+
+/// A multicast forwarding cache entry
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct MulticastRoute {
+    /// Source of the traffic, unspecified for `(*, G)` entries
+    pub source: IpAddr,
+    pub group: IpAddr,
+    pub table: u32,
+    /// Index of the interface the traffic is expected on. Unresolved
+    /// entries, i.e. the packets waiting for the multicast routing daemon to
+    /// add an entry, have no input interface.
+    pub input_interface: Option<u32>,
+    pub outputs: Vec<MulticastRouteOutput>,
+    pub stats: Option<MfcStats>,
+    /// Time since the entry was last used, in hundredths of seconds
+    pub last_use: Option<u64>,
+}
+
+fn parse_address(family: u8, bytes: &[u8]) -> Result<IpAddr, Error> {
+    match (family, bytes.len()) {
+        (RTNL_FAMILY_IPMR, 4) => Ok(IpAddr::V4(Ipv4Addr::new(
+            bytes[0], bytes[1], bytes[2], bytes[3],
+        ))),
+        (RTNL_FAMILY_IP6MR, 16) => {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(bytes);
+            Ok(IpAddr::V6(Ipv6Addr::from(octets)))
+        }
+        _ => Err(Error::InvalidIp(bytes.to_vec())),
+    }
+}
+
+impl TryFrom<RouteMessage> for MulticastRoute {
+    type Error = Error;
+
+    fn try_from(message: RouteMessage) -> Result<Self, Self::Error> {
+        let family = message.header.address_family;
+        let unspecified = match family {
+            RTNL_FAMILY_IPMR => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            RTNL_FAMILY_IP6MR => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            _ => {
+                return Err(Error::UnexpectedMessage(NetlinkMessage::from(
+                    RtnlMessage::NewRoute(message),
+                )))
+            }
+        };
+        let mut route = MulticastRoute {
+            source: unspecified,
+            group: unspecified,
+            table: message.header.table as u32,
+            input_interface: None,
+            outputs: vec![],
+            stats: message.mfc_stats(),
+            last_use: None,
+        };
+        if let Some(nexthops) = message.multipath() {
+            route.outputs = nexthops
+                .iter()
+                .map(|nexthop| MulticastRouteOutput {
+                    index: nexthop.interface_id,
+                    ttl: nexthop.hops,
+                })
+                .collect();
+        }
+        for nla in message.nlas {
+            match nla {
+                Nla::Source(bytes) => route.source = parse_address(family, &bytes)?,
+                Nla::Destination(bytes) => route.group = parse_address(family, &bytes)?,
+                Nla::Table(table) => route.table = table,
+                Nla::Iif(index) => route.input_interface = Some(index),
+                Nla::Expires(bytes) => {
+                    route.last_use = <[u8; 8]>::try_from(&bytes[..]).ok().map(u64::from_ne_bytes);
+                }
+                _ => {}
+            }
+        }
+        Ok(route)
+    }
+}
+
+pub struct MulticastRouteGetRequest {
+    handle: Handle,
+    message: RouteMessage,
+}
+
+impl MulticastRouteGetRequest {
+    pub(crate) fn new(handle: Handle, ip_version: IpVersion) -> Self {
+        let mut message = RouteMessage::default();
+        message.header.address_family = match ip_version {
+            IpVersion::V4 => RTNL_FAMILY_IPMR,
+            IpVersion::V6 => RTNL_FAMILY_IP6MR,
+        };
+        MulticastRouteGetRequest { handle, message }
+    }
+
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+
+    pub fn execute(self) -> impl TryStream<Ok = MulticastRoute, Error = Error> {
+        let MulticastRouteGetRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(message));
+        req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+
+        match handle.request(req) {
+            Ok(response) => Either::Left(
+                response
+                    .map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewRoute)))
+                    .and_then(|msg| future::ready(MulticastRoute::try_from(msg))),
+            ),
+            Err(e) => Either::Right(future::err::<MulticastRoute, Error>(e).into_stream()),
+        }
+    }
+}
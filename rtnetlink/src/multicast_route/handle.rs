@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::Ipv4Addr;
+
+use crate::{
+    Handle,
+    IpVersion,
+    MulticastRouteAddRequest,
+    MulticastRouteDelRequest,
+    MulticastRouteGetRequest,
+};
+
+pub struct MulticastRouteHandle(Handle);
+
+impl MulticastRouteHandle {
+    pub fn new(handle: Handle) -> Self {
+        MulticastRouteHandle(handle)
+    }
+
+    /// Retrieve the multicast forwarding cache entries (equivalent to
+    /// `ip mroute show`)
+    pub fn get(&self, ip_version: IpVersion) -> MulticastRouteGetRequest {
+        MulticastRouteGetRequest::new(self.0.clone(), ip_version)
+    }
+
+    /// Add or update the IPv4 multicast forwarding cache entry for the given
+    /// source and group. Use `Ipv4Addr::UNSPECIFIED` as source for a
+    /// `(*, G)` entry. The kernel does not support adding IPv6 entries over
+    /// rtnetlink.
+    pub fn add(&self, source: Ipv4Addr, group: Ipv4Addr) -> MulticastRouteAddRequest {
+        MulticastRouteAddRequest::new(self.0.clone(), source, group)
+    }
+
+    /// Delete the IPv4 multicast forwarding cache entry for the given source
+    /// and group
+    pub fn del(&self, source: Ipv4Addr, group: Ipv4Addr) -> MulticastRouteDelRequest {
+        MulticastRouteDelRequest::new(self.0.clone(), source, group)
+    }
+}
@@ -21,7 +21,8 @@ impl NeighbourHandle {
         NeighbourAddRequest::new(self.0.clone(), index, destination)
     }
 
-    /// Add a new fdb entry (equivalent to `bridge fdb add`)
+    /// Add a new fdb entry (equivalent to `bridge fdb add`). See [`Handle::fdb`](crate::Handle::fdb)
+    /// for a higher level API.
     pub fn add_bridge(&self, index: u32, lla: &[u8]) -> NeighbourAddRequest {
         NeighbourAddRequest::new_bridge(self.0.clone(), index, lla)
     }
@@ -0,0 +1,84 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::netconf::Nla,
+    NetconfMessage,
+    NetlinkMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle, IpVersion};
+
+pub struct NetconfGetRequest {
+    handle: Handle,
+    message: NetconfMessage,
+    // If `dump` is `true`, the configuration of all the interfaces is
+    // fetched. Otherwise, only the one of the given interface is.
+    dump: bool,
+}
+
+impl NetconfGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        NetconfGetRequest {
+            handle,
+            message: NetconfMessage::default(),
+            dump: true,
+        }
+    }
+
+    /// Only fetch the configuration for the given IP version
+    pub fn set_family(mut self, ip_version: IpVersion) -> Self {
+        self.message.header.family = ip_version.family();
+        self
+    }
+
+    /// Only fetch the MPLS configuration
+    pub fn mpls(mut self) -> Self {
+        self.message.header.family = AF_MPLS as u8;
+        self
+    }
+
+    /// Fetch the configuration of a single interface (equivalent to `ip netconf show dev DEV`).
+    /// `NETCONFA_IFINDEX_ALL` and `NETCONFA_IFINDEX_DEFAULT` select the `all` and `default`
+    /// configurations. The kernel requires a family to be set for such a lookup.
+    pub fn match_index(mut self, index: i32) -> Self {
+        self.dump = false;
+        self.message.nlas.push(Nla::IfIndex(index));
+        self
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = NetconfMessage, Error = Error> {
+        let NetconfGetRequest {
+            mut handle,
+            message,
+            dump,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNetconf(message));
+        if dump {
+            req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+        } else {
+            req.header.flags = NLM_F_REQUEST;
+        }
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewNetconf))))
+            }
+            Err(e) => Either::Right(future::err::<NetconfMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NetconfMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT
+
+#[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+use crate::MonitorRequest;
+use crate::{Handle, NetconfGetRequest};
+
+pub struct NetconfHandle(Handle);
+
+impl NetconfHandle {
+    pub fn new(handle: Handle) -> Self {
+        NetconfHandle(handle)
+    }
+
+    /// Retrieve the per-interface configuration (equivalent to `ip netconf show`)
+    pub fn get(&self) -> NetconfGetRequest {
+        NetconfGetRequest::new(self.0.clone())
+    }
+
+    /// Listen to configuration changes (equivalent to `ip monitor netconf`)
+    #[cfg(any(feature = "tokio_socket", feature = "smol_socket"))]
+    pub fn monitor(&self) -> MonitorRequest {
+        MonitorRequest::new().netconf()
+    }
+}
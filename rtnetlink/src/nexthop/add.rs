@@ -0,0 +1,209 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::{
+        nexthop::{NexthopGroupEntry, Nla, ResilientGroup},
+        route::{MplsIpTunnel, MplsLabel, RouteEncap},
+    },
+    NetlinkMessage,
+    NexthopMessage,
+    RtnlMessage,
+};
+
+use crate::{try_nl, Error, Handle};
+
+/// A request to create a new nexthop object or group. This is equivalent to the
+/// `ip nexthop add` commands.
+pub struct NexthopAddRequest {
+    handle: Handle,
+    message: NexthopMessage,
+    // Member ids and weights, checked when the request is executed
+    group: Vec<(u32, u16)>,
+    resilient: Vec<ResilientGroup>,
+    replace: bool,
+}
+
+impl NexthopAddRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        let mut message = NexthopMessage::default();
+        message.header.protocol = RTPROT_STATIC;
+
+        NexthopAddRequest {
+            handle,
+            message,
+            group: vec![],
+            resilient: vec![],
+            replace: false,
+        }
+    }
+
+    /// Sets the nexthop id. If not set, the kernel allocates one.
+    pub fn id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::Id(id));
+        self
+    }
+
+    /// Sets the gateway address. This also sets the address family of the nexthop.
+    pub fn gateway(mut self, addr: IpAddr) -> Self {
+        let (family, octets) = match addr {
+            IpAddr::V4(addr) => (AF_INET, addr.octets().to_vec()),
+            IpAddr::V6(addr) => (AF_INET6, addr.octets().to_vec()),
+        };
+        self.message.header.family = family as u8;
+        self.message.nlas.push(Nla::Gateway(octets));
+        self
+    }
+
+    /// Make this an IPv6 nexthop. This is only needed for nexthops that have no gateway.
+    pub fn ipv6(mut self) -> Self {
+        self.message.header.family = AF_INET6 as u8;
+        self
+    }
+
+    /// Sets the output interface index.
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Pretend that the gateway is directly attached to the output interface, even if it
+    /// does not match any interface prefix (equivalent to `ip nexthop add ... onlink`).
+    pub fn onlink(mut self) -> Self {
+        self.message.header.flags |= RTNH_F_ONLINK as u32;
+        self
+    }
+
+    /// Sets the lightweight tunnel encapsulation of the packets sent through this nexthop.
+    pub fn encap(mut self, encap: RouteEncap) -> Self {
+        self.message.nlas.push(Nla::EncapType(encap.kind()));
+        self.message.nlas.push(Nla::Encap(encap));
+        self
+    }
+
+    /// Push the given MPLS labels on the packets sent through this nexthop (equivalent to
+    /// `ip nexthop add ... encap mpls LABEL/LABEL... [ttl TTL]`). The first label is the top
+    /// of the stack.
+    pub fn encap_mpls(self, labels: &[u32], ttl: Option<u8>) -> Self {
+        let mut tunnel = vec![MplsIpTunnel::Destination(MplsLabel::stack(labels))];
+        if let Some(ttl) = ttl {
+            tunnel.push(MplsIpTunnel::Ttl(ttl));
+        }
+        self.encap(RouteEncap::Mpls(tunnel))
+    }
+
+    /// Make this nexthop drop packets (equivalent to `ip nexthop add ... blackhole`).
+    ///
+    /// The kernel requires an address family for blackhole nexthops: IPv4 is used unless
+    /// [`NexthopAddRequest::ipv6`] is called.
+    pub fn blackhole(mut self) -> Self {
+        if self.message.header.family == AF_UNSPEC as u8 {
+            self.message.header.family = AF_INET as u8;
+        }
+        self.message.nlas.push(Nla::Blackhole);
+        self
+    }
+
+    /// Mark this nexthop or group as usable by FDB entries (equivalent to
+    /// `ip nexthop add ... fdb`).
+    pub fn fdb(mut self) -> Self {
+        self.message.nlas.push(Nla::Fdb);
+        self
+    }
+
+    /// Sets the nexthop protocol.
+    ///
+    /// Default is static protocol.
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.header.protocol = protocol;
+        self
+    }
+
+    /// Adds a member to the nexthop group, with the given weight (equivalent to
+    /// `ip nexthop add ... group ID,WEIGHT/...`). The weight must be between 1 and 256,
+    /// otherwise executing the request fails with [`Error::InvalidArgument`].
+    pub fn group_member(mut self, id: u32, weight: u16) -> Self {
+        self.group.push((id, weight));
+        self
+    }
+
+    /// Make the group resilient, with the given number of hash buckets (equivalent to
+    /// `ip nexthop add ... type resilient buckets BUCKETS`).
+    pub fn resilient(mut self, buckets: u16) -> Self {
+        self.resilient.push(ResilientGroup::Buckets(buckets));
+        self
+    }
+
+    /// Sets the time, in clock ticks, after which an idle bucket of a resilient group
+    /// may be migrated to another nexthop.
+    pub fn idle_timer(mut self, ticks: u32) -> Self {
+        self.resilient.push(ResilientGroup::IdleTimer(ticks));
+        self
+    }
+
+    /// Sets the time, in clock ticks, after which a resilient group that stayed
+    /// unbalanced is forcibly rebalanced.
+    pub fn unbalanced_timer(mut self, ticks: u32) -> Self {
+        self.resilient.push(ResilientGroup::UnbalancedTimer(ticks));
+        self
+    }
+
+    /// Replace the existing nexthop with the same id.
+    pub fn replace(self) -> Self {
+        Self {
+            replace: true,
+            ..self
+        }
+    }
+
+    /// Execute the request.
+    pub async fn execute(self) -> Result<(), Error> {
+        let NexthopAddRequest {
+            mut handle,
+            mut message,
+            group,
+            resilient,
+            replace,
+        } = self;
+
+        if !group.is_empty() {
+            let mut entries = Vec::with_capacity(group.len());
+            for (id, weight) in group {
+                // The kernel stores the weight minus one
+                let weight = match weight {
+                    1..=256 => (weight - 1) as u8,
+                    _ => {
+                        return Err(Error::InvalidArgument(format!(
+                            "invalid weight {} for nexthop {}, expected 1 to 256",
+                            weight, id
+                        )))
+                    }
+                };
+                entries.push(NexthopGroupEntry { id, weight });
+            }
+            message.nlas.push(Nla::Group(entries));
+        }
+        if !resilient.is_empty() {
+            message.nlas.push(Nla::GroupType(NEXTHOP_GRP_TYPE_RES));
+            message.nlas.push(Nla::ResilientGroup(resilient));
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::NewNexthop(message));
+        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut NexthopMessage {
+        &mut self.message
+    }
+}
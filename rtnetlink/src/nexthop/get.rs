@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::nexthop::Nla,
+    NetlinkMessage,
+    NexthopMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle, IpVersion};
+
+pub struct NexthopGetRequest {
+    handle: Handle,
+    message: NexthopMessage,
+    // If `dump` is `true`, all the nexthops matching the filters are
+    // fetched. Otherwise, only the nexthop with the given id is.
+    dump: bool,
+}
+
+impl NexthopGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        NexthopGetRequest {
+            handle,
+            message: NexthopMessage::default(),
+            dump: true,
+        }
+    }
+
+    /// Lookup a nexthop by id (equivalent to `ip nexthop get id ID`)
+    pub fn match_id(mut self, id: u32) -> Self {
+        self.dump = false;
+        self.message.nlas.push(Nla::Id(id));
+        self
+    }
+
+    /// Only list nexthops of the given IP version
+    pub fn set_family(mut self, ip_version: IpVersion) -> Self {
+        self.message.header.family = ip_version.family();
+        self
+    }
+
+    /// Only list nexthop groups (equivalent to `ip nexthop show groups`)
+    pub fn groups(mut self) -> Self {
+        self.message.nlas.push(Nla::Groups);
+        self
+    }
+
+    /// Only list nexthops used by FDB entries (equivalent to `ip nexthop show fdb`)
+    pub fn fdb(mut self) -> Self {
+        self.message.nlas.push(Nla::Fdb);
+        self
+    }
+
+    /// Only list nexthops going out through the given interface (equivalent to
+    /// `ip nexthop show dev DEV`)
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Only list nexthops using interfaces enslaved to the given master (equivalent to
+    /// `ip nexthop show master DEV`)
+    pub fn master(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Master(index));
+        self
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = NexthopMessage, Error = Error> {
+        let NexthopGetRequest {
+            mut handle,
+            message,
+            dump,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetNexthop(message));
+        if dump {
+            req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+        } else {
+            req.header.flags = NLM_F_REQUEST;
+        }
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewNexthop))))
+            }
+            Err(e) => Either::Right(future::err::<NexthopMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut NexthopMessage {
+        &mut self.message
+    }
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, NexthopAddRequest, NexthopDelRequest, NexthopGetRequest};
+
+pub struct NexthopHandle(Handle);
+
+impl NexthopHandle {
+    pub fn new(handle: Handle) -> Self {
+        NexthopHandle(handle)
+    }
+
+    /// Retrieve the list of nexthop objects (equivalent to `ip nexthop show`)
+    pub fn get(&self) -> NexthopGetRequest {
+        NexthopGetRequest::new(self.0.clone())
+    }
+
+    /// Add a nexthop object or group (equivalent to `ip nexthop add`)
+    pub fn add(&self) -> NexthopAddRequest {
+        NexthopAddRequest::new(self.0.clone())
+    }
+
+    /// Delete the nexthop object with the given id (equivalent to `ip nexthop del id ID`)
+    pub fn del(&self, id: u32) -> NexthopDelRequest {
+        NexthopDelRequest::new(self.0.clone(), id)
+    }
+}
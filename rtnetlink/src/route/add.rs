@@ -3,24 +3,43 @@
 use futures::stream::StreamExt;
 use std::{
     marker::PhantomData,
-    net::{Ipv4Addr, Ipv6Addr},
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
 };
 
 use netlink_packet_route::{
     constants::*,
-    nlas::route::Nla,
+    nlas::route::{
+        Ip6Tunnel,
+        IpTunnel,
+        Metrics,
+        MplsIpTunnel,
+        MplsLabel,
+        Nla,
+        RouteEncap,
+        RouteVia,
+        Seg6Local,
+        Seg6Tunnel,
+        SegmentRoutingHeader,
+        MPLS_LABEL_LEN,
+    },
     NetlinkMessage,
     RouteMessage,
     RtnlMessage,
 };
 
-use crate::{try_nl, Error, Handle};
+use crate::{try_nl, Error, Handle, MultipathNextHop};
 
 /// A request to create a new route. This is equivalent to the `ip route add` commands.
 pub struct RouteAddRequest<T = ()> {
     handle: Handle,
     message: RouteMessage,
-    replace: bool,
+    // NLM_F_EXCL, NLM_F_REPLACE, NLM_F_APPEND or none of them (prepend)
+    flags: u16,
+    // RTAX_* metrics to lock, validated when executing the request
+    locked: Vec<u16>,
+    // Weights of the multipath next-hops outside of 1..=256, reported when executing the
+    // request
+    invalid_weights: Vec<u16>,
     _phantom: PhantomData<T>,
 }
 
@@ -36,7 +55,9 @@ impl<T> RouteAddRequest<T> {
         RouteAddRequest {
             handle,
             message,
-            replace: false,
+            flags: NLM_F_EXCL,
+            locked: vec![],
+            invalid_weights: vec![],
             _phantom: Default::default(),
         }
     }
@@ -53,6 +74,13 @@ impl<T> RouteAddRequest<T> {
         self
     }
 
+    /// Sets the nexthop object (see [`Handle::nexthop`](crate::Handle::nexthop)) the
+    /// route resolves through, instead of a gateway and output interface.
+    pub fn nexthop_id(mut self, id: u32) -> Self {
+        self.message.nlas.push(Nla::NexthopId(id));
+        self
+    }
+
     /// Sets the route table.
     ///
     /// Default is main route table.
@@ -91,7 +119,9 @@ impl<T> RouteAddRequest<T> {
         RouteAddRequest {
             handle: self.handle,
             message: self.message,
-            replace: false,
+            flags: self.flags,
+            locked: self.locked,
+            invalid_weights: self.invalid_weights,
             _phantom: Default::default(),
         }
     }
@@ -102,30 +132,225 @@ impl<T> RouteAddRequest<T> {
         RouteAddRequest {
             handle: self.handle,
             message: self.message,
-            replace: false,
+            flags: self.flags,
+            locked: self.locked,
+            invalid_weights: self.invalid_weights,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// Build an MPLS route request
+    pub fn mpls(mut self) -> RouteAddRequest<MplsLabel> {
+        self.message.header.address_family = AF_MPLS as u8;
+        RouteAddRequest {
+            handle: self.handle,
+            message: self.message,
+            flags: self.flags,
+            locked: self.locked,
+            invalid_weights: self.invalid_weights,
             _phantom: Default::default(),
         }
     }
 
+    /// Sets the lightweight tunnel encapsulation of the route, for instance BPF programs or
+    /// IOAM6 tracing.
+    pub fn encap(mut self, encap: RouteEncap) -> Self {
+        self.message.nlas.push(Nla::EncapType(encap.kind()));
+        self.message.nlas.push(Nla::Encap(encap));
+        self
+    }
+
+    // Push the given labels on the packets routed by an IP route
+    fn push_mpls_encap(self, labels: &[u32], ttl: Option<u8>) -> Self {
+        let mut tunnel = vec![MplsIpTunnel::Destination(MplsLabel::stack(labels))];
+        if let Some(ttl) = ttl {
+            tunnel.push(MplsIpTunnel::Ttl(ttl));
+        }
+        self.encap(RouteEncap::Mpls(tunnel))
+    }
+
+    // Steer the packets routed by an IP route through the given SRv6 segments
+    fn push_seg6_encap(self, mode: u32, segments: &[Ipv6Addr]) -> Self {
+        let srh = if mode == SEG6_IPTUN_MODE_INLINE {
+            // the first slot of the segment list receives the original destination
+            let mut segments = segments.to_vec();
+            segments.push(Ipv6Addr::UNSPECIFIED);
+            SegmentRoutingHeader::new(&segments)
+        } else {
+            SegmentRoutingHeader::new(segments)
+        };
+        self.encap(RouteEncap::Seg6(vec![Seg6Tunnel::Srh(mode, srh)]))
+    }
+
+    // Attach tunnel metadata to the packets routed by an IP route
+    fn push_ip_tunnel_encap(self, id: u64, remote: IpAddr) -> Self {
+        let encap = match remote {
+            IpAddr::V4(addr) => RouteEncap::Ip(vec![IpTunnel::Id(id), IpTunnel::Destination(addr)]),
+            IpAddr::V6(addr) => {
+                RouteEncap::Ip6(vec![Ip6Tunnel::Id(id), Ip6Tunnel::Destination(addr)])
+            }
+        };
+        self.encap(encap)
+    }
+
     /// Replace existing matching route.
     pub fn replace(self) -> Self {
         Self {
-            replace: true,
+            flags: NLM_F_REPLACE,
+            ..self
+        }
+    }
+
+    /// Add the route after the existing routes to the same destination, like `ip route append`.
+    /// The next-hops of an IPv6 route are added to the existing route instead.
+    pub fn append(self) -> Self {
+        Self {
+            flags: NLM_F_APPEND,
             ..self
         }
     }
 
+    /// Add the route before the existing routes to the same destination, like
+    /// `ip route prepend`.
+    pub fn prepend(self) -> Self {
+        Self { flags: 0, ..self }
+    }
+
+    // Add a next-hop to the multipath route
+    fn push_multipath_nexthop(mut self, nexthop: MultipathNextHop) -> Self {
+        let nexthop = match nexthop.into_nexthop() {
+            Ok(nexthop) => nexthop,
+            Err(weight) => {
+                self.invalid_weights.push(weight);
+                return self;
+            }
+        };
+        let mut nexthops = self.message.multipath().unwrap_or_default();
+        nexthops.push(nexthop);
+        self.message
+            .nlas
+            .retain(|nla| !matches!(nla, Nla::MultiPath(_)));
+        self.message.nlas.push(Nla::multipath(nexthops));
+        self
+    }
+
+    // Add a metric to the route, replacing a previous value of the same metric
+    fn push_metric(mut self, metric: Metrics) -> Self {
+        match self.message.nlas.iter_mut().find_map(|nla| match nla {
+            Nla::Metrics(metrics) => Some(metrics),
+            _ => None,
+        }) {
+            Some(metrics) => {
+                metrics.retain(|m| std::mem::discriminant(m) != std::mem::discriminant(&metric));
+                metrics.push(metric);
+            }
+            None => self.message.nlas.push(Nla::Metrics(vec![metric])),
+        }
+        self
+    }
+
+    /// Sets the path MTU.
+    pub fn mtu(self, mtu: u32) -> Self {
+        self.push_metric(Metrics::Mtu(mtu))
+    }
+
+    /// Sets the TCP maximum segment size advertised to the destination.
+    pub fn advmss(self, advmss: u32) -> Self {
+        self.push_metric(Metrics::Advmss(advmss))
+    }
+
+    /// Sets the maximal TCP window advertised by the destination, in bytes.
+    pub fn window(self, window: u32) -> Self {
+        self.push_metric(Metrics::Window(window))
+    }
+
+    /// Sets the initial TCP round trip time estimate, in milliseconds.
+    pub fn rtt(self, rtt: u32) -> Self {
+        self.push_metric(Metrics::Rtt(rtt.saturating_mul(8)))
+    }
+
+    /// Sets the initial TCP round trip time variance estimate, in milliseconds.
+    pub fn rttvar(self, rttvar: u32) -> Self {
+        self.push_metric(Metrics::RttVar(rttvar.saturating_mul(4)))
+    }
+
+    /// Sets the initial TCP congestion window, in packets.
+    pub fn initcwnd(self, initcwnd: u32) -> Self {
+        self.push_metric(Metrics::InitCwnd(initcwnd))
+    }
+
+    /// Sets the initial TCP receive window advertised to the destination, in packets.
+    pub fn initrwnd(self, initrwnd: u32) -> Self {
+        self.push_metric(Metrics::InitRwnd(initrwnd))
+    }
+
+    /// Sets the hop limit (TTL) of the packets sent through the route.
+    pub fn hoplimit(self, hoplimit: u32) -> Self {
+        self.push_metric(Metrics::Hoplimit(hoplimit))
+    }
+
+    /// Enable or disable TCP quick acknowledgments.
+    pub fn quickack(self, enable: bool) -> Self {
+        self.push_metric(Metrics::QuickAck(enable as u32))
+    }
+
+    /// Sets the `RTAX_FEATURE_*` flags of the route. Only `RTAX_FEATURE_ECN` can be set.
+    pub fn features(self, features: u32) -> Self {
+        self.push_metric(Metrics::Features(features))
+    }
+
+    /// Sets the TCP congestion control algorithm, for instance `"cubic"`.
+    pub fn congestion_control(self, name: &str) -> Self {
+        self.push_metric(Metrics::CcAlgo(name.to_string()))
+    }
+
+    /// Lock the given `RTAX_*` metric, so that TCP and path MTU discovery don't update it.
+    /// The metric must be between `RTAX_LOCK` and `RTAX_MAX`, otherwise executing the
+    /// request fails with [`Error::InvalidArgument`].
+    pub fn lock(mut self, metric: u16) -> Self {
+        self.locked.push(metric);
+        self
+    }
+
     /// Execute the request.
-    pub async fn execute(self) -> Result<(), Error> {
+    pub async fn execute(mut self) -> Result<(), Error> {
+        if let Some(weight) = self.invalid_weights.first() {
+            return Err(Error::InvalidArgument(format!(
+                "invalid weight {} for next-hop, expected 1 to 256",
+                weight
+            )));
+        }
+        if !self.locked.is_empty() {
+            let mut lock = self
+                .message
+                .metrics()
+                .and_then(|metrics| {
+                    metrics.iter().find_map(|m| match m {
+                        Metrics::Lock(lock) => Some(*lock),
+                        _ => None,
+                    })
+                })
+                .unwrap_or(0);
+            for metric in std::mem::take(&mut self.locked) {
+                if !(RTAX_LOCK..=RTAX_MAX).contains(&metric) {
+                    return Err(Error::InvalidArgument(format!(
+                        "invalid metric {} to lock, expected {} to {}",
+                        metric, RTAX_LOCK, RTAX_MAX
+                    )));
+                }
+                lock |= 1 << metric;
+            }
+            self = self.push_metric(Metrics::Lock(lock));
+        }
+
         let RouteAddRequest {
             mut handle,
             message,
-            replace,
+            flags,
             ..
         } = self;
         let mut req = NetlinkMessage::from(RtnlMessage::NewRoute(message));
-        let replace = if replace { NLM_F_REPLACE } else { NLM_F_EXCL };
-        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | replace | NLM_F_CREATE;
+        req.header.flags = NLM_F_REQUEST | NLM_F_ACK | flags | NLM_F_CREATE;
 
         let mut response = handle.request(req)?;
         while let Some(message) = response.next().await {
@@ -170,6 +395,30 @@ impl RouteAddRequest<Ipv4Addr> {
         self.message.nlas.push(Nla::Gateway(gtw));
         self
     }
+
+    /// Add a next-hop to the route, making it a multipath (ECMP) route. Can be called several
+    /// times, once per next-hop.
+    pub fn multipath_nexthop(self, nexthop: MultipathNextHop) -> Self {
+        self.push_multipath_nexthop(nexthop)
+    }
+
+    /// Push the given MPLS labels, outermost first, on the packets matching the route. The TTL of
+    /// the labels is copied from the IP packets, unless `ttl` is given.
+    pub fn mpls_encap(self, labels: &[u32], ttl: Option<u8>) -> Self {
+        self.push_mpls_encap(labels, ttl)
+    }
+
+    /// Steer the packets matching the route through the given SRv6 segments, first segment
+    /// first. `mode` is one of the `SEG6_IPTUN_MODE_*` constants.
+    pub fn seg6_encap(self, mode: u32, segments: &[Ipv6Addr]) -> Self {
+        self.push_seg6_encap(mode, segments)
+    }
+
+    /// Attach tunnel metadata with the given key and remote endpoint to the packets matching the
+    /// route, for a tunnel device in external mode.
+    pub fn ip_tunnel_encap(self, id: u64, remote: IpAddr) -> Self {
+        self.push_ip_tunnel_encap(id, remote)
+    }
 }
 
 impl RouteAddRequest<Ipv6Addr> {
@@ -202,4 +451,67 @@ impl RouteAddRequest<Ipv6Addr> {
         self.message.nlas.push(Nla::Gateway(gtw));
         self
     }
+
+    /// Add a next-hop to the route, making it a multipath (ECMP) route. Can be called several
+    /// times, once per next-hop.
+    pub fn multipath_nexthop(self, nexthop: MultipathNextHop) -> Self {
+        self.push_multipath_nexthop(nexthop)
+    }
+
+    /// Push the given MPLS labels, outermost first, on the packets matching the route. The TTL of
+    /// the labels is copied from the IP packets, unless `ttl` is given.
+    pub fn mpls_encap(self, labels: &[u32], ttl: Option<u8>) -> Self {
+        self.push_mpls_encap(labels, ttl)
+    }
+
+    /// Steer the packets matching the route through the given SRv6 segments, first segment
+    /// first. `mode` is one of the `SEG6_IPTUN_MODE_*` constants.
+    pub fn seg6_encap(self, mode: u32, segments: &[Ipv6Addr]) -> Self {
+        self.push_seg6_encap(mode, segments)
+    }
+
+    /// Attach tunnel metadata with the given key and remote endpoint to the packets matching the
+    /// route, for a tunnel device in external mode.
+    pub fn ip_tunnel_encap(self, id: u64, remote: IpAddr) -> Self {
+        self.push_ip_tunnel_encap(id, remote)
+    }
+
+    /// Apply the given SRv6 behavior to the packets whose destination is the route prefix.
+    /// `action` is one of the `SEG6_LOCAL_ACTION_*` constants, `params` the attributes it needs
+    /// (table, nexthop...).
+    pub fn seg6_local(self, action: u32, params: Vec<Seg6Local>) -> Self {
+        let mut nlas = vec![Seg6Local::Action(action)];
+        nlas.extend(params);
+        self.encap(RouteEncap::Seg6Local(nlas))
+    }
+}
+
+impl RouteAddRequest<MplsLabel> {
+    /// Sets the incoming label the route applies to.
+    pub fn label(mut self, label: u32) -> Self {
+        self.message.header.destination_prefix_length = 20;
+        let mut dst = vec![0; MPLS_LABEL_LEN];
+        MplsLabel::emit_stack(&MplsLabel::stack(&[label]), &mut dst);
+        self.message.nlas.push(Nla::Destination(dst));
+        self
+    }
+
+    /// Swap the incoming label with the given labels, outermost first. The incoming label is
+    /// popped if this is not set.
+    pub fn new_destination(mut self, labels: &[u32]) -> Self {
+        self.message
+            .nlas
+            .push(Nla::NewDestination(MplsLabel::stack(labels)));
+        self
+    }
+
+    /// Sets the address of the nexthop.
+    pub fn via(mut self, addr: IpAddr) -> Self {
+        let via = match addr {
+            IpAddr::V4(addr) => RouteVia::Inet(addr),
+            IpAddr::V6(addr) => RouteVia::Inet6(addr),
+        };
+        self.message.nlas.push(Nla::Via(via));
+        self
+    }
 }
@@ -0,0 +1,130 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+use std::net::IpAddr;
+
+use netlink_packet_route::{
+    constants::*,
+    nlas::route::Nla,
+    NetlinkMessage,
+    RouteFlags,
+    RouteMessage,
+    RtnlMessage,
+};
+
+use crate::{try_rtnl, Error, Handle};
+
+/// A request to look up the route the kernel selects for a packet. This is equivalent to the
+/// `ip route get` command.
+pub struct RouteLookupRequest {
+    handle: Handle,
+    message: RouteMessage,
+}
+
+fn address_nla(addr: IpAddr) -> (u8, u8, Vec<u8>) {
+    match addr {
+        IpAddr::V4(addr) => (AF_INET as u8, 32, addr.octets().to_vec()),
+        IpAddr::V6(addr) => (AF_INET6 as u8, 128, addr.octets().to_vec()),
+    }
+}
+
+impl RouteLookupRequest {
+    pub(crate) fn new(handle: Handle, destination: IpAddr) -> Self {
+        let mut message = RouteMessage::default();
+        let (family, prefix_length, octets) = address_nla(destination);
+        message.header.address_family = family;
+        message.header.destination_prefix_length = prefix_length;
+        // Report the table of the selected route
+        message.header.flags = RouteFlags::RTM_F_LOOKUP_TABLE;
+        message.nlas.push(Nla::Destination(octets));
+
+        RouteLookupRequest { handle, message }
+    }
+
+    /// Sets the source address of the packet.
+    pub fn source(mut self, addr: IpAddr) -> Self {
+        let (_, prefix_length, octets) = address_nla(addr);
+        self.message.header.source_prefix_length = prefix_length;
+        self.message.nlas.push(Nla::Source(octets));
+        self
+    }
+
+    /// Look up the route of a packet received on the given interface, instead of a locally
+    /// generated one.
+    pub fn input_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Iif(index));
+        self
+    }
+
+    /// Force the output interface of the packet.
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.message.nlas.push(Nla::Oif(index));
+        self
+    }
+
+    /// Sets the firewall mark of the packet.
+    pub fn mark(mut self, mark: u32) -> Self {
+        self.message.nlas.push(Nla::Mark(mark));
+        self
+    }
+
+    /// Sets the user id of the socket sending the packet.
+    pub fn uid(mut self, uid: u32) -> Self {
+        self.message.nlas.push(Nla::Uid(uid));
+        self
+    }
+
+    /// Sets the type of service of the packet.
+    pub fn tos(mut self, tos: u8) -> Self {
+        self.message.header.tos = tos;
+        self
+    }
+
+    /// Sets the layer 4 protocol of the packet (`IPPROTO_*`).
+    pub fn ip_protocol(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::IpProtocol(protocol));
+        self
+    }
+
+    /// Sets the layer 4 source port of the packet.
+    pub fn source_port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::SourcePort(port));
+        self
+    }
+
+    /// Sets the layer 4 destination port of the packet.
+    pub fn destination_port(mut self, port: u16) -> Self {
+        self.message.nlas.push(Nla::DestinationPort(port));
+        self
+    }
+
+    /// Return the matching route as it is in the routing table, instead of the route resolved
+    /// for the packet.
+    pub fn fib_match(mut self) -> Self {
+        self.message.header.flags |= RouteFlags::RTM_F_FIB_MATCH;
+        self
+    }
+
+    /// Return a mutable reference to the request message.
+    pub fn message_mut(&mut self) -> &mut RouteMessage {
+        &mut self.message
+    }
+
+    /// Execute the request. The returned route gives the resolved gateway, output interface,
+    /// preferred source, table and cache information of the lookup.
+    pub async fn execute(self) -> Result<RouteMessage, Error> {
+        let RouteLookupRequest {
+            mut handle,
+            message,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetRoute(message));
+        req.header.flags = NLM_F_REQUEST;
+
+        let mut response = handle.request(req)?;
+        match response.next().await {
+            Some(message) => Ok(try_rtnl!(message, RtnlMessage::NewRoute)),
+            None => Err(Error::RequestFailed),
+        }
+    }
+}
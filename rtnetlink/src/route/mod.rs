@@ -11,3 +11,9 @@ pub use self::del::*;
 
 mod get;
 pub use self::get::*;
+
+mod lookup;
+pub use self::lookup::*;
+
+mod multipath;
+pub use self::multipath::*;
@@ -0,0 +1,102 @@
+// SPDX-License-Identifier: MIT
+
+use std::net::IpAddr;
+
+use netlink_packet_route::nlas::route::{NextHop, NextHopFlags, Nla, RouteEncap, RouteVia};
+
+/// A next-hop of a multipath route, see
+/// [`RouteAddRequest::multipath_nexthop`](crate::RouteAddRequest::multipath_nexthop).
+#[derive(Debug, Clone)]
+pub struct MultipathNextHop {
+    nexthop: NextHop,
+    weight: u16,
+}
+
+impl Default for MultipathNextHop {
+    fn default() -> Self {
+        MultipathNextHop {
+            nexthop: NextHop {
+                flags: NextHopFlags::empty(),
+                hops: 0,
+                interface_id: 0,
+                nlas: vec![],
+            },
+            weight: 1,
+        }
+    }
+}
+
+impl MultipathNextHop {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the output interface index.
+    pub fn output_interface(mut self, index: u32) -> Self {
+        self.nexthop.interface_id = index;
+        self
+    }
+
+    /// Sets the gateway address, in the address family of the route.
+    pub fn gateway(mut self, addr: IpAddr) -> Self {
+        let gateway = match addr {
+            IpAddr::V4(addr) => addr.octets().to_vec(),
+            IpAddr::V6(addr) => addr.octets().to_vec(),
+        };
+        self.nexthop.nlas.push(Nla::Gateway(gateway));
+        self
+    }
+
+    /// Sets a gateway in another address family than the route, for instance an IPv6
+    /// gateway for an IPv4 route.
+    pub fn via(mut self, addr: IpAddr) -> Self {
+        let via = match addr {
+            IpAddr::V4(addr) => RouteVia::Inet(addr),
+            IpAddr::V6(addr) => RouteVia::Inet6(addr),
+        };
+        self.nexthop.nlas.push(Nla::Via(via));
+        self
+    }
+
+    /// Sets the weight of the next-hop, from 1 (the default) to 256. Adding a next-hop with
+    /// another weight to a route makes executing the request fail with
+    /// [`Error::InvalidArgument`](crate::Error::InvalidArgument).
+    pub fn weight(mut self, weight: u16) -> Self {
+        self.weight = weight;
+        self
+    }
+
+    /// Pretend the gateway is directly attached to the output interface.
+    pub fn onlink(mut self) -> Self {
+        self.nexthop.flags |= NextHopFlags::RTNH_F_ONLINK;
+        self
+    }
+
+    pub fn pervasive(mut self) -> Self {
+        self.nexthop.flags |= NextHopFlags::RTNH_F_PERVASIVE;
+        self
+    }
+
+    /// Sets the lightweight tunnel encapsulation of the packets sent through this next-hop.
+    pub fn encap(mut self, encap: RouteEncap) -> Self {
+        self.nexthop.nlas.push(Nla::EncapType(encap.kind()));
+        self.nexthop.nlas.push(Nla::Encap(encap));
+        self
+    }
+}
+
+impl MultipathNextHop {
+    // The kernel stores the weight minus one in `rtnh_hops`. An invalid weight is
+    // returned as the error.
+    pub(crate) fn into_nexthop(self) -> Result<NextHop, u16> {
+        let MultipathNextHop {
+            mut nexthop,
+            weight,
+        } = self;
+        if !(1..=256).contains(&weight) {
+            return Err(weight);
+        }
+        nexthop.hops = (weight - 1) as u8;
+        Ok(nexthop)
+    }
+}
@@ -8,7 +8,7 @@ use std::{
 
 use netlink_packet_route::{
     constants::*,
-    nlas::rule::Nla,
+    nlas::rule::{Nla, PortRange, UidRange},
     NetlinkMessage,
     RtnlMessage,
     RuleMessage,
@@ -59,6 +59,111 @@ impl<T> RuleAddRequest<T> {
         self
     }
 
+    /// Sets the rule table, including the tables above 255 that don't fit in the rule
+    /// header.
+    pub fn table_id(mut self, table: u32) -> Self {
+        if table < 256 {
+            self.message.header.table = table as u8;
+        } else {
+            self.message.header.table = RT_TABLE_UNSPEC;
+            self.message.nlas.push(Nla::Table(table));
+        }
+        self
+    }
+
+    /// Sets the priority of the rule. Rules are evaluated by increasing priority.
+    pub fn priority(mut self, priority: u32) -> Self {
+        self.message.nlas.push(Nla::Priority(priority));
+        self
+    }
+
+    /// Match the packets with the given firewall mark.
+    pub fn fw_mark(mut self, mark: u32) -> Self {
+        self.message.nlas.push(Nla::FwMark(mark));
+        self
+    }
+
+    /// Sets the mask applied to the firewall mark of the packets before matching it.
+    pub fn fw_mask(mut self, mask: u32) -> Self {
+        self.message.nlas.push(Nla::FwMask(mask));
+        self
+    }
+
+    /// Jump to the rule with the given priority.
+    pub fn goto(mut self, priority: u32) -> Self {
+        self.message.header.action = FR_ACT_GOTO;
+        self.message.nlas.push(Nla::Goto(priority));
+        self
+    }
+
+    /// Ignore the routing decision if the prefix length of the selected route is lower or
+    /// equal to the given length.
+    pub fn suppress_prefixlen(mut self, prefix_length: u32) -> Self {
+        self.message
+            .nlas
+            .push(Nla::SuppressPrefixLen(prefix_length));
+        self
+    }
+
+    /// Ignore the routing decision if the output interface of the selected route belongs to
+    /// the given interface group.
+    pub fn suppress_ifgroup(mut self, group: u32) -> Self {
+        self.message.nlas.push(Nla::SuppressIfGroup(group));
+        self
+    }
+
+    /// Look up the table of the L3 master device (VRF) of the input or output interface.
+    /// This can't be combined with a table.
+    pub fn l3mdev(mut self) -> Self {
+        self.message.header.table = RT_TABLE_UNSPEC;
+        self.message.header.action = FR_ACT_TO_TBL;
+        self.message.nlas.push(Nla::L3MDev(1));
+        self
+    }
+
+    /// Match the packets with the given layer 4 protocol (`IPPROTO_*`).
+    pub fn ip_proto(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::IpProto(protocol));
+        self
+    }
+
+    /// Match the packets with a source port in the given range, bounds included.
+    pub fn source_port_range(mut self, start: u16, end: u16) -> Self {
+        self.message
+            .nlas
+            .push(Nla::SourcePortRange(PortRange { start, end }));
+        self
+    }
+
+    /// Match the packets with a destination port in the given range, bounds included.
+    pub fn destination_port_range(mut self, start: u16, end: u16) -> Self {
+        self.message
+            .nlas
+            .push(Nla::DestinationPortRange(PortRange { start, end }));
+        self
+    }
+
+    /// Match the packets sent by sockets of users in the given range of uids, bounds
+    /// included.
+    pub fn uid_range(mut self, start: u32, end: u32) -> Self {
+        self.message
+            .nlas
+            .push(Nla::UidRange(UidRange { start, end }));
+        self
+    }
+
+    /// Match the packets received with the given tunnel id on a collect-metadata tunnel.
+    pub fn tun_id(mut self, id: u64) -> Self {
+        self.message.nlas.push(Nla::TunId(id));
+        self
+    }
+
+    /// Sets the protocol which installed the rule (`RTPROT_*`).
+    pub fn protocol(mut self, protocol: u8) -> Self {
+        self.message.nlas.push(Nla::Protocol(protocol));
+        self
+    }
+
     /// Set the tos.
     pub fn tos(mut self, tos: u8) -> Self {
         self.message.header.tos = tos;
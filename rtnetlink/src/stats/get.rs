@@ -0,0 +1,105 @@
+// SPDX-License-Identifier: MIT
+
+use futures::{
+    future::{self, Either},
+    stream::{StreamExt, TryStream},
+    FutureExt,
+};
+
+use netlink_packet_route::{constants::*, NetlinkMessage, RtnlMessage, StatsMessage};
+
+use crate::{try_rtnl, Error, Handle};
+
+pub struct StatsGetRequest {
+    handle: Handle,
+    message: StatsMessage,
+}
+
+impl StatsGetRequest {
+    pub(crate) fn new(handle: Handle) -> Self {
+        StatsGetRequest {
+            handle,
+            message: StatsMessage::default(),
+        }
+    }
+
+    /// Only fetch the statistics of the link with the given index. By default, the statistics of
+    /// all the links are dumped.
+    pub fn match_index(mut self, index: u32) -> Self {
+        self.message.header.ifindex = index;
+        self
+    }
+
+    /// Set the raw filter mask, built with the `IFLA_STATS_FILTER_BIT_*` constants. This
+    /// overrides the groups previously selected with the other methods.
+    pub fn filter_mask(mut self, mask: u32) -> Self {
+        self.message.header.filter_mask = mask;
+        self
+    }
+
+    /// Fetch the link counters (`IFLA_STATS_LINK_64`). This is the default if no other group is
+    /// selected.
+    pub fn link_64(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_BIT_LINK_64;
+        self
+    }
+
+    /// Fetch the link type specific counters, e.g. bridge and bond counters
+    /// (`IFLA_STATS_LINK_XSTATS`)
+    pub fn link_xstats(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_BIT_LINK_XSTATS;
+        self
+    }
+
+    /// Fetch the counters of the links as ports of their master, e.g. bridge port and bond slave
+    /// counters (`IFLA_STATS_LINK_XSTATS_SLAVE`)
+    pub fn link_xstats_slave(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_BIT_LINK_XSTATS_SLAVE;
+        self
+    }
+
+    /// Fetch the offloaded traffic counters (`IFLA_STATS_LINK_OFFLOAD_XSTATS`)
+    pub fn offload_xstats(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_BIT_LINK_OFFLOAD_XSTATS;
+        self
+    }
+
+    /// Fetch the protocol specific counters, e.g. MPLS counters (`IFLA_STATS_AF_SPEC`)
+    pub fn af_spec(mut self) -> Self {
+        self.message.header.filter_mask |= IFLA_STATS_FILTER_BIT_AF_SPEC;
+        self
+    }
+
+    /// Execute the request
+    pub fn execute(self) -> impl TryStream<Ok = StatsMessage, Error = Error> {
+        let StatsGetRequest {
+            mut handle,
+            mut message,
+        } = self;
+
+        // The kernel rejects requests with an empty filter mask
+        if message.header.filter_mask == 0 {
+            message.header.filter_mask = IFLA_STATS_FILTER_BIT_LINK_64;
+        }
+        let dump = message.header.ifindex == 0;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetStats(message));
+        if dump {
+            req.header.flags = NLM_F_REQUEST | NLM_F_DUMP;
+        } else {
+            req.header.flags = NLM_F_REQUEST;
+        }
+
+        match handle.request(req) {
+            Ok(response) => {
+                Either::Left(response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::NewStats))))
+            }
+            Err(e) => Either::Right(future::err::<StatsMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut StatsMessage {
+        &mut self.message
+    }
+}
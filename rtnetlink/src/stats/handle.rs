@@ -0,0 +1,16 @@
+// SPDX-License-Identifier: MIT
+
+use crate::{Handle, StatsGetRequest};
+
+pub struct StatsHandle(Handle);
+
+impl StatsHandle {
+    pub fn new(handle: Handle) -> Self {
+        StatsHandle(handle)
+    }
+
+    /// Retrieve link statistics (equivalent to `ip stats show`)
+    pub fn get(&self) -> StatsGetRequest {
+        StatsGetRequest::new(self.0.clone())
+    }
+}
@@ -0,0 +1,96 @@
+// SPDX-License-Identifier: MIT
+
+use futures::stream::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::tc_action::Nla,
+        tc::{self, constants::*},
+        NetlinkMessage,
+        RtnlMessage,
+        TcActionMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficActionNewRequest {
+    handle: Handle,
+    message: TcActionMessage,
+    actions: Vec<tc::Action>,
+    flags: u16,
+}
+
+impl TrafficActionNewRequest {
+    pub(crate) fn new(handle: Handle, flags: u16) -> Self {
+        Self {
+            handle,
+            message: TcActionMessage::default(),
+            actions: vec![],
+            flags: NLM_F_REQUEST | flags,
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let Self {
+            mut handle,
+            mut message,
+            actions,
+            flags,
+        } = self;
+
+        message.nlas.push(Nla::Actions(actions));
+        let mut req = NetlinkMessage::from(RtnlMessage::NewTrafficAction(message));
+        req.header.flags = NLM_F_ACK | flags;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message);
+        }
+        Ok(())
+    }
+
+    /// Add an action. It must have a `Kind` and `Options`, the index of the
+    /// action being given by the parameters in its options. Several actions
+    /// can be created by the same request.
+    pub fn action(mut self, mut action: tc::Action) -> Self {
+        action.tab = self.actions.len() as u16 + 1;
+        self.actions.push(action);
+        self
+    }
+
+    /// Add a mirred action with the given index, redirecting or mirroring
+    /// the packets to the interface `ifindex`.
+    /// Equivalent to `tc actions add action mirred egress (redirect | mirror) dev STRING index INDEX`
+    pub fn mirred(self, index: u32, ifindex: u32, redirect: bool) -> Self {
+        let (eaction, action) = if redirect {
+            (TCA_EGRESS_REDIR, TC_ACT_STOLEN)
+        } else {
+            (TCA_EGRESS_MIRROR, TC_ACT_PIPE)
+        };
+        self.action(tc::Action {
+            nlas: vec![
+                tc::ActNla::Kind(tc::mirred::KIND.to_string()),
+                tc::ActNla::Options(vec![tc::ActOpt::Mirred(tc::mirred::Nla::Parms(
+                    tc::mirred::TcMirred {
+                        index,
+                        action,
+                        eaction,
+                        ifindex,
+                        ..tc::mirred::TcMirred::default()
+                    },
+                ))]),
+            ],
+            ..Default::default()
+        })
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut TcActionMessage {
+        &mut self.message
+    }
+}
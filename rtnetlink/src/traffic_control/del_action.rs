@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT
+
+use futures::StreamExt;
+
+use crate::{
+    packet::{
+        nlas::tc_action::Nla,
+        tc,
+        NetlinkMessage,
+        RtnlMessage,
+        TcActionMessage,
+        NLM_F_ACK,
+        NLM_F_REQUEST,
+        NLM_F_ROOT,
+    },
+    try_nl,
+    Error,
+    Handle,
+};
+
+pub struct TrafficActionDelRequest {
+    handle: Handle,
+    message: TcActionMessage,
+    flags: u16,
+}
+
+impl TrafficActionDelRequest {
+    /// Delete the action of the given kind and index, or all the actions of
+    /// that kind when `index` is `None`.
+    pub(crate) fn new(handle: Handle, kind: &str, index: Option<u32>) -> Self {
+        let mut action = tc::Action::default();
+        action.nlas.push(tc::ActNla::Kind(kind.to_string()));
+        let flags = match index {
+            Some(index) => {
+                action.nlas.push(tc::ActNla::Index(index));
+                NLM_F_REQUEST | NLM_F_ACK
+            }
+            // The kernel flushes the actions of a kind when NLM_F_ROOT is set
+            None => NLM_F_REQUEST | NLM_F_ACK | NLM_F_ROOT,
+        };
+        let mut message = TcActionMessage::default();
+        message.nlas.push(Nla::Actions(vec![action]));
+        TrafficActionDelRequest {
+            handle,
+            message,
+            flags,
+        }
+    }
+
+    /// Execute the request
+    pub async fn execute(self) -> Result<(), Error> {
+        let TrafficActionDelRequest {
+            mut handle,
+            message,
+            flags,
+        } = self;
+
+        let mut req = NetlinkMessage::from(RtnlMessage::DelTrafficAction(message));
+        req.header.flags = flags;
+
+        let mut response = handle.request(req)?;
+        while let Some(message) = response.next().await {
+            try_nl!(message)
+        }
+        Ok(())
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut TcActionMessage {
+        &mut self.message
+    }
+}
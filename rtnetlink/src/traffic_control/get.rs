@@ -7,7 +7,16 @@ use futures::{
 };
 
 use crate::{
-    packet::{tc::constants::*, NetlinkMessage, RtnlMessage, TcMessage, NLM_F_DUMP, NLM_F_REQUEST},
+    packet::{
+        nlas::tc_action::{Nla, RootFlags},
+        tc::{self, constants::*},
+        NetlinkMessage,
+        RtnlMessage,
+        TcActionMessage,
+        TcMessage,
+        NLM_F_DUMP,
+        NLM_F_REQUEST,
+    },
     try_rtnl,
     Error,
     Handle,
@@ -156,3 +165,83 @@ impl TrafficChainGetRequest {
         }
     }
 }
+
+pub struct TrafficActionGetRequest {
+    handle: Handle,
+    message: TcActionMessage,
+    action: tc::Action,
+    flags: u32,
+}
+
+impl TrafficActionGetRequest {
+    pub(crate) fn new(handle: Handle, kind: &str) -> Self {
+        let mut action = tc::Action::default();
+        action.nlas.push(tc::ActNla::Kind(kind.to_string()));
+        TrafficActionGetRequest {
+            handle,
+            message: TcActionMessage::default(),
+            action,
+            flags: TCA_ACT_FLAG_LARGE_DUMP_ON,
+        }
+    }
+
+    /// Execute the request. A dump returns the actions in the `Actions`
+    /// attribute of one or more messages, along with their `Count`.
+    pub fn execute(self) -> impl TryStream<Ok = TcActionMessage, Error = Error> {
+        let TrafficActionGetRequest {
+            mut handle,
+            mut message,
+            action,
+            flags,
+        } = self;
+
+        let dump = !action
+            .nlas
+            .iter()
+            .any(|nla| matches!(nla, tc::ActNla::Index(_)));
+        message.nlas.push(Nla::Actions(vec![action]));
+        let mut req_flags = NLM_F_REQUEST;
+        if dump {
+            message.nlas.push(Nla::Flags(RootFlags::new(flags)));
+            req_flags |= NLM_F_DUMP;
+        }
+
+        let mut req = NetlinkMessage::from(RtnlMessage::GetTrafficAction(message));
+        req.header.flags = req_flags;
+
+        match handle.request(req) {
+            // The kernel answers with RTM_GETACTION messages, and only uses
+            // RTM_NEWACTION for notifications.
+            Ok(response) => Either::Left(
+                response.map(move |msg| Ok(try_rtnl!(msg, RtnlMessage::GetTrafficAction))),
+            ),
+            Err(e) => Either::Right(future::err::<TcActionMessage, Error>(e).into_stream()),
+        }
+    }
+
+    /// Only retrieve the action with the given index
+    /// Equivalent to `tc actions get action KIND index INDEX`
+    pub fn index(mut self, index: u32) -> Self {
+        self.action.nlas.push(tc::ActNla::Index(index));
+        self
+    }
+
+    /// Only dump the kind, index and statistics of the actions.
+    /// Equivalent to `tc -brief actions ls action KIND`
+    pub fn terse(mut self) -> Self {
+        self.flags |= TCA_ACT_FLAG_TERSE_DUMP;
+        self
+    }
+
+    /// Only dump the actions used in the last `msecs` milliseconds.
+    /// Equivalent to `tc actions ls action KIND since MSECS`
+    pub fn since(mut self, msecs: u32) -> Self {
+        self.message.nlas.push(Nla::TimeDelta(msecs));
+        self
+    }
+
+    /// Return a mutable reference to the request
+    pub fn message_mut(&mut self) -> &mut TcActionMessage {
+        &mut self.message
+    }
+}
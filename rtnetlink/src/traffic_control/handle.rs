@@ -4,6 +4,9 @@ use super::{
     QDiscDelRequest,
     QDiscGetRequest,
     QDiscNewRequest,
+    TrafficActionDelRequest,
+    TrafficActionGetRequest,
+    TrafficActionNewRequest,
     TrafficChainGetRequest,
     TrafficClassGetRequest,
     TrafficFilterGetRequest,
@@ -132,3 +135,41 @@ impl TrafficChainHandle {
         TrafficChainGetRequest::new(self.handle.clone(), self.ifindex)
     }
 }
+
+pub struct TrafficActionHandle(Handle);
+
+impl TrafficActionHandle {
+    pub fn new(handle: Handle) -> Self {
+        TrafficActionHandle(handle)
+    }
+
+    /// Retrieve the list of actions of the given kind (equivalent to
+    /// `tc actions list action KIND`)
+    pub fn get(&mut self, kind: &str) -> TrafficActionGetRequest {
+        TrafficActionGetRequest::new(self.0.clone(), kind)
+    }
+
+    /// Create new actions, don't replace if the objects already exist.
+    /// ( equivalent to `tc actions add`)
+    pub fn add(&mut self) -> TrafficActionNewRequest {
+        TrafficActionNewRequest::new(self.0.clone(), NLM_F_EXCL | NLM_F_CREATE)
+    }
+
+    /// Replace existing actions, create them if they don't already exist.
+    /// ( equivalent to `tc actions replace`)
+    pub fn replace(&mut self) -> TrafficActionNewRequest {
+        TrafficActionNewRequest::new(self.0.clone(), NLM_F_CREATE | NLM_F_REPLACE)
+    }
+
+    /// Delete the action of the given kind and index
+    /// ( equivalent to `tc actions delete action KIND index INDEX`)
+    pub fn del(&mut self, kind: &str, index: u32) -> TrafficActionDelRequest {
+        TrafficActionDelRequest::new(self.0.clone(), kind, Some(index))
+    }
+
+    /// Delete all the actions of the given kind that are not bound to a filter
+    /// ( equivalent to `tc actions flush action KIND`)
+    pub fn flush(&mut self, kind: &str) -> TrafficActionDelRequest {
+        TrafficActionDelRequest::new(self.0.clone(), kind, None)
+    }
+}